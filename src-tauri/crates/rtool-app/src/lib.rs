@@ -8,14 +8,15 @@ pub use rtool_kernel::i18n;
 pub use rtool_kernel::i18n_catalog;
 pub use rtool_kernel::{AppLocalePreference, AppLocaleState, LocaleStateDto, ResolvedAppLocale};
 pub use rtool_logging::{
-    LoggingEventSink, LoggingGuard, RecordLogInput, export_log_entries, get_log_config,
-    init_log_center, init_logging, query_log_entries, record_log_event,
+    LoggingEventSink, LoggingGuard, RecordLogInput, build_error_cause_summary, export_log_entries,
+    get_log_config, init_log_center, init_logging, query_log_entries, record_log_event,
     record_log_event_best_effort, resolve_log_level, sanitize_for_log, sanitize_json_value,
     sanitize_path, update_log_config,
 };
 pub use rtool_settings::{load_or_init_settings, update_locale_preference, update_settings};
 pub use services::{
     AppManagerApplicationService, ApplicationServices, BootstrapApplicationService,
-    ClipboardApplicationService, LauncherApplicationService, LocaleApplicationService,
-    LoggingApplicationService, ScreenshotApplicationService, SettingsApplicationService,
+    ClipboardApplicationService, ClipboardImagePreview, LauncherApplicationService,
+    LocaleApplicationService, LoggingApplicationService, MaintenanceApplicationService,
+    ScreenshotApplicationService, SettingsApplicationService,
 };
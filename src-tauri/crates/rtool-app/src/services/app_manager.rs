@@ -1,18 +1,26 @@
 use rtool_contracts::AppResult;
 use rtool_contracts::models::{
-    AppManagerActionResultDto, AppManagerCleanupInputDto, AppManagerCleanupResultDto,
-    AppManagerDetailQueryDto, AppManagerExportScanInputDto, AppManagerExportScanResultDto,
-    AppManagerIndexUpdatedPayloadDto, AppManagerPageDto, AppManagerQueryDto,
-    AppManagerResidueScanInputDto, AppManagerResidueScanResultDto, AppManagerResolveSizesInputDto,
-    AppManagerResolveSizesResultDto, AppManagerSnapshotMetaDto, AppManagerStartupUpdateInputDto,
-    AppManagerUninstallInputDto, ManagedAppDetailDto,
+    AppManagerActionResultDto, AppManagerAnnotateResidueInputDto, AppManagerBackupMetaDto,
+    AppManagerBulkStartupResultDto, AppManagerCleanupInputDto, AppManagerCleanupResultDto,
+    AppManagerClearExportsInputDto, AppManagerClearExportsResultDto, AppManagerDetailQueryDto,
+    AppManagerExportListDto, AppManagerExportScanInputDto, AppManagerExportScanResultDto,
+    AppManagerGhostStartupItemDto, AppManagerIndexUpdatedPayloadDto, AppManagerPageDto,
+    AppManagerQueryDto, AppManagerResidueScanInputDto, AppManagerResidueScanResultDto,
+    AppManagerResolveSizesInputDto, AppManagerResolveSizesResultDto, AppManagerScanPathInputDto,
+    AppManagerSnapshotMetaDto, AppManagerStartupEntryDto, AppManagerStartupUpdateInputDto,
+    AppManagerUninstallInputDto, AppRelatedRootDto, ManagedAppDetailDto,
 };
 use rtool_discovery::app_manager::{
-    cleanup_managed_app_residue, export_managed_app_scan_result, get_managed_app_detail_core,
-    get_managed_app_detail_heavy, list_managed_apps, list_managed_apps_snapshot_meta,
-    open_permission_help, open_uninstall_help, poll_managed_apps_auto_refresh,
-    refresh_managed_apps_index, resolve_managed_app_sizes, set_managed_app_startup,
-    uninstall_managed_app,
+    annotate_managed_app_residue_item, cancel_managed_app_size_resolution,
+    cleanup_managed_app_residue, clear_managed_app_exports, export_managed_app_scan_result,
+    get_managed_app_detail_core, get_managed_app_detail_heavy, get_managed_apps_snapshot_meta,
+    list_managed_app_exports, list_managed_app_related_roots,
+    list_managed_app_startup_auto_backups, list_managed_apps, list_managed_apps_snapshot_meta,
+    locate_managed_app_startup_entry, open_permission_help, open_uninstall_help,
+    pending_managed_apps_index_refresh, poll_managed_apps_auto_refresh, rebuild_managed_apps_index,
+    refresh_managed_apps_index, remove_ghost_startup_item, resolve_managed_app_sizes,
+    restore_managed_app_startup_auto_backup, scan_ghost_startup_items, scan_managed_path_residue,
+    set_managed_app_startup, uninstall_managed_app,
 };
 use rtool_platform::launcher::LauncherHost;
 
@@ -42,47 +50,111 @@ impl AppManagerApplicationService {
         AppManagerSnapshotMetaDto,
         list_managed_apps_snapshot_meta
     );
+    pub fn get_snapshot_meta(self) -> AppResult<AppManagerSnapshotMetaDto> {
+        get_managed_apps_snapshot_meta()
+    }
     forward_with_arg!(
         resolve_sizes,
         input: AppManagerResolveSizesInputDto,
         AppManagerResolveSizesResultDto,
         resolve_managed_app_sizes
     );
+    pub fn cancel_resolve_sizes(self, cancel_token: String) -> AppResult<bool> {
+        cancel_managed_app_size_resolution(cancel_token)
+    }
     forward_with_arg!(
         get_detail_core,
         query: AppManagerDetailQueryDto,
         ManagedAppDetailDto,
         get_managed_app_detail_core
     );
+    forward_with_arg!(
+        list_related_roots,
+        app_id: String,
+        Vec<AppRelatedRootDto>,
+        list_managed_app_related_roots
+    );
     forward_with_arg!(
         get_detail_heavy,
         input: AppManagerResidueScanInputDto,
         AppManagerResidueScanResultDto,
         get_managed_app_detail_heavy
     );
+    pub fn scan_path(
+        self,
+        input: AppManagerScanPathInputDto,
+    ) -> AppResult<AppManagerResidueScanResultDto> {
+        scan_managed_path_residue(input)
+    }
     forward_with_arg!(
         cleanup,
         input: AppManagerCleanupInputDto,
         AppManagerCleanupResultDto,
         cleanup_managed_app_residue
     );
+    forward_with_arg!(
+        annotate_residue_item,
+        input: AppManagerAnnotateResidueInputDto,
+        (),
+        annotate_managed_app_residue_item
+    );
     forward_with_arg!(
         export_scan_result,
         input: AppManagerExportScanInputDto,
         AppManagerExportScanResultDto,
         export_managed_app_scan_result
     );
+    pub fn list_exports(self) -> AppResult<AppManagerExportListDto> {
+        list_managed_app_exports()
+    }
+    pub fn clear_exports(
+        self,
+        input: AppManagerClearExportsInputDto,
+    ) -> AppResult<AppManagerClearExportsResultDto> {
+        clear_managed_app_exports(input)
+    }
     forward_no_arg!(
         refresh_index,
         AppManagerActionResultDto,
         refresh_managed_apps_index
     );
+    pub fn pending_index_refresh(self) -> AppManagerSnapshotMetaDto {
+        pending_managed_apps_index_refresh()
+    }
+    forward_no_arg!(
+        rebuild_index,
+        AppManagerIndexUpdatedPayloadDto,
+        rebuild_managed_apps_index
+    );
     forward_with_arg!(
         set_startup,
         input: AppManagerStartupUpdateInputDto,
         AppManagerActionResultDto,
         set_managed_app_startup
     );
+    forward_with_arg!(
+        locate_startup_entry,
+        app_id: String,
+        AppManagerStartupEntryDto,
+        locate_managed_app_startup_entry
+    );
+    forward_no_arg!(
+        list_startup_auto_backups,
+        Vec<AppManagerBackupMetaDto>,
+        list_managed_app_startup_auto_backups
+    );
+    forward_with_arg!(
+        restore_startup_auto_backup,
+        backup_id: String,
+        AppManagerBulkStartupResultDto,
+        restore_managed_app_startup_auto_backup
+    );
+    pub fn scan_ghost_startup_items(self) -> AppResult<Vec<AppManagerGhostStartupItemDto>> {
+        scan_ghost_startup_items()
+    }
+    pub fn remove_ghost_startup_item(self, label: String) -> AppResult<()> {
+        remove_ghost_startup_item(label)
+    }
     forward_with_arg!(
         uninstall,
         input: AppManagerUninstallInputDto,
@@ -1,20 +1,37 @@
-use rtool_capture::helpers::{build_image_clipboard_item, parse_file_paths_from_text};
+use rtool_capture::helpers::{
+    build_image_clipboard_item, compute_image_dhash, parse_file_paths_from_text,
+};
 use rtool_capture::service::{
-    ClipboardSaveResult, ClipboardService, ClipboardSettingsUpdateResult,
+    ClipboardPinResult, ClipboardSaveResult, ClipboardService, ClipboardSettingsUpdateResult,
+    transform_clipboard_text,
+};
+use rtool_contracts::models::{
+    ClipboardFilterDto, ClipboardItemDetailDto, ClipboardItemDto, ClipboardMigrateResultDto,
+    ClipboardPreviewRepairResultDto, ClipboardSearchResultDto, ClipboardSettingsDto,
+    ClipboardSizeSummaryDto, ClipboardStorageStatsDto, ClipboardTextTransformDto,
+    SettingsClipboardDto,
 };
-use rtool_contracts::models::{ClipboardFilterDto, ClipboardItemDto, SettingsClipboardDto};
 use rtool_contracts::{AppError, AppResult};
-use rtool_data::db::{self, DbConn};
+use std::path::{Path, PathBuf};
+
+/// What the watcher did with the preview file of a captured image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClipboardImagePreview {
+    Saved(String),
+    /// Not written because the image exceeded `max_preview_pixels`.
+    Skipped,
+    /// Writing failed; the item is listed with `preview_missing`.
+    Unavailable,
+}
 
 #[derive(Clone)]
 pub struct ClipboardApplicationService {
-    db_conn: DbConn,
     service: ClipboardService,
 }
 
 impl ClipboardApplicationService {
-    pub fn new(db_conn: DbConn, service: ClipboardService) -> Self {
-        Self { db_conn, service }
+    pub fn new(service: ClipboardService) -> Self {
+        Self { service }
     }
 
     pub fn ensure_disk_space_for_new_item(&self) -> AppResult<()> {
@@ -30,14 +47,30 @@ impl ClipboardApplicationService {
         })
     }
 
+    pub fn compute_image_dhash(png_bytes: &[u8]) -> Option<String> {
+        compute_image_dhash(png_bytes)
+    }
+
     pub async fn list(&self, filter: ClipboardFilterDto) -> AppResult<Vec<ClipboardItemDto>> {
         self.service.list(filter).await
     }
 
-    pub async fn pin(&self, id: String, pinned: bool) -> AppResult<ClipboardItemDto> {
+    pub async fn search(
+        &self,
+        query: &str,
+        limit: Option<u32>,
+    ) -> AppResult<ClipboardSearchResultDto> {
+        self.service.search(query, limit).await
+    }
+
+    pub async fn pin(&self, id: String, pinned: bool) -> AppResult<ClipboardPinResult> {
         self.service.pin(id, pinned).await
     }
 
+    pub async fn reorder_pinned(&self, item_ids: Vec<String>) -> AppResult<Vec<ClipboardItemDto>> {
+        self.service.reorder_pinned(&item_ids).await
+    }
+
     pub async fn delete(&self, id: String) -> AppResult<()> {
         self.service.delete(id).await
     }
@@ -59,12 +92,34 @@ impl ClipboardApplicationService {
         width: usize,
         height: usize,
         signature: &str,
-        preview_path: Option<String>,
+        preview: ClipboardImagePreview,
         source_app: Option<String>,
+        perceptual_hash: Option<&str>,
     ) -> AppResult<ClipboardSaveResult> {
-        let item =
+        let preview_skipped = preview == ClipboardImagePreview::Skipped;
+        let preview_path = match preview {
+            ClipboardImagePreview::Saved(path) => Some(path),
+            ClipboardImagePreview::Skipped | ClipboardImagePreview::Unavailable => None,
+        };
+        let mut item =
             build_image_clipboard_item(width, height, signature, preview_path, None, source_app);
-        self.service.save_item(item).await
+        item.preview_skipped = preview_skipped;
+        let result = self.service.save_item(item).await?;
+        if let Some(perceptual_hash) = perceptual_hash {
+            self.service
+                .set_perceptual_hash(result.item.id.as_str(), perceptual_hash)
+                .await?;
+        }
+        Ok(result)
+    }
+
+    pub async fn find_perceptual_duplicate(
+        &self,
+        perceptual_hash: &str,
+    ) -> AppResult<Option<String>> {
+        self.service
+            .find_perceptual_duplicate(perceptual_hash)
+            .await
     }
 
     pub async fn touch_item(&self, id: String) -> AppResult<ClipboardItemDto> {
@@ -72,10 +127,63 @@ impl ClipboardApplicationService {
     }
 
     pub async fn get_item_or_not_found(&self, query_id: String) -> AppResult<ClipboardItemDto> {
-        let item = db::get_clipboard_item(&self.db_conn, query_id.as_str()).await?;
+        let item = self.service.get_item(query_id.as_str()).await?;
         item.ok_or_else(|| AppError::new("clipboard_not_found", "未找到对应剪贴板记录"))
     }
 
+    pub async fn transform_item_text(
+        &self,
+        id: String,
+        transform: ClipboardTextTransformDto,
+    ) -> AppResult<String> {
+        let item = self.get_item_or_not_found(id).await?;
+        transform_clipboard_text(&item, transform)
+    }
+
+    pub async fn get_item_detail(&self, id: String) -> AppResult<ClipboardItemDetailDto> {
+        self.service.get_item_detail(id.as_str()).await
+    }
+
+    pub async fn repair_previews(&self) -> AppResult<ClipboardPreviewRepairResultDto> {
+        self.service.repair_previews().await
+    }
+
+    pub async fn item_count(&self) -> AppResult<u64> {
+        self.service.item_count().await
+    }
+
+    pub async fn storage_stats(
+        &self,
+        preview_dir: Option<&Path>,
+    ) -> AppResult<ClipboardStorageStatsDto> {
+        self.service.storage_stats(preview_dir).await
+    }
+
+    pub async fn size_summary(
+        &self,
+        preview_dir: Option<&Path>,
+    ) -> AppResult<ClipboardSizeSummaryDto> {
+        self.service.size_summary(preview_dir).await
+    }
+
+    pub fn preview_dir(&self) -> Option<PathBuf> {
+        self.service.preview_dir()
+    }
+
+    pub async fn migrate_storage_dir(
+        &self,
+        new_dir: &Path,
+        persist_storage_dir: impl AsyncFnOnce(String) -> AppResult<()>,
+    ) -> AppResult<ClipboardMigrateResultDto> {
+        self.service
+            .migrate_storage_dir(new_dir, persist_storage_dir)
+            .await
+    }
+
+    pub fn get_settings(&self) -> ClipboardSettingsDto {
+        self.service.get_settings()
+    }
+
     pub async fn apply_settings(
         &self,
         settings: &SettingsClipboardDto,
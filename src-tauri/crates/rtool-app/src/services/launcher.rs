@@ -1,6 +1,8 @@
+use super::ClipboardApplicationService;
 use rtool_contracts::AppResult;
 use rtool_contracts::models::{
-    LauncherActionDto, LauncherIndexStatusDto, LauncherRebuildResultDto, LauncherRuntimeStatusDto,
+    ClipboardFilterDto, ClipboardItemDto, LauncherActionDto, LauncherIndexStatusDto,
+    LauncherItemDto, LauncherRebuildResultDto, LauncherRuntimeStatusDto,
     LauncherSearchDiagnosticsDto, LauncherSearchIndexStateDto, LauncherSearchResponseDto,
     LauncherSearchSettingsDto, LauncherStatusDto, LauncherUpdateSearchSettingsInputDto,
 };
@@ -11,10 +13,17 @@ use rtool_discovery::launcher::index::{
     stop_background_indexer, update_search_settings_async,
 };
 use rtool_discovery::launcher::service::{
-    LauncherSearchDiagnostics, LauncherSearchResult, execute_launcher_action, search_launcher_async,
+    LauncherSearchDiagnostics, LauncherSearchResult, execute_launcher_action, hide_launcher_window,
+    is_launcher_window_visible, launcher_title_match_ranges, record_launcher_launch_async,
+    search_launcher_async,
 };
 use rtool_platform::launcher::LauncherHost;
 
+const CLIPBOARD_QUERY_PREFIX: &str = "clip";
+const CLIPBOARD_RESULT_LIMIT_DEFAULT: u16 = 20;
+const CLIPBOARD_RESULT_LIMIT_MAX: u16 = 50;
+const CLIPBOARD_TITLE_MAX_CHARS: usize = 80;
+
 #[derive(Clone)]
 pub struct LauncherApplicationService {
     db_conn: DbConn,
@@ -38,6 +47,59 @@ impl LauncherApplicationService {
         build_search_response(query, result, &runtime, index_status.as_ref())
     }
 
+    pub fn clipboard_query(query: &str) -> Option<&str> {
+        let trimmed = query.trim_start();
+        let prefix = trimmed.get(..CLIPBOARD_QUERY_PREFIX.len())?;
+        if !prefix.eq_ignore_ascii_case(CLIPBOARD_QUERY_PREFIX) {
+            return None;
+        }
+        let rest = &trimmed[CLIPBOARD_QUERY_PREFIX.len()..];
+        if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+            return None;
+        }
+        Some(rest.trim())
+    }
+
+    pub async fn search_clipboard(
+        &self,
+        clipboard: &ClipboardApplicationService,
+        query: &str,
+        keyword: &str,
+        limit: Option<u16>,
+    ) -> AppResult<LauncherSearchResponseDto> {
+        let limit = limit
+            .unwrap_or(CLIPBOARD_RESULT_LIMIT_DEFAULT)
+            .clamp(1, CLIPBOARD_RESULT_LIMIT_MAX);
+        let clipboard_items = clipboard
+            .list(ClipboardFilterDto {
+                query: (!keyword.is_empty()).then(|| keyword.to_string()),
+                item_type: None,
+                only_pinned: Some(false),
+                limit: Some(u32::from(limit)),
+                content_filter: None,
+            })
+            .await?;
+        let items = clipboard_items
+            .into_iter()
+            .enumerate()
+            .map(|(rank, item)| build_clipboard_launcher_item(item, rank, keyword))
+            .collect();
+
+        let runtime = get_indexer_runtime_status();
+        let index_status = get_index_status_async(&self.db_conn).await.ok();
+        Ok(LauncherSearchResponseDto {
+            query: query.to_string(),
+            limit,
+            items,
+            index: build_search_index_state(&runtime, index_status.as_ref()),
+            diagnostics: LauncherSearchDiagnosticsDto {
+                index_used: false,
+                fallback_to_like: true,
+                query_duration_ms: None,
+            },
+        })
+    }
+
     pub fn execute(
         &self,
         host: &dyn LauncherHost,
@@ -46,6 +108,19 @@ impl LauncherApplicationService {
         execute_launcher_action(host, action)
     }
 
+    pub fn hide_window(&self, host: &dyn LauncherHost, window_label: &str) -> AppResult<String> {
+        hide_launcher_window(host, window_label)
+    }
+
+    pub fn is_window_visible(&self, host: &dyn LauncherHost, window_label: &str) -> bool {
+        is_launcher_window_visible(host, window_label)
+    }
+
+    pub async fn record_launch(&self, item_id: &str) -> AppResult<()> {
+        record_launcher_launch_async(&self.db_conn, item_id).await?;
+        Ok(())
+    }
+
     pub async fn get_search_settings(&self) -> AppResult<LauncherSearchSettingsDto> {
         get_search_settings_async(&self.db_conn).await
     }
@@ -103,6 +178,43 @@ fn build_search_response(
     }
 }
 
+fn build_clipboard_launcher_item(
+    item: ClipboardItemDto,
+    rank: usize,
+    keyword: &str,
+) -> LauncherItemDto {
+    let first_line = item.plain_text.lines().next().unwrap_or_default().trim();
+    let mut title = first_line
+        .chars()
+        .take(CLIPBOARD_TITLE_MAX_CHARS)
+        .collect::<String>();
+    if first_line.chars().count() > CLIPBOARD_TITLE_MAX_CHARS {
+        title.push('…');
+    }
+    let (icon_kind, icon_value) = match item.preview_data_url.as_deref() {
+        Some(data_url) if item.item_type == "image" => ("raster", data_url.to_string()),
+        _ => ("iconify", "i-noto:clipboard".to_string()),
+    };
+    let title_match_ranges = launcher_title_match_ranges(&title, keyword);
+
+    LauncherItemDto {
+        id: format!("clipboard:{}", item.id),
+        title,
+        subtitle: item.source_app.unwrap_or_else(|| item.item_type.clone()),
+        category: "clipboard".to_string(),
+        group: String::new(),
+        source: Some("clipboard".to_string()),
+        shortcut: None,
+        score: i32::try_from(rank).map_or(0, |value| 1_000 - value.min(1_000)),
+        icon_kind: icon_kind.to_string(),
+        icon_value,
+        action: LauncherActionDto::CopyClipboardItem { item_id: item.id },
+        undoable: false,
+        title_match_ranges,
+        matched_via_alias: false,
+    }
+}
+
 fn build_search_index_state(
     runtime: &LauncherRuntimeStatusDto,
     index_status: Option<&LauncherIndexStatusDto>,
@@ -138,3 +250,93 @@ fn build_status(
         settings,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clipboard_item(id: &str, item_type: &str, plain_text: &str) -> ClipboardItemDto {
+        ClipboardItemDto {
+            id: id.to_string(),
+            content_key: format!("key-{id}"),
+            item_type: item_type.to_string(),
+            plain_text: plain_text.to_string(),
+            source_app: None,
+            preview_path: None,
+            preview_data_url: None,
+            created_at: 0,
+            pinned: false,
+            pin_order: 0,
+            preview_missing: false,
+            preview_skipped: false,
+            content_size_bytes: None,
+            width: None,
+            height: None,
+            original_width: None,
+            original_height: None,
+        }
+    }
+
+    #[test]
+    fn clipboard_query_requires_the_standalone_prefix() {
+        let parse = LauncherApplicationService::clipboard_query;
+        assert_eq!(parse("clip"), Some(""));
+        assert_eq!(parse("CLIP foo"), Some("foo"));
+        assert_eq!(parse("   Clip  foo bar  "), Some("foo bar"));
+        assert_eq!(parse("clipboard"), None);
+        assert_eq!(parse("cli"), None);
+        assert_eq!(parse("code clip"), None);
+    }
+
+    #[test]
+    fn clipboard_item_title_uses_the_first_line_and_truncates() {
+        let short = build_clipboard_launcher_item(
+            clipboard_item("a", "text", "  hello world  \nsecond line"),
+            0,
+            "",
+        );
+        assert_eq!(short.title, "hello world");
+        assert_eq!(short.id, "clipboard:a");
+        assert_eq!(short.subtitle, "text");
+
+        let long_text = "字".repeat(CLIPBOARD_TITLE_MAX_CHARS + 5);
+        let long = build_clipboard_launcher_item(clipboard_item("b", "text", &long_text), 0, "");
+        assert_eq!(long.title.chars().count(), CLIPBOARD_TITLE_MAX_CHARS + 1);
+        assert!(long.title.ends_with('…'));
+
+        let exact_text = "x".repeat(CLIPBOARD_TITLE_MAX_CHARS);
+        let exact = build_clipboard_launcher_item(clipboard_item("c", "text", &exact_text), 0, "");
+        assert_eq!(exact.title, exact_text);
+    }
+
+    #[test]
+    fn clipboard_item_icon_prefers_the_image_preview() {
+        let mut image = clipboard_item("img", "image", "");
+        image.preview_data_url = Some("data:image/png;base64,AAAA".to_string());
+        let image = build_clipboard_launcher_item(image, 0, "");
+        assert_eq!(image.icon_kind, "raster");
+        assert_eq!(image.icon_value, "data:image/png;base64,AAAA");
+
+        let missing_preview =
+            build_clipboard_launcher_item(clipboard_item("m", "image", ""), 0, "");
+        assert_eq!(missing_preview.icon_kind, "iconify");
+        assert_eq!(missing_preview.icon_value, "i-noto:clipboard");
+
+        let mut text = clipboard_item("t", "text", "hello");
+        text.preview_data_url = Some("data:image/png;base64,AAAA".to_string());
+        let text = build_clipboard_launcher_item(text, 0, "");
+        assert_eq!(text.icon_kind, "iconify");
+    }
+
+    #[test]
+    fn clipboard_item_score_follows_rank() {
+        let scores = (0..3)
+            .map(|rank| {
+                build_clipboard_launcher_item(clipboard_item("s", "text", "hello"), rank, "").score
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(scores, vec![1_000, 999, 998]);
+        let last = build_clipboard_launcher_item(clipboard_item("z", "text", "z"), 5_000, "");
+        assert_eq!(last.score, 0);
+    }
+}
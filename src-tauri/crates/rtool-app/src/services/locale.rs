@@ -1,8 +1,18 @@
-use rtool_contracts::models::SettingsDto;
+use anyhow::Context;
+use rtool_contracts::models::{
+    AppExportLocaleResultDto, AppImportLocaleResultDto, I18nLocaleStatsDto, SettingsDto,
+};
+use rtool_contracts::{AppError, AppResult, ResultExt};
 use rtool_kernel::i18n::{
     AppLocalePreference, AppLocaleState, ResolvedAppLocale, SYSTEM_LOCALE_PREFERENCE,
     init_i18n_catalog, normalize_locale_preference, resolve_locale, t,
 };
+use rtool_kernel::i18n_catalog::{
+    LocaleImportParseError, LocaleStats, import_overlay_namespace, list_locale_stats,
+    locale_entries, locale_stats, parse_locale_import,
+};
+use rtool_kernel::i18n_format::{ByteUnitSystem, format_bytes, format_number};
+use std::fs;
 use std::path::Path;
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -36,4 +46,124 @@ impl LocaleApplicationService {
     pub fn translate(self, locale: &str, key: &str) -> String {
         t(locale, key)
     }
+
+    pub fn format_bytes(self, locale: &str, bytes: u64, byte_unit: &str) -> String {
+        let unit = ByteUnitSystem::parse(byte_unit).unwrap_or_default();
+        format_bytes(locale, bytes, unit)
+    }
+
+    pub fn format_number(self, locale: &str, value: i64) -> String {
+        format_number(locale, value)
+    }
+
+    pub fn export_locale(
+        self,
+        locale: &str,
+        output_path: &Path,
+    ) -> AppResult<AppExportLocaleResultDto> {
+        let entries = locale_entries(locale).ok_or_else(|| {
+            AppError::new("i18n_locale_not_found", "未找到对应语言包")
+                .with_context("locale", locale.to_string())
+        })?;
+        let content = serde_json::to_string_pretty(&entries)
+            .with_context(|| format!("序列化语言包失败: locale={locale}"))
+            .with_code("i18n_locale_export_failed", "导出语言包失败")?;
+        if let Some(parent) = output_path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("创建导出目录失败: {}", parent.display()))
+                .with_code("i18n_locale_export_failed", "导出语言包失败")?;
+        }
+        fs::write(output_path, format!("{content}\n"))
+            .with_context(|| format!("写入语言包失败: {}", output_path.display()))
+            .with_code("i18n_locale_export_failed", "导出语言包失败")
+            .with_ctx("path", output_path.to_string_lossy().to_string())?;
+
+        Ok(AppExportLocaleResultDto {
+            file_path: output_path.to_string_lossy().to_string(),
+            key_count: entries.len() as u32,
+            locale: locale.to_string(),
+        })
+    }
+
+    pub fn locale_stats(self, locale: &str) -> AppResult<I18nLocaleStatsDto> {
+        locale_stats(locale.trim()).map(stats_dto).ok_or_else(|| {
+            AppError::new("i18n_locale_not_found", "未找到对应语言包")
+                .with_context("locale", locale.to_string())
+        })
+    }
+
+    pub fn list_locale_stats(self) -> Vec<I18nLocaleStatsDto> {
+        list_locale_stats().into_iter().map(stats_dto).collect()
+    }
+
+    pub fn import_locale_file(
+        self,
+        app_data_dir: &Path,
+        locale: &str,
+        namespace: Option<&str>,
+        file_path: &Path,
+    ) -> AppResult<AppImportLocaleResultDto> {
+        let namespace = namespace
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(ToString::to_string)
+            .or_else(|| {
+                file_path
+                    .file_stem()
+                    .map(|value| value.to_string_lossy().trim().to_string())
+            })
+            .unwrap_or_default();
+        let content = fs::read_to_string(file_path)
+            .with_context(|| format!("读取语言包失败: {}", file_path.display()))
+            .with_code("i18n_locale_import_read_failed", "读取语言包文件失败")
+            .with_ctx("path", file_path.to_string_lossy().to_string())?;
+        let report = parse_locale_import(content.as_str()).map_err(|error| match error {
+            LocaleImportParseError::Syntax {
+                line,
+                column,
+                message,
+            } => AppError::new("i18n_locale_import_parse_failed", "语言包 JSON 解析失败")
+                .with_context("line", line.to_string())
+                .with_context("column", column.to_string())
+                .with_context("detail", message),
+            LocaleImportParseError::NotObject => {
+                AppError::new("i18n_locale_import_not_object", "语言包必须为 JSON 对象")
+            }
+        })?;
+        if !report.skipped_keys.is_empty() {
+            tracing::warn!(
+                event = "i18n_locale_import_unknown_keys",
+                locale = locale,
+                namespace = namespace.as_str(),
+                skipped_count = report.skipped_keys.len()
+            );
+        }
+
+        let overlay_path =
+            import_overlay_namespace(app_data_dir, locale, namespace.as_str(), &report.entries)
+                .with_code("i18n_locale_import_write_failed", "写入语言包失败")
+                .with_ctx("locale", locale.to_string())
+                .with_ctx("namespace", namespace.clone())?;
+
+        Ok(AppImportLocaleResultDto {
+            locale: locale.to_string(),
+            namespace,
+            overlay_path: overlay_path.to_string_lossy().to_string(),
+            imported_count: report.entries.len() as u32,
+            skipped_count: report.skipped_keys.len() as u32,
+            invalid_count: report.invalid_keys.len() as u32,
+            skipped_keys: report.skipped_keys,
+        })
+    }
+}
+
+fn stats_dto(stats: LocaleStats) -> I18nLocaleStatsDto {
+    I18nLocaleStatsDto {
+        locale: stats.locale,
+        total_keys: stats.total_keys,
+        translated_keys: stats.translated_keys,
+        missing_keys: stats.missing_keys,
+        empty_value_keys: stats.empty_value_keys,
+        completeness_percent: stats.completeness_percent,
+    }
 }
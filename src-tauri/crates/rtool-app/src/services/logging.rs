@@ -1,10 +1,11 @@
-use rtool_contracts::models::{LogConfigDto, LogPageDto, LogQueryDto};
+use rtool_contracts::models::{LogConfigDto, LogExportResultDto, LogPageDto, LogQueryDto};
 use rtool_contracts::{AppError, AppResult};
 use rtool_logging::{
-    RecordLogInput, export_log_entries, get_log_config, record_log_event, sanitize_for_log,
-    sanitize_json_value, update_log_config,
+    RecordLogInput, convert_cbor_log_export_to_jsonl, export_log_entries, export_log_entries_cbor,
+    get_log_config, record_log_event, sanitize_for_log, sanitize_json_value, update_log_config,
 };
 use serde_json::Value;
+use std::path::Path;
 
 const MAX_MESSAGE_LEN: usize = 2048;
 
@@ -67,6 +68,7 @@ impl LoggingApplicationService {
             message: message.clone(),
             metadata: metadata.clone(),
             raw_ref: None,
+            error_context: None,
         };
         if let Err(error) = record_log_event(record).await {
             tracing::warn!(
@@ -135,7 +137,35 @@ impl LoggingApplicationService {
         self,
         query: LogQueryDto,
         output_path: Option<String>,
-    ) -> AppResult<String> {
+    ) -> AppResult<LogExportResultDto> {
         export_log_entries(query, output_path).await
     }
+
+    pub async fn export_cbor(
+        self,
+        query: LogQueryDto,
+        output_path: Option<String>,
+    ) -> AppResult<LogExportResultDto> {
+        export_log_entries_cbor(query, output_path).await
+    }
+
+    pub fn convert_cbor_to_jsonl(
+        self,
+        cbor_path: String,
+        output_path: String,
+    ) -> AppResult<String> {
+        let cbor_path = cbor_path.trim();
+        let output_path = output_path.trim();
+        if cbor_path.is_empty() || output_path.is_empty() {
+            return Err(AppError::new(
+                "log_convert_path_invalid",
+                "转换失败：文件路径不能为空",
+            ));
+        }
+
+        let converted =
+            convert_cbor_log_export_to_jsonl(Path::new(cbor_path), Path::new(output_path))?;
+        tracing::info!(event = "log_cbor_converted", converted);
+        Ok(output_path.to_string())
+    }
 }
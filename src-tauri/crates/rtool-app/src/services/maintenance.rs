@@ -0,0 +1,37 @@
+use rtool_contracts::models::DbMaintenanceResultDto;
+use rtool_contracts::{AppError, AppResult};
+use rtool_data::db::{DbConn, vacuum_database};
+use rtool_discovery::launcher::index::get_indexer_runtime_status;
+use std::path::Path;
+use std::time::Instant;
+
+#[derive(Debug, Clone)]
+pub struct MaintenanceApplicationService {
+    db_conn: DbConn,
+}
+
+impl MaintenanceApplicationService {
+    pub fn new(db_conn: DbConn) -> Self {
+        Self { db_conn }
+    }
+
+    pub async fn vacuum_database(&self, db_path: &Path) -> AppResult<DbMaintenanceResultDto> {
+        if get_indexer_runtime_status().building {
+            return Err(AppError::new(
+                "db_maintenance_busy",
+                "启动器索引正在写入数据库，请稍后再试",
+            ));
+        }
+
+        let started_at = Instant::now();
+        let outcome = vacuum_database(&self.db_conn, db_path)
+            .await
+            .map_err(AppError::from)?;
+        Ok(DbMaintenanceResultDto {
+            size_before_bytes: outcome.size_before_bytes,
+            size_after_bytes: outcome.size_after_bytes,
+            reclaimed_bytes: outcome.reclaimed_bytes(),
+            duration_ms: started_at.elapsed().as_millis() as u64,
+        })
+    }
+}
@@ -4,15 +4,17 @@ mod clipboard;
 mod launcher;
 mod locale;
 mod logging;
+mod maintenance;
 mod screenshot;
 mod settings;
 
 pub use app_manager::AppManagerApplicationService;
 pub use bootstrap::BootstrapApplicationService;
-pub use clipboard::ClipboardApplicationService;
+pub use clipboard::{ClipboardApplicationService, ClipboardImagePreview};
 pub use launcher::LauncherApplicationService;
 pub use locale::LocaleApplicationService;
 pub use logging::LoggingApplicationService;
+pub use maintenance::MaintenanceApplicationService;
 pub use screenshot::ScreenshotApplicationService;
 pub use settings::SettingsApplicationService;
 
@@ -26,6 +28,7 @@ pub struct ApplicationServices {
     pub launcher: LauncherApplicationService,
     pub locale: LocaleApplicationService,
     pub logging: LoggingApplicationService,
+    pub maintenance: MaintenanceApplicationService,
     pub screenshot: ScreenshotApplicationService,
     pub settings: SettingsApplicationService,
 }
@@ -34,10 +37,11 @@ impl ApplicationServices {
     pub fn new(db_conn: DbConn, clipboard_service: ClipboardService) -> Self {
         Self {
             app_manager: AppManagerApplicationService,
-            clipboard: ClipboardApplicationService::new(db_conn.clone(), clipboard_service),
+            clipboard: ClipboardApplicationService::new(clipboard_service),
             launcher: LauncherApplicationService::new(db_conn.clone()),
             locale: LocaleApplicationService,
             logging: LoggingApplicationService,
+            maintenance: MaintenanceApplicationService::new(db_conn.clone()),
             screenshot: ScreenshotApplicationService,
             settings: SettingsApplicationService::new(db_conn),
         }
@@ -23,4 +23,11 @@ impl SettingsApplicationService {
     pub async fn update_locale_preference(&self, preference: &str) -> AppResult<SettingsDto> {
         rtool_settings::update_locale_preference(&self.db_conn, preference).await
     }
+
+    pub async fn update_clipboard_storage_dir(
+        &self,
+        storage_dir: Option<&str>,
+    ) -> AppResult<SettingsDto> {
+        rtool_settings::update_clipboard_storage_dir(&self.db_conn, storage_dir).await
+    }
 }
@@ -0,0 +1,24 @@
+use rtool_contracts::{AppError, AppResult};
+
+pub(crate) async fn run_blocking<T, F>(label: &'static str, job: F) -> AppResult<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> AppResult<T> + Send + 'static,
+{
+    match tokio::task::spawn_blocking(job).await {
+        Ok(result) => result,
+        Err(error) if error.is_cancelled() => {
+            Err(AppError::new("blocking_task_canceled", "阻塞任务被取消")
+                .with_context("blockingTask", label))
+        }
+        Err(error) if error.is_panic() => Err(AppError::new(
+            "blocking_task_panicked",
+            "阻塞任务发生 panic",
+        )
+        .with_context("joinError", format!("{error:?}"))
+        .with_context("blockingTask", label)),
+        Err(error) => Err(AppError::new("blocking_task_failed", "阻塞任务执行失败")
+            .with_context("joinError", format!("{error:?}"))
+            .with_context("blockingTask", label)),
+    }
+}
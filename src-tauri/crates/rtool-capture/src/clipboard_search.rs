@@ -0,0 +1,175 @@
+use rtool_contracts::models::{ClipboardItemDto, ClipboardSearchField, ClipboardSearchMatchDto};
+
+/// Folds ASCII letters only, like SQLite's default `LIKE` that selects the candidates,
+/// so a case variant the query drops can never match here either.
+fn fold_char(ch: char) -> char {
+    ch.to_ascii_lowercase()
+}
+
+pub(crate) fn parse_search_terms(query: &str) -> Vec<String> {
+    let mut terms: Vec<String> = Vec::new();
+    for term in query.split_whitespace() {
+        let folded = term.chars().map(fold_char).collect::<String>();
+        if !terms.contains(&folded) {
+            terms.push(folded);
+        }
+    }
+    terms
+}
+
+fn find_term_ranges(text: &str, term: &str) -> Vec<(u32, u32)> {
+    let term_chars = term.chars().collect::<Vec<_>>();
+    if term_chars.is_empty() {
+        return Vec::new();
+    }
+
+    let mut folded = Vec::new();
+    let mut utf16_offsets = Vec::new();
+    let mut offset = 0u32;
+    for ch in text.chars() {
+        folded.push(fold_char(ch));
+        utf16_offsets.push(offset);
+        offset += ch.len_utf16() as u32;
+    }
+    utf16_offsets.push(offset);
+
+    let mut ranges = Vec::new();
+    if folded.len() < term_chars.len() {
+        return ranges;
+    }
+    for start in 0..=folded.len() - term_chars.len() {
+        let end = start + term_chars.len();
+        if folded[start..end] == term_chars[..] {
+            ranges.push((utf16_offsets[start], utf16_offsets[end]));
+        }
+    }
+    ranges
+}
+
+fn merge_ranges(mut ranges: Vec<(u32, u32)>) -> Vec<(u32, u32)> {
+    ranges.sort_unstable();
+    let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// Returns highlight ranges (UTF-16 offsets) when every term matches at least one field.
+pub(crate) fn match_clipboard_item(
+    item: &ClipboardItemDto,
+    terms: &[String],
+) -> Option<Vec<ClipboardSearchMatchDto>> {
+    let fields = [
+        (
+            ClipboardSearchField::PlainText,
+            Some(item.plain_text.as_str()),
+        ),
+        (ClipboardSearchField::SourceApp, item.source_app.as_deref()),
+    ];
+
+    let mut field_ranges = vec![Vec::new(); fields.len()];
+    for term in terms {
+        let mut matched = false;
+        for (index, (_, text)) in fields.iter().enumerate() {
+            let Some(text) = text else {
+                continue;
+            };
+            let ranges = find_term_ranges(text, term);
+            matched |= !ranges.is_empty();
+            field_ranges[index].extend(ranges);
+        }
+        if !matched {
+            return None;
+        }
+    }
+
+    let mut matches = Vec::new();
+    for ((field, _), ranges) in fields.iter().zip(field_ranges) {
+        matches.extend(merge_ranges(ranges).into_iter().map(|(start, end)| {
+            ClipboardSearchMatchDto {
+                field: *field,
+                start,
+                end,
+            }
+        }));
+    }
+    Some(matches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(plain_text: &str, source_app: Option<&str>) -> ClipboardItemDto {
+        ClipboardItemDto {
+            id: "id".to_string(),
+            content_key: "key".to_string(),
+            item_type: "text".to_string(),
+            plain_text: plain_text.to_string(),
+            source_app: source_app.map(ToString::to_string),
+            preview_path: None,
+            preview_data_url: None,
+            created_at: 0,
+            pinned: false,
+            pin_order: 0,
+            preview_missing: false,
+            preview_skipped: false,
+            content_size_bytes: None,
+            width: None,
+            height: None,
+            original_width: None,
+            original_height: None,
+        }
+    }
+
+    fn ranges(matches: &[ClipboardSearchMatchDto]) -> Vec<(ClipboardSearchField, u32, u32)> {
+        matches
+            .iter()
+            .map(|value| (value.field, value.start, value.end))
+            .collect()
+    }
+
+    #[test]
+    fn parses_terms_case_insensitively_without_duplicates() {
+        assert_eq!(parse_search_terms("  Foo bar FOO "), vec!["foo", "bar"]);
+        assert!(parse_search_terms("   ").is_empty());
+    }
+
+    #[test]
+    fn folds_ascii_case_only_like_sqlite() {
+        assert_eq!(parse_search_terms("ÄRGER"), vec!["Ärger"]);
+        assert!(match_clipboard_item(&item("Ärger", None), &parse_search_terms("ÄRGER")).is_some());
+        assert!(match_clipboard_item(&item("ärger", None), &parse_search_terms("ÄRGER")).is_none());
+    }
+
+    #[test]
+    fn requires_every_term_across_fields() {
+        let terms = parse_search_terms("hello code");
+        let matches = match_clipboard_item(&item("Hello world, hello", Some("VS Code")), &terms)
+            .expect("all terms match");
+        assert_eq!(
+            ranges(&matches),
+            vec![
+                (ClipboardSearchField::PlainText, 0, 5),
+                (ClipboardSearchField::PlainText, 13, 18),
+                (ClipboardSearchField::SourceApp, 3, 7),
+            ]
+        );
+
+        assert!(match_clipboard_item(&item("Hello world", None), &terms).is_none());
+    }
+
+    #[test]
+    fn offsets_are_utf16_and_overlaps_merge() {
+        let terms = parse_search_terms("译文 文本");
+        let matches = match_clipboard_item(&item("😀译文本", None), &terms).expect("terms match");
+        assert_eq!(
+            ranges(&matches),
+            vec![(ClipboardSearchField::PlainText, 2, 5)]
+        );
+    }
+}
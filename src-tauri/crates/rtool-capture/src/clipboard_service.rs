@@ -1,14 +1,25 @@
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use crate::helpers::build_clipboard_item;
+use crate::blocking::run_blocking;
+use crate::clipboard_search::{match_clipboard_item, parse_search_terms};
+use crate::helpers::{
+    IMAGE_DHASH_MAX_DISTANCE, apply_clipboard_image_dimensions, build_clipboard_item,
+    build_clipboard_item_detail, clipboard_content_size_bytes, image_dhash_distance,
+};
+use anyhow::Context;
 use rtool_contracts::models::{
-    ClipboardFilterDto, ClipboardItemDto, ClipboardSettingsDto, SettingsClipboardDto,
+    ClipboardFilterDto, ClipboardItemDetailDto, ClipboardItemDto, ClipboardKindCountDto,
+    ClipboardMigrateResultDto, ClipboardPreviewFailurePolicy, ClipboardPreviewRepairResultDto,
+    ClipboardSearchHitDto, ClipboardSearchResultDto, ClipboardSettingsDto, ClipboardSizeSummaryDto,
+    ClipboardStorageStatsDto, ClipboardWindowContentFilter, SettingsClipboardDto,
 };
 use rtool_contracts::{AppError, AppResult, ResultExt};
 use rtool_data::db::{self, DbConn};
 use sysinfo::Disks;
+use walkdir::WalkDir;
 
 pub const CLIPBOARD_MAX_ITEMS_DEFAULT: u32 = 1000;
 pub const CLIPBOARD_MAX_ITEMS_MIN: u32 = 100;
@@ -17,13 +28,39 @@ pub const CLIPBOARD_SIZE_CLEANUP_ENABLED_DEFAULT: bool = true;
 pub const CLIPBOARD_MAX_TOTAL_SIZE_MB_DEFAULT: u32 = 500;
 pub const CLIPBOARD_MAX_TOTAL_SIZE_MB_MIN: u32 = 100;
 pub const CLIPBOARD_MAX_TOTAL_SIZE_MB_MAX: u32 = 10_240;
+pub const CLIPBOARD_MAX_PINNED_ITEMS_DEFAULT: u32 = 100;
+pub const CLIPBOARD_MAX_PINNED_ITEMS_MIN: u32 = 1;
+pub const CLIPBOARD_MAX_PINNED_ITEMS_MAX: u32 = 1000;
 pub const CLIPBOARD_MIN_FREE_DISK_BYTES: u64 = 512 * 1024 * 1024;
+const CLIPBOARD_PREVIEW_SCAN_MAX_DEPTH: usize = 4;
+const CLIPBOARD_PREVIEW_SCAN_MAX_ENTRIES: usize = 50_000;
+const CLIPBOARD_PREVIEW_DIR_NAME: &str = "clipboard_previews";
+/// A custom storage dir may be shared with user files, so rtool only writes into these entries.
+const CLIPBOARD_STORAGE_PREVIEW_DIR_NAME: &str = "rtool-clipboard-previews";
+const CLIPBOARD_STORAGE_DB_FILE_NAME: &str = "rtool-clipboard.db";
+const CLIPBOARD_ORPHAN_PREVIEW_MIN_AGE: Duration = Duration::from_secs(60);
+const CLIPBOARD_SEARCH_LIMIT_DEFAULT: u32 = 50;
+const CLIPBOARD_SEARCH_LIMIT_MAX: u32 = 500;
+const CLIPBOARD_SEARCH_CANDIDATE_MAX: u32 = 2_000;
+const CLIPBOARD_PERCEPTUAL_DEDUP_RECENT_MAX: u32 = 200;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 struct ClipboardRuntimeSettings {
     max_items: u32,
     size_cleanup_enabled: bool,
     max_total_size_mb: u32,
+    capture_text: bool,
+    capture_images: bool,
+    capture_files: bool,
+    perceptual_deduplicate: bool,
+    storage_dir: Option<PathBuf>,
+    image_preview_max_width: Option<u32>,
+    image_preview_max_height: Option<u32>,
+    max_preview_pixels: Option<u64>,
+    preview_failure_policy: ClipboardPreviewFailurePolicy,
+    compact_content_filter: ClipboardWindowContentFilter,
+    regular_content_filter: ClipboardWindowContentFilter,
+    max_pinned_items: u32,
 }
 
 impl Default for ClipboardRuntimeSettings {
@@ -32,6 +69,18 @@ impl Default for ClipboardRuntimeSettings {
             max_items: CLIPBOARD_MAX_ITEMS_DEFAULT,
             size_cleanup_enabled: CLIPBOARD_SIZE_CLEANUP_ENABLED_DEFAULT,
             max_total_size_mb: CLIPBOARD_MAX_TOTAL_SIZE_MB_DEFAULT,
+            capture_text: true,
+            capture_images: true,
+            capture_files: true,
+            perceptual_deduplicate: false,
+            storage_dir: None,
+            image_preview_max_width: None,
+            image_preview_max_height: None,
+            max_preview_pixels: None,
+            preview_failure_policy: ClipboardPreviewFailurePolicy::KeepWithoutPreview,
+            compact_content_filter: ClipboardWindowContentFilter::All,
+            regular_content_filter: ClipboardWindowContentFilter::All,
+            max_pinned_items: CLIPBOARD_MAX_PINNED_ITEMS_DEFAULT,
         }
     }
 }
@@ -47,6 +96,49 @@ impl ClipboardRuntimeSettings {
                 CLIPBOARD_MAX_TOTAL_SIZE_MB_MIN,
                 CLIPBOARD_MAX_TOTAL_SIZE_MB_MAX,
             ),
+            capture_text: value.capture_text,
+            capture_images: value.capture_images,
+            capture_files: value.capture_files,
+            perceptual_deduplicate: value.perceptual_deduplicate,
+            storage_dir: value
+                .storage_dir
+                .as_deref()
+                .map(str::trim)
+                .filter(|dir| !dir.is_empty())
+                .map(PathBuf::from),
+            image_preview_max_width: value.image_preview_max_width.filter(|value| *value > 0),
+            image_preview_max_height: value.image_preview_max_height.filter(|value| *value > 0),
+            max_preview_pixels: value.max_preview_pixels.filter(|value| *value > 0),
+            preview_failure_policy: value.preview_failure_policy,
+            compact_content_filter: value.compact_content_filter,
+            regular_content_filter: value.regular_content_filter,
+            max_pinned_items: value.max_pinned_items.clamp(
+                CLIPBOARD_MAX_PINNED_ITEMS_MIN,
+                CLIPBOARD_MAX_PINNED_ITEMS_MAX,
+            ),
+        }
+    }
+
+    fn to_dto(&self) -> ClipboardSettingsDto {
+        ClipboardSettingsDto {
+            max_items: self.max_items,
+            size_cleanup_enabled: self.size_cleanup_enabled,
+            max_total_size_mb: self.max_total_size_mb,
+            capture_text: self.capture_text,
+            capture_images: self.capture_images,
+            capture_files: self.capture_files,
+            perceptual_deduplicate: self.perceptual_deduplicate,
+            clipboard_storage_dir: self
+                .storage_dir
+                .as_ref()
+                .map(|dir| dir.to_string_lossy().to_string()),
+            image_preview_max_width: self.image_preview_max_width,
+            image_preview_max_height: self.image_preview_max_height,
+            max_preview_pixels: self.max_preview_pixels,
+            preview_failure_policy: self.preview_failure_policy,
+            compact_content_filter: self.compact_content_filter,
+            regular_content_filter: self.regular_content_filter,
+            max_pinned_items: self.max_pinned_items,
         }
     }
 }
@@ -156,6 +248,261 @@ fn remove_preview_file(path: &str) {
     }
 }
 
+fn ensure_storage_dir_writable(dir: &Path) -> AppResult<()> {
+    let dir_text = dir.to_string_lossy().to_string();
+    if dir_text.trim().is_empty() {
+        return Err(AppError::new(
+            "clipboard_storage_dir_invalid",
+            "剪贴板存储目录不能为空",
+        ));
+    }
+    if !dir.is_absolute() {
+        return Err(AppError::new(
+            "clipboard_storage_dir_invalid",
+            "剪贴板存储目录必须是绝对路径",
+        )
+        .with_context("path", dir_text));
+    }
+
+    let probe = dir.join(format!(".rtool-write-probe-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(dir)
+        .and_then(|_| std::fs::write(&probe, b""))
+        .and_then(|_| std::fs::remove_file(&probe))
+        .with_context(|| format!("剪贴板存储目录不可写: {}", dir.display()))
+        .with_code("clipboard_storage_dir_not_writable", "剪贴板存储目录不可写")
+        .with_ctx("path", dir_text)
+}
+
+fn move_preview_file(source: &Path, target: &Path) -> std::io::Result<()> {
+    if target.exists() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::AlreadyExists,
+            "preview target already exists",
+        ));
+    }
+    if std::fs::rename(source, target).is_err() {
+        // rename fails across volumes; fall back to copy + delete.
+        std::fs::copy(source, target)?;
+        std::fs::remove_file(source)?;
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PreviewMove {
+    item_id: String,
+    source: PathBuf,
+    target: PathBuf,
+}
+
+fn plan_preview_moves(
+    preview_paths: Vec<(String, String)>,
+    target_dir: &Path,
+) -> AppResult<Vec<PreviewMove>> {
+    let mut planned_targets = HashSet::new();
+    let mut moves = Vec::new();
+    for (item_id, preview_path) in preview_paths {
+        let source = PathBuf::from(preview_path.trim());
+        if !source.is_file() || source.parent() == Some(target_dir) {
+            continue;
+        }
+        let Some(file_name) = source.file_name() else {
+            continue;
+        };
+        let target = target_dir.join(file_name);
+        if target.exists() || !planned_targets.insert(target.clone()) {
+            return Err(AppError::new(
+                "clipboard_storage_migrate_conflict",
+                "目标目录中已存在同名预览文件",
+            )
+            .with_context("itemId", item_id)
+            .with_context("path", target.to_string_lossy().to_string()));
+        }
+        moves.push(PreviewMove {
+            item_id,
+            source,
+            target,
+        });
+    }
+    Ok(moves)
+}
+
+/// Moves every planned preview, putting already moved files back if one of them fails.
+fn apply_preview_moves(moves: &[PreviewMove]) -> AppResult<()> {
+    for (index, entry) in moves.iter().enumerate() {
+        let Err(error) = move_preview_file(&entry.source, &entry.target) else {
+            continue;
+        };
+        for moved in moves[..index].iter().rev() {
+            if let Err(rollback_error) = move_preview_file(&moved.target, &moved.source) {
+                tracing::warn!(
+                    event = "clipboard_storage_migrate_rollback_failed",
+                    preview_path = %moved.target.display(),
+                    error = rollback_error.to_string()
+                );
+            }
+        }
+        return Err(
+            AppError::new("clipboard_storage_migrate_failed", "迁移剪贴板存储目录失败")
+                .with_source(error)
+                .with_context("itemId", entry.item_id.clone())
+                .with_context("path", entry.source.to_string_lossy().to_string()),
+        );
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct PreviewDirUsage {
+    size_bytes: u64,
+    file_count: u64,
+    truncated: bool,
+}
+
+fn preview_dir_usage(dir: &Path, max_depth: usize, max_entries: usize) -> PreviewDirUsage {
+    let mut usage = PreviewDirUsage::default();
+    if !dir.is_dir() {
+        return usage;
+    }
+
+    let mut visited = 0usize;
+    for entry in WalkDir::new(dir).max_depth(max_depth).into_iter().flatten() {
+        visited += 1;
+        if visited > max_entries {
+            usage.truncated = true;
+            break;
+        }
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        if let Ok(metadata) = entry.metadata() {
+            usage.size_bytes = usage.size_bytes.saturating_add(metadata.len());
+            usage.file_count += 1;
+        }
+    }
+    usage
+}
+
+/// Previews written by the clipboard watcher are named `<blake3 hex signature>.png`.
+fn is_watcher_preview_file_name(file_name: &str) -> bool {
+    file_name.strip_suffix(".png").is_some_and(|signature| {
+        signature.len() == 64
+            && signature
+                .bytes()
+                .all(|byte| byte.is_ascii_digit() || (b'a'..=b'f').contains(&byte))
+    })
+}
+
+fn find_orphan_previews(
+    dir: &Path,
+    known_file_names: &HashSet<String>,
+    min_age: Duration,
+) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let now = SystemTime::now();
+    let mut orphans = Vec::new();
+    for entry in entries.flatten() {
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if !metadata.is_file() {
+            continue;
+        }
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        if !is_watcher_preview_file_name(&file_name) || known_file_names.contains(&file_name) {
+            continue;
+        }
+        let recently_written = metadata
+            .modified()
+            .ok()
+            .and_then(|modified| now.duration_since(modified).ok())
+            .is_some_and(|age| age < min_age);
+        if recently_written {
+            continue;
+        }
+        orphans.push(entry.path());
+    }
+    orphans
+}
+
+fn mark_missing_preview(item: &mut ClipboardItemDto) {
+    if item.item_type != "image" {
+        return;
+    }
+    item.preview_missing = match item.preview_path.as_deref() {
+        Some(preview_path) => !Path::new(preview_path).is_file(),
+        // Recorded after the preview failed to write; skipped previews are intentional.
+        None => item.preview_data_url.is_none() && !item.preview_skipped,
+    };
+}
+
+async fn scan_preview_dir_usage(preview_dir: Option<&Path>) -> AppResult<PreviewDirUsage> {
+    let Some(dir) = preview_dir.map(Path::to_path_buf) else {
+        return Ok(PreviewDirUsage::default());
+    };
+    run_blocking("clipboard_preview_dir_usage", move || {
+        Ok(preview_dir_usage(
+            &dir,
+            CLIPBOARD_PREVIEW_SCAN_MAX_DEPTH,
+            CLIPBOARD_PREVIEW_SCAN_MAX_ENTRIES,
+        ))
+    })
+    .await
+}
+
+/// Sizes are stored at capture time; only image and file sizes touch the disk.
+async fn with_content_size(mut item: ClipboardItemDto) -> AppResult<ClipboardItemDto> {
+    if item.content_size_bytes.is_some() {
+        return Ok(item);
+    }
+    if !matches!(item.item_type.as_str(), "image" | "file") {
+        item.content_size_bytes = clipboard_content_size_bytes(&item);
+        return Ok(item);
+    }
+    run_blocking("clipboard_content_size", move || {
+        item.content_size_bytes = clipboard_content_size_bytes(&item);
+        Ok(item)
+    })
+    .await
+}
+
+/// Matches up to `candidate_max` candidates; any extra candidate marks the result truncated.
+fn collect_search_hits(
+    mut candidates: Vec<ClipboardItemDto>,
+    terms: &[String],
+    limit: usize,
+    candidate_max: usize,
+) -> ClipboardSearchResultDto {
+    let truncated = candidates.len() > candidate_max;
+    candidates.truncate(candidate_max);
+    let hits = candidates
+        .into_iter()
+        .filter_map(|item| {
+            match_clipboard_item(&item, terms)
+                .map(|matches| ClipboardSearchHitDto { item, matches })
+        })
+        .collect::<Vec<_>>();
+
+    let total_count = u32::try_from(hits.len()).unwrap_or(u32::MAX);
+    let items = hits.into_iter().take(limit).collect::<Vec<_>>();
+    ClipboardSearchResultDto {
+        items,
+        total_count,
+        truncated,
+    }
+}
+
+fn decorate_item(item: &mut ClipboardItemDto) {
+    mark_missing_preview(item);
+    if item.content_size_bytes.is_none() && !matches!(item.item_type.as_str(), "image" | "file") {
+        item.content_size_bytes = Some(item.plain_text.len() as u64);
+    }
+    apply_clipboard_image_dimensions(item);
+}
+
 fn now_millis() -> i64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -163,11 +510,27 @@ fn now_millis() -> i64 {
         .unwrap_or_default()
 }
 
+/// The database holding clipboard rows; moves to the storage dir once one is chosen.
+#[derive(Clone)]
+struct ClipboardDb {
+    conn: DbConn,
+    path: PathBuf,
+}
+
+async fn open_clipboard_db(path: &Path) -> AppResult<DbConn> {
+    let conn = db::open_db(path).await?;
+    db::init_db(&conn).await?;
+    Ok(conn)
+}
+
 #[derive(Clone)]
 pub struct ClipboardService {
-    db_conn: DbConn,
-    db_path: PathBuf,
+    db: Arc<RwLock<ClipboardDb>>,
+    app_db_path: PathBuf,
     settings: Arc<RwLock<ClipboardRuntimeSettings>>,
+    /// Shared by every writer; `migrate_storage_dir` holds it exclusively so nothing is written
+    /// to the database or preview folder being moved away from.
+    storage_gate: Arc<tokio::sync::RwLock<()>>,
 }
 
 #[derive(Debug, Clone)]
@@ -176,6 +539,13 @@ pub struct ClipboardSaveResult {
     pub removed_ids: Vec<String>,
 }
 
+#[derive(Debug, Clone)]
+pub struct ClipboardPinResult {
+    pub item: ClipboardItemDto,
+    /// Items unpinned to stay within `max_pinned_items`.
+    pub unpinned: Vec<ClipboardItemDto>,
+}
+
 #[derive(Debug, Clone)]
 pub struct ClipboardSettingsUpdateResult {
     pub settings: ClipboardSettingsDto,
@@ -189,16 +559,300 @@ impl ClipboardService {
         initial_settings: SettingsClipboardDto,
     ) -> AppResult<Self> {
         let runtime_settings = ClipboardRuntimeSettings::from_settings(&initial_settings);
+        let mut clipboard_db = ClipboardDb {
+            conn: db_conn,
+            path: db_path.clone(),
+        };
+        if let Some(path) = runtime_settings
+            .storage_dir
+            .as_deref()
+            .map(|dir| dir.join(CLIPBOARD_STORAGE_DB_FILE_NAME))
+            .filter(|path| path.is_file())
+        {
+            match open_clipboard_db(&path).await {
+                Ok(conn) => clipboard_db = ClipboardDb { conn, path },
+                Err(error) => tracing::warn!(
+                    event = "clipboard_storage_db_open_failed",
+                    db_path = %path.display(),
+                    error_code = error.code.as_str()
+                ),
+            }
+        }
 
         let service = Self {
-            db_conn,
-            db_path,
+            db: Arc::new(RwLock::new(clipboard_db)),
+            app_db_path: db_path,
             settings: Arc::new(RwLock::new(runtime_settings)),
+            storage_gate: Arc::new(tokio::sync::RwLock::new(())),
         };
         let _ = service.enforce_capacity().await?;
         Ok(service)
     }
 
+    pub fn preview_dir(&self) -> Option<PathBuf> {
+        if let Some(dir) = self.current_settings().storage_dir {
+            return Some(dir.join(CLIPBOARD_STORAGE_PREVIEW_DIR_NAME));
+        }
+        self.app_db_path
+            .parent()
+            .map(|dir| dir.join(CLIPBOARD_PREVIEW_DIR_NAME))
+    }
+
+    fn conn(&self) -> DbConn {
+        match self.db.read() {
+            Ok(db) => db.conn.clone(),
+            Err(poisoned) => poisoned.into_inner().conn.clone(),
+        }
+    }
+
+    fn db_path(&self) -> PathBuf {
+        match self.db.read() {
+            Ok(db) => db.path.clone(),
+            Err(poisoned) => poisoned.into_inner().path.clone(),
+        }
+    }
+
+    fn replace_db(&self, next: ClipboardDb) -> AppResult<()> {
+        let mut guard = self
+            .db
+            .write()
+            .map_err(|_| AppError::new("clipboard_db_lock_failed", "切换剪贴板数据库失败"))?;
+        *guard = next;
+        Ok(())
+    }
+
+    async fn storage_write_guard(&self) -> tokio::sync::RwLockReadGuard<'_, ()> {
+        self.storage_gate.read().await
+    }
+
+    /// Moves previews and rows into `new_dir`. `persist_storage_dir` must store the new dir
+    /// before the old rows are cleared, so a restart never opens an emptied database.
+    pub async fn migrate_storage_dir(
+        &self,
+        new_dir: &Path,
+        persist_storage_dir: impl AsyncFnOnce(String) -> AppResult<()>,
+    ) -> AppResult<ClipboardMigrateResultDto> {
+        ensure_storage_dir_writable(new_dir)?;
+        let target_preview_dir = new_dir.join(CLIPBOARD_STORAGE_PREVIEW_DIR_NAME);
+        std::fs::create_dir_all(&target_preview_dir)
+            .with_context(|| format!("创建剪贴板预览目录失败: {}", target_preview_dir.display()))
+            .with_code("clipboard_storage_dir_not_writable", "剪贴板存储目录不可写")
+            .with_ctx("path", target_preview_dir.to_string_lossy().to_string())?;
+
+        let _exclusive = self.storage_gate.write().await;
+        let old_path = self
+            .current_settings()
+            .storage_dir
+            .or_else(|| self.app_db_path.parent().map(Path::to_path_buf))
+            .map(|dir| dir.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let new_path = new_dir.to_string_lossy().to_string();
+        let source_conn = self.conn();
+        let source_db_path = self.db_path();
+
+        let moves = plan_preview_moves(
+            db::list_clipboard_preview_paths(&source_conn).await?,
+            &target_preview_dir,
+        )?;
+        let moves = run_blocking("clipboard_storage_migrate", move || {
+            apply_preview_moves(&moves)?;
+            Ok(moves)
+        })
+        .await?;
+        for entry in &moves {
+            db::set_clipboard_preview_path(
+                &source_conn,
+                entry.item_id.as_str(),
+                entry.target.to_string_lossy().as_ref(),
+            )
+            .await?;
+        }
+        let files_moved = u32::try_from(moves.len()).unwrap_or(u32::MAX);
+
+        let target_db_path = new_dir.join(CLIPBOARD_STORAGE_DB_FILE_NAME);
+        let db_migrated = source_db_path != target_db_path;
+        let target_conn = if db_migrated {
+            let target_conn = open_clipboard_db(&target_db_path).await?;
+            db::copy_clipboard_items_from(&target_conn, &source_db_path)
+                .await
+                .map_err(|error| {
+                    AppError::from(error)
+                        .with_code("clipboard_storage_migrate_failed", "迁移剪贴板存储目录失败")
+                        .with_context("path", target_db_path.to_string_lossy().to_string())
+                })?;
+            Some(target_conn)
+        } else {
+            None
+        };
+
+        persist_storage_dir(new_path.clone()).await?;
+        if let Some(target_conn) = target_conn {
+            self.replace_db(ClipboardDb {
+                conn: target_conn,
+                path: target_db_path,
+            })?;
+        }
+        let next = ClipboardRuntimeSettings {
+            storage_dir: Some(new_dir.to_path_buf()),
+            ..self.current_settings()
+        };
+        self.set_cached_settings(next)?;
+
+        if db_migrated {
+            // Rows now live in the new database; the preview files they point to must stay.
+            if let Err(error) = db::clear_all_clipboard_items(&source_conn).await {
+                tracing::warn!(
+                    event = "clipboard_storage_old_rows_clear_failed",
+                    db_path = %source_db_path.display(),
+                    error = error.to_string()
+                );
+            }
+        }
+        tracing::info!(
+            event = "clipboard_storage_migrated",
+            files_moved,
+            db_migrated,
+            old_path = old_path.as_str(),
+            new_path = new_path.as_str()
+        );
+
+        Ok(ClipboardMigrateResultDto {
+            files_moved,
+            db_migrated,
+            old_path,
+            new_path,
+        })
+    }
+
+    /// A preview written by the watcher right before a migration lands in the old preview folder;
+    /// move it next to the other previews before the row is stored.
+    async fn adopt_stale_preview(&self, item: &mut ClipboardItemDto) {
+        let Some(preview_dir) = self.preview_dir() else {
+            return;
+        };
+        let Some(source) = item
+            .preview_path
+            .as_deref()
+            .map(|path| PathBuf::from(path.trim()))
+        else {
+            return;
+        };
+        let stale = source.parent().is_some_and(|parent| {
+            parent != preview_dir
+                && parent.file_name().is_some_and(|name| {
+                    name == CLIPBOARD_PREVIEW_DIR_NAME || name == CLIPBOARD_STORAGE_PREVIEW_DIR_NAME
+                })
+        });
+        let Some(file_name) = source.file_name().filter(|_| stale) else {
+            return;
+        };
+        let target = preview_dir.join(file_name);
+        let moved = {
+            let target = target.clone();
+            run_blocking("clipboard_preview_adopt", move || {
+                if !source.is_file() {
+                    return Ok(false);
+                }
+                std::fs::create_dir_all(&preview_dir)
+                    .and_then(|_| move_preview_file(&source, &target))
+                    .map(|_| true)
+                    .with_context(|| format!("移动剪贴板预览失败: {}", source.display()))
+                    .with_code("clipboard_preview_adopt_failed", "移动剪贴板预览失败")
+            })
+            .await
+        };
+        match moved {
+            Ok(true) => item.preview_path = Some(target.to_string_lossy().to_string()),
+            Ok(false) => {}
+            Err(error) => tracing::warn!(
+                event = "clipboard_preview_adopt_failed",
+                preview_path = %target.display(),
+                error_code = error.code.as_str()
+            ),
+        }
+    }
+
+    pub async fn repair_preview_paths(&self) -> AppResult<u32> {
+        let conn = self.conn();
+        let preview_paths = db::list_clipboard_preview_paths(&conn).await?;
+        let missing_ids = run_blocking("clipboard_preview_repair", move || {
+            Ok(preview_paths
+                .into_iter()
+                .filter(|(_, preview_path)| !Path::new(preview_path.trim()).is_file())
+                .map(|(id, _)| id)
+                .collect::<Vec<_>>())
+        })
+        .await?;
+        for id in &missing_ids {
+            db::clear_clipboard_preview_path(&conn, id.as_str()).await?;
+        }
+        Ok(u32::try_from(missing_ids.len()).unwrap_or(u32::MAX))
+    }
+
+    /// Fills content sizes for items captured before sizes were stored.
+    async fn backfill_content_sizes(&self) -> AppResult<u32> {
+        let conn = self.conn();
+        let items = db::list_clipboard_items_missing_content_size(&conn).await?;
+        if items.is_empty() {
+            return Ok(0);
+        }
+        let sizes = run_blocking("clipboard_content_size_backfill", move || {
+            Ok(items
+                .into_iter()
+                .filter_map(|item| clipboard_content_size_bytes(&item).map(|size| (item.id, size)))
+                .collect::<Vec<_>>())
+        })
+        .await?;
+        for (id, size_bytes) in &sizes {
+            db::set_clipboard_content_size(&conn, id.as_str(), *size_bytes).await?;
+        }
+        Ok(u32::try_from(sizes.len()).unwrap_or(u32::MAX))
+    }
+
+    async fn remove_orphan_previews(&self) -> AppResult<u32> {
+        let Some(preview_dir) = self.preview_dir() else {
+            return Ok(0);
+        };
+        let known_file_names = db::list_clipboard_preview_paths(&self.conn())
+            .await?
+            .into_iter()
+            .filter_map(|(_, preview_path)| {
+                Path::new(preview_path.trim())
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+            })
+            .collect::<HashSet<_>>();
+
+        run_blocking("clipboard_orphan_preview_sweep", move || {
+            let orphans = find_orphan_previews(
+                &preview_dir,
+                &known_file_names,
+                CLIPBOARD_ORPHAN_PREVIEW_MIN_AGE,
+            );
+            for orphan in &orphans {
+                remove_preview_file(&orphan.to_string_lossy());
+            }
+            Ok(u32::try_from(orphans.len()).unwrap_or(u32::MAX))
+        })
+        .await
+    }
+
+    pub async fn repair_previews(&self) -> AppResult<ClipboardPreviewRepairResultDto> {
+        let _storage = self.storage_write_guard().await;
+        let result = ClipboardPreviewRepairResultDto {
+            repaired_count: self.repair_preview_paths().await?,
+            orphan_removed_count: self.remove_orphan_previews().await?,
+        };
+        let size_backfilled_count = self.backfill_content_sizes().await?;
+        tracing::info!(
+            event = "clipboard_preview_repair",
+            repaired_count = result.repaired_count,
+            orphan_removed_count = result.orphan_removed_count,
+            size_backfilled_count
+        );
+        Ok(result)
+    }
+
     fn current_settings(&self) -> ClipboardRuntimeSettings {
         self.settings
             .read()
@@ -216,7 +870,7 @@ impl ClipboardService {
     }
 
     pub fn ensure_disk_space_for_new_item(&self) -> AppResult<()> {
-        let available = resolve_available_space_bytes(self.db_path.as_path());
+        let available = resolve_available_space_bytes(self.db_path().as_path());
         ensure_available_space(available, CLIPBOARD_MIN_FREE_DISK_BYTES)
     }
 
@@ -228,7 +882,7 @@ impl ClipboardService {
             None
         };
         let removed_items =
-            db::prune_clipboard_items(&self.db_conn, settings.max_items, size_limit).await?;
+            db::prune_clipboard_items(&self.conn(), settings.max_items, size_limit).await?;
         let mut removed_ids = Vec::with_capacity(removed_items.len());
         for removed in removed_items {
             removed_ids.push(removed.id);
@@ -245,8 +899,10 @@ impl ClipboardService {
         source_app: Option<String>,
     ) -> AppResult<ClipboardSaveResult> {
         self.ensure_disk_space_for_new_item()?;
-        let item = build_clipboard_item(text, source_app);
-        let stored = db::insert_clipboard_item(&self.db_conn, &item).await?;
+        let item = with_content_size(build_clipboard_item(text, source_app)).await?;
+        let _storage = self.storage_write_guard().await;
+        let mut stored = db::insert_clipboard_item(&self.conn(), &item).await?;
+        decorate_item(&mut stored);
         let removed_ids = self.enforce_capacity().await?;
         Ok(ClipboardSaveResult {
             item: stored,
@@ -256,7 +912,11 @@ impl ClipboardService {
 
     pub async fn save_item(&self, item: ClipboardItemDto) -> AppResult<ClipboardSaveResult> {
         self.ensure_disk_space_for_new_item()?;
-        let stored = db::insert_clipboard_item(&self.db_conn, &item).await?;
+        let mut item = with_content_size(item).await?;
+        let _storage = self.storage_write_guard().await;
+        self.adopt_stale_preview(&mut item).await;
+        let mut stored = db::insert_clipboard_item(&self.conn(), &item).await?;
+        decorate_item(&mut stored);
         let removed_ids = self.enforce_capacity().await?;
         Ok(ClipboardSaveResult {
             item: stored,
@@ -265,47 +925,186 @@ impl ClipboardService {
     }
 
     pub async fn list(&self, filter: ClipboardFilterDto) -> AppResult<Vec<ClipboardItemDto>> {
-        db::list_clipboard_items(&self.db_conn, &filter)
-            .await
-            .map_err(AppError::from)
+        let mut items = db::list_clipboard_items(&self.conn(), &filter).await?;
+        items.iter_mut().for_each(decorate_item);
+        Ok(items)
+    }
+
+    pub async fn search(
+        &self,
+        query: &str,
+        limit: Option<u32>,
+    ) -> AppResult<ClipboardSearchResultDto> {
+        let terms = parse_search_terms(query);
+        let Some(needle) = terms.iter().max_by_key(|term| term.chars().count()) else {
+            return Ok(ClipboardSearchResultDto::default());
+        };
+        let limit = limit
+            .unwrap_or(CLIPBOARD_SEARCH_LIMIT_DEFAULT)
+            .clamp(1, CLIPBOARD_SEARCH_LIMIT_MAX) as usize;
+
+        // One extra row tells a full candidate set apart from a capped one.
+        let candidates = db::list_clipboard_search_candidates(
+            &self.conn(),
+            needle,
+            CLIPBOARD_SEARCH_CANDIDATE_MAX + 1,
+        )
+        .await?;
+        let mut result = collect_search_hits(
+            candidates,
+            &terms,
+            limit,
+            CLIPBOARD_SEARCH_CANDIDATE_MAX as usize,
+        );
+        for hit in &mut result.items {
+            decorate_item(&mut hit.item);
+        }
+        if result.truncated {
+            tracing::debug!(
+                event = "clipboard_search_candidates_capped",
+                candidate_max = CLIPBOARD_SEARCH_CANDIDATE_MAX,
+                total_count = result.total_count
+            );
+        }
+        Ok(result)
     }
 
-    pub async fn pin(&self, id: String, pinned: bool) -> AppResult<ClipboardItemDto> {
-        db::pin_clipboard_item(&self.db_conn, &id, pinned).await?;
-        db::get_clipboard_item(&self.db_conn, &id)
+    pub async fn find_perceptual_duplicate(
+        &self,
+        perceptual_hash: &str,
+    ) -> AppResult<Option<String>> {
+        let entries = db::list_clipboard_perceptual_hashes(
+            &self.conn(),
+            CLIPBOARD_PERCEPTUAL_DEDUP_RECENT_MAX,
+        )
+        .await?;
+        Ok(entries.into_iter().find_map(|(id, existing)| {
+            image_dhash_distance(perceptual_hash, &existing)
+                .filter(|distance| *distance <= IMAGE_DHASH_MAX_DISTANCE)
+                .map(|_| id)
+        }))
+    }
+
+    pub async fn set_perceptual_hash(&self, id: &str, perceptual_hash: &str) -> AppResult<()> {
+        let _storage = self.storage_write_guard().await;
+        Ok(db::set_clipboard_perceptual_hash(&self.conn(), id, perceptual_hash).await?)
+    }
+
+    pub async fn get_item(&self, id: &str) -> AppResult<Option<ClipboardItemDto>> {
+        Ok(db::get_clipboard_item(&self.conn(), id).await?)
+    }
+
+    pub async fn get_item_detail(&self, id: &str) -> AppResult<ClipboardItemDetailDto> {
+        let mut item = db::get_clipboard_item(&self.conn(), id)
             .await?
-            .ok_or_else(|| AppError::new("clipboard_not_found", "未找到对应剪贴板记录"))
+            .ok_or_else(|| AppError::new("clipboard_not_found", "未找到对应剪贴板记录"))?;
+        decorate_item(&mut item);
+        Ok(build_clipboard_item_detail(item))
+    }
+
+    pub async fn pin(&self, id: String, pinned: bool) -> AppResult<ClipboardPinResult> {
+        let _storage = self.storage_write_guard().await;
+        db::pin_clipboard_item(&self.conn(), &id, pinned, now_millis()).await?;
+        let mut item = db::get_clipboard_item(&self.conn(), &id)
+            .await?
+            .ok_or_else(|| AppError::new("clipboard_not_found", "未找到对应剪贴板记录"))?;
+        decorate_item(&mut item);
+
+        let mut unpinned = Vec::new();
+        if pinned {
+            let max_pinned = self.current_settings().max_pinned_items;
+            for unpinned_id in db::unpin_clipboard_overflow(&self.conn(), max_pinned, &id).await? {
+                if let Some(mut entry) = db::get_clipboard_item(&self.conn(), &unpinned_id).await? {
+                    decorate_item(&mut entry);
+                    unpinned.push(entry);
+                }
+            }
+        }
+        Ok(ClipboardPinResult { item, unpinned })
+    }
+
+    pub async fn reorder_pinned(&self, item_ids: &[String]) -> AppResult<Vec<ClipboardItemDto>> {
+        let _storage = self.storage_write_guard().await;
+        db::reorder_pinned_clipboard_items(&self.conn(), item_ids).await?;
+        let mut items = Vec::with_capacity(item_ids.len());
+        for id in item_ids {
+            if let Some(mut item) = db::get_clipboard_item(&self.conn(), id).await?
+                && item.pinned
+            {
+                decorate_item(&mut item);
+                items.push(item);
+            }
+        }
+        Ok(items)
     }
 
     pub async fn touch_item(&self, id: String) -> AppResult<ClipboardItemDto> {
+        let _storage = self.storage_write_guard().await;
         let created_at = now_millis();
-        db::touch_clipboard_item(&self.db_conn, &id, created_at)
+        let mut item = db::touch_clipboard_item(&self.conn(), &id, created_at)
             .await?
-            .ok_or_else(|| AppError::new("clipboard_not_found", "未找到对应剪贴板记录"))
+            .ok_or_else(|| AppError::new("clipboard_not_found", "未找到对应剪贴板记录"))?;
+        decorate_item(&mut item);
+        Ok(item)
     }
 
     pub async fn delete(&self, id: String) -> AppResult<()> {
-        if let Some(preview_path) = db::delete_clipboard_item(&self.db_conn, &id).await? {
+        let _storage = self.storage_write_guard().await;
+        if let Some(preview_path) = db::delete_clipboard_item(&self.conn(), &id).await? {
             remove_preview_file(&preview_path);
         }
         Ok(())
     }
 
     pub async fn clear_all(&self) -> AppResult<()> {
-        let removed_paths = db::clear_all_clipboard_items(&self.db_conn).await?;
+        let _storage = self.storage_write_guard().await;
+        let removed_paths = db::clear_all_clipboard_items(&self.conn()).await?;
         for preview_path in removed_paths {
             remove_preview_file(&preview_path);
         }
         Ok(())
     }
 
-    pub fn get_settings(&self) -> ClipboardSettingsDto {
+    pub async fn item_count(&self) -> AppResult<u64> {
+        Ok(db::count_clipboard_items(&self.conn()).await?)
+    }
+
+    pub async fn storage_stats(
+        &self,
+        preview_dir: Option<&Path>,
+    ) -> AppResult<ClipboardStorageStatsDto> {
+        let usage = db::clipboard_storage_usage(&self.conn()).await?;
+        let preview_usage = scan_preview_dir_usage(preview_dir).await?;
         let settings = self.current_settings();
-        ClipboardSettingsDto {
+
+        Ok(ClipboardStorageStatsDto {
+            total_count: usage.total_count,
+            count_by_kind: usage
+                .count_by_kind
+                .into_iter()
+                .map(|(item_type, count)| ClipboardKindCountDto { item_type, count })
+                .collect(),
+            db_bytes: usage.stored_bytes,
+            preview_bytes: preview_usage.size_bytes,
+            preview_file_count: preview_usage.file_count,
+            preview_scan_truncated: preview_usage.truncated,
             max_items: settings.max_items,
             size_cleanup_enabled: settings.size_cleanup_enabled,
             max_total_size_mb: settings.max_total_size_mb,
-        }
+        })
+    }
+
+    pub async fn size_summary(
+        &self,
+        preview_dir: Option<&Path>,
+    ) -> AppResult<ClipboardSizeSummaryDto> {
+        let mut summary = db::clipboard_size_summary(&self.conn()).await?;
+        summary.preview_dir_size_bytes = scan_preview_dir_usage(preview_dir).await?.size_bytes;
+        Ok(summary)
+    }
+
+    pub fn get_settings(&self) -> ClipboardSettingsDto {
+        self.current_settings().to_dto()
     }
 
     pub async fn update_settings(
@@ -320,18 +1119,17 @@ impl ClipboardService {
         let max_total_size_mb =
             validate_max_total_size_mb(max_total_size_mb.unwrap_or(current.max_total_size_mb))?;
 
-        self.set_cached_settings(ClipboardRuntimeSettings {
+        let next = ClipboardRuntimeSettings {
             max_items,
             size_cleanup_enabled,
             max_total_size_mb,
-        })?;
+            ..current
+        };
+        let _storage = self.storage_write_guard().await;
+        self.set_cached_settings(next.clone())?;
         let removed_ids = self.enforce_capacity().await?;
         Ok(ClipboardSettingsUpdateResult {
-            settings: ClipboardSettingsDto {
-                max_items,
-                size_cleanup_enabled,
-                max_total_size_mb,
-            },
+            settings: next.to_dto(),
             removed_ids,
         })
     }
@@ -342,29 +1140,457 @@ impl ClipboardService {
     ) -> AppResult<ClipboardSettingsUpdateResult> {
         let normalized = ClipboardRuntimeSettings::from_settings(settings);
         let current = self.current_settings();
-        if current.max_items == normalized.max_items
-            && current.size_cleanup_enabled == normalized.size_cleanup_enabled
-            && current.max_total_size_mb == normalized.max_total_size_mb
-        {
+        if current == normalized {
             return Ok(ClipboardSettingsUpdateResult {
-                settings: ClipboardSettingsDto {
-                    max_items: current.max_items,
-                    size_cleanup_enabled: current.size_cleanup_enabled,
-                    max_total_size_mb: current.max_total_size_mb,
-                },
+                settings: current.to_dto(),
                 removed_ids: Vec::new(),
             });
         }
 
+        let limits_changed = current.max_items != normalized.max_items
+            || current.size_cleanup_enabled != normalized.size_cleanup_enabled
+            || current.max_total_size_mb != normalized.max_total_size_mb;
+        let _storage = self.storage_write_guard().await;
         self.set_cached_settings(normalized.clone())?;
-        let removed_ids = self.enforce_capacity().await?;
+        let removed_ids = if limits_changed {
+            self.enforce_capacity().await?
+        } else {
+            Vec::new()
+        };
         Ok(ClipboardSettingsUpdateResult {
-            settings: ClipboardSettingsDto {
-                max_items: normalized.max_items,
-                size_cleanup_enabled: normalized.size_cleanup_enabled,
-                max_total_size_mb: normalized.max_total_size_mb,
-            },
+            settings: normalized.to_dto(),
             removed_ids,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_preview_dir(label: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "rtool-clipboard-preview-{label}-{}",
+            uuid::Uuid::new_v4()
+        ));
+        std::fs::create_dir_all(dir.join("screenshot_pins")).expect("create preview dir");
+        std::fs::write(dir.join("a.png"), [0u8; 10]).expect("write preview");
+        std::fs::write(dir.join("b.png"), [0u8; 20]).expect("write preview");
+        std::fs::write(dir.join("screenshot_pins").join("c.png"), [0u8; 30])
+            .expect("write pin preview");
+        dir
+    }
+
+    #[test]
+    fn move_preview_file_relocates_into_target_dir() {
+        let dir = temp_preview_dir("move");
+        let target_dir = dir.join("moved");
+        ensure_storage_dir_writable(&target_dir).expect("target writable");
+        move_preview_file(&dir.join("a.png"), &target_dir.join("a.png")).expect("move preview");
+        let source_exists = dir.join("a.png").exists();
+        let moved_len = std::fs::metadata(target_dir.join("a.png"))
+            .map(|meta| meta.len())
+            .ok();
+        let overwrite = move_preview_file(&dir.join("b.png"), &target_dir.join("a.png"));
+        let relative = ensure_storage_dir_writable(Path::new("relative/previews"))
+            .expect_err("relative dir rejected");
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert!(!source_exists);
+        assert_eq!(moved_len, Some(10));
+        assert_eq!(
+            overwrite.map_err(|error| error.kind()),
+            Err(std::io::ErrorKind::AlreadyExists)
+        );
+        assert_eq!(relative.code, "clipboard_storage_dir_invalid");
+    }
+
+    #[test]
+    fn preview_moves_refuse_to_overwrite_existing_files() {
+        let dir = temp_preview_dir("plan");
+        let target_dir = dir.join(CLIPBOARD_STORAGE_PREVIEW_DIR_NAME);
+        std::fs::create_dir_all(&target_dir).expect("create target");
+        let entry = |id: &str, path: PathBuf| (id.to_string(), path.to_string_lossy().to_string());
+
+        let moves = plan_preview_moves(
+            vec![
+                entry("a", dir.join("a.png")),
+                entry("gone", dir.join("gone.png")),
+            ],
+            &target_dir,
+        )
+        .expect("plan");
+        apply_preview_moves(&moves).expect("apply");
+        let moved = target_dir.join("a.png").is_file();
+        let in_place = plan_preview_moves(vec![entry("a", target_dir.join("a.png"))], &target_dir)
+            .expect("plan in place");
+        std::fs::write(target_dir.join("b.png"), b"user file").expect("write existing");
+        let conflict = plan_preview_moves(vec![entry("b", dir.join("b.png"))], &target_dir)
+            .expect_err("conflict");
+        let source_kept = dir.join("b.png").is_file();
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(moves.len(), 1);
+        assert!(moved);
+        assert!(in_place.is_empty());
+        assert_eq!(conflict.code, "clipboard_storage_migrate_conflict");
+        assert!(source_kept);
+    }
+
+    #[tokio::test]
+    async fn migrate_storage_dir_moves_previews_and_database() {
+        let root =
+            std::env::temp_dir().join(format!("rtool-clipboard-migrate-{}", uuid::Uuid::new_v4()));
+        let app_dir = root.join("app");
+        let storage_dir = root.join("storage");
+        std::fs::create_dir_all(app_dir.join(CLIPBOARD_PREVIEW_DIR_NAME)).expect("create app dir");
+        std::fs::create_dir_all(&storage_dir).expect("create storage dir");
+        std::fs::write(storage_dir.join("notes.txt"), b"user file").expect("write user file");
+        let app_db_path = app_dir.join("rtool-turso.db");
+        let app_conn = db::open_db(&app_db_path).await.expect("open db");
+        db::init_db(&app_conn).await.expect("init db");
+        let settings = SettingsClipboardDto::default();
+        let service =
+            ClipboardService::new(app_conn.clone(), app_db_path.clone(), settings.clone())
+                .await
+                .expect("service");
+
+        let preview = app_dir.join(CLIPBOARD_PREVIEW_DIR_NAME).join("sig.png");
+        std::fs::write(&preview, [0u8; 8]).expect("write preview");
+        let item = crate::helpers::build_image_clipboard_item(
+            1,
+            1,
+            "sig",
+            Some(preview.to_string_lossy().to_string()),
+            None,
+            None,
+        );
+        let saved = service.save_item(item).await.expect("save").item;
+
+        let result = service
+            .migrate_storage_dir(&storage_dir, async |_| Ok(()))
+            .await
+            .expect("migrate");
+        let moved = service
+            .get_item(&saved.id)
+            .await
+            .expect("get")
+            .expect("item in new db");
+        let old_rows = db::count_clipboard_items(&app_conn).await.expect("count");
+        let reopened = ClipboardService::new(
+            app_conn.clone(),
+            app_db_path,
+            SettingsClipboardDto {
+                storage_dir: Some(storage_dir.to_string_lossy().to_string()),
+                ..settings
+            },
+        )
+        .await
+        .expect("reopen");
+        let reopened_count = reopened.item_count().await.expect("count");
+        let user_file_kept = storage_dir.join("notes.txt").is_file();
+        drop((service, reopened, app_conn));
+        let _ = std::fs::remove_dir_all(&root);
+
+        let target_preview = storage_dir
+            .join(CLIPBOARD_STORAGE_PREVIEW_DIR_NAME)
+            .join("sig.png");
+        assert_eq!(result.files_moved, 1);
+        assert!(result.db_migrated);
+        assert_eq!(
+            moved.preview_path.as_deref(),
+            Some(target_preview.to_string_lossy().as_ref())
+        );
+        assert_eq!(old_rows, 0);
+        assert_eq!(reopened_count, 1);
+        assert!(user_file_kept);
+    }
+
+    async fn migration_fixture(
+        label: &str,
+    ) -> (PathBuf, PathBuf, PathBuf, DbConn, ClipboardService) {
+        let root = std::env::temp_dir().join(format!(
+            "rtool-clipboard-migrate-{label}-{}",
+            uuid::Uuid::new_v4()
+        ));
+        let app_dir = root.join("app");
+        let storage_dir = root.join("storage");
+        std::fs::create_dir_all(app_dir.join(CLIPBOARD_PREVIEW_DIR_NAME)).expect("create app dir");
+        let app_db_path = app_dir.join("rtool-turso.db");
+        let app_conn = db::open_db(&app_db_path).await.expect("open db");
+        db::init_db(&app_conn).await.expect("init db");
+        let service = ClipboardService::new(
+            app_conn.clone(),
+            app_db_path,
+            SettingsClipboardDto::default(),
+        )
+        .await
+        .expect("service");
+        service
+            .save_text("before".to_string(), None)
+            .await
+            .expect("save before");
+        (root, app_dir, storage_dir, app_conn, service)
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn migrate_storage_dir_keeps_items_saved_while_moving() {
+        let (root, app_dir, storage_dir, app_conn, service) = migration_fixture("during").await;
+        let late_preview = app_dir.join(CLIPBOARD_PREVIEW_DIR_NAME).join("late.png");
+        std::fs::write(&late_preview, [0u8; 8]).expect("write late preview");
+        let late_item = crate::helpers::build_image_clipboard_item(
+            1,
+            1,
+            "late",
+            Some(late_preview.to_string_lossy().to_string()),
+            None,
+            None,
+        );
+
+        let mut pending = Vec::new();
+        service
+            .migrate_storage_dir(&storage_dir, async |_| {
+                let saver = service.clone();
+                pending.push(tokio::spawn(async move {
+                    saver.save_text("during".to_string(), None).await
+                }));
+                let saver = service.clone();
+                pending.push(tokio::spawn(
+                    async move { saver.save_item(late_item).await },
+                ));
+                // Give both saves time to reach the storage gate before the move finishes.
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                Ok(())
+            })
+            .await
+            .expect("migrate");
+        let mut saved = Vec::new();
+        for task in pending {
+            saved.push(task.await.expect("join").expect("save").item);
+        }
+        let mut stored = Vec::new();
+        for item in &saved {
+            stored.push(service.get_item(&item.id).await.expect("get"));
+        }
+        let new_count = service.item_count().await.expect("count");
+        let old_rows = db::count_clipboard_items(&app_conn)
+            .await
+            .expect("count old");
+        let target_preview = storage_dir
+            .join(CLIPBOARD_STORAGE_PREVIEW_DIR_NAME)
+            .join("late.png");
+        let preview_moved = target_preview.is_file() && !late_preview.exists();
+        drop((service, app_conn));
+        let _ = std::fs::remove_dir_all(&root);
+
+        assert!(stored.iter().all(Option::is_some));
+        assert_eq!(new_count, 3);
+        assert_eq!(old_rows, 0);
+        assert_eq!(
+            saved[1].preview_path.as_deref(),
+            Some(target_preview.to_string_lossy().as_ref())
+        );
+        assert!(preview_moved);
+    }
+
+    #[tokio::test]
+    async fn migrate_storage_dir_keeps_old_rows_when_persisting_fails() {
+        let (root, _app_dir, storage_dir, app_conn, service) = migration_fixture("persist").await;
+        let preview_dir_before = service.preview_dir();
+
+        let error = service
+            .migrate_storage_dir(&storage_dir, async |_| {
+                Err(AppError::new("settings_write_failed", "写入设置失败"))
+            })
+            .await
+            .expect_err("persist fails");
+        let count = service.item_count().await.expect("count");
+        let old_rows = db::count_clipboard_items(&app_conn)
+            .await
+            .expect("count old");
+        let preview_dir_after = service.preview_dir();
+        drop((service, app_conn));
+        let _ = std::fs::remove_dir_all(&root);
+
+        assert_eq!(error.code, "settings_write_failed");
+        assert_eq!(count, 1);
+        assert_eq!(old_rows, 1);
+        assert_eq!(preview_dir_after, preview_dir_before);
+    }
+
+    #[test]
+    fn orphan_previews_only_match_watcher_preview_names() {
+        let dir = temp_preview_dir("orphan");
+        let known_name = format!("{}.png", "a".repeat(64));
+        let orphan_name = format!("{}.png", "0f".repeat(32));
+        for name in [
+            known_name.as_str(),
+            orphan_name.as_str(),
+            &format!("{}.png", "A".repeat(64)),
+            &format!("{}.png", "a".repeat(63)),
+            &format!("{}.jpg", "b".repeat(64)),
+        ] {
+            std::fs::write(dir.join(name), [0u8; 4]).expect("write preview");
+        }
+        let known = HashSet::from([known_name]);
+        let orphans = find_orphan_previews(&dir, &known, Duration::ZERO);
+        let recent = find_orphan_previews(&dir, &known, Duration::from_secs(3600));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(orphans, vec![dir.join(orphan_name)]);
+        assert!(recent.is_empty());
+    }
+
+    #[test]
+    fn search_hits_report_when_candidates_are_capped() {
+        let candidate = |text: &str| {
+            let mut item = crate::helpers::build_clipboard_item(text.to_string(), None);
+            item.id = text.to_string();
+            item
+        };
+        let terms = parse_search_terms("note");
+        let candidates = || vec![candidate("note a"), candidate("other"), candidate("note b")];
+
+        let full = collect_search_hits(candidates(), &terms, 1, 3);
+        assert_eq!(full.total_count, 2);
+        assert_eq!(full.items.len(), 1);
+        assert!(!full.truncated);
+
+        let capped = collect_search_hits(candidates(), &terms, 10, 2);
+        assert_eq!(capped.total_count, 1);
+        assert_eq!(capped.items[0].item.id, "note a");
+        assert!(capped.truncated);
+    }
+
+    #[test]
+    fn missing_preview_is_flagged_for_image_items_only() {
+        let dir = temp_preview_dir("missing");
+        let preview = |name: &str| Some(dir.join(name).to_string_lossy().to_string());
+        let mut present = crate::helpers::build_image_clipboard_item(
+            1,
+            1,
+            "present",
+            preview("a.png"),
+            None,
+            None,
+        );
+        let mut missing = crate::helpers::build_image_clipboard_item(
+            1,
+            1,
+            "missing",
+            preview("gone.png"),
+            None,
+            None,
+        );
+        let mut failed =
+            crate::helpers::build_image_clipboard_item(1, 1, "failed", None, None, None);
+        let mut skipped =
+            crate::helpers::build_image_clipboard_item(1, 1, "skipped", None, None, None);
+        skipped.preview_skipped = true;
+        let mut text = build_clipboard_item("hello".to_string(), None);
+        text.preview_path = preview("gone.png");
+        for item in [
+            &mut present,
+            &mut missing,
+            &mut failed,
+            &mut skipped,
+            &mut text,
+        ] {
+            mark_missing_preview(item);
+        }
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert!(!present.preview_missing);
+        assert!(missing.preview_missing);
+        assert!(failed.preview_missing);
+        assert!(!skipped.preview_missing);
+        assert!(!text.preview_missing);
+    }
+
+    #[tokio::test]
+    async fn size_summary_counts_kinds_and_pinned_bytes() {
+        let root =
+            std::env::temp_dir().join(format!("rtool-clipboard-sizes-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&root).expect("create dir");
+        let db_path = root.join("rtool-turso.db");
+        let conn = db::open_db(&db_path).await.expect("open db");
+        db::init_db(&conn).await.expect("init db");
+        let service = ClipboardService::new(conn, db_path, SettingsClipboardDto::default())
+            .await
+            .expect("service");
+
+        let preview = root.join("sig.png");
+        std::fs::write(&preview, [0u8; 8]).expect("write preview");
+        let pinned = service
+            .save_text("hello".to_string(), None)
+            .await
+            .expect("save")
+            .item;
+        service
+            .save_text("let x = 1;".to_string(), None)
+            .await
+            .expect("save");
+        let image = crate::helpers::build_image_clipboard_item(
+            1,
+            1,
+            "sig",
+            Some(preview.to_string_lossy().to_string()),
+            None,
+            None,
+        );
+        let image = service.save_item(image).await.expect("save").item;
+        db::pin_clipboard_item(&service.conn(), &pinned.id, true, 1)
+            .await
+            .expect("pin");
+        std::fs::remove_file(&preview).expect("remove preview");
+
+        let summary = service.size_summary(Some(&root)).await.expect("summary");
+        let _ = std::fs::remove_dir_all(&root);
+
+        assert_eq!(image.content_size_bytes, Some(8));
+        assert_eq!(summary.total_items, 3);
+        assert_eq!(summary.text_items, 2);
+        assert_eq!(summary.image_items, 1);
+        assert_eq!(summary.total_size_bytes, 23);
+        assert_eq!(summary.pinned_size_bytes, 5);
+        assert!(summary.preview_dir_size_bytes > 0);
+    }
+
+    #[test]
+    fn preview_dir_usage_sums_nested_files() {
+        let dir = temp_preview_dir("sum");
+        let usage = preview_dir_usage(&dir, 4, 100);
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(
+            usage,
+            PreviewDirUsage {
+                size_bytes: 60,
+                file_count: 3,
+                truncated: false,
+            }
+        );
+    }
+
+    #[test]
+    fn preview_dir_usage_stops_at_entry_limit() {
+        let dir = temp_preview_dir("limit");
+        let usage = preview_dir_usage(&dir, 4, 2);
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert!(usage.truncated);
+        assert!(usage.file_count < 3);
+    }
+
+    #[test]
+    fn preview_dir_usage_ignores_missing_dir() {
+        let missing = std::env::temp_dir().join(format!(
+            "rtool-clipboard-preview-missing-{}",
+            uuid::Uuid::new_v4()
+        ));
+        assert_eq!(
+            preview_dir_usage(&missing, 4, 100),
+            PreviewDirUsage::default()
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD;
+use rtool_contracts::models::{ClipboardItemDto, ClipboardTextTransformDto};
+use rtool_contracts::{AppError, AppResult};
+
+pub fn transform_clipboard_text(
+    item: &ClipboardItemDto,
+    transform: ClipboardTextTransformDto,
+) -> AppResult<String> {
+    if item.item_type == "image" {
+        return Err(AppError::new(
+            "clipboard_transform_unsupported_item",
+            "图片记录不支持文本转换",
+        )
+        .with_context("itemId", item.id.clone()));
+    }
+    apply_text_transform(&item.plain_text, transform)
+}
+
+fn apply_text_transform(text: &str, transform: ClipboardTextTransformDto) -> AppResult<String> {
+    match transform {
+        ClipboardTextTransformDto::Trim => Ok(text.trim().to_string()),
+        ClipboardTextTransformDto::Lowercase => Ok(text.to_lowercase()),
+        ClipboardTextTransformDto::Uppercase => Ok(text.to_uppercase()),
+        ClipboardTextTransformDto::JsonPretty => json_pretty(text),
+        ClipboardTextTransformDto::Base64Encode => Ok(STANDARD.encode(text.as_bytes())),
+        ClipboardTextTransformDto::Base64Decode => base64_decode(text),
+        ClipboardTextTransformDto::UrlEncode => Ok(url_encode(text)),
+        ClipboardTextTransformDto::UrlDecode => url_decode(text),
+    }
+}
+
+fn json_pretty(text: &str) -> AppResult<String> {
+    let value: serde_json::Value = serde_json::from_str(text.trim()).map_err(|error| {
+        AppError::new("clipboard_transform_invalid_json", "内容不是有效的 JSON")
+            .with_context("line", error.line().to_string())
+            .with_context("column", error.column().to_string())
+            .with_source(error)
+    })?;
+    serde_json::to_string_pretty(&value).map_err(|error| {
+        AppError::new("clipboard_transform_invalid_json", "内容不是有效的 JSON").with_source(error)
+    })
+}
+
+fn base64_decode(text: &str) -> AppResult<String> {
+    let compact: String = text.chars().filter(|ch| !ch.is_whitespace()).collect();
+    let bytes = STANDARD.decode(compact).map_err(|error| {
+        AppError::new(
+            "clipboard_transform_invalid_base64",
+            "内容不是有效的 Base64",
+        )
+        .with_source(error)
+    })?;
+    String::from_utf8(bytes).map_err(|error| {
+        AppError::new(
+            "clipboard_transform_invalid_utf8",
+            "解码结果不是有效的 UTF-8 文本",
+        )
+        .with_source(error)
+    })
+}
+
+fn url_encode(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            encoded.push(char::from(byte));
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+fn url_decode(text: &str) -> AppResult<String> {
+    let invalid = || AppError::new("clipboard_transform_invalid_url", "内容不是有效的 URL 编码");
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'%' => {
+                let hex = text.get(index + 1..index + 3).ok_or_else(invalid)?;
+                let byte = u8::from_str_radix(hex, 16).map_err(|_| invalid())?;
+                decoded.push(byte);
+                index += 3;
+            }
+            b'+' => {
+                decoded.push(b' ');
+                index += 1;
+            }
+            byte => {
+                decoded.push(byte);
+                index += 1;
+            }
+        }
+    }
+    String::from_utf8(decoded).map_err(|error| invalid().with_source(error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_transforms_round_trip() {
+        let text = "  Hello, 世界 a+b=c  ";
+        let encoded =
+            apply_text_transform(text, ClipboardTextTransformDto::UrlEncode).expect("url encode");
+        assert_eq!(
+            encoded,
+            "%20%20Hello%2C%20%E4%B8%96%E7%95%8C%20a%2Bb%3Dc%20%20"
+        );
+        assert_eq!(
+            apply_text_transform(&encoded, ClipboardTextTransformDto::UrlDecode)
+                .expect("url decode"),
+            text
+        );
+
+        let base64 = apply_text_transform(text, ClipboardTextTransformDto::Base64Encode)
+            .expect("base64 encode");
+        assert_eq!(
+            apply_text_transform(&base64, ClipboardTextTransformDto::Base64Decode)
+                .expect("base64 decode"),
+            text
+        );
+
+        assert_eq!(
+            apply_text_transform(text, ClipboardTextTransformDto::Trim).expect("trim"),
+            "Hello, 世界 a+b=c"
+        );
+        assert_eq!(
+            apply_text_transform("MiXeD", ClipboardTextTransformDto::Lowercase).expect("lowercase"),
+            "mixed"
+        );
+    }
+
+    #[test]
+    fn json_pretty_formats_or_reports_invalid_json() {
+        let pretty = apply_text_transform(r#"{"a":[1,2]}"#, ClipboardTextTransformDto::JsonPretty)
+            .expect("valid json");
+        assert_eq!(pretty, "{\n  \"a\": [\n    1,\n    2\n  ]\n}");
+
+        let error = apply_text_transform("{oops", ClipboardTextTransformDto::JsonPretty)
+            .expect_err("invalid json");
+        assert_eq!(error.code, "clipboard_transform_invalid_json");
+    }
+
+    #[test]
+    fn malformed_escapes_are_rejected() {
+        let error = apply_text_transform("100%", ClipboardTextTransformDto::UrlDecode)
+            .expect_err("truncated escape");
+        assert_eq!(error.code, "clipboard_transform_invalid_url");
+
+        let error = apply_text_transform("not base64!", ClipboardTextTransformDto::Base64Decode)
+            .expect_err("invalid base64");
+        assert_eq!(error.code, "clipboard_transform_invalid_base64");
+    }
+}
@@ -1,11 +1,13 @@
 use regex::Regex;
 use rtool_contracts::clipboard_key::derive_content_key;
-use rtool_contracts::models::ClipboardItemDto;
+use rtool_contracts::models::{ClipboardItemDetailDto, ClipboardItemDto};
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 use std::path::Path;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+pub const IMAGE_DHASH_MAX_DISTANCE: u32 = 4;
+
 fn now_millis() -> i64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -126,6 +128,65 @@ pub fn classify_text(text: &str) -> String {
     "text".to_string()
 }
 
+pub fn build_clipboard_item_detail(item: ClipboardItemDto) -> ClipboardItemDetailDto {
+    let char_count = item.plain_text.chars().count() as u32;
+    let line_count = item.plain_text.lines().count() as u32;
+    let file_count = if item.item_type == "file" {
+        item.plain_text
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .count() as u32
+    } else {
+        0
+    };
+    ClipboardItemDetailDto {
+        item,
+        char_count,
+        line_count,
+        file_count,
+    }
+}
+
+/// Text bytes, the preview PNG size for images, or the summed sizes of a file
+/// list when every listed path exists.
+pub fn clipboard_content_size_bytes(item: &ClipboardItemDto) -> Option<u64> {
+    match item.item_type.as_str() {
+        "image" => item
+            .preview_path
+            .as_deref()
+            .and_then(|path| std::fs::metadata(path).ok())
+            .filter(|metadata| metadata.is_file())
+            .map(|metadata| metadata.len()),
+        "file" => parse_file_paths_from_text(item.plain_text.trim())?
+            .iter()
+            .map(|path| std::fs::metadata(path).ok().map(|metadata| metadata.len()))
+            .sum(),
+        _ => Some(item.plain_text.len() as u64),
+    }
+}
+
+/// Fills preview and original dimensions for image items. The original size comes
+/// from the `[图片] W x H` label written at capture time, the preview size from the
+/// stored file's header.
+pub fn apply_clipboard_image_dimensions(item: &mut ClipboardItemDto) {
+    if item.item_type != "image" {
+        return;
+    }
+    let original = parse_image_label_dimensions(item.plain_text.as_str());
+    let preview = item
+        .preview_path
+        .as_deref()
+        .and_then(|path| image::image_dimensions(path).ok())
+        .map(|(width, height)| (width as usize, height as usize));
+    (item.width, item.height) = preview.or(original).unzip();
+    (item.original_width, item.original_height) = original.or(preview).unzip();
+}
+
+fn parse_image_label_dimensions(text: &str) -> Option<(usize, usize)> {
+    let (width, height) = text.strip_prefix("[图片]")?.split_once('x')?;
+    Some((width.trim().parse().ok()?, height.trim().parse().ok()?))
+}
+
 pub fn build_clipboard_item(text: String, source_app: Option<String>) -> ClipboardItemDto {
     let created_at = now_millis();
     let item_type = classify_text(&text);
@@ -144,6 +205,14 @@ pub fn build_clipboard_item(text: String, source_app: Option<String>) -> Clipboa
         preview_data_url: None,
         created_at,
         pinned: false,
+        pin_order: 0,
+        preview_missing: false,
+        preview_skipped: false,
+        content_size_bytes: None,
+        width: None,
+        height: None,
+        original_width: None,
+        original_height: None,
     }
 }
 
@@ -170,5 +239,176 @@ pub fn build_image_clipboard_item(
         preview_data_url,
         created_at,
         pinned: false,
+        pin_order: 0,
+        preview_missing: false,
+        preview_skipped: false,
+        content_size_bytes: None,
+        width: None,
+        height: None,
+        original_width: None,
+        original_height: None,
+    }
+}
+
+/// Difference hash over a 9x8 grayscale thumbnail, encoded as 16 hex digits.
+pub fn compute_image_dhash(bytes: &[u8]) -> Option<String> {
+    let thumbnail = image::load_from_memory(bytes)
+        .ok()?
+        .thumbnail_exact(9, 8)
+        .to_luma8();
+    let mut hash = 0u64;
+    for y in 0..8 {
+        for x in 0..8 {
+            hash <<= 1;
+            if thumbnail.get_pixel(x, y)[0] < thumbnail.get_pixel(x + 1, y)[0] {
+                hash |= 1;
+            }
+        }
+    }
+    Some(format!("{hash:016x}"))
+}
+
+pub fn image_dhash_distance(left: &str, right: &str) -> Option<u32> {
+    let left = u64::from_str_radix(left, 16).ok()?;
+    let right = u64::from_str_radix(right, 16).ok()?;
+    Some((left ^ right).count_ones())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::RgbImage;
+    use image::codecs::png::{CompressionType, FilterType, PngEncoder};
+
+    fn encode_png(image: &RgbImage, compression: CompressionType) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        image
+            .write_with_encoder(PngEncoder::new_with_quality(
+                &mut bytes,
+                compression,
+                FilterType::Adaptive,
+            ))
+            .expect("encode png");
+        bytes
+    }
+
+    fn gradient(width: u32, height: u32, invert: bool) -> RgbImage {
+        RgbImage::from_fn(width, height, |x, _| {
+            let value = (x * 255 / width.max(1)) as u8;
+            let value = if invert { 255 - value } else { value };
+            image::Rgb([value, value, value])
+        })
+    }
+
+    #[test]
+    fn dhash_matches_reencoded_image_and_separates_different_content() {
+        let source = gradient(64, 48, false);
+        let fast = encode_png(&source, CompressionType::Fast);
+        let best = encode_png(&source, CompressionType::Best);
+        let inverted = encode_png(&gradient(64, 48, true), CompressionType::Fast);
+        assert_ne!(fast, best);
+
+        let fast_hash = compute_image_dhash(&fast).expect("hash fast");
+        let best_hash = compute_image_dhash(&best).expect("hash best");
+        let inverted_hash = compute_image_dhash(&inverted).expect("hash inverted");
+
+        assert_eq!(image_dhash_distance(&fast_hash, &best_hash), Some(0));
+        assert!(
+            image_dhash_distance(&fast_hash, &inverted_hash).expect("distance")
+                > IMAGE_DHASH_MAX_DISTANCE
+        );
+        assert!(compute_image_dhash(b"not an image").is_none());
+    }
+
+    #[test]
+    fn content_size_covers_text_images_and_file_lists() {
+        let dir =
+            std::env::temp_dir().join(format!("rtool-clipboard-size-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).expect("create temp dir");
+        let png = dir.join("preview.png");
+        let doc = dir.join("doc.txt");
+        std::fs::write(&png, [0u8; 64]).expect("write preview");
+        std::fs::write(&doc, [0u8; 10]).expect("write doc");
+
+        let text = build_clipboard_item("第一行".to_string(), None);
+        let image = build_image_clipboard_item(
+            1,
+            1,
+            "sig",
+            Some(png.to_string_lossy().to_string()),
+            None,
+            None,
+        );
+        let mut files = build_clipboard_item(String::new(), None);
+        files.item_type = "file".to_string();
+        files.plain_text = format!("{}\n{}", doc.display(), png.display());
+        let mut partial = files.clone();
+        partial.plain_text = format!("{}\n{}", doc.display(), dir.join("gone.txt").display());
+
+        let sizes = [&text, &image, &files, &partial].map(clipboard_content_size_bytes);
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(sizes, [Some(9), Some(64), Some(74), None]);
+    }
+
+    #[test]
+    fn image_dimensions_keep_original_size_for_downscaled_previews() {
+        let dir =
+            std::env::temp_dir().join(format!("rtool-clipboard-dims-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).expect("create temp dir");
+        let png = dir.join("preview.png");
+        std::fs::write(
+            &png,
+            encode_png(&gradient(40, 30, false), CompressionType::Fast),
+        )
+        .expect("write preview");
+
+        let mut downscaled = build_image_clipboard_item(
+            400,
+            300,
+            "sig",
+            Some(png.to_string_lossy().to_string()),
+            None,
+            None,
+        );
+        apply_clipboard_image_dimensions(&mut downscaled);
+        let mut missing = build_image_clipboard_item(
+            400,
+            300,
+            "sig",
+            Some(dir.join("gone.png").to_string_lossy().to_string()),
+            None,
+            None,
+        );
+        apply_clipboard_image_dimensions(&mut missing);
+        let mut text = build_clipboard_item("[图片] 1 x 1".to_string(), None);
+        apply_clipboard_image_dimensions(&mut text);
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!((downscaled.width, downscaled.height), (Some(40), Some(30)));
+        assert_eq!(
+            (downscaled.original_width, downscaled.original_height),
+            (Some(400), Some(300))
+        );
+        assert_eq!(
+            (missing.width, missing.original_width),
+            (Some(400), Some(400))
+        );
+        assert_eq!((text.width, text.original_width), (None, None));
+    }
+
+    #[test]
+    fn item_detail_counts_chars_lines_and_files() {
+        let mut item = build_clipboard_item("第一行\nsecond".to_string(), None);
+        let detail = build_clipboard_item_detail(item.clone());
+        assert_eq!(detail.char_count, 10);
+        assert_eq!(detail.line_count, 2);
+        assert_eq!(detail.file_count, 0);
+
+        item.item_type = "file".to_string();
+        item.plain_text = "/tmp/a.txt\n\n/tmp/b.txt\n".to_string();
+        let detail = build_clipboard_item_detail(item);
+        assert_eq!(detail.line_count, 3);
+        assert_eq!(detail.file_count, 2);
     }
 }
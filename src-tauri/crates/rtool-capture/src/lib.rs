@@ -1,16 +1,25 @@
 pub mod helpers;
 
+#[path = "blocking.rs"]
+mod blocking;
+
+#[path = "clipboard_search.rs"]
+mod clipboard_search;
 #[path = "clipboard_service.rs"]
 mod clipboard_service;
+#[path = "clipboard_transform.rs"]
+mod clipboard_transform;
 
 pub mod service {
     pub use super::clipboard_service::{
         CLIPBOARD_MAX_ITEMS_DEFAULT, CLIPBOARD_MAX_ITEMS_MAX, CLIPBOARD_MAX_ITEMS_MIN,
-        CLIPBOARD_MAX_TOTAL_SIZE_MB_DEFAULT, CLIPBOARD_MAX_TOTAL_SIZE_MB_MAX,
-        CLIPBOARD_MAX_TOTAL_SIZE_MB_MIN, CLIPBOARD_MIN_FREE_DISK_BYTES,
-        CLIPBOARD_SIZE_CLEANUP_ENABLED_DEFAULT, ClipboardSaveResult, ClipboardService,
-        ClipboardSettingsUpdateResult,
+        CLIPBOARD_MAX_PINNED_ITEMS_DEFAULT, CLIPBOARD_MAX_PINNED_ITEMS_MAX,
+        CLIPBOARD_MAX_PINNED_ITEMS_MIN, CLIPBOARD_MAX_TOTAL_SIZE_MB_DEFAULT,
+        CLIPBOARD_MAX_TOTAL_SIZE_MB_MAX, CLIPBOARD_MAX_TOTAL_SIZE_MB_MIN,
+        CLIPBOARD_MIN_FREE_DISK_BYTES, CLIPBOARD_SIZE_CLEANUP_ENABLED_DEFAULT, ClipboardPinResult,
+        ClipboardSaveResult, ClipboardService, ClipboardSettingsUpdateResult,
     };
+    pub use super::clipboard_transform::transform_clipboard_text;
 }
 
 #[path = "screenshot.rs"]
@@ -33,7 +33,7 @@ pub struct AppError(Box<AppErrorPayload>);
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct InvokeError {
+pub struct InvokeErrorPayload {
     pub code: String,
     pub message: String,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -41,31 +41,43 @@ pub struct InvokeError {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub causes: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub cause_summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub request_id: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct InvokeError(Box<InvokeErrorPayload>);
+
 impl InvokeError {
     pub fn with_request_id(mut self, request_id: impl Into<String>) -> Self {
         let request_id = request_id.into();
         if !request_id.trim().is_empty() {
-            self.request_id = Some(request_id);
+            self.0.request_id = Some(request_id);
         }
         self
     }
 
+    pub fn with_cause_summary(mut self, summary: Option<String>) -> Self {
+        self.0.cause_summary = summary.filter(|value| !value.trim().is_empty());
+        self
+    }
+
     pub fn from_anyhow(error: anyhow::Error) -> Self {
         if let Some(app_error) = error.downcast_ref::<AppError>() {
             return Self::from(app_error.clone());
         }
 
         let causes = visible_causes_for_mode(collect_error_chain(&error));
-        Self {
+        Self(Box::new(InvokeErrorPayload {
             code: DEFAULT_CODE.to_string(),
             message: DEFAULT_MESSAGE.to_string(),
             context: Vec::new(),
             causes,
+            cause_summary: None,
             request_id: None,
-        }
+        }))
     }
 }
 
@@ -199,6 +211,20 @@ impl DerefMut for AppError {
     }
 }
 
+impl Deref for InvokeError {
+    type Target = InvokeErrorPayload;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.as_ref()
+    }
+}
+
+impl DerefMut for InvokeError {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.0.as_mut()
+    }
+}
+
 fn collect_error_chain(error: &anyhow::Error) -> Vec<String> {
     let mut causes = Vec::new();
     for cause in error.chain() {
@@ -303,13 +329,14 @@ impl From<AppError> for InvokeError {
             request_id,
         } = *value.0;
 
-        Self {
+        Self(Box::new(InvokeErrorPayload {
             code,
             message,
             context,
             causes: visible_causes_for_mode(causes),
+            cause_summary: None,
             request_id,
-        }
+        }))
     }
 }
 
@@ -325,13 +352,14 @@ impl From<&anyhow::Error> for InvokeError {
             return Self::from(app_error.clone());
         }
 
-        Self {
+        Self(Box::new(InvokeErrorPayload {
             code: DEFAULT_CODE.to_string(),
             message: DEFAULT_MESSAGE.to_string(),
             context: Vec::new(),
             causes: visible_causes_for_mode(collect_error_chain(value)),
+            cause_summary: None,
             request_id: None,
-        }
+        }))
     }
 }
 
@@ -2,4 +2,7 @@ pub mod clipboard_key;
 mod errors;
 pub mod models;
 
-pub use errors::{AppError, AppErrorPayload, AppResult, ErrorContextItem, InvokeError, ResultExt};
+pub use errors::{
+    AppError, AppErrorPayload, AppResult, ErrorContextItem, InvokeError, InvokeErrorPayload,
+    ResultExt,
+};
@@ -16,6 +16,9 @@ pub struct SettingsDto {
     pub locale: LocaleSettingsDto,
     pub clipboard: SettingsClipboardDto,
     pub screenshot: SettingsScreenshotDto,
+    pub tray: TraySettingsDto,
+    pub window_auto_hide: WindowAutoHideSettingsDto,
+    pub app_manager: AppManagerSettingsDto,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -49,15 +52,57 @@ impl Default for LayoutSettingsDto {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all = "camelCase", default)]
 pub struct LocaleSettingsDto {
     pub preference: String,
+    pub byte_unit: String,
 }
 
 impl Default for LocaleSettingsDto {
     fn default() -> Self {
         Self {
             preference: "system".to_string(),
+            byte_unit: "decimal".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TraySettingsDto {
+    pub live_tooltip: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct WindowAutoHideSettingsDto {
+    pub launcher_enabled: bool,
+    pub launcher_delay_ms: u32,
+    pub clipboard_enabled: bool,
+    pub clipboard_delay_ms: u32,
+}
+
+impl Default for WindowAutoHideSettingsDto {
+    fn default() -> Self {
+        Self {
+            launcher_enabled: true,
+            launcher_delay_ms: 80,
+            clipboard_enabled: false,
+            clipboard_delay_ms: 150,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AppManagerSettingsDto {
+    pub default_delete_mode: AppManagerCleanupDeleteMode,
+}
+
+impl Default for AppManagerSettingsDto {
+    fn default() -> Self {
+        Self {
+            default_delete_mode: AppManagerCleanupDeleteMode::Trash,
         }
     }
 }
@@ -70,6 +115,9 @@ pub struct SettingsUpdateInputDto {
     pub locale: Option<LocaleSettingsUpdateInputDto>,
     pub clipboard: Option<SettingsClipboardUpdateInputDto>,
     pub screenshot: Option<SettingsScreenshotUpdateInputDto>,
+    pub tray: Option<TraySettingsUpdateInputDto>,
+    pub window_auto_hide: Option<WindowAutoHideSettingsUpdateInputDto>,
+    pub app_manager: Option<AppManagerSettingsUpdateInputDto>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -89,22 +137,150 @@ pub struct LayoutSettingsUpdateInputDto {
 #[serde(rename_all = "camelCase", default)]
 pub struct LocaleSettingsUpdateInputDto {
     pub preference: Option<String>,
+    pub byte_unit: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppExportLocaleResultDto {
+    pub file_path: String,
+    pub key_count: u32,
+    pub locale: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+pub struct AppImportLocaleResultDto {
+    pub locale: String,
+    pub namespace: String,
+    pub overlay_path: String,
+    pub imported_count: u32,
+    pub skipped_count: u32,
+    pub invalid_count: u32,
+    pub skipped_keys: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct I18nLocaleStatsDto {
+    pub locale: String,
+    pub total_keys: u32,
+    pub translated_keys: u32,
+    pub missing_keys: u32,
+    pub empty_value_keys: u32,
+    pub completeness_percent: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TraySettingsUpdateInputDto {
+    pub live_tooltip: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct WindowAutoHideSettingsUpdateInputDto {
+    pub launcher_enabled: Option<bool>,
+    pub launcher_delay_ms: Option<u32>,
+    pub clipboard_enabled: Option<bool>,
+    pub clipboard_delay_ms: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AppManagerSettingsUpdateInputDto {
+    pub default_delete_mode: Option<AppManagerCleanupDeleteMode>,
+}
+
+pub const CLIPBOARD_SETTINGS_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct SettingsClipboardDto {
+    #[serde(default)]
+    pub settings_version: u32,
     pub max_items: u32,
     pub size_cleanup_enabled: bool,
     pub max_total_size_mb: u32,
+    pub paste_after_copy: bool,
+    pub capture_text: bool,
+    pub capture_images: bool,
+    pub capture_files: bool,
+    pub perceptual_deduplicate: bool,
+    #[serde(default)]
+    pub storage_dir: Option<String>,
+    #[serde(default)]
+    pub image_preview_max_width: Option<u32>,
+    #[serde(default)]
+    pub image_preview_max_height: Option<u32>,
+    /// Images with more pixels than this are recorded without a preview file.
+    #[serde(default)]
+    pub max_preview_pixels: Option<u64>,
+    #[serde(default)]
+    pub preview_failure_policy: ClipboardPreviewFailurePolicy,
+    #[serde(default)]
+    pub compact_content_filter: ClipboardWindowContentFilter,
+    #[serde(default)]
+    pub regular_content_filter: ClipboardWindowContentFilter,
+    pub max_pinned_items: u32,
 }
 
 impl Default for SettingsClipboardDto {
     fn default() -> Self {
         Self {
+            settings_version: CLIPBOARD_SETTINGS_VERSION,
             max_items: 1000,
             size_cleanup_enabled: true,
             max_total_size_mb: 500,
+            paste_after_copy: false,
+            capture_text: true,
+            capture_images: true,
+            capture_files: true,
+            perceptual_deduplicate: false,
+            storage_dir: None,
+            image_preview_max_width: None,
+            image_preview_max_height: None,
+            max_preview_pixels: None,
+            preview_failure_policy: ClipboardPreviewFailurePolicy::KeepWithoutPreview,
+            compact_content_filter: ClipboardWindowContentFilter::All,
+            regular_content_filter: ClipboardWindowContentFilter::All,
+            max_pinned_items: 100,
+        }
+    }
+}
+
+/// What the watcher does with a captured image whose preview could not be written.
+/// Kept items are listed with `preview_missing`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ClipboardPreviewFailurePolicy {
+    #[default]
+    KeepWithoutPreview,
+    SkipItem,
+}
+
+/// Which item kinds a clipboard window mode lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ClipboardWindowContentFilter {
+    #[default]
+    All,
+    TextOnly,
+}
+
+impl ClipboardWindowContentFilter {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::All => "all",
+            Self::TextOnly => "text_only",
+        }
+    }
+
+    /// `TextOnly` hides image and file items; every text-like kind stays visible.
+    pub fn allows(self, item_type: &str) -> bool {
+        match self {
+            Self::All => true,
+            Self::TextOnly => !matches!(item_type, "image" | "file"),
         }
     }
 }
@@ -115,6 +291,21 @@ pub struct SettingsClipboardUpdateInputDto {
     pub max_items: Option<u32>,
     pub size_cleanup_enabled: Option<bool>,
     pub max_total_size_mb: Option<u32>,
+    pub paste_after_copy: Option<bool>,
+    pub capture_text: Option<bool>,
+    pub capture_images: Option<bool>,
+    pub capture_files: Option<bool>,
+    pub perceptual_deduplicate: Option<bool>,
+    /// `0` clears the limit.
+    pub image_preview_max_width: Option<u32>,
+    /// `0` clears the limit.
+    pub image_preview_max_height: Option<u32>,
+    /// `0` clears the limit.
+    pub max_preview_pixels: Option<u64>,
+    pub preview_failure_policy: Option<ClipboardPreviewFailurePolicy>,
+    pub compact_content_filter: Option<ClipboardWindowContentFilter>,
+    pub regular_content_filter: Option<ClipboardWindowContentFilter>,
+    pub max_pinned_items: Option<u32>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -149,6 +340,68 @@ pub struct SettingsScreenshotUpdateInputDto {
     pub pin_max_instances: Option<u32>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SettingsBundleDto {
+    pub version: u32,
+    pub exported_at: i64,
+    pub theme: Option<Value>,
+    pub layout: Option<Value>,
+    pub locale: Option<Value>,
+    pub clipboard: Option<Value>,
+    pub screenshot: Option<Value>,
+    pub logging: Option<Value>,
+    pub launcher_search: Option<Value>,
+    pub tray: Option<Value>,
+    pub window_auto_hide: Option<Value>,
+    pub app_manager: Option<Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsExportResultDto {
+    pub file_path: String,
+    pub sections: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsImportSectionResultDto {
+    pub section: String,
+    pub ok: bool,
+    pub error_code: Option<String>,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsImportResultDto {
+    pub sections: Vec<SettingsImportSectionResultDto>,
+    pub settings: SettingsDto,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DbMaintenanceResultDto {
+    pub size_before_bytes: u64,
+    pub size_after_bytes: u64,
+    pub reclaimed_bytes: u64,
+    pub duration_ms: u64,
+}
+
+/// Registration state of one global shortcut. `reason` is `invalid_accelerator`,
+/// `dropped_by_fallback` or `not_registered` (usually taken by another app).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShortcutStatusDto {
+    pub action: String,
+    pub accelerator: String,
+    pub registered: bool,
+    pub fell_back: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 #[allow(clippy::enum_variant_names)]
@@ -173,6 +426,30 @@ pub enum LauncherActionDto {
     OpenApplication {
         path: String,
     },
+    CopyClipboardItem {
+        #[serde(rename = "itemId")]
+        item_id: String,
+    },
+}
+
+impl LauncherActionDto {
+    /// The window a builtin action shows; only these actions support launcher undo.
+    pub fn builtin_window_label(&self) -> Option<&str> {
+        match self {
+            Self::OpenBuiltinRoute { .. } | Self::OpenBuiltinTool { .. } => Some("main"),
+            Self::OpenBuiltinWindow { window_label } => Some(window_label.as_str()),
+            _ => None,
+        }
+    }
+
+    /// The main window route a builtin route or tool action navigates to.
+    pub fn builtin_route(&self) -> Option<String> {
+        match self {
+            Self::OpenBuiltinRoute { route } => Some(route.clone()),
+            Self::OpenBuiltinTool { tool_id } => Some(format!("/tools/{tool_id}")),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -191,6 +468,28 @@ pub struct LauncherItemDto {
     pub icon_kind: String,
     pub icon_value: String,
     pub action: LauncherActionDto,
+    #[serde(default)]
+    pub undoable: bool,
+    /// Character ranges of `title` that the matcher scored, in ascending order.
+    #[serde(default)]
+    pub title_match_ranges: Vec<LauncherMatchRangeDto>,
+    #[serde(default)]
+    pub matched_via_alias: bool,
+}
+
+/// Half-open `[start, end)` range counted in Unicode scalar values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LauncherMatchRangeDto {
+    pub start: u32,
+    pub end: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LauncherUndoResultDto {
+    pub undone_action_id: String,
+    pub message: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -285,6 +584,10 @@ pub struct AppManagerQueryDto {
     pub category: AppManagerCategory,
     pub limit: Option<u32>,
     pub cursor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_accuracy: Option<AppManagerSizeAccuracyMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub folder_filter: Option<String>,
 }
 
 impl Default for AppManagerQueryDto {
@@ -294,6 +597,8 @@ impl Default for AppManagerQueryDto {
             category: AppManagerCategory::All,
             limit: Some(100),
             cursor: None,
+            size_accuracy: None,
+            folder_filter: None,
         }
     }
 }
@@ -305,6 +610,12 @@ pub struct AppManagerSnapshotMetaDto {
     pub revision: u64,
     pub total_count: u64,
     pub index_state: AppManagerIndexState,
+    #[serde(default)]
+    pub source_fingerprint: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build_duration_ms: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -386,6 +697,27 @@ pub enum AppManagerResidueKind {
 }
 
 impl AppManagerResidueKind {
+    pub const ALL: [Self; 18] = [
+        Self::Install,
+        Self::AppSupport,
+        Self::Cache,
+        Self::Preferences,
+        Self::Logs,
+        Self::Startup,
+        Self::AppScript,
+        Self::Container,
+        Self::GroupContainer,
+        Self::SavedState,
+        Self::WebkitData,
+        Self::LaunchAgent,
+        Self::LaunchDaemon,
+        Self::HelperTool,
+        Self::AppData,
+        Self::RegistryKey,
+        Self::RegistryValue,
+        Self::MainApp,
+    ];
+
     pub fn as_str(self) -> &'static str {
         match self {
             Self::Install => "install",
@@ -408,6 +740,35 @@ impl AppManagerResidueKind {
             Self::MainApp => "main_app",
         }
     }
+
+    /// Kinds that can be removed without breaking the installed app.
+    pub fn is_cleanable(self) -> bool {
+        matches!(
+            self,
+            Self::Cache | Self::Logs | Self::Startup | Self::AppSupport
+        )
+    }
+}
+
+impl std::fmt::Display for AppManagerResidueKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for AppManagerResidueKind {
+    type Err = crate::AppError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let normalized = value.trim();
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.as_str() == normalized)
+            .ok_or_else(|| {
+                crate::AppError::new("app_manager_residue_kind_invalid", "未知的残留类型")
+                    .with_context("kind", normalized)
+            })
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -476,6 +837,26 @@ impl AppManagerSizeAccuracy {
     }
 }
 
+/// Walk budget requested when measuring app sizes. `Exact` walks the whole
+/// bundle and may take several seconds for large apps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AppManagerSizeAccuracyMode {
+    Quick,
+    Standard,
+    Exact,
+}
+
+impl AppManagerSizeAccuracyMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Quick => "quick",
+            Self::Standard => "standard",
+            Self::Exact => "exact",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum AppManagerSizeSource {
@@ -598,6 +979,25 @@ pub enum AppManagerUninstallKind {
     RegistryCommand,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AppManagerUninstallHelpTarget {
+    RegisteredUninstaller,
+    AppsAndFeatures,
+    FinderReveal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AppManagerPackageManager {
+    Winget,
+    Scoop,
+    Chocolatey,
+    Msi,
+    Inno,
+    Nsis,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum AppManagerResidueMatchReason {
@@ -612,6 +1012,7 @@ pub enum AppManagerResidueMatchReason {
     UninstallRegistry,
     StartupRegistry,
     RunRegistry,
+    UserSoftwareRegistry,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -636,6 +1037,8 @@ pub struct ManagedAppDto {
     pub size_source: AppManagerSizeSource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub size_computed_at: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size_accuracy_used: Option<AppManagerSizeAccuracyMode>,
     pub startup_enabled: bool,
     pub startup_scope: AppManagerStartupScope,
     pub startup_editable: bool,
@@ -644,6 +1047,14 @@ pub struct ManagedAppDto {
     pub uninstall_supported: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub uninstall_kind: Option<AppManagerUninstallKind>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub package_manager: Option<AppManagerPackageManager>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub package_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub folder_path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub folder_name: Option<String>,
     pub capabilities: AppManagerCapabilitiesDto,
     pub identity: AppManagerIdentityDto,
     pub risk_level: AppManagerRiskLevel,
@@ -699,6 +1110,8 @@ pub struct AppManagerUninstallInputDto {
 #[serde(rename_all = "camelCase")]
 pub struct AppManagerDetailQueryDto {
     pub app_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size_accuracy: Option<AppManagerSizeAccuracyMode>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -712,6 +1125,7 @@ pub struct AppRelatedRootDto {
     pub kind: AppManagerResidueKind,
     pub exists: bool,
     pub readonly: bool,
+    pub cleanable: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub readonly_reason_code: Option<AppReadonlyReasonCode>,
 }
@@ -722,6 +1136,8 @@ pub struct AppSizeSummaryDto {
     pub app_bytes: Option<u64>,
     pub residue_bytes: Option<u64>,
     pub total_bytes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size_accuracy_used: Option<AppManagerSizeAccuracyMode>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -741,6 +1157,14 @@ pub struct AppManagerResidueScanInputDto {
     pub mode: Option<AppManagerResidueScanMode>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppManagerScanPathInputDto {
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<AppManagerResidueScanMode>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum AppManagerResidueScanMode {
@@ -752,6 +1176,10 @@ pub enum AppManagerResidueScanMode {
 #[serde(rename_all = "camelCase")]
 pub struct AppManagerResolveSizesInputDto {
     pub app_ids: Vec<String>,
+    #[serde(default)]
+    pub cancel_token: Option<String>,
+    #[serde(default)]
+    pub parallelism: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -763,12 +1191,17 @@ pub struct AppManagerResolvedSizeDto {
     pub size_source: AppManagerSizeSource,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub size_computed_at: Option<i64>,
+    /// The walk was cancelled part-way; `size_bytes` is the partial total.
+    #[serde(default)]
+    pub cancelled: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AppManagerResolveSizesResultDto {
     pub items: Vec<AppManagerResolvedSizeDto>,
+    #[serde(default)]
+    pub cancelled: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -788,6 +1221,10 @@ pub struct AppManagerResidueItemDto {
     pub readonly: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub readonly_reason_code: Option<AppReadonlyReasonCode>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub annotation: Option<String>,
+    #[serde(default)]
+    pub user_skip: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -798,6 +1235,8 @@ pub struct AppManagerResidueGroupDto {
     pub scope: AppManagerScope,
     pub kind: AppManagerResidueKind,
     pub total_size_bytes: u64,
+    pub total_items: u32,
+    pub recommended_items: u32,
     pub items: Vec<AppManagerResidueItemDto>,
 }
 
@@ -867,11 +1306,14 @@ pub struct AppManagerResidueScanResultDto {
 pub struct AppManagerCleanupInputDto {
     pub app_id: String,
     pub selected_item_ids: Vec<String>,
-    pub delete_mode: AppManagerCleanupDeleteMode,
+    #[serde(default)]
+    pub delete_mode: Option<AppManagerCleanupDeleteMode>,
     pub include_main_app: bool,
     pub skip_on_error: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub confirmed_fingerprint: Option<String>,
+    #[serde(default)]
+    pub confirmed_item_ids: Vec<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -905,6 +1347,8 @@ pub enum AppManagerCleanupReasonCode {
     SelfUninstallForbidden,
     ManagedByPolicy,
     NotFound,
+    UserSkip,
+    RequiresConfirmation,
     AppManagerCleanupDeleteFailed,
     AppManagerCleanupNotFound,
     AppManagerCleanupPathInvalid,
@@ -949,10 +1393,54 @@ pub struct AppManagerCleanupResultDto {
     pub failed: Vec<AppManagerCleanupItemResultDto>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppManagerAnnotateResidueInputDto {
+    pub app_id: String,
+    pub item_id: String,
+    pub note: String,
+    pub skip: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AppManagerExportScanInputDto {
     pub app_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<AppManagerExportFormat>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AppManagerExportFormat {
+    #[default]
+    Json,
+    Markdown,
+    Html,
+}
+
+impl AppManagerExportFormat {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Markdown => "markdown",
+            Self::Html => "html",
+        }
+    }
+
+    pub fn file_extension(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Markdown => "md",
+            Self::Html => "html",
+        }
+    }
+
+    pub fn from_file_extension(extension: &str) -> Option<Self> {
+        [Self::Json, Self::Markdown, Self::Html]
+            .into_iter()
+            .find(|format| extension.eq_ignore_ascii_case(format.file_extension()))
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -961,6 +1449,42 @@ pub struct AppManagerExportScanResultDto {
     pub app_id: String,
     pub file_path: String,
     pub directory_path: String,
+    pub format_used: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppManagerExportFileDto {
+    pub file_name: String,
+    pub file_path: String,
+    pub format: AppManagerExportFormat,
+    pub size_bytes: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified_at: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppManagerExportListDto {
+    pub directory_path: String,
+    pub files: Vec<AppManagerExportFileDto>,
+    pub total_bytes: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppManagerClearExportsInputDto {
+    /// Only delete exports last modified at least this many days ago; `None` deletes all.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub older_than_days: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppManagerClearExportsResultDto {
+    pub deleted_count: u32,
+    pub failed_count: u32,
+    pub reclaimed_bytes: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -971,6 +1495,78 @@ pub struct AppManagerActionResultDto {
     pub message: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub detail: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uninstall_help_target: Option<AppManagerUninstallHelpTarget>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AppManagerStartupEntryKind {
+    File,
+    RegistryKey,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppManagerStartupEntryDto {
+    pub app_id: String,
+    pub scope: AppManagerStartupScope,
+    pub kind: AppManagerStartupEntryKind,
+    pub location: String,
+}
+
+/// An automatic startup backup kept in the app data directory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppManagerBackupMetaDto {
+    pub backup_id: String,
+    pub revision: u64,
+    pub created_at: i64,
+    pub item_count: u32,
+    pub enabled_count: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AppManagerBulkStartupStatus {
+    Updated,
+    Unchanged,
+    Skipped,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppManagerBulkStartupItemDto {
+    pub app_id: String,
+    pub name: String,
+    pub enabled: bool,
+    pub status: AppManagerBulkStartupStatus,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppManagerBulkStartupResultDto {
+    pub backup_id: String,
+    pub updated_count: u32,
+    pub failed_count: u32,
+    pub items: Vec<AppManagerBulkStartupItemDto>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AppManagerGhostStartupReason {
+    PathNotFound,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppManagerGhostStartupItemDto {
+    pub label: String,
+    pub path: String,
+    pub scope: AppManagerStartupScope,
+    pub reason: AppManagerGhostStartupReason,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -990,6 +1586,8 @@ pub struct ClipboardFilterDto {
     pub item_type: Option<String>,
     pub only_pinned: Option<bool>,
     pub limit: Option<u32>,
+    #[serde(default)]
+    pub content_filter: Option<ClipboardWindowContentFilter>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1004,6 +1602,67 @@ pub struct ClipboardItemDto {
     pub preview_data_url: Option<String>,
     pub created_at: i64,
     pub pinned: bool,
+    /// Position within the pinned group; lower sorts first. `0` when not pinned.
+    #[serde(default)]
+    pub pin_order: i64,
+    #[serde(default)]
+    pub preview_missing: bool,
+    /// No preview was written because the image exceeded `max_preview_pixels`.
+    #[serde(default)]
+    pub preview_skipped: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_size_bytes: Option<u64>,
+    /// Dimensions of the stored (possibly downscaled) preview.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<usize>,
+    /// Dimensions of the image as it was copied.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original_width: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original_height: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClipboardItemDetailDto {
+    pub item: ClipboardItemDto,
+    pub char_count: u32,
+    pub line_count: u32,
+    pub file_count: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ClipboardSearchField {
+    PlainText,
+    SourceApp,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClipboardSearchMatchDto {
+    pub field: ClipboardSearchField,
+    pub start: u32,
+    pub end: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClipboardSearchHitDto {
+    pub item: ClipboardItemDto,
+    pub matches: Vec<ClipboardSearchMatchDto>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClipboardSearchResultDto {
+    pub items: Vec<ClipboardSearchHitDto>,
+    pub total_count: u32,
+    /// The candidate scan hit its cap, so `total_count` is a lower bound.
+    #[serde(default)]
+    pub truncated: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1012,6 +1671,90 @@ pub struct ClipboardSettingsDto {
     pub max_items: u32,
     pub size_cleanup_enabled: bool,
     pub max_total_size_mb: u32,
+    pub capture_text: bool,
+    pub capture_images: bool,
+    pub capture_files: bool,
+    pub perceptual_deduplicate: bool,
+    pub clipboard_storage_dir: Option<String>,
+    pub image_preview_max_width: Option<u32>,
+    pub image_preview_max_height: Option<u32>,
+    pub max_preview_pixels: Option<u64>,
+    pub preview_failure_policy: ClipboardPreviewFailurePolicy,
+    pub compact_content_filter: ClipboardWindowContentFilter,
+    pub regular_content_filter: ClipboardWindowContentFilter,
+    pub max_pinned_items: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClipboardKindCountDto {
+    pub item_type: String,
+    pub count: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClipboardPreviewRepairResultDto {
+    pub repaired_count: u32,
+    pub orphan_removed_count: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClipboardTextTransformDto {
+    Trim,
+    Lowercase,
+    Uppercase,
+    JsonPretty,
+    Base64Encode,
+    Base64Decode,
+    UrlEncode,
+    UrlDecode,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClipboardMigrateResultDto {
+    pub files_moved: u32,
+    pub db_migrated: bool,
+    pub old_path: String,
+    pub new_path: String,
+}
+
+/// `text_items` counts every non-image, non-file item. Sizes use the same
+/// rules as `ClipboardItemDto::content_size_bytes`.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClipboardSizeSummaryDto {
+    pub total_items: u32,
+    pub text_items: u32,
+    pub image_items: u32,
+    pub total_size_bytes: u64,
+    pub pinned_size_bytes: u64,
+    pub preview_dir_size_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClipboardStorageStatsDto {
+    pub total_count: u64,
+    pub count_by_kind: Vec<ClipboardKindCountDto>,
+    pub db_bytes: u64,
+    pub preview_bytes: u64,
+    pub preview_file_count: u64,
+    pub preview_scan_truncated: bool,
+    pub max_items: u32,
+    pub size_cleanup_enabled: bool,
+    pub max_total_size_mb: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClipboardWatcherStatusDto {
+    pub running: bool,
+    pub paused: bool,
+    pub paused_at: Option<i64>,
+    pub resume_at: Option<i64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1024,6 +1767,9 @@ pub struct ClipboardWindowOpenedPayload {
 #[serde(rename_all = "camelCase")]
 pub struct ClipboardWindowModeAppliedDto {
     pub compact: bool,
+    /// Content filter configured for the applied mode.
+    #[serde(default)]
+    pub content_filter: ClipboardWindowContentFilter,
     pub applied_width_logical: f64,
     pub applied_height_logical: f64,
     pub scale_factor: f64,
@@ -1167,6 +1913,7 @@ pub struct LogQueryDto {
     pub request_id: Option<String>,
     pub window_label: Option<String>,
     pub keyword: Option<String>,
+    pub error_context_key: Option<String>,
     pub start_at: Option<i64>,
     pub end_at: Option<i64>,
 }
@@ -1181,6 +1928,7 @@ impl Default for LogQueryDto {
             request_id: None,
             window_label: None,
             keyword: None,
+            error_context_key: None,
             start_at: None,
             end_at: None,
         }
@@ -1201,6 +1949,8 @@ pub struct LogEntryDto {
     pub metadata: Option<Value>,
     pub raw_ref: Option<String>,
     pub aggregated_count: Option<u32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub error_context_keys: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1210,6 +1960,14 @@ pub struct LogPageDto {
     pub next_cursor: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogExportResultDto {
+    pub path: String,
+    pub exported_count: u64,
+    pub file_size_bytes: u64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LogConfigDto {
@@ -1219,4 +1977,59 @@ pub struct LogConfigDto {
     pub high_freq_window_ms: u32,
     pub high_freq_max_per_key: u32,
     pub allow_raw_view: bool,
+    #[serde(default)]
+    pub log_format: String,
+    #[serde(default)]
+    pub error_detail_level: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Exhaustive on purpose: a new variant stops compiling here until `ALL` is updated too.
+    fn residue_kind_ordinal(kind: AppManagerResidueKind) -> usize {
+        match kind {
+            AppManagerResidueKind::Install => 0,
+            AppManagerResidueKind::AppSupport => 1,
+            AppManagerResidueKind::Cache => 2,
+            AppManagerResidueKind::Preferences => 3,
+            AppManagerResidueKind::Logs => 4,
+            AppManagerResidueKind::Startup => 5,
+            AppManagerResidueKind::AppScript => 6,
+            AppManagerResidueKind::Container => 7,
+            AppManagerResidueKind::GroupContainer => 8,
+            AppManagerResidueKind::SavedState => 9,
+            AppManagerResidueKind::WebkitData => 10,
+            AppManagerResidueKind::LaunchAgent => 11,
+            AppManagerResidueKind::LaunchDaemon => 12,
+            AppManagerResidueKind::HelperTool => 13,
+            AppManagerResidueKind::AppData => 14,
+            AppManagerResidueKind::RegistryKey => 15,
+            AppManagerResidueKind::RegistryValue => 16,
+            AppManagerResidueKind::MainApp => 17,
+        }
+    }
+
+    #[test]
+    fn residue_kind_all_lists_every_variant_once_and_round_trips() {
+        let mut seen = [false; AppManagerResidueKind::ALL.len()];
+        for kind in AppManagerResidueKind::ALL {
+            let ordinal = residue_kind_ordinal(kind);
+            assert!(ordinal < seen.len(), "{kind} is missing from ALL");
+            assert!(!seen[ordinal], "{kind} is listed twice in ALL");
+            seen[ordinal] = true;
+
+            let json = serde_json::to_string(&kind).expect("serialize kind");
+            assert_eq!(json, format!("\"{}\"", kind.as_str()));
+            let decoded =
+                serde_json::from_str::<AppManagerResidueKind>(&json).expect("deserialize kind");
+            assert_eq!(decoded, kind);
+            assert_eq!(
+                kind.as_str().parse::<AppManagerResidueKind>().ok(),
+                Some(kind)
+            );
+        }
+        assert!(seen.iter().all(|listed| *listed), "ALL misses a variant");
+    }
 }
@@ -11,20 +11,36 @@ pub struct PrunedClipboardItem {
     pub preview_path: Option<String>,
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct ClipboardStorageUsage {
+    pub total_count: u64,
+    pub count_by_kind: Vec<(String, u64)>,
+    pub stored_bytes: u64,
+}
+
 pub type DbConn = Connection;
 
 #[path = "db_bootstrap.rs"]
 mod db_bootstrap;
 #[path = "db_clipboard_store.rs"]
 mod db_clipboard_store;
+#[path = "db_maintenance.rs"]
+mod db_maintenance;
 #[path = "db_settings_store.rs"]
 mod db_settings_store;
 
 pub use db_bootstrap::{init_db, open_db};
 pub use db_clipboard_store::{
-    clear_all_clipboard_items, delete_clipboard_item, get_clipboard_item, insert_clipboard_item,
-    list_clipboard_items, pin_clipboard_item, prune_clipboard_items, touch_clipboard_item,
+    clear_all_clipboard_items, clear_clipboard_preview_path, clipboard_size_summary,
+    clipboard_storage_usage, copy_clipboard_items_from, count_clipboard_items,
+    delete_clipboard_item, get_clipboard_item, insert_clipboard_item, list_clipboard_items,
+    list_clipboard_items_missing_content_size, list_clipboard_perceptual_hashes,
+    list_clipboard_preview_paths, list_clipboard_search_candidates, pin_clipboard_item,
+    prune_clipboard_items, reorder_pinned_clipboard_items, set_clipboard_content_size,
+    set_clipboard_perceptual_hash, set_clipboard_preview_path, touch_clipboard_item,
+    unpin_clipboard_overflow,
 };
+pub use db_maintenance::{DbVacuumOutcome, db_write_permit, vacuum_database};
 pub use db_settings_store::{
     delete_app_settings, get_app_setting, get_app_settings_batch, set_app_setting,
     set_app_settings_batch,
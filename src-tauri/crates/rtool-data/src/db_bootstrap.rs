@@ -8,6 +8,14 @@ use std::path::Path;
 const SCHEMA_VERSION_ADD_PREVIEW_PATH: i64 = 1;
 const SCHEMA_VERSION_ADD_PREVIEW_DATA_URL: i64 = 2;
 const SCHEMA_VERSION_ADD_CONTENT_KEY: i64 = 3;
+const SCHEMA_VERSION_ADD_LOG_ERROR_CONTEXT_KEYS: i64 = 4;
+const SCHEMA_VERSION_ADD_PERCEPTUAL_HASH: i64 = 5;
+const SCHEMA_VERSION_ADD_PIN_ORDER: i64 = 6;
+const SCHEMA_VERSION_ADD_COMPRESSED_TEXT: i64 = 7;
+const SCHEMA_VERSION_ADD_PREVIEW_SKIPPED: i64 = 8;
+const SCHEMA_VERSION_ADD_CONTENT_SIZE: i64 = 9;
+const SCHEMA_VERSION_DROP_LOG_ERROR_CONTEXT_KEYS_INDEX: i64 = 10;
+const SCHEMA_VERSION_ADD_PINNED_AT: i64 = 11;
 
 fn is_duplicate_column_error(error: LibsqlError) -> DbResult<()> {
     let message = error.to_string();
@@ -223,6 +231,26 @@ async fn migrate_add_preview_path(conn: &DbConn) -> DbResult<()> {
     Ok(())
 }
 
+async fn migrate_add_log_error_context_keys(conn: &DbConn) -> DbResult<()> {
+    if let Err(error) = conn
+        .execute(
+            "ALTER TABLE log_entries ADD COLUMN error_context_keys TEXT",
+            (),
+        )
+        .await
+    {
+        is_duplicate_column_error(error)?;
+    }
+    Ok(())
+}
+
+/// Filters match keys with a leading-wildcard LIKE, which this index could never serve.
+async fn migrate_drop_log_error_context_keys_index(conn: &DbConn) -> DbResult<()> {
+    conn.execute("DROP INDEX IF EXISTS idx_log_error_context_keys", ())
+        .await?;
+    Ok(())
+}
+
 async fn migrate_add_preview_data_url(conn: &DbConn) -> DbResult<()> {
     if let Err(error) = conn
         .execute(
@@ -236,6 +264,105 @@ async fn migrate_add_preview_data_url(conn: &DbConn) -> DbResult<()> {
     Ok(())
 }
 
+async fn migrate_add_perceptual_hash(conn: &DbConn) -> DbResult<()> {
+    if let Err(error) = conn
+        .execute(
+            "ALTER TABLE clipboard_items ADD COLUMN perceptual_hash TEXT",
+            (),
+        )
+        .await
+    {
+        is_duplicate_column_error(error)?;
+    }
+    Ok(())
+}
+
+async fn migrate_add_pin_order(conn: &DbConn) -> DbResult<()> {
+    if let Err(error) = conn
+        .execute(
+            "ALTER TABLE clipboard_items ADD COLUMN pin_order INTEGER NOT NULL DEFAULT 0",
+            (),
+        )
+        .await
+    {
+        is_duplicate_column_error(error)?;
+    }
+    conn.execute(
+        "UPDATE clipboard_items SET pin_order = created_at WHERE pinned = 1 AND pin_order = 0",
+        (),
+    )
+    .await?;
+    Ok(())
+}
+
+/// `pin_order` only held the pin time until the first reorder, so older rows fall back to
+/// `created_at` when it is larger.
+async fn migrate_add_pinned_at(conn: &DbConn) -> DbResult<()> {
+    if let Err(error) = conn
+        .execute(
+            "ALTER TABLE clipboard_items ADD COLUMN pinned_at INTEGER NOT NULL DEFAULT 0",
+            (),
+        )
+        .await
+    {
+        is_duplicate_column_error(error)?;
+    }
+    conn.execute(
+        "UPDATE clipboard_items SET pinned_at = MAX(pin_order, created_at)
+         WHERE pinned = 1 AND pinned_at = 0",
+        (),
+    )
+    .await?;
+    Ok(())
+}
+
+async fn migrate_add_compressed_text(conn: &DbConn) -> DbResult<()> {
+    for statement in [
+        "ALTER TABLE clipboard_items ADD COLUMN text_compressed INTEGER NOT NULL DEFAULT 0",
+        "ALTER TABLE clipboard_items ADD COLUMN compressed_text BLOB",
+    ] {
+        if let Err(error) = conn.execute(statement, ()).await {
+            is_duplicate_column_error(error)?;
+        }
+    }
+    Ok(())
+}
+
+async fn migrate_add_preview_skipped(conn: &DbConn) -> DbResult<()> {
+    if let Err(error) = conn
+        .execute(
+            "ALTER TABLE clipboard_items ADD COLUMN preview_skipped INTEGER NOT NULL DEFAULT 0",
+            (),
+        )
+        .await
+    {
+        is_duplicate_column_error(error)?;
+    }
+    Ok(())
+}
+
+async fn migrate_add_content_size(conn: &DbConn) -> DbResult<()> {
+    if let Err(error) = conn
+        .execute(
+            "ALTER TABLE clipboard_items ADD COLUMN content_size_bytes INTEGER",
+            (),
+        )
+        .await
+    {
+        is_duplicate_column_error(error)?;
+    }
+    conn.execute(
+        "UPDATE clipboard_items
+         SET content_size_bytes = LENGTH(CAST(plain_text AS BLOB))
+         WHERE content_size_bytes IS NULL
+           AND text_compressed = 0
+           AND item_type NOT IN ('image', 'file')",
+        (),
+    )
+    .await?;
+    Ok(())
+}
+
 async fn migrate_add_content_key(conn: &DbConn) -> DbResult<()> {
     if let Err(error) = conn
         .execute(
@@ -284,6 +411,71 @@ async fn apply_schema_migrations(conn: &DbConn) -> DbResult<()> {
         record_schema_migration(conn, SCHEMA_VERSION_ADD_CONTENT_KEY, "add_content_key").await?;
     }
 
+    if !has_schema_migration(conn, SCHEMA_VERSION_ADD_LOG_ERROR_CONTEXT_KEYS).await? {
+        migrate_add_log_error_context_keys(conn).await?;
+        record_schema_migration(
+            conn,
+            SCHEMA_VERSION_ADD_LOG_ERROR_CONTEXT_KEYS,
+            "add_log_error_context_keys",
+        )
+        .await?;
+    }
+
+    if !has_schema_migration(conn, SCHEMA_VERSION_ADD_PERCEPTUAL_HASH).await? {
+        migrate_add_perceptual_hash(conn).await?;
+        record_schema_migration(
+            conn,
+            SCHEMA_VERSION_ADD_PERCEPTUAL_HASH,
+            "add_perceptual_hash",
+        )
+        .await?;
+    }
+
+    if !has_schema_migration(conn, SCHEMA_VERSION_ADD_PIN_ORDER).await? {
+        migrate_add_pin_order(conn).await?;
+        record_schema_migration(conn, SCHEMA_VERSION_ADD_PIN_ORDER, "add_pin_order").await?;
+    }
+
+    if !has_schema_migration(conn, SCHEMA_VERSION_ADD_COMPRESSED_TEXT).await? {
+        migrate_add_compressed_text(conn).await?;
+        record_schema_migration(
+            conn,
+            SCHEMA_VERSION_ADD_COMPRESSED_TEXT,
+            "add_compressed_text",
+        )
+        .await?;
+    }
+
+    if !has_schema_migration(conn, SCHEMA_VERSION_ADD_PREVIEW_SKIPPED).await? {
+        migrate_add_preview_skipped(conn).await?;
+        record_schema_migration(
+            conn,
+            SCHEMA_VERSION_ADD_PREVIEW_SKIPPED,
+            "add_preview_skipped",
+        )
+        .await?;
+    }
+
+    if !has_schema_migration(conn, SCHEMA_VERSION_ADD_CONTENT_SIZE).await? {
+        migrate_add_content_size(conn).await?;
+        record_schema_migration(conn, SCHEMA_VERSION_ADD_CONTENT_SIZE, "add_content_size").await?;
+    }
+
+    if !has_schema_migration(conn, SCHEMA_VERSION_DROP_LOG_ERROR_CONTEXT_KEYS_INDEX).await? {
+        migrate_drop_log_error_context_keys_index(conn).await?;
+        record_schema_migration(
+            conn,
+            SCHEMA_VERSION_DROP_LOG_ERROR_CONTEXT_KEYS_INDEX,
+            "drop_log_error_context_keys_index",
+        )
+        .await?;
+    }
+
+    if !has_schema_migration(conn, SCHEMA_VERSION_ADD_PINNED_AT).await? {
+        migrate_add_pinned_at(conn).await?;
+        record_schema_migration(conn, SCHEMA_VERSION_ADD_PINNED_AT, "add_pinned_at").await?;
+    }
+
     Ok(())
 }
 
@@ -336,7 +528,14 @@ pub async fn init_db(conn: &DbConn) -> DbResult<()> {
                 preview_path TEXT,
                 preview_data_url TEXT,
                 created_at INTEGER NOT NULL,
-                pinned INTEGER NOT NULL DEFAULT 0
+                pinned INTEGER NOT NULL DEFAULT 0,
+                perceptual_hash TEXT,
+                pin_order INTEGER NOT NULL DEFAULT 0,
+                text_compressed INTEGER NOT NULL DEFAULT 0,
+                compressed_text BLOB,
+                preview_skipped INTEGER NOT NULL DEFAULT 0,
+                content_size_bytes INTEGER,
+                pinned_at INTEGER NOT NULL DEFAULT 0
             );
 
             CREATE TABLE IF NOT EXISTS command_history (
@@ -361,7 +560,8 @@ pub async fn init_db(conn: &DbConn) -> DbResult<()> {
                 message TEXT NOT NULL,
                 metadata TEXT,
                 raw_ref TEXT,
-                aggregated_count INTEGER
+                aggregated_count INTEGER,
+                error_context_keys TEXT
             );
 
             CREATE VIRTUAL TABLE IF NOT EXISTS log_entries_fts USING fts5(
@@ -481,6 +681,12 @@ pub async fn init_db(conn: &DbConn) -> DbResult<()> {
                 scan_token TEXT
             );
 
+            CREATE TABLE IF NOT EXISTS launcher_items_history (
+                item_id TEXT PRIMARY KEY,
+                launch_count INTEGER NOT NULL DEFAULT 0,
+                last_launched_at INTEGER NOT NULL
+            );
+
             CREATE VIRTUAL TABLE IF NOT EXISTS launcher_index_entries_fts USING fts5(
                 name,
                 parent,
@@ -577,6 +783,8 @@ pub async fn init_db(conn: &DbConn) -> DbResult<()> {
             CREATE INDEX IF NOT EXISTS idx_log_level_ts ON log_entries(level, timestamp DESC);
             CREATE INDEX IF NOT EXISTS idx_log_scope_ts ON log_entries(scope, timestamp DESC);
             CREATE INDEX IF NOT EXISTS idx_log_request_id ON log_entries(request_id);
+            CREATE INDEX IF NOT EXISTS idx_log_scope_level_ts ON log_entries(scope, level, timestamp DESC);
+            CREATE INDEX IF NOT EXISTS idx_log_event_ts ON log_entries(event, timestamp DESC);
             CREATE INDEX IF NOT EXISTS idx_launcher_index_kind_name ON launcher_index_entries(kind, name COLLATE NOCASE);
             CREATE INDEX IF NOT EXISTS idx_launcher_index_source_root_name ON launcher_index_entries(source_root, name COLLATE NOCASE);
             CREATE INDEX IF NOT EXISTS idx_launcher_index_scan_token ON launcher_index_entries(scan_token);
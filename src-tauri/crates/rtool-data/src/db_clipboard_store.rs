@@ -1,21 +1,109 @@
-use super::{CLIPBOARD_LIST_LIMIT_MAX, DbConn, PrunedClipboardItem};
+use super::{CLIPBOARD_LIST_LIMIT_MAX, ClipboardStorageUsage, DbConn, PrunedClipboardItem};
 use crate::db_error::DbResult;
-use libsql::{Row, params};
+use libsql::{Row, Rows, params};
 use rtool_contracts::AppError;
-use rtool_contracts::models::{ClipboardFilterDto, ClipboardItemDto};
+use rtool_contracts::models::{
+    ClipboardFilterDto, ClipboardItemDto, ClipboardSizeSummaryDto, ClipboardWindowContentFilter,
+};
 use std::io::ErrorKind;
+use std::path::Path;
+
+/// Text above this size is stored zstd-compressed. Sampled log and JSON text compresses
+/// 9-11x at 16 KiB and decompresses in ~12µs, so a list page of such rows stays around a
+/// millisecond; smaller entries save little once the uncompressed search head is kept.
+const CLIPBOARD_TEXT_COMPRESSION_THRESHOLD_BYTES: usize = 16 * 1024;
+const CLIPBOARD_TEXT_COMPRESSION_LEVEL: i32 = 3;
+/// Leading text kept in `plain_text` of compressed rows. Queries also select every compressed
+/// row and match the decompressed text, since `LIKE` only sees this head.
+const CLIPBOARD_COMPRESSED_SEARCH_HEAD_BYTES: usize = 4 * 1024;
+
+/// Returns the `plain_text` column value and, for large text, the compressed full content.
+fn encode_clipboard_text(text: &str) -> DbResult<(&str, Option<Vec<u8>>)> {
+    if text.len() <= CLIPBOARD_TEXT_COMPRESSION_THRESHOLD_BYTES {
+        return Ok((text, None));
+    }
+
+    let compressed = zstd::bulk::compress(text.as_bytes(), CLIPBOARD_TEXT_COMPRESSION_LEVEL)
+        .map_err(|error| {
+            AppError::new("clipboard_text_compress_failed", "剪贴板文本压缩失败").with_source(error)
+        })?;
+    let mut head_end = CLIPBOARD_COMPRESSED_SEARCH_HEAD_BYTES;
+    while !text.is_char_boundary(head_end) {
+        head_end -= 1;
+    }
+    if compressed.len() + head_end >= text.len() {
+        return Ok((text, None));
+    }
+    Ok((&text[..head_end], Some(compressed)))
+}
+
+fn decode_clipboard_text(compressed: &[u8]) -> DbResult<String> {
+    let bytes = zstd::stream::decode_all(compressed).map_err(|error| {
+        AppError::new("clipboard_text_decompress_failed", "剪贴板文本解压失败").with_source(error)
+    })?;
+    String::from_utf8(bytes).map_err(|error| {
+        AppError::new("clipboard_text_decompress_failed", "剪贴板文本解压失败")
+            .with_source(error)
+            .into()
+    })
+}
+
+/// Matches like SQLite's default `LIKE`, which folds ASCII letters only.
+fn contains_like_match(haystack: &str, needle: &str) -> bool {
+    let needle = needle.as_bytes();
+    needle.is_empty()
+        || haystack
+            .as_bytes()
+            .windows(needle.len())
+            .any(|window| window.eq_ignore_ascii_case(needle))
+}
+
+/// Maps rows up to `limit`, dropping compressed rows whose full text fails `matches`.
+async fn collect_matching_rows(
+    mut rows: Rows,
+    limit: usize,
+    matches: impl Fn(&ClipboardItemDto) -> bool,
+) -> DbResult<Vec<ClipboardItemDto>> {
+    let mut items = Vec::new();
+    while items.len() < limit {
+        let Some(row) = rows.next().await? else {
+            break;
+        };
+        let compressed = row.get::<i64>(10)? == 1;
+        let item = map_clipboard_item_row(&row)?;
+        if compressed && !matches(&item) {
+            continue;
+        }
+        items.push(item);
+    }
+    Ok(items)
+}
 
 fn map_clipboard_item_row(row: &Row) -> DbResult<ClipboardItemDto> {
+    let plain_text = if row.get::<i64>(10)? == 1 {
+        decode_clipboard_text(&row.get::<Vec<u8>>(11)?)?
+    } else {
+        row.get(3)?
+    };
+
     Ok(ClipboardItemDto {
         id: row.get(0)?,
         content_key: row.get(1)?,
         item_type: row.get(2)?,
-        plain_text: row.get(3)?,
+        plain_text,
         source_app: row.get(4)?,
         preview_path: row.get(5)?,
         preview_data_url: row.get(6)?,
         created_at: row.get(7)?,
         pinned: row.get::<i64>(8)? == 1,
+        pin_order: row.get(9)?,
+        preview_missing: false,
+        preview_skipped: row.get::<i64>(12)? == 1,
+        content_size_bytes: row.get::<Option<i64>>(13)?.map(|value| value.max(0) as u64),
+        width: None,
+        height: None,
+        original_width: None,
+        original_height: None,
     })
 }
 
@@ -23,33 +111,48 @@ pub async fn insert_clipboard_item(
     conn: &DbConn,
     item: &ClipboardItemDto,
 ) -> DbResult<ClipboardItemDto> {
+    let _write_permit = super::db_write_permit().await;
+    let (stored_text, compressed_text) = encode_clipboard_text(item.plain_text.as_str())?;
     conn.execute(
-        "INSERT INTO clipboard_items (id, content_key, item_type, plain_text, source_app, preview_path, preview_data_url, created_at, pinned)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+        "INSERT INTO clipboard_items (id, content_key, item_type, plain_text, source_app, preview_path, preview_data_url, created_at, pinned, text_compressed, compressed_text, preview_skipped, content_size_bytes, pinned_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)
          ON CONFLICT(content_key) DO UPDATE SET
              item_type = excluded.item_type,
              plain_text = excluded.plain_text,
+             text_compressed = excluded.text_compressed,
+             compressed_text = excluded.compressed_text,
              source_app = excluded.source_app,
              preview_path = COALESCE(excluded.preview_path, clipboard_items.preview_path),
              preview_data_url = COALESCE(excluded.preview_data_url, clipboard_items.preview_data_url),
+             preview_skipped = CASE
+                 WHEN COALESCE(excluded.preview_path, clipboard_items.preview_path) IS NULL
+                 THEN excluded.preview_skipped
+                 ELSE 0
+             END,
+             content_size_bytes = COALESCE(excluded.content_size_bytes, clipboard_items.content_size_bytes),
              created_at = excluded.created_at",
         params![
             item.id.as_str(),
             item.content_key.as_str(),
             item.item_type.as_str(),
-            item.plain_text.as_str(),
+            stored_text,
             item.source_app.as_deref(),
             item.preview_path.as_deref(),
             item.preview_data_url.as_deref(),
             item.created_at,
             if item.pinned { 1 } else { 0 },
+            if compressed_text.is_some() { 1 } else { 0 },
+            compressed_text,
+            if item.preview_skipped { 1 } else { 0 },
+            item.content_size_bytes.map(|value| value.min(i64::MAX as u64) as i64),
+            if item.pinned { item.created_at } else { 0 },
         ],
     )
     .await?;
 
     let mut rows = conn
         .query(
-            "SELECT id, content_key, item_type, plain_text, source_app, preview_path, preview_data_url, created_at, pinned
+            "SELECT id, content_key, item_type, plain_text, source_app, preview_path, preview_data_url, created_at, pinned, pin_order, text_compressed, compressed_text, preview_skipped, content_size_bytes
              FROM clipboard_items
              WHERE content_key = ?1
              LIMIT 1",
@@ -71,28 +174,52 @@ pub async fn list_clipboard_items(
     let limit = filter
         .limit
         .unwrap_or(100)
-        .clamp(1, CLIPBOARD_LIST_LIMIT_MAX) as i64;
+        .clamp(1, CLIPBOARD_LIST_LIMIT_MAX) as usize;
     let query = filter.query.clone().unwrap_or_default();
 
-    let mut rows = conn
+    let rows = conn
         .query(
-            "SELECT id, content_key, item_type, plain_text, source_app, preview_path, preview_data_url, created_at, pinned
+            "SELECT id, content_key, item_type, plain_text, source_app, preview_path, preview_data_url, created_at, pinned, pin_order, text_compressed, compressed_text, preview_skipped, content_size_bytes
              FROM clipboard_items
              WHERE (?1 = '' OR item_type = ?1)
-               AND (?2 = '' OR plain_text LIKE ?3)
+               AND (?2 = '' OR plain_text LIKE ?3 ESCAPE '\\' OR text_compressed = 1)
                AND (?4 = 0 OR pinned = 1)
-             ORDER BY pinned DESC, created_at DESC
-             LIMIT ?5",
+               AND (?5 = 0 OR item_type NOT IN ('image', 'file'))
+             ORDER BY pinned DESC, CASE WHEN pinned = 1 THEN pin_order END ASC, created_at DESC",
             params![
                 filter.item_type.clone().unwrap_or_default(),
-                query,
-                format!("%{}%", filter.query.clone().unwrap_or_default()),
+                query.as_str(),
+                format!("%{}%", escape_like_pattern(query.as_str())),
                 if filter.only_pinned.unwrap_or(false) { 1 } else { 0 },
-                limit,
+                if filter.content_filter.unwrap_or_default() == ClipboardWindowContentFilter::TextOnly {
+                    1
+                } else {
+                    0
+                },
             ],
         )
         .await?;
 
+    collect_matching_rows(rows, limit, |item| {
+        contains_like_match(item.plain_text.as_str(), query.as_str())
+    })
+    .await
+}
+
+/// Items captured before content sizes were stored at capture time.
+pub async fn list_clipboard_items_missing_content_size(
+    conn: &DbConn,
+) -> DbResult<Vec<ClipboardItemDto>> {
+    let mut rows = conn
+        .query(
+            "SELECT id, content_key, item_type, plain_text, source_app, preview_path, preview_data_url, created_at, pinned, pin_order, text_compressed, compressed_text, preview_skipped, content_size_bytes
+             FROM clipboard_items
+             WHERE content_size_bytes IS NULL
+             ORDER BY created_at DESC",
+            (),
+        )
+        .await?;
+
     let mut items = Vec::new();
     while let Some(row) = rows.next().await? {
         items.push(map_clipboard_item_row(&row)?);
@@ -101,10 +228,51 @@ pub async fn list_clipboard_items(
     Ok(items)
 }
 
+fn escape_like_pattern(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        if matches!(ch, '\\' | '%' | '_') {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
+pub async fn list_clipboard_search_candidates(
+    conn: &DbConn,
+    needle: &str,
+    limit: u32,
+) -> DbResult<Vec<ClipboardItemDto>> {
+    let limit = limit.clamp(1, CLIPBOARD_LIST_LIMIT_MAX) as usize;
+    let pattern = format!("%{}%", escape_like_pattern(needle));
+
+    let rows = conn
+        .query(
+            "SELECT id, content_key, item_type, plain_text, source_app, preview_path, preview_data_url, created_at, pinned, pin_order, text_compressed, compressed_text, preview_skipped, content_size_bytes
+             FROM clipboard_items
+             WHERE plain_text LIKE ?1 ESCAPE '\\'
+                OR source_app LIKE ?1 ESCAPE '\\'
+                OR text_compressed = 1
+             ORDER BY pinned DESC, CASE WHEN pinned = 1 THEN pin_order END ASC, created_at DESC",
+            params![pattern],
+        )
+        .await?;
+
+    collect_matching_rows(rows, limit, |item| {
+        contains_like_match(item.plain_text.as_str(), needle)
+            || item
+                .source_app
+                .as_deref()
+                .is_some_and(|source_app| contains_like_match(source_app, needle))
+    })
+    .await
+}
+
 pub async fn get_clipboard_item(conn: &DbConn, id: &str) -> DbResult<Option<ClipboardItemDto>> {
     let mut rows = conn
         .query(
-            "SELECT id, content_key, item_type, plain_text, source_app, preview_path, preview_data_url, created_at, pinned
+            "SELECT id, content_key, item_type, plain_text, source_app, preview_path, preview_data_url, created_at, pinned, pin_order, text_compressed, compressed_text, preview_skipped, content_size_bytes
              FROM clipboard_items
              WHERE id = ?1
              LIMIT 1",
@@ -119,20 +287,94 @@ pub async fn get_clipboard_item(conn: &DbConn, id: &str) -> DbResult<Option<Clip
     Ok(None)
 }
 
-pub async fn pin_clipboard_item(conn: &DbConn, id: &str, pinned: bool) -> DbResult<()> {
+/// New pins go last in display order; `pinned_at` keeps the pin time for overflow eviction.
+pub async fn pin_clipboard_item(
+    conn: &DbConn,
+    id: &str,
+    pinned: bool,
+    pinned_at: i64,
+) -> DbResult<()> {
+    let _write_permit = super::db_write_permit().await;
     conn.execute(
-        "UPDATE clipboard_items SET pinned = ?1 WHERE id = ?2",
-        params![if pinned { 1 } else { 0 }, id],
+        "UPDATE clipboard_items
+         SET pinned = ?1,
+             pin_order = CASE WHEN ?1 = 0 THEN 0 WHEN pinned = 1 THEN pin_order ELSE ?2 END,
+             pinned_at = CASE WHEN ?1 = 0 THEN 0 WHEN pinned = 1 THEN pinned_at ELSE ?2 END
+         WHERE id = ?3",
+        params![if pinned { 1 } else { 0 }, pinned_at, id],
     )
     .await?;
     Ok(())
 }
 
+pub async fn reorder_pinned_clipboard_items(conn: &DbConn, item_ids: &[String]) -> DbResult<()> {
+    let _write_permit = super::db_write_permit().await;
+    let transaction = conn.transaction().await?;
+    for (index, id) in item_ids.iter().enumerate() {
+        transaction
+            .execute(
+                "UPDATE clipboard_items SET pin_order = ?1 WHERE id = ?2 AND pinned = 1",
+                params![index as i64 + 1, id.as_str()],
+            )
+            .await?;
+    }
+    transaction.commit().await?;
+    Ok(())
+}
+
+/// Unpins the oldest-pinned items (lowest `pinned_at`) beyond `max_pinned`, sparing `keep_id`.
+/// Display order from `reorder_pinned_clipboard_items` does not affect which items go.
+pub async fn unpin_clipboard_overflow(
+    conn: &DbConn,
+    max_pinned: u32,
+    keep_id: &str,
+) -> DbResult<Vec<String>> {
+    let _write_permit = super::db_write_permit().await;
+    let transaction = conn.transaction().await?;
+    let mut rows = transaction
+        .query("SELECT COUNT(*) FROM clipboard_items WHERE pinned = 1", ())
+        .await?;
+    let total = match rows.next().await? {
+        Some(row) => row.get::<i64>(0)?,
+        None => 0,
+    };
+    let overflow = total - i64::from(max_pinned);
+    if overflow <= 0 {
+        transaction.commit().await?;
+        return Ok(Vec::new());
+    }
+
+    let mut rows = transaction
+        .query(
+            "SELECT id FROM clipboard_items
+             WHERE pinned = 1 AND id != ?1
+             ORDER BY pinned_at ASC, created_at ASC, id ASC
+             LIMIT ?2",
+            params![keep_id, overflow],
+        )
+        .await?;
+    let mut unpinned = Vec::new();
+    while let Some(row) = rows.next().await? {
+        unpinned.push(row.get::<String>(0)?);
+    }
+    for id in &unpinned {
+        transaction
+            .execute(
+                "UPDATE clipboard_items SET pinned = 0, pin_order = 0, pinned_at = 0 WHERE id = ?1",
+                params![id.as_str()],
+            )
+            .await?;
+    }
+    transaction.commit().await?;
+    Ok(unpinned)
+}
+
 pub async fn touch_clipboard_item(
     conn: &DbConn,
     id: &str,
     created_at: i64,
 ) -> DbResult<Option<ClipboardItemDto>> {
+    let _write_permit = super::db_write_permit().await;
     conn.execute(
         "UPDATE clipboard_items SET created_at = ?1 WHERE id = ?2",
         params![created_at, id],
@@ -143,6 +385,7 @@ pub async fn touch_clipboard_item(
 }
 
 pub async fn delete_clipboard_item(conn: &DbConn, id: &str) -> DbResult<Option<String>> {
+    let _write_permit = super::db_write_permit().await;
     let mut rows = conn
         .query(
             "SELECT preview_path FROM clipboard_items WHERE id = ?1 LIMIT 1",
@@ -160,7 +403,80 @@ pub async fn delete_clipboard_item(conn: &DbConn, id: &str) -> DbResult<Option<S
     Ok(preview_path)
 }
 
+pub async fn list_clipboard_preview_paths(conn: &DbConn) -> DbResult<Vec<(String, String)>> {
+    let mut rows = conn
+        .query(
+            "SELECT id, preview_path FROM clipboard_items WHERE preview_path IS NOT NULL",
+            (),
+        )
+        .await?;
+    let mut entries = Vec::new();
+    while let Some(row) = rows.next().await? {
+        entries.push((row.get::<String>(0)?, row.get::<String>(1)?));
+    }
+    Ok(entries)
+}
+
+pub async fn clear_clipboard_preview_path(conn: &DbConn, id: &str) -> DbResult<()> {
+    let _write_permit = super::db_write_permit().await;
+    conn.execute(
+        "UPDATE clipboard_items SET preview_path = NULL WHERE id = ?1",
+        params![id],
+    )
+    .await?;
+    Ok(())
+}
+
+pub async fn set_clipboard_preview_path(
+    conn: &DbConn,
+    id: &str,
+    preview_path: &str,
+) -> DbResult<()> {
+    let _write_permit = super::db_write_permit().await;
+    conn.execute(
+        "UPDATE clipboard_items SET preview_path = ?2 WHERE id = ?1",
+        params![id, preview_path],
+    )
+    .await?;
+    Ok(())
+}
+
+pub async fn list_clipboard_perceptual_hashes(
+    conn: &DbConn,
+    limit: u32,
+) -> DbResult<Vec<(String, String)>> {
+    let mut rows = conn
+        .query(
+            "SELECT id, perceptual_hash FROM clipboard_items
+             WHERE item_type = 'image' AND perceptual_hash IS NOT NULL
+             ORDER BY created_at DESC
+             LIMIT ?1",
+            params![i64::from(limit)],
+        )
+        .await?;
+    let mut entries = Vec::new();
+    while let Some(row) = rows.next().await? {
+        entries.push((row.get::<String>(0)?, row.get::<String>(1)?));
+    }
+    Ok(entries)
+}
+
+pub async fn set_clipboard_perceptual_hash(
+    conn: &DbConn,
+    id: &str,
+    perceptual_hash: &str,
+) -> DbResult<()> {
+    let _write_permit = super::db_write_permit().await;
+    conn.execute(
+        "UPDATE clipboard_items SET perceptual_hash = ?1 WHERE id = ?2",
+        params![perceptual_hash, id],
+    )
+    .await?;
+    Ok(())
+}
+
 pub async fn clear_all_clipboard_items(conn: &DbConn) -> DbResult<Vec<String>> {
+    let _write_permit = super::db_write_permit().await;
     let mut rows = conn
         .query("SELECT preview_path FROM clipboard_items", ())
         .await?;
@@ -175,6 +491,114 @@ pub async fn clear_all_clipboard_items(conn: &DbConn) -> DbResult<Vec<String>> {
     Ok(preview_paths)
 }
 
+const CLIPBOARD_ITEM_COLUMNS: &str = "id, content_key, item_type, plain_text, source_app, \
+     preview_path, preview_data_url, created_at, pinned, perceptual_hash, pin_order, \
+     text_compressed, compressed_text, preview_skipped, content_size_bytes, pinned_at";
+
+/// Replaces the clipboard rows of `conn` with the ones stored in the database at `source_path`.
+pub async fn copy_clipboard_items_from(conn: &DbConn, source_path: &Path) -> DbResult<u64> {
+    let _write_permit = super::db_write_permit().await;
+    conn.execute(
+        "ATTACH DATABASE ?1 AS clipboard_source",
+        params![source_path.to_string_lossy().to_string()],
+    )
+    .await?;
+    let copied = async {
+        conn.execute("DELETE FROM clipboard_items", ()).await?;
+        conn.execute(
+            &format!(
+                "INSERT INTO clipboard_items ({CLIPBOARD_ITEM_COLUMNS})
+                 SELECT {CLIPBOARD_ITEM_COLUMNS} FROM clipboard_source.clipboard_items"
+            ),
+            (),
+        )
+        .await
+    }
+    .await;
+    let detached = conn.execute("DETACH DATABASE clipboard_source", ()).await;
+    let copied = copied?;
+    detached?;
+    Ok(copied)
+}
+
+pub async fn set_clipboard_content_size(conn: &DbConn, id: &str, size_bytes: u64) -> DbResult<()> {
+    let _write_permit = super::db_write_permit().await;
+    conn.execute(
+        "UPDATE clipboard_items SET content_size_bytes = ?1 WHERE id = ?2",
+        params![size_bytes.min(i64::MAX as u64) as i64, id],
+    )
+    .await?;
+    Ok(())
+}
+
+/// Item counts and stored content sizes; `preview_dir_size_bytes` is left for the caller.
+pub async fn clipboard_size_summary(conn: &DbConn) -> DbResult<ClipboardSizeSummaryDto> {
+    let mut rows = conn
+        .query(
+            "SELECT COUNT(*),
+                    COALESCE(SUM(CASE WHEN item_type NOT IN ('image', 'file') THEN 1 ELSE 0 END), 0),
+                    COALESCE(SUM(CASE WHEN item_type = 'image' THEN 1 ELSE 0 END), 0),
+                    COALESCE(SUM(COALESCE(content_size_bytes, 0)), 0),
+                    COALESCE(SUM(CASE WHEN pinned = 1 THEN COALESCE(content_size_bytes, 0) ELSE 0 END), 0)
+             FROM clipboard_items",
+            (),
+        )
+        .await?;
+
+    let Some(row) = rows.next().await? else {
+        return Ok(ClipboardSizeSummaryDto::default());
+    };
+    let count = |index| -> DbResult<u32> {
+        Ok(u32::try_from(row.get::<i64>(index)?.max(0)).unwrap_or(u32::MAX))
+    };
+    Ok(ClipboardSizeSummaryDto {
+        total_items: count(0)?,
+        text_items: count(1)?,
+        image_items: count(2)?,
+        total_size_bytes: row.get::<i64>(3)?.max(0) as u64,
+        pinned_size_bytes: row.get::<i64>(4)?.max(0) as u64,
+        preview_dir_size_bytes: 0,
+    })
+}
+
+pub async fn count_clipboard_items(conn: &DbConn) -> DbResult<u64> {
+    let mut rows = conn
+        .query("SELECT COUNT(*) FROM clipboard_items", ())
+        .await?;
+    let count = match rows.next().await? {
+        Some(row) => row.get::<i64>(0)?.max(0) as u64,
+        None => 0,
+    };
+    Ok(count)
+}
+
+pub async fn clipboard_storage_usage(conn: &DbConn) -> DbResult<ClipboardStorageUsage> {
+    let mut rows = conn
+        .query(
+            "SELECT item_type,
+                    COUNT(*),
+                    COALESCE(SUM(LENGTH(CAST(plain_text AS BLOB))
+                        + COALESCE(LENGTH(compressed_text), 0)
+                        + COALESCE(LENGTH(CAST(preview_data_url AS BLOB)), 0)), 0)
+             FROM clipboard_items
+             GROUP BY item_type
+             ORDER BY item_type ASC",
+            (),
+        )
+        .await?;
+
+    let mut usage = ClipboardStorageUsage::default();
+    while let Some(row) = rows.next().await? {
+        let item_type = row.get::<String>(0)?;
+        let count = row.get::<i64>(1)?.max(0) as u64;
+        let stored_bytes = row.get::<i64>(2)?.max(0) as u64;
+        usage.total_count = usage.total_count.saturating_add(count);
+        usage.stored_bytes = usage.stored_bytes.saturating_add(stored_bytes);
+        usage.count_by_kind.push((item_type, count));
+    }
+    Ok(usage)
+}
+
 fn preview_file_size_bytes(preview_path: Option<&str>) -> u64 {
     let Some(path) = preview_path else {
         return 0;
@@ -206,10 +630,12 @@ fn preview_file_size_bytes(preview_path: Option<&str>) -> u64 {
 
 fn clipboard_row_size_bytes(
     plain_text: &str,
+    compressed_text_bytes: u64,
     preview_data_url: Option<&str>,
     preview_path: Option<&str>,
 ) -> u64 {
     plain_text.len() as u64
+        + compressed_text_bytes
         + preview_data_url
             .map(|value| value.len() as u64)
             .unwrap_or(0)
@@ -221,6 +647,7 @@ pub async fn prune_clipboard_items(
     max_items: u32,
     max_total_size_bytes: Option<u64>,
 ) -> DbResult<Vec<PrunedClipboardItem>> {
+    let _write_permit = super::db_write_permit().await;
     let transaction = conn.transaction().await?;
 
     if max_total_size_bytes.is_none() {
@@ -277,7 +704,7 @@ pub async fn prune_clipboard_items(
 
     let mut rows = transaction
         .query(
-            "SELECT id, preview_path, plain_text, preview_data_url
+            "SELECT id, preview_path, plain_text, preview_data_url, COALESCE(LENGTH(compressed_text), 0)
              FROM clipboard_items
              ORDER BY pinned ASC, created_at ASC, id ASC",
             (),
@@ -289,8 +716,10 @@ pub async fn prune_clipboard_items(
         let preview_path = row.get::<Option<String>>(1)?;
         let plain_text = row.get::<String>(2)?;
         let preview_data_url = row.get::<Option<String>>(3)?;
+        let compressed_text_bytes = row.get::<i64>(4)?.max(0) as u64;
         let size_bytes = clipboard_row_size_bytes(
             plain_text.as_str(),
+            compressed_text_bytes,
             preview_data_url.as_deref(),
             preview_path.as_deref(),
         );
@@ -327,3 +756,399 @@ pub async fn prune_clipboard_items(
     transaction.commit().await?;
     Ok(to_remove)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::{init_db, open_db};
+
+    fn sample_item(id: &str, created_at: i64) -> ClipboardItemDto {
+        ClipboardItemDto {
+            id: id.to_string(),
+            content_key: format!("key-{id}"),
+            item_type: "text".to_string(),
+            plain_text: id.to_string(),
+            source_app: None,
+            preview_path: None,
+            preview_data_url: None,
+            created_at,
+            pinned: false,
+            pin_order: 0,
+            preview_missing: false,
+            preview_skipped: false,
+            content_size_bytes: None,
+            width: None,
+            height: None,
+            original_width: None,
+            original_height: None,
+        }
+    }
+
+    fn listed_ids(items: &[ClipboardItemDto]) -> Vec<&str> {
+        items.iter().map(|item| item.id.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn pinned_items_follow_pin_order_and_overflow_unpins_oldest() {
+        let db_path = std::env::temp_dir().join(format!(
+            "rtool-clipboard-pin-order-{}.db",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|value| value.as_nanos())
+                .unwrap_or_default()
+        ));
+        let conn = open_db(&db_path).await.expect("open db");
+        init_db(&conn).await.expect("init db");
+
+        for (id, created_at) in [("a", 1), ("b", 2), ("c", 3), ("d", 4)] {
+            insert_clipboard_item(&conn, &sample_item(id, created_at))
+                .await
+                .expect("insert");
+        }
+        pin_clipboard_item(&conn, "c", true, 100)
+            .await
+            .expect("pin");
+        pin_clipboard_item(&conn, "a", true, 200)
+            .await
+            .expect("pin");
+        pin_clipboard_item(&conn, "b", true, 300)
+            .await
+            .expect("pin");
+        pin_clipboard_item(&conn, "a", true, 400)
+            .await
+            .expect("re-pin keeps order");
+
+        let filter = ClipboardFilterDto {
+            query: None,
+            item_type: None,
+            only_pinned: None,
+            limit: None,
+            content_filter: None,
+        };
+        let items = list_clipboard_items(&conn, &filter).await.expect("list");
+        assert_eq!(listed_ids(&items), vec!["c", "a", "b", "d"]);
+
+        reorder_pinned_clipboard_items(&conn, &["b".to_string(), "c".to_string()])
+            .await
+            .expect("reorder");
+        let items = list_clipboard_items(&conn, &filter).await.expect("list");
+        assert_eq!(listed_ids(&items), vec!["b", "c", "a", "d"]);
+
+        // "b" was moved to the top but pinned last, so "c" (pinned first) goes.
+        let unpinned = unpin_clipboard_overflow(&conn, 2, "a")
+            .await
+            .expect("overflow");
+        assert_eq!(unpinned, vec!["c".to_string()]);
+        let items = list_clipboard_items(&conn, &filter).await.expect("list");
+        assert_eq!(listed_ids(&items), vec!["b", "a", "d", "c"]);
+        assert_eq!(items[3].pin_order, 0);
+
+        pin_clipboard_item(&conn, "c", true, 500)
+            .await
+            .expect("pin again");
+        let unpinned = unpin_clipboard_overflow(&conn, 2, "c")
+            .await
+            .expect("overflow");
+        assert_eq!(unpinned, vec!["a".to_string()]);
+
+        drop(conn);
+        for suffix in ["", "-wal", "-shm"] {
+            let mut path = db_path.clone().into_os_string();
+            path.push(suffix);
+            let _ = std::fs::remove_file(path);
+        }
+    }
+
+    #[tokio::test]
+    async fn large_text_round_trips_through_compressed_storage() {
+        let db_path = std::env::temp_dir().join(format!(
+            "rtool-clipboard-compressed-{}.db",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|value| value.as_nanos())
+                .unwrap_or_default()
+        ));
+        let conn = open_db(&db_path).await.expect("open db");
+        init_db(&conn).await.expect("init db");
+
+        let text = (0..4_000)
+            .map(|index| format!("{{\"line\":{index},\"msg\":\"剪贴板日志 entry {index}\"}}\n"))
+            .collect::<String>();
+        assert!(text.len() > CLIPBOARD_TEXT_COMPRESSION_THRESHOLD_BYTES);
+        let mut large = sample_item("large", 1);
+        large.plain_text = text.clone();
+        let saved = insert_clipboard_item(&conn, &large).await.expect("insert");
+        assert_eq!(saved.plain_text.as_bytes(), text.as_bytes());
+        insert_clipboard_item(&conn, &sample_item("small", 2))
+            .await
+            .expect("insert");
+
+        let mut rows = conn
+            .query(
+                "SELECT text_compressed, LENGTH(CAST(plain_text AS BLOB)), LENGTH(compressed_text)
+                 FROM clipboard_items WHERE id = 'large'",
+                (),
+            )
+            .await
+            .expect("query");
+        let row = rows.next().await.expect("row").expect("large row");
+        assert_eq!(row.get::<i64>(0).expect("flag"), 1);
+        assert!(
+            row.get::<i64>(1).expect("head") as usize <= CLIPBOARD_COMPRESSED_SEARCH_HEAD_BYTES
+        );
+        assert!((row.get::<i64>(2).expect("blob") as usize) < text.len() / 4);
+
+        let fetched = get_clipboard_item(&conn, "large")
+            .await
+            .expect("get")
+            .expect("large item");
+        assert_eq!(fetched.plain_text.as_bytes(), text.as_bytes());
+
+        let filter = ClipboardFilterDto {
+            query: Some("entry 1".to_string()),
+            item_type: None,
+            only_pinned: None,
+            limit: None,
+            content_filter: None,
+        };
+        let items = list_clipboard_items(&conn, &filter).await.expect("list");
+        assert_eq!(listed_ids(&items), vec!["large"]);
+        assert_eq!(items[0].plain_text, text);
+        let small = get_clipboard_item(&conn, "small")
+            .await
+            .expect("get")
+            .expect("small item");
+        assert_eq!(small.plain_text, "small");
+
+        drop(conn);
+        for suffix in ["", "-wal", "-shm"] {
+            let mut path = db_path.clone().into_os_string();
+            path.push(suffix);
+            let _ = std::fs::remove_file(path);
+        }
+    }
+
+    #[tokio::test]
+    async fn search_matches_compressed_text_past_the_head() {
+        let db_path = std::env::temp_dir().join(format!(
+            "rtool-clipboard-compressed-search-{}.db",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|value| value.as_nanos())
+                .unwrap_or_default()
+        ));
+        let conn = open_db(&db_path).await.expect("open db");
+        init_db(&conn).await.expect("init db");
+
+        let body = (0..4_000)
+            .map(|index| format!("{{\"line\":{index}}}\n"))
+            .collect::<String>();
+        let mut large = sample_item("large", 1);
+        large.plain_text = format!("{body}Tail-Marker 100%");
+        insert_clipboard_item(&conn, &large).await.expect("insert");
+        let mut other = sample_item("other", 2);
+        other.plain_text = format!("{body}unrelated");
+        insert_clipboard_item(&conn, &other).await.expect("insert");
+        let mut small = sample_item("small", 3);
+        small.plain_text = "small tail-marker".to_string();
+        insert_clipboard_item(&conn, &small).await.expect("insert");
+        let tail_offset = large.plain_text.find("Tail-Marker").expect("marker");
+        assert!(tail_offset > CLIPBOARD_COMPRESSED_SEARCH_HEAD_BYTES);
+
+        let filter = |query: &str, limit: Option<u32>| ClipboardFilterDto {
+            query: Some(query.to_string()),
+            item_type: None,
+            only_pinned: None,
+            limit,
+            content_filter: None,
+        };
+        let matched = list_clipboard_items(&conn, &filter("tail-marker", None))
+            .await
+            .expect("list");
+        let limited = list_clipboard_items(&conn, &filter("tail-marker", Some(1)))
+            .await
+            .expect("list");
+        let literal_percent = list_clipboard_items(&conn, &filter("100%", None))
+            .await
+            .expect("list");
+        let candidates = list_clipboard_search_candidates(&conn, "TAIL-MARKER", 10)
+            .await
+            .expect("candidates");
+
+        assert_eq!(listed_ids(&matched), vec!["small", "large"]);
+        assert_eq!(matched[1].plain_text, large.plain_text);
+        assert_eq!(listed_ids(&limited), vec!["small"]);
+        assert_eq!(listed_ids(&literal_percent), vec!["large"]);
+        assert_eq!(listed_ids(&candidates), vec!["small", "large"]);
+
+        drop(conn);
+        for suffix in ["", "-wal", "-shm"] {
+            let mut path = db_path.clone().into_os_string();
+            path.push(suffix);
+            let _ = std::fs::remove_file(path);
+        }
+    }
+
+    #[tokio::test]
+    async fn size_summary_sums_content_sizes_stored_at_capture() {
+        let db_path = std::env::temp_dir().join(format!(
+            "rtool-clipboard-size-summary-{}.db",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|value| value.as_nanos())
+                .unwrap_or_default()
+        ));
+        let conn = open_db(&db_path).await.expect("open db");
+        init_db(&conn).await.expect("init db");
+
+        let mut text = sample_item("text", 1);
+        text.content_size_bytes = Some(4);
+        let mut image = sample_item("image", 2);
+        image.item_type = "image".to_string();
+        image.content_size_bytes = Some(100);
+        let mut files = sample_item("files", 3);
+        files.item_type = "file".to_string();
+        for item in [&text, &image, &files] {
+            insert_clipboard_item(&conn, item).await.expect("insert");
+        }
+        pin_clipboard_item(&conn, "image", true, 1)
+            .await
+            .expect("pin");
+
+        let missing = list_clipboard_items_missing_content_size(&conn)
+            .await
+            .expect("missing sizes");
+        assert_eq!(listed_ids(&missing), vec!["files"]);
+        set_clipboard_content_size(&conn, "files", 20)
+            .await
+            .expect("set size");
+
+        let mut refreshed = image.clone();
+        refreshed.content_size_bytes = None;
+        let upserted = insert_clipboard_item(&conn, &refreshed)
+            .await
+            .expect("upsert");
+        assert_eq!(upserted.content_size_bytes, Some(100));
+
+        let summary = clipboard_size_summary(&conn).await.expect("summary");
+        assert_eq!(summary.total_items, 3);
+        assert_eq!(summary.text_items, 1);
+        assert_eq!(summary.image_items, 1);
+        assert_eq!(summary.total_size_bytes, 124);
+        assert_eq!(summary.pinned_size_bytes, 100);
+
+        drop(conn);
+        for suffix in ["", "-wal", "-shm"] {
+            let mut path = db_path.clone().into_os_string();
+            path.push(suffix);
+            let _ = std::fs::remove_file(path);
+        }
+    }
+
+    #[tokio::test]
+    async fn preview_skipped_persists_until_a_preview_is_stored() {
+        let db_path = std::env::temp_dir().join(format!(
+            "rtool-clipboard-preview-skipped-{}.db",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|value| value.as_nanos())
+                .unwrap_or_default()
+        ));
+        let conn = open_db(&db_path).await.expect("open db");
+        init_db(&conn).await.expect("init db");
+
+        let mut skipped = sample_item("huge", 1);
+        skipped.item_type = "image".to_string();
+        skipped.preview_skipped = true;
+        let saved = insert_clipboard_item(&conn, &skipped)
+            .await
+            .expect("insert");
+        assert!(saved.preview_skipped);
+        assert_eq!(saved.preview_path, None);
+
+        let mut previewed = skipped.clone();
+        previewed.preview_skipped = false;
+        previewed.preview_path = Some("/tmp/huge.png".to_string());
+        insert_clipboard_item(&conn, &previewed)
+            .await
+            .expect("upsert");
+        let fetched = get_clipboard_item(&conn, "huge")
+            .await
+            .expect("get")
+            .expect("huge item");
+        assert!(!fetched.preview_skipped);
+
+        insert_clipboard_item(&conn, &skipped)
+            .await
+            .expect("upsert skipped");
+        let fetched = get_clipboard_item(&conn, "huge")
+            .await
+            .expect("get")
+            .expect("huge item");
+        assert!(!fetched.preview_skipped);
+        assert_eq!(fetched.preview_path.as_deref(), Some("/tmp/huge.png"));
+
+        drop(conn);
+        for suffix in ["", "-wal", "-shm"] {
+            let mut path = db_path.clone().into_os_string();
+            path.push(suffix);
+            let _ = std::fs::remove_file(path);
+        }
+    }
+
+    #[tokio::test]
+    async fn copy_clipboard_items_replaces_target_rows() {
+        let stamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|value| value.as_nanos())
+            .unwrap_or_default();
+        let source_path = std::env::temp_dir().join(format!("rtool-clipboard-copy-src-{stamp}.db"));
+        let target_path = std::env::temp_dir().join(format!("rtool-clipboard-copy-dst-{stamp}.db"));
+        let source = open_db(&source_path).await.expect("open source");
+        init_db(&source).await.expect("init source");
+        let target = open_db(&target_path).await.expect("open target");
+        init_db(&target).await.expect("init target");
+
+        let mut pinned = sample_item("a", 1);
+        pinned.preview_path = Some("/tmp/a.png".to_string());
+        insert_clipboard_item(&source, &pinned)
+            .await
+            .expect("insert");
+        pin_clipboard_item(&source, "a", true, 10)
+            .await
+            .expect("pin");
+        insert_clipboard_item(&source, &sample_item("b", 2))
+            .await
+            .expect("insert");
+        insert_clipboard_item(&target, &sample_item("stale", 3))
+            .await
+            .expect("insert");
+
+        let copied = copy_clipboard_items_from(&target, &source_path)
+            .await
+            .expect("copy");
+        let filter = ClipboardFilterDto {
+            query: None,
+            item_type: None,
+            only_pinned: None,
+            limit: None,
+            content_filter: None,
+        };
+        let items = list_clipboard_items(&target, &filter).await.expect("list");
+        assert_eq!(copied, 2);
+        assert_eq!(listed_ids(&items), vec!["a", "b"]);
+        assert!(items[0].pinned);
+        assert_eq!(items[0].preview_path.as_deref(), Some("/tmp/a.png"));
+        assert_eq!(count_clipboard_items(&source).await.expect("count"), 2);
+
+        drop(source);
+        drop(target);
+        for db_path in [source_path, target_path] {
+            for suffix in ["", "-wal", "-shm"] {
+                let mut path = db_path.clone().into_os_string();
+                path.push(suffix);
+                let _ = std::fs::remove_file(path);
+            }
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use super::DbConn;
+use crate::db_error::DbResult;
+use rtool_contracts::AppError;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use tokio::sync::{RwLock, RwLockReadGuard};
+
+static DB_MAINTENANCE_RUNNING: AtomicBool = AtomicBool::new(false);
+static DB_WRITE_LOCK: RwLock<()> = RwLock::const_new(());
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DbVacuumOutcome {
+    pub size_before_bytes: u64,
+    pub size_after_bytes: u64,
+}
+
+impl DbVacuumOutcome {
+    pub fn reclaimed_bytes(&self) -> u64 {
+        self.size_before_bytes.saturating_sub(self.size_after_bytes)
+    }
+}
+
+struct DbMaintenanceGuard;
+
+impl DbMaintenanceGuard {
+    fn acquire() -> Option<Self> {
+        DB_MAINTENANCE_RUNNING
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .ok()
+            .map(|_| Self)
+    }
+}
+
+impl Drop for DbMaintenanceGuard {
+    fn drop(&mut self) {
+        DB_MAINTENANCE_RUNNING.store(false, Ordering::Release);
+    }
+}
+
+/// Taken by every write in the store modules and by the log ingest and launcher index writers;
+/// `vacuum_database` waits for them. Not reentrant, so never hold it across a store call.
+pub async fn db_write_permit() -> RwLockReadGuard<'static, ()> {
+    DB_WRITE_LOCK.read().await
+}
+
+fn wal_path(db_path: &Path) -> PathBuf {
+    let mut value = db_path.as_os_str().to_os_string();
+    value.push("-wal");
+    PathBuf::from(value)
+}
+
+fn database_file_size(db_path: &Path) -> u64 {
+    [db_path.to_path_buf(), wal_path(db_path)]
+        .iter()
+        .filter_map(|path| std::fs::metadata(path).ok())
+        .map(|metadata| metadata.len())
+        .sum()
+}
+
+pub async fn vacuum_database(conn: &DbConn, db_path: &Path) -> DbResult<DbVacuumOutcome> {
+    let Some(_guard) = DbMaintenanceGuard::acquire() else {
+        return Err(AppError::new("db_maintenance_in_progress", "数据库维护正在进行中").into());
+    };
+
+    let _writers = DB_WRITE_LOCK.write().await;
+
+    let size_before_bytes = database_file_size(db_path);
+    conn.execute_batch("VACUUM;").await?;
+    conn.execute_batch("PRAGMA optimize;").await?;
+    let mut rows = conn.query("PRAGMA wal_checkpoint(TRUNCATE)", ()).await?;
+    while rows.next().await?.is_some() {}
+    let size_after_bytes = database_file_size(db_path);
+
+    tracing::info!(
+        event = "db_vacuum_completed",
+        size_before_bytes,
+        size_after_bytes
+    );
+    Ok(DbVacuumOutcome {
+        size_before_bytes,
+        size_after_bytes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::{init_db, open_db};
+    use libsql::params;
+
+    #[tokio::test]
+    async fn vacuum_reclaims_space_after_bulk_delete() {
+        let db_path = std::env::temp_dir().join(format!(
+            "rtool-db-maintenance-{}.db",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|value| value.as_nanos())
+                .unwrap_or_default()
+        ));
+        let conn = open_db(&db_path).await.expect("open db");
+        init_db(&conn).await.expect("init db");
+
+        let payload = "x".repeat(4096);
+        for index in 0..256 {
+            conn.execute(
+                "INSERT INTO app_settings (key, value) VALUES (?1, ?2)",
+                params![format!("bulk.{index}"), payload.as_str()],
+            )
+            .await
+            .expect("insert");
+        }
+        conn.execute("DELETE FROM app_settings WHERE key LIKE 'bulk.%'", ())
+            .await
+            .expect("delete");
+
+        let outcome = vacuum_database(&conn, &db_path).await.expect("vacuum");
+        assert!(outcome.size_before_bytes > outcome.size_after_bytes);
+        assert_eq!(
+            outcome.reclaimed_bytes(),
+            outcome.size_before_bytes - outcome.size_after_bytes
+        );
+        assert!(!DB_MAINTENANCE_RUNNING.load(Ordering::Acquire));
+
+        let guard = DbMaintenanceGuard::acquire().expect("guard");
+        let error = vacuum_database(&conn, &db_path)
+            .await
+            .expect_err("concurrent vacuum rejected");
+        assert_eq!(error.into_inner().code, "db_maintenance_in_progress");
+        drop(guard);
+
+        let permit = db_write_permit().await;
+        assert!(DB_WRITE_LOCK.try_write().is_err());
+        drop(permit);
+
+        drop(conn);
+        for path in [db_path.clone(), wal_path(&db_path)] {
+            let _ = std::fs::remove_file(path);
+        }
+        let mut shm = db_path.into_os_string();
+        shm.push("-shm");
+        let _ = std::fs::remove_file(shm);
+    }
+
+    #[tokio::test]
+    async fn store_writes_wait_for_running_maintenance() {
+        let db_path = std::env::temp_dir().join(format!(
+            "rtool-db-maintenance-writers-{}.db",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|value| value.as_nanos())
+                .unwrap_or_default()
+        ));
+        let conn = open_db(&db_path).await.expect("open db");
+        init_db(&conn).await.expect("init db");
+
+        let maintenance = DB_WRITE_LOCK.write().await;
+        let blocked = tokio::time::timeout(
+            std::time::Duration::from_millis(100),
+            crate::db::set_app_setting(&conn, "maintenance.blocked", "1"),
+        )
+        .await;
+        assert!(blocked.is_err());
+        drop(maintenance);
+
+        crate::db::set_app_setting(&conn, "maintenance.blocked", "1")
+            .await
+            .expect("write after maintenance");
+
+        drop(conn);
+        for path in [db_path.clone(), wal_path(&db_path)] {
+            let _ = std::fs::remove_file(path);
+        }
+        let mut shm = db_path.into_os_string();
+        shm.push("-shm");
+        let _ = std::fs::remove_file(shm);
+    }
+}
@@ -19,6 +19,7 @@ pub async fn get_app_setting(conn: &DbConn, key: &str) -> DbResult<Option<String
 }
 
 pub async fn set_app_setting(conn: &DbConn, key: &str, value: &str) -> DbResult<()> {
+    let _write_permit = super::db_write_permit().await;
     conn.execute(
         "INSERT INTO app_settings (key, value) VALUES (?1, ?2)
          ON CONFLICT(key) DO UPDATE SET value = excluded.value",
@@ -55,6 +56,7 @@ pub async fn get_app_settings_batch(
 }
 
 pub async fn set_app_settings_batch(conn: &DbConn, entries: &[(&str, &str)]) -> DbResult<()> {
+    let _write_permit = super::db_write_permit().await;
     if entries.is_empty() {
         return Ok(());
     }
@@ -73,6 +75,7 @@ pub async fn set_app_settings_batch(conn: &DbConn, entries: &[(&str, &str)]) ->
 }
 
 pub async fn delete_app_settings(conn: &DbConn, keys: &[&str]) -> DbResult<()> {
+    let _write_permit = super::db_write_permit().await;
     if keys.is_empty() {
         return Ok(());
     }
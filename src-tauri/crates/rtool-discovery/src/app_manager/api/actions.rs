@@ -18,7 +18,8 @@ pub fn set_managed_app_startup(
         Path::new(item.path.as_str()),
         input.enabled,
     )?;
-    let _ = load_or_refresh_index(app, true)?;
+    let cache = load_or_refresh_index(app, true)?;
+    record_startup_auto_backup(app, &cache);
 
     let message = if input.enabled {
         "已启用开机启动"
@@ -33,13 +34,36 @@ pub fn set_managed_app_startup(
     ))
 }
 
+pub fn locate_managed_app_startup_entry(
+    app: &dyn LauncherHost,
+    app_id: String,
+) -> AppResult<AppManagerStartupEntryDto> {
+    let item = load_indexed_item(app, app_id.as_str())?;
+    let (scope, kind, location) =
+        platform_locate_startup_entry(item.id.as_str(), Path::new(item.path.as_str())).ok_or_else(
+            || {
+                app_error(
+                    AppManagerErrorCode::StartupEntryNotFound,
+                    "未找到该应用的启动项",
+                )
+                .with_context("appId", item.id.clone())
+            },
+        )?;
+    Ok(AppManagerStartupEntryDto {
+        app_id: item.id,
+        scope,
+        kind,
+        location,
+    })
+}
+
 pub fn uninstall_managed_app(
     app: &dyn LauncherHost,
     input: AppManagerUninstallInputDto,
 ) -> AppResult<AppManagerActionResultDto> {
     let item = load_indexed_item(app, input.app_id.as_str())?;
 
-    if item.fingerprint != input.confirmed_fingerprint {
+    if !fingerprint_matches(&item, input.confirmed_fingerprint.as_str()) {
         return Err(app_error(
             AppManagerErrorCode::FingerprintMismatch,
             "应用信息已变化，请刷新后重试",
@@ -76,13 +100,20 @@ pub fn open_uninstall_help(
     app_id: String,
 ) -> AppResult<AppManagerActionResultDto> {
     let item = load_indexed_item(app, app_id.as_str())?;
-    platform_open_uninstall_help(&item)?;
-    Ok(make_action_result(
+    let target = platform_open_uninstall_help(&item)?;
+    let message = match target {
+        AppManagerUninstallHelpTarget::RegisteredUninstaller => "已启动应用自带的卸载程序",
+        AppManagerUninstallHelpTarget::AppsAndFeatures => "已打开系统应用与功能设置",
+        AppManagerUninstallHelpTarget::FinderReveal => "已在访达中显示应用，可拖到废纸篓卸载",
+    };
+    let mut result = make_action_result(
         true,
         AppManagerActionCode::AppManagerUninstallHelpOpened,
-        "已打开系统卸载入口",
+        message,
         Some(item.name),
-    ))
+    );
+    result.uninstall_help_target = Some(target);
+    Ok(result)
 }
 
 pub fn open_permission_help(
@@ -98,3 +129,15 @@ pub fn open_permission_help(
         Some(item.name),
     ))
 }
+
+pub fn scan_ghost_startup_items() -> AppResult<Vec<AppManagerGhostStartupItemDto>> {
+    Ok(platform_scan_ghost_startup_items())
+}
+
+pub fn remove_ghost_startup_item(label: String) -> AppResult<()> {
+    let label = label.trim();
+    if label.is_empty() {
+        return Err(ghost_startup_not_found(label));
+    }
+    platform_remove_ghost_startup_item(label)
+}
@@ -5,7 +5,15 @@ pub fn get_managed_app_detail(
     query: AppManagerDetailQueryDto,
 ) -> AppResult<ManagedAppDetailDto> {
     let item = load_indexed_item(app, query.app_id.as_str())?;
-    Ok(build_app_detail(item))
+    Ok(build_app_detail(item, query.size_accuracy))
+}
+
+pub fn list_managed_app_related_roots(
+    app: &dyn LauncherHost,
+    app_id: String,
+) -> AppResult<Vec<AppRelatedRootDto>> {
+    let item = load_indexed_item(app, app_id.as_str())?;
+    Ok(build_related_roots(&item))
 }
 
 pub fn get_managed_app_detail_core(
@@ -0,0 +1,434 @@
+use super::*;
+
+const HTML_REPORT_STYLE: &str = "body{font-family:-apple-system,BlinkMacSystemFont,\"Segoe UI\",sans-serif;margin:24px;color:#1f2328;background:#fff}\
+h1{font-size:22px}h2{font-size:17px;margin-top:28px;border-bottom:1px solid #d0d7de;padding-bottom:4px}\
+h3{font-size:15px;margin-top:20px}table{border-collapse:collapse;width:100%;font-size:12px;margin-top:8px}\
+th,td{border:1px solid #d0d7de;padding:4px 8px;text-align:left;vertical-align:top}\
+th{background:#f6f8fa}td.path{font-family:ui-monospace,Menlo,Consolas,monospace;word-break:break-all}\
+p.meta{color:#57606a;font-size:12px}";
+
+struct ScanReportTable {
+    headers: &'static [&'static str],
+    rows: Vec<Vec<String>>,
+}
+
+struct ScanReportGroup {
+    title: String,
+    summary: String,
+    table: ScanReportTable,
+}
+
+struct ScanReport {
+    title: String,
+    exported_at: i64,
+    app_info: ScanReportTable,
+    related_roots: ScanReportTable,
+    groups: Vec<ScanReportGroup>,
+    warnings: ScanReportTable,
+}
+
+pub(super) fn render_scan_report(
+    format: AppManagerExportFormat,
+    item: &ManagedAppDto,
+    detail: &ManagedAppDetailDto,
+    scan_result: &AppManagerResidueScanResultDto,
+    exported_at: i64,
+) -> Result<String, serde_json::Error> {
+    match format {
+        AppManagerExportFormat::Json => {
+            let payload = serde_json::json!({
+                "exportedAt": exported_at,
+                "app": item,
+                "detail": detail,
+                "scanResult": scan_result
+            });
+            serde_json::to_string_pretty(&payload)
+        }
+        AppManagerExportFormat::Markdown => Ok(render_markdown(&build_scan_report(
+            item,
+            detail,
+            scan_result,
+            exported_at,
+        ))),
+        AppManagerExportFormat::Html => Ok(render_html(&build_scan_report(
+            item,
+            detail,
+            scan_result,
+            exported_at,
+        ))),
+    }
+}
+
+fn build_scan_report(
+    item: &ManagedAppDto,
+    detail: &ManagedAppDetailDto,
+    scan_result: &AppManagerResidueScanResultDto,
+    exported_at: i64,
+) -> ScanReport {
+    let size_summary = &detail.size_summary;
+    let app_rows = vec![
+        vec!["ID".to_string(), item.id.clone()],
+        vec!["Name".to_string(), item.name.clone()],
+        vec!["Path".to_string(), item.path.clone()],
+        vec!["Install Path".to_string(), detail.install_path.clone()],
+        vec![
+            "Bundle / App ID".to_string(),
+            item.bundle_or_app_id.clone().unwrap_or_default(),
+        ],
+        vec![
+            "Version".to_string(),
+            item.version.clone().unwrap_or_default(),
+        ],
+        vec![
+            "Publisher".to_string(),
+            item.publisher.clone().unwrap_or_default(),
+        ],
+        vec!["Platform".to_string(), enum_label(&item.platform)],
+        vec!["Source".to_string(), enum_label(&item.source)],
+        vec![
+            "App Size".to_string(),
+            optional_bytes(size_summary.app_bytes),
+        ],
+        vec![
+            "Residue Size".to_string(),
+            optional_bytes(size_summary.residue_bytes),
+        ],
+        vec![
+            "Total Size".to_string(),
+            optional_bytes(size_summary.total_bytes),
+        ],
+        vec!["Scan Mode".to_string(), enum_label(&scan_result.scan_mode)],
+        vec![
+            "Residue Total".to_string(),
+            format_bytes(scan_result.total_size_bytes),
+        ],
+    ];
+
+    let root_rows = detail
+        .related_roots
+        .iter()
+        .map(|root| {
+            vec![
+                root.label.clone(),
+                root.path.clone(),
+                enum_label(&root.kind),
+                enum_label(&root.scope),
+                yes_no(root.exists),
+                yes_no(root.cleanable),
+                root.readonly_reason_code
+                    .as_ref()
+                    .map(enum_label)
+                    .unwrap_or_default(),
+            ]
+        })
+        .collect();
+
+    let groups = scan_result
+        .groups
+        .iter()
+        .map(|group| ScanReportGroup {
+            title: group.label.clone(),
+            summary: format!(
+                "kind: {} · scope: {} · items: {} · recommended: {} · size: {}",
+                enum_label(&group.kind),
+                enum_label(&group.scope),
+                group.total_items,
+                group.recommended_items,
+                format_bytes(group.total_size_bytes)
+            ),
+            table: ScanReportTable {
+                headers: &[
+                    "Path",
+                    "Size",
+                    "Match Reason",
+                    "Confidence",
+                    "Risk",
+                    "Recommended",
+                    "Readonly",
+                    "Evidence",
+                    "Annotation",
+                ],
+                rows: group
+                    .items
+                    .iter()
+                    .map(|residue| {
+                        vec![
+                            residue.path.clone(),
+                            format_bytes(residue.size_bytes),
+                            enum_label(&residue.match_reason),
+                            enum_label(&residue.confidence),
+                            enum_label(&residue.risk_level),
+                            yes_no(residue.recommended),
+                            yes_no(residue.readonly),
+                            residue.evidence.join("; "),
+                            residue.annotation.clone().unwrap_or_default(),
+                        ]
+                    })
+                    .collect(),
+            },
+        })
+        .collect();
+
+    let warning_rows = scan_result
+        .warnings
+        .iter()
+        .map(|warning| {
+            vec![
+                enum_label(&warning.code),
+                warning
+                    .detail_code
+                    .as_ref()
+                    .map(enum_label)
+                    .unwrap_or_default(),
+                warning.path.clone().unwrap_or_default(),
+            ]
+        })
+        .collect();
+
+    ScanReport {
+        title: format!("{} Scan Report", item.name),
+        exported_at,
+        app_info: ScanReportTable {
+            headers: &["Field", "Value"],
+            rows: app_rows,
+        },
+        related_roots: ScanReportTable {
+            headers: &[
+                "Label",
+                "Path",
+                "Kind",
+                "Scope",
+                "Exists",
+                "Cleanable",
+                "Readonly Reason",
+            ],
+            rows: root_rows,
+        },
+        groups,
+        warnings: ScanReportTable {
+            headers: &["Code", "Detail", "Path"],
+            rows: warning_rows,
+        },
+    }
+}
+
+fn render_markdown(report: &ScanReport) -> String {
+    let mut out = format!(
+        "# {}\n\nExported at (unix seconds): {}\n\n## App Info\n\n{}\n## Related Roots\n\n{}\n## Residue Groups\n\n",
+        markdown_text(report.title.as_str()),
+        report.exported_at,
+        markdown_table(&report.app_info),
+        markdown_table(&report.related_roots)
+    );
+    if report.groups.is_empty() {
+        out.push_str("_No residue found._\n\n");
+    }
+    for group in &report.groups {
+        out.push_str(
+            format!(
+                "### {}\n\n{}\n\n{}\n",
+                markdown_text(group.title.as_str()),
+                markdown_text(group.summary.as_str()),
+                markdown_table(&group.table)
+            )
+            .as_str(),
+        );
+    }
+    if !report.warnings.rows.is_empty() {
+        out.push_str(format!("## Warnings\n\n{}", markdown_table(&report.warnings)).as_str());
+    }
+    out
+}
+
+fn markdown_table(table: &ScanReportTable) -> String {
+    if table.rows.is_empty() {
+        return "_None._\n".to_string();
+    }
+    let mut out = format!(
+        "| {} |\n|{}\n",
+        table.headers.join(" | "),
+        " --- |".repeat(table.headers.len())
+    );
+    for row in &table.rows {
+        let cells = row
+            .iter()
+            .map(|cell| markdown_cell(cell))
+            .collect::<Vec<_>>();
+        out.push_str(format!("| {} |\n", cells.join(" | ")).as_str());
+    }
+    out
+}
+
+fn markdown_text(value: &str) -> String {
+    value.replace(['\r', '\n'], " ")
+}
+
+fn markdown_cell(value: &str) -> String {
+    markdown_text(value).replace('|', "\\|")
+}
+
+fn render_html(report: &ScanReport) -> String {
+    let mut groups_html = String::new();
+    if report.groups.is_empty() {
+        groups_html.push_str("<p class=\"meta\">No residue found.</p>\n");
+    }
+    for group in &report.groups {
+        groups_html.push_str(
+            format!(
+                "<h3>{}</h3>\n<p class=\"meta\">{}</p>\n{}",
+                html_escape(group.title.as_str()),
+                html_escape(group.summary.as_str()),
+                html_table(&group.table, 0)
+            )
+            .as_str(),
+        );
+    }
+    let warnings_html = if report.warnings.rows.is_empty() {
+        String::new()
+    } else {
+        format!("<h2>Warnings</h2>\n{}", html_table(&report.warnings, 2))
+    };
+    let title = html_escape(report.title.as_str());
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>{HTML_REPORT_STYLE}</style>\n</head>\n<body>\n<h1>{title}</h1>\n<p class=\"meta\">Exported at (unix seconds): {}</p>\n<h2>App Info</h2>\n{}<h2>Related Roots</h2>\n{}<h2>Residue Groups</h2>\n{groups_html}{warnings_html}</body>\n</html>\n",
+        report.exported_at,
+        html_table(&report.app_info, 1),
+        html_table(&report.related_roots, 1)
+    )
+}
+
+fn html_table(table: &ScanReportTable, path_column: usize) -> String {
+    if table.rows.is_empty() {
+        return "<p class=\"meta\">None.</p>\n".to_string();
+    }
+    let headers = table
+        .headers
+        .iter()
+        .map(|header| format!("<th>{}</th>", html_escape(header)))
+        .collect::<String>();
+    let rows = table
+        .rows
+        .iter()
+        .map(|row| {
+            let cells = row
+                .iter()
+                .enumerate()
+                .map(|(index, cell)| {
+                    let class = if index == path_column {
+                        " class=\"path\""
+                    } else {
+                        ""
+                    };
+                    format!("<td{class}>{}</td>", html_escape(cell))
+                })
+                .collect::<String>();
+            format!("<tr>{cells}</tr>\n")
+        })
+        .collect::<String>();
+    format!("<table>\n<thead><tr>{headers}</tr></thead>\n<tbody>\n{rows}</tbody>\n</table>\n")
+}
+
+fn html_escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+fn enum_label<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+fn yes_no(value: bool) -> String {
+    if value { "yes" } else { "no" }.to_string()
+}
+
+fn optional_bytes(value: Option<u64>) -> String {
+    value.map(format_bytes).unwrap_or_else(|| "-".to_string())
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_report() -> ScanReport {
+        ScanReport {
+            title: "Demo <App> Scan Report".to_string(),
+            exported_at: 1_700_000_000,
+            app_info: ScanReportTable {
+                headers: &["Field", "Value"],
+                rows: vec![vec!["Name".to_string(), "Demo|App".to_string()]],
+            },
+            related_roots: ScanReportTable {
+                headers: &["Label", "Path"],
+                rows: Vec::new(),
+            },
+            groups: vec![ScanReportGroup {
+                title: "Caches".to_string(),
+                summary: "items: 1".to_string(),
+                table: ScanReportTable {
+                    headers: &["Path", "Size"],
+                    rows: vec![vec!["/tmp/a&b".to_string(), format_bytes(2048)]],
+                },
+            }],
+            warnings: ScanReportTable {
+                headers: &["Code"],
+                rows: Vec::new(),
+            },
+        }
+    }
+
+    #[test]
+    fn markdown_report_renders_tables_and_escapes_pipes() {
+        let markdown = render_markdown(&sample_report());
+        assert!(markdown.starts_with("# Demo <App> Scan Report\n"));
+        assert!(markdown.contains("| Field | Value |\n| --- | --- |\n| Name | Demo\\|App |\n"));
+        assert!(markdown.contains("## Related Roots\n\n_None._\n"));
+        assert!(markdown.contains("### Caches\n\nitems: 1\n\n| Path | Size |"));
+        assert!(markdown.contains("| /tmp/a&b | 2.0 KB |"));
+        assert!(!markdown.contains("## Warnings"));
+    }
+
+    #[test]
+    fn html_report_is_self_contained_and_escaped() {
+        let html = render_html(&sample_report());
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<style>"));
+        assert!(html.contains("<title>Demo &lt;App&gt; Scan Report</title>"));
+        assert!(html.contains("<td class=\"path\">/tmp/a&amp;b</td><td>2.0 KB</td>"));
+        assert!(!html.contains("<script"));
+    }
+
+    #[test]
+    fn format_bytes_uses_binary_units() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MB");
+    }
+}
@@ -0,0 +1,177 @@
+use super::*;
+
+const EXPORT_FILE_STEM_SUFFIX: &str = "-scan";
+const SECONDS_PER_DAY: i64 = 86_400;
+
+pub fn list_managed_app_exports() -> AppResult<AppManagerExportListDto> {
+    let export_dir = export_root_dir();
+    let files = collect_export_files(export_dir.as_path())?;
+    Ok(AppManagerExportListDto {
+        directory_path: export_dir.to_string_lossy().to_string(),
+        total_bytes: files.iter().map(|file| file.size_bytes).sum(),
+        files,
+    })
+}
+
+pub fn clear_managed_app_exports(
+    input: AppManagerClearExportsInputDto,
+) -> AppResult<AppManagerClearExportsResultDto> {
+    clear_export_files(
+        export_root_dir().as_path(),
+        input.older_than_days,
+        now_unix_seconds(),
+    )
+}
+
+fn export_file_format(path: &Path) -> Option<AppManagerExportFormat> {
+    let format = AppManagerExportFormat::from_file_extension(path.extension()?.to_str()?)?;
+    path.file_stem()?
+        .to_str()?
+        .ends_with(EXPORT_FILE_STEM_SUFFIX)
+        .then_some(format)
+}
+
+fn collect_export_files(export_dir: &Path) -> AppResult<Vec<AppManagerExportFileDto>> {
+    let entries = match fs::read_dir(export_dir) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => {
+            return Err(error)
+                .with_context(|| format!("读取导出目录失败: {}", export_dir.display()))
+                .with_code(
+                    AppManagerErrorCode::ExportListFailed.as_str(),
+                    "读取导出目录失败",
+                )
+                .with_ctx("exportDir", export_dir.display().to_string());
+        }
+    };
+
+    let mut files = entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            let format = export_file_format(path.as_path())?;
+            let metadata = entry
+                .metadata()
+                .ok()
+                .filter(|metadata| metadata.is_file())?;
+            Some(AppManagerExportFileDto {
+                file_name: entry.file_name().to_string_lossy().to_string(),
+                file_path: path.to_string_lossy().to_string(),
+                format,
+                size_bytes: metadata.len(),
+                modified_at: metadata
+                    .modified()
+                    .ok()
+                    .and_then(|value| value.duration_since(UNIX_EPOCH).ok())
+                    .map(|value| value.as_secs() as i64),
+            })
+        })
+        .collect::<Vec<_>>();
+    files.sort_by(|left, right| {
+        right
+            .modified_at
+            .cmp(&left.modified_at)
+            .then_with(|| left.file_name.cmp(&right.file_name))
+    });
+    Ok(files)
+}
+
+fn clear_export_files(
+    export_dir: &Path,
+    older_than_days: Option<u32>,
+    now_seconds: i64,
+) -> AppResult<AppManagerClearExportsResultDto> {
+    let cutoff = older_than_days.map(|days| now_seconds - i64::from(days) * SECONDS_PER_DAY);
+    let mut result = AppManagerClearExportsResultDto {
+        deleted_count: 0,
+        failed_count: 0,
+        reclaimed_bytes: 0,
+    };
+    for file in collect_export_files(export_dir)? {
+        if let Some(cutoff) = cutoff
+            && file
+                .modified_at
+                .is_none_or(|modified_at| modified_at > cutoff)
+        {
+            continue;
+        }
+        match fs::remove_file(file.file_path.as_str()) {
+            Ok(()) => {
+                result.deleted_count += 1;
+                result.reclaimed_bytes = result.reclaimed_bytes.saturating_add(file.size_bytes);
+            }
+            Err(error) => {
+                result.failed_count += 1;
+                tracing::warn!(
+                    event = "app_manager_export_delete_failed",
+                    file_path = file.file_path.as_str(),
+                    error = error.to_string()
+                );
+            }
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_export_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "rtool-app-manager-exports-{name}-{}",
+            now_unix_millis()
+        ));
+        fs::create_dir_all(&dir).expect("create export dir");
+        dir
+    }
+
+    #[test]
+    fn missing_export_dir_lists_nothing() {
+        let dir = std::env::temp_dir().join(format!(
+            "rtool-app-manager-exports-missing-{}",
+            now_unix_millis()
+        ));
+        assert!(
+            collect_export_files(dir.as_path())
+                .expect("list")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn lists_only_export_files_and_clears_them() {
+        let dir = temp_export_dir("clear");
+        fs::write(dir.join("Foo-1700000000000-scan.json"), "{}").expect("write json");
+        fs::write(dir.join("Foo-1700000000001-scan.md"), "# Foo").expect("write md");
+        fs::write(dir.join("notes.txt"), "keep").expect("write other");
+        fs::write(dir.join("Foo-scan.pdf"), "keep").expect("write pdf");
+        fs::create_dir_all(dir.join("Bar-scan.html")).expect("create dir");
+
+        let files = collect_export_files(dir.as_path()).expect("list");
+        let mut names = files
+            .iter()
+            .map(|file| file.file_name.as_str())
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        assert_eq!(
+            names,
+            vec!["Foo-1700000000000-scan.json", "Foo-1700000000001-scan.md"]
+        );
+
+        let now = now_unix_seconds();
+        let kept = clear_export_files(dir.as_path(), Some(1), now).expect("clear old");
+        assert_eq!(kept.deleted_count, 0);
+
+        let cleared = clear_export_files(dir.as_path(), Some(1), now + 2 * SECONDS_PER_DAY)
+            .expect("clear aged");
+        assert_eq!(cleared.deleted_count, 2);
+        assert_eq!(cleared.failed_count, 0);
+        assert_eq!(cleared.reclaimed_bytes, 7);
+        assert!(dir.join("notes.txt").exists());
+        assert!(dir.join("Bar-scan.html").is_dir());
+
+        let _ = fs::remove_dir_all(dir);
+    }
+}
@@ -17,15 +17,28 @@ pub fn refresh_managed_apps_index(app: &dyn LauncherHost) -> AppResult<AppManage
     ))
 }
 
+pub fn pending_managed_apps_index_refresh() -> AppManagerSnapshotMetaDto {
+    let mut meta = snapshot_meta_from_cache(&read_index_snapshot());
+    meta.index_state = AppManagerIndexState::Building;
+    meta
+}
+
+pub fn rebuild_managed_apps_index(
+    app: &dyn LauncherHost,
+) -> AppResult<AppManagerIndexUpdatedPayloadDto> {
+    let meta = refresh_index_with_meta(app, true)?;
+    Ok(AppManagerIndexUpdatedPayloadDto {
+        revision: meta.cache.revision,
+        indexed_at: meta.cache.indexed_at,
+        changed_count: meta.changed_count,
+        reason: AppManagerIndexUpdateReason::Manual,
+    })
+}
+
 pub fn poll_managed_apps_auto_refresh(
     app: &dyn LauncherHost,
 ) -> AppResult<Option<AppManagerIndexUpdatedPayloadDto>> {
-    let runtime = app_index_runtime();
-    let cache = runtime
-        .cache
-        .lock()
-        .unwrap_or_else(|poisoned| poisoned.into_inner())
-        .clone();
+    let cache = read_index_snapshot();
     let latest_fingerprint = collect_index_source_fingerprint();
     if !cache.source_fingerprint.is_empty() && latest_fingerprint == cache.source_fingerprint {
         return Ok(None);
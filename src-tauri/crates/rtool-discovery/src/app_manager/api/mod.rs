@@ -2,15 +2,21 @@ use super::*;
 
 mod actions;
 mod details;
+mod export_report;
+mod exports;
 mod indexing;
 mod query;
 mod residue;
+mod startup_backup;
 
 pub use actions::*;
 pub use details::*;
+use export_report::*;
+pub use exports::*;
 pub use indexing::*;
 pub use query::*;
 pub use residue::*;
+pub use startup_backup::*;
 
 pub(super) fn scan_cache_key(app_id: &str, mode: AppManagerResidueScanMode) -> String {
     let mode_key = match mode {
@@ -25,6 +31,17 @@ pub(super) fn load_indexed_item(app: &dyn LauncherHost, app_id: &str) -> AppResu
     find_indexed_item_in_cache(&cache, app_id)
 }
 
+/// Residue actions also accept items from path scans, which never enter the index.
+pub(super) fn load_residue_target_item(
+    app: &dyn LauncherHost,
+    app_id: &str,
+) -> AppResult<ManagedAppDto> {
+    if is_scan_path_app_id(app_id) {
+        return find_scan_path_item(app_id);
+    }
+    load_indexed_item(app, app_id)
+}
+
 pub(super) fn find_indexed_item_in_cache(
     cache: &AppIndexCache,
     app_id: &str,
@@ -1,13 +1,38 @@
 use super::*;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
 
-fn resolve_single_app_size(item: &ManagedAppDto) -> AppManagerResolvedSizeDto {
+/// Apps reached after a cancel are skipped; the app whose walk was interrupted is returned with
+/// its partial total as an estimate.
+fn resolve_single_app_size(
+    item: &ManagedAppDto,
+    cancel: &AtomicBool,
+) -> Option<AppManagerResolvedSizeDto> {
+    if cancel.load(AtomicOrdering::Relaxed) {
+        return None;
+    }
     let size_resolution = resolve_managed_app_size_path(item);
-    let measured = exact_path_size_bytes(size_resolution.path.as_path())
-        .map(|size_bytes| (Some(size_bytes), AppManagerSizeAccuracy::Exact))
-        .or_else(|| {
-            try_get_path_size_bytes(size_resolution.path.as_path())
-                .map(|size_bytes| (Some(size_bytes), AppManagerSizeAccuracy::Estimated))
-        });
+    let computation = exact_path_size_bytes_cancellable(size_resolution.path.as_path(), cancel);
+    Some(resolved_size_from_walk(item, size_resolution, computation))
+}
+
+fn resolved_size_from_walk(
+    item: &ManagedAppDto,
+    size_resolution: AppSizePathResolution,
+    computation: Option<PathSizeComputation>,
+) -> AppManagerResolvedSizeDto {
+    let cancelled = computation
+        .as_ref()
+        .is_some_and(|computation| computation.cancelled);
+    let measured = match computation {
+        Some(computation) if computation.cancelled => Some((
+            Some(computation.size_bytes),
+            AppManagerSizeAccuracy::Estimated,
+        )),
+        Some(computation) => Some((Some(computation.size_bytes), AppManagerSizeAccuracy::Exact)),
+        None => try_get_path_size_bytes(size_resolution.path.as_path())
+            .map(|size_bytes| (Some(size_bytes), AppManagerSizeAccuracy::Estimated)),
+    };
 
     let (size_bytes, size_accuracy, size_source, size_computed_at) = match measured {
         Some((size_bytes, size_accuracy)) => (
@@ -30,6 +55,39 @@ fn resolve_single_app_size(item: &ManagedAppDto) -> AppManagerResolvedSizeDto {
         size_accuracy,
         size_source,
         size_computed_at,
+        cancelled,
+    }
+}
+
+/// The list never walks past the `Standard` budget; `Exact` stays on the detail view and the
+/// cancellable resolve path. `Quick` sizes, and anything already measured at least as well,
+/// come straight from the index.
+fn list_size_measure_mode(
+    item: &ManagedAppDto,
+    requested: AppManagerSizeAccuracyMode,
+) -> Option<AppManagerSizeAccuracyMode> {
+    if requested == AppManagerSizeAccuracyMode::Quick
+        || item.size_accuracy == AppManagerSizeAccuracy::Exact
+        || item
+            .size_accuracy_used
+            .is_some_and(|used| used != AppManagerSizeAccuracyMode::Quick)
+    {
+        return None;
+    }
+    Some(AppManagerSizeAccuracyMode::Standard)
+}
+
+/// Applies `update` to the shared index cache, refreshing the fingerprint of every changed item.
+fn update_cached_items(mut update: impl FnMut(&mut ManagedAppDto) -> bool) {
+    let runtime = app_index_runtime();
+    let mut guard = runtime
+        .cache
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    for item in &mut Arc::make_mut(&mut guard).items {
+        if update(item) {
+            item.fingerprint = fingerprint_for_app(item);
+        }
     }
 }
 
@@ -45,6 +103,12 @@ pub fn list_managed_apps(
         .filter(|value| !value.is_empty())
         .map(|value| value.to_ascii_lowercase());
     let normalized_category = query.category;
+    let normalized_folder = query
+        .folder_filter
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(normalize_path_key);
     let limit = query
         .limit
         .map(|value| value as usize)
@@ -65,6 +129,16 @@ pub fn list_managed_apps(
         if !item_matches_keyword(item, normalized_keyword.as_deref()) {
             continue;
         }
+        if let Some(folder) = normalized_folder.as_deref()
+            && item
+                .folder_path
+                .as_deref()
+                .map(normalize_path_key)
+                .as_deref()
+                != Some(folder)
+        {
+            continue;
+        }
         if total >= offset && items.len() < limit {
             items.push(item.clone());
         }
@@ -82,6 +156,32 @@ pub fn list_managed_apps(
         });
     }
 
+    if let Some(mode) = query.size_accuracy {
+        let mut measured = HashMap::new();
+        for item in &mut items {
+            let Some(list_mode) = list_size_measure_mode(item, mode) else {
+                continue;
+            };
+            if apply_size_accuracy_mode(item, list_mode) {
+                item.fingerprint = fingerprint_for_app(item);
+                measured.insert(item.id.clone(), item.clone());
+            }
+        }
+        if !measured.is_empty() {
+            update_cached_items(|cached| {
+                let Some(value) = measured.get(cached.id.as_str()) else {
+                    return false;
+                };
+                cached.size_bytes = value.size_bytes;
+                cached.size_accuracy = value.size_accuracy;
+                cached.size_source = value.size_source;
+                cached.size_computed_at = value.size_computed_at;
+                cached.size_accuracy_used = value.size_accuracy_used;
+                true
+            });
+        }
+    }
+
     let consumed = offset.saturating_add(items.len());
     let next_cursor = if consumed < total {
         Some(consumed.to_string())
@@ -99,16 +199,28 @@ pub fn list_managed_apps(
     })
 }
 
-pub fn list_managed_apps_snapshot_meta(
-    app: &dyn LauncherHost,
-) -> AppResult<AppManagerSnapshotMetaDto> {
-    let cache = load_or_refresh_index(app, false)?;
-    Ok(AppManagerSnapshotMetaDto {
+pub(super) fn snapshot_meta_from_cache(cache: &AppIndexCache) -> AppManagerSnapshotMetaDto {
+    AppManagerSnapshotMetaDto {
         indexed_at: cache.indexed_at,
         revision: cache.revision,
         total_count: cache.items.len() as u64,
         index_state: cache.index_state,
-    })
+        source_fingerprint: cache.source_fingerprint.clone(),
+        last_error: cache.last_error.clone(),
+        build_duration_ms: cache.build_duration_ms,
+    }
+}
+
+pub fn list_managed_apps_snapshot_meta(
+    app: &dyn LauncherHost,
+) -> AppResult<AppManagerSnapshotMetaDto> {
+    let cache = load_or_refresh_index(app, false)?;
+    Ok(snapshot_meta_from_cache(&cache))
+}
+
+pub fn get_managed_apps_snapshot_meta() -> AppResult<AppManagerSnapshotMetaDto> {
+    let cache = read_index_snapshot();
+    Ok(snapshot_meta_from_cache(&cache))
 }
 
 pub fn resolve_managed_app_sizes(
@@ -122,38 +234,163 @@ pub fn resolve_managed_app_sizes(
         .map(|value| value.as_str())
         .collect::<HashSet<_>>();
     if wanted.is_empty() {
-        return Ok(AppManagerResolveSizesResultDto { items: Vec::new() });
+        return Ok(AppManagerResolveSizesResultDto {
+            items: Vec::new(),
+            cancelled: false,
+        });
     }
 
-    let mut resolved = Vec::with_capacity(wanted.len());
-    for item in cache
+    let cancel_token = input
+        .cancel_token
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty());
+    let cancel = match cancel_token {
+        Some(token) => register_size_cancel_token(token),
+        None => Arc::new(AtomicBool::new(false)),
+    };
+    let targets = cache
         .items
         .iter()
         .filter(|candidate| wanted.contains(candidate.id.as_str()))
-    {
-        resolved.push(resolve_single_app_size(item));
+        .collect::<Vec<_>>();
+    let parallelism = input
+        .parallelism
+        .map(|value| value as usize)
+        .unwrap_or(1)
+        .clamp(1, SIZE_RESOLVE_MAX_PARALLELISM)
+        .min(targets.len().max(1));
+    let resolved = if parallelism <= 1 {
+        targets
+            .iter()
+            .filter_map(|item| resolve_single_app_size(item, cancel.as_ref()))
+            .collect::<Vec<_>>()
+    } else {
+        let chunk_size = targets.len().div_ceil(parallelism);
+        std::thread::scope(|scope| {
+            let workers = targets
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    let cancel = cancel.as_ref();
+                    scope.spawn(move || {
+                        chunk
+                            .iter()
+                            .filter_map(|item| resolve_single_app_size(item, cancel))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect::<Vec<_>>();
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().unwrap_or_default())
+                .collect::<Vec<_>>()
+        })
+    };
+    let cancelled = cancel.load(AtomicOrdering::Relaxed);
+    if let Some(token) = cancel_token {
+        release_size_cancel_token(token);
     }
 
     if !resolved.is_empty() {
+        // Partial totals are only reported back, never cached over the indexed size.
         let resolved_by_id = resolved
             .iter()
+            .filter(|value| !value.cancelled)
             .map(|value| (value.app_id.as_str(), value))
             .collect::<HashMap<_, _>>();
-        let runtime = app_index_runtime();
-        let mut guard = runtime
-            .cache
-            .lock()
-            .unwrap_or_else(|poisoned| poisoned.into_inner());
-        for item in &mut guard.items {
-            if let Some(value) = resolved_by_id.get(item.id.as_str()) {
-                item.size_bytes = value.size_bytes;
-                item.size_accuracy = value.size_accuracy;
-                item.size_source = value.size_source;
-                item.size_computed_at = value.size_computed_at;
-                item.fingerprint = fingerprint_for_app(item);
-            }
-        }
+        update_cached_items(|item| {
+            let Some(value) = resolved_by_id.get(item.id.as_str()) else {
+                return false;
+            };
+            item.size_bytes = value.size_bytes;
+            item.size_accuracy = value.size_accuracy;
+            item.size_source = value.size_source;
+            item.size_computed_at = value.size_computed_at;
+            true
+        });
+    }
+
+    Ok(AppManagerResolveSizesResultDto {
+        items: resolved,
+        cancelled,
+    })
+}
+
+pub fn cancel_managed_app_size_resolution(cancel_token: String) -> AppResult<bool> {
+    Ok(cancel_size_token(cancel_token.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sized_app(
+        size_accuracy: AppManagerSizeAccuracy,
+        size_accuracy_used: Option<AppManagerSizeAccuracyMode>,
+    ) -> ManagedAppDto {
+        let mut item = build_scan_path_item(Path::new("/Applications/Sized.app"));
+        item.size_accuracy = size_accuracy;
+        item.size_accuracy_used = size_accuracy_used;
+        item
+    }
+
+    #[test]
+    fn list_measures_at_most_standard_and_reuses_indexed_sizes() {
+        let estimated = sized_app(AppManagerSizeAccuracy::Estimated, None);
+        assert_eq!(
+            list_size_measure_mode(&estimated, AppManagerSizeAccuracyMode::Quick),
+            None
+        );
+        assert_eq!(
+            list_size_measure_mode(&estimated, AppManagerSizeAccuracyMode::Standard),
+            Some(AppManagerSizeAccuracyMode::Standard)
+        );
+        assert_eq!(
+            list_size_measure_mode(&estimated, AppManagerSizeAccuracyMode::Exact),
+            Some(AppManagerSizeAccuracyMode::Standard)
+        );
+
+        let standard = sized_app(
+            AppManagerSizeAccuracy::Estimated,
+            Some(AppManagerSizeAccuracyMode::Standard),
+        );
+        assert_eq!(
+            list_size_measure_mode(&standard, AppManagerSizeAccuracyMode::Exact),
+            None
+        );
+        let exact = sized_app(AppManagerSizeAccuracy::Exact, None);
+        assert_eq!(
+            list_size_measure_mode(&exact, AppManagerSizeAccuracyMode::Standard),
+            None
+        );
     }
 
-    Ok(AppManagerResolveSizesResultDto { items: resolved })
+    #[test]
+    fn cancelled_resolution_reports_a_partial_estimate() {
+        let item = build_scan_path_item(Path::new("/Applications/Partial.app"));
+        let walk = |size_bytes, cancelled| {
+            resolved_size_from_walk(
+                &item,
+                resolve_managed_app_size_path(&item),
+                Some(PathSizeComputation {
+                    size_bytes,
+                    warnings: Vec::new(),
+                    cancelled,
+                }),
+            )
+        };
+
+        let full = walk(64, false);
+        assert!(!full.cancelled);
+        assert_eq!(full.size_bytes, Some(64));
+        assert_eq!(full.size_accuracy, AppManagerSizeAccuracy::Exact);
+
+        let partial = walk(16, true);
+        assert!(partial.cancelled);
+        assert_eq!(partial.size_bytes, Some(16));
+        assert_eq!(partial.size_accuracy, AppManagerSizeAccuracy::Estimated);
+
+        let cancel = AtomicBool::new(true);
+        assert!(resolve_single_app_size(&item, &cancel).is_none());
+    }
 }
@@ -4,7 +4,9 @@ fn read_cached_scan_result(cache_key: &str) -> Option<AppManagerResidueScanResul
     let scan_cache = residue_scan_cache()
         .lock()
         .unwrap_or_else(|poisoned| poisoned.into_inner());
-    scan_cache.get(cache_key).map(|entry| entry.result.clone())
+    scan_cache
+        .get(cache_key)
+        .map(ResidueScanCacheEntry::annotated_result)
 }
 
 pub fn scan_managed_app_residue(
@@ -13,27 +15,38 @@ pub fn scan_managed_app_residue(
 ) -> AppResult<AppManagerResidueScanResultDto> {
     cleanup_stale_scan_cache();
     let scan_mode = input.mode.unwrap_or(AppManagerResidueScanMode::Deep);
-    let item = load_indexed_item(app, input.app_id.as_str())?;
+    let item = load_residue_target_item(app, input.app_id.as_str())?;
+    Ok(load_or_build_scan(&item, scan_mode))
+}
 
+pub fn scan_managed_path_residue(
+    input: AppManagerScanPathInputDto,
+) -> AppResult<AppManagerResidueScanResultDto> {
+    cleanup_stale_scan_cache();
+    let scan_mode = input.mode.unwrap_or(AppManagerResidueScanMode::Deep);
+    let path = resolve_scan_path(input.path.as_str(), &ScanPathScope::from_env())?;
+    let item = build_scan_path_item(path.as_path());
+    remember_scan_path_item(&item);
+    Ok(load_or_build_scan(&item, scan_mode))
+}
+
+fn load_or_build_scan(
+    item: &ManagedAppDto,
+    scan_mode: AppManagerResidueScanMode,
+) -> AppManagerResidueScanResultDto {
     let cache_key = scan_cache_key(item.id.as_str(), scan_mode);
     if let Some(result) = read_cached_scan_result(cache_key.as_str()) {
-        return Ok(result);
+        return result;
     }
 
-    let result = build_residue_scan_result(&item, scan_mode);
+    let result = build_residue_scan_result(item, scan_mode);
     {
         let mut scan_cache = residue_scan_cache()
             .lock()
             .unwrap_or_else(|poisoned| poisoned.into_inner());
-        scan_cache.insert(
-            cache_key,
-            ResidueScanCacheEntry {
-                refreshed_at: Instant::now(),
-                result: result.clone(),
-            },
-        );
+        scan_cache.insert(cache_key, ResidueScanCacheEntry::new(result.clone()));
     }
-    Ok(result)
+    annotate_scan_result(result)
 }
 
 pub fn cleanup_managed_app_residue(
@@ -41,22 +54,50 @@ pub fn cleanup_managed_app_residue(
     input: AppManagerCleanupInputDto,
 ) -> AppResult<AppManagerCleanupResultDto> {
     cleanup_stale_scan_cache();
-    let item = load_indexed_item(app, input.app_id.as_str())?;
+    let item = load_residue_target_item(app, input.app_id.as_str())?;
     let scan_result = load_or_build_deep_scan(&item);
 
     let result = execute_cleanup_plan(&item, &scan_result, input)?;
-    let _ = load_or_refresh_index(app, true)?;
+    if !is_scan_path_app_id(item.id.as_str()) {
+        let _ = load_or_refresh_index(app, true)?;
+    }
     Ok(result)
 }
 
+pub fn annotate_managed_app_residue_item(
+    app: &dyn LauncherHost,
+    input: AppManagerAnnotateResidueInputDto,
+) -> AppResult<()> {
+    cleanup_stale_scan_cache();
+    let annotation = ResidueAnnotation::parse(input.note.as_str(), input.skip)?;
+    let item = load_residue_target_item(app, input.app_id.as_str())?;
+    let scan_result = load_or_build_deep_scan(&item);
+    let item_exists = scan_result
+        .groups
+        .iter()
+        .flat_map(|group| group.items.iter())
+        .any(|entry| entry.item_id == input.item_id);
+    if !item_exists {
+        return Err(app_error(
+            AppManagerErrorCode::ResidueItemNotFound,
+            "残留项不存在，请重新扫描",
+        )
+        .with_context("appId", input.app_id)
+        .with_context("itemId", input.item_id));
+    }
+
+    set_residue_annotation(item.id.as_str(), input.item_id.as_str(), annotation);
+    Ok(())
+}
+
 pub fn export_managed_app_scan_result(
     app: &dyn LauncherHost,
     input: AppManagerExportScanInputDto,
 ) -> AppResult<AppManagerExportScanResultDto> {
     cleanup_stale_scan_cache();
-    let item = load_indexed_item(app, input.app_id.as_str())?;
+    let item = load_residue_target_item(app, input.app_id.as_str())?;
     let scan_result = load_or_build_deep_scan(&item);
-    let detail = build_app_detail(item.clone());
+    let detail = build_app_detail(item.clone(), None);
 
     let export_dir = export_root_dir();
     fs::create_dir_all(&export_dir)
@@ -67,22 +108,23 @@ pub fn export_managed_app_scan_result(
         )
         .with_ctx("exportDir", export_dir.display().to_string())?;
 
+    let format = input.format.unwrap_or_default();
     let stem = sanitize_file_stem(item.name.as_str());
-    let file_name = format!("{}-{}-scan.json", stem, now_unix_millis());
+    let file_name = format!(
+        "{}-{}-scan.{}",
+        stem,
+        now_unix_millis(),
+        format.file_extension()
+    );
     let file_path = export_dir.join(file_name);
-    let payload = serde_json::json!({
-        "exportedAt": now_unix_seconds(),
-        "app": item,
-        "detail": detail,
-        "scanResult": scan_result
-    });
-    let content = serde_json::to_string_pretty(&payload)
+    let content = render_scan_report(format, &item, &detail, &scan_result, now_unix_seconds())
         .with_context(|| format!("序列化导出内容失败: app_id={}", input.app_id))
         .with_code(
             AppManagerErrorCode::ExportSerializeFailed.as_str(),
             "序列化导出内容失败",
         )
-        .with_ctx("appId", input.app_id.clone())?;
+        .with_ctx("appId", input.app_id.clone())
+        .with_ctx("format", format.as_str())?;
     fs::write(&file_path, content)
         .with_context(|| format!("写入导出文件失败: {}", file_path.display()))
         .with_code(
@@ -96,6 +138,7 @@ pub fn export_managed_app_scan_result(
         app_id: input.app_id,
         file_path: file_path.to_string_lossy().to_string(),
         directory_path: export_dir.to_string_lossy().to_string(),
+        format_used: format.as_str().to_string(),
     })
 }
 
@@ -110,13 +153,10 @@ fn load_or_build_deep_scan(item: &ManagedAppDto) -> AppManagerResidueScanResultD
         .lock()
         .unwrap_or_else(|poisoned| poisoned.into_inner());
     match scan_cache.entry(deep_key) {
-        std::collections::hash_map::Entry::Occupied(entry) => entry.get().result.clone(),
+        std::collections::hash_map::Entry::Occupied(entry) => entry.get().annotated_result(),
         std::collections::hash_map::Entry::Vacant(entry) => {
-            entry.insert(ResidueScanCacheEntry {
-                refreshed_at: Instant::now(),
-                result: result.clone(),
-            });
-            result
+            entry.insert(ResidueScanCacheEntry::new(result.clone()));
+            annotate_scan_result(result)
         }
     }
 }
@@ -0,0 +1,386 @@
+use super::*;
+
+const STARTUP_BACKUP_FILE_PREFIX: &str = "startup_";
+const STARTUP_BACKUP_FILE_EXTENSION: &str = "json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StartupBackupFile {
+    revision: u64,
+    created_at: i64,
+    items: Vec<StartupBackupEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StartupBackupEntry {
+    app_id: String,
+    name: String,
+    enabled: bool,
+}
+
+impl StartupBackupFile {
+    fn from_items(items: &[ManagedAppDto], revision: u64, created_at: i64) -> Self {
+        Self {
+            revision,
+            created_at,
+            items: items
+                .iter()
+                .filter(|item| item.startup_editable)
+                .map(|item| StartupBackupEntry {
+                    app_id: item.id.clone(),
+                    name: item.name.clone(),
+                    enabled: item.startup_enabled,
+                })
+                .collect(),
+        }
+    }
+
+    fn backup_id(&self) -> String {
+        format!(
+            "{STARTUP_BACKUP_FILE_PREFIX}{}_{}",
+            self.revision, self.created_at
+        )
+    }
+
+    fn meta(&self, backup_id: String) -> AppManagerBackupMetaDto {
+        AppManagerBackupMetaDto {
+            backup_id,
+            revision: self.revision,
+            created_at: self.created_at,
+            item_count: self.items.len() as u32,
+            enabled_count: self.items.iter().filter(|item| item.enabled).count() as u32,
+        }
+    }
+}
+
+/// Backup ids are `startup_<revision>_<createdAt>`; anything else is never read or deleted.
+fn parse_startup_backup_id(backup_id: &str) -> Option<(u64, i64)> {
+    let (revision, created_at) = backup_id
+        .strip_prefix(STARTUP_BACKUP_FILE_PREFIX)?
+        .split_once('_')?;
+    let all_digits = |value: &str| !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit());
+    if !all_digits(revision) || !all_digits(created_at) {
+        return None;
+    }
+    Some((revision.parse().ok()?, created_at.parse().ok()?))
+}
+
+fn startup_backup_dir(app: &dyn LauncherHost) -> AppResult<PathBuf> {
+    Ok(app.app_data_dir()?.join(STARTUP_BACKUP_DIR_NAME))
+}
+
+fn startup_backup_path(dir: &Path, backup_id: &str) -> PathBuf {
+    dir.join(format!("{backup_id}.{STARTUP_BACKUP_FILE_EXTENSION}"))
+}
+
+fn write_startup_backup(dir: &Path, backup: &StartupBackupFile) -> AppResult<String> {
+    let backup_id = backup.backup_id();
+    let path = startup_backup_path(dir, backup_id.as_str());
+    let temp_path = path.with_extension("json.tmp");
+    let content = serde_json::to_vec_pretty(backup)
+        .with_context(|| "序列化启动项备份失败".to_string())
+        .with_code(
+            AppManagerErrorCode::StartupBackupWriteFailed.as_str(),
+            "写入启动项备份失败",
+        )?;
+    fs::create_dir_all(dir)
+        .and_then(|_| fs::write(&temp_path, content))
+        .and_then(|_| fs::rename(&temp_path, &path))
+        .with_context(|| format!("写入启动项备份失败: {}", path.display()))
+        .with_code(
+            AppManagerErrorCode::StartupBackupWriteFailed.as_str(),
+            "写入启动项备份失败",
+        )
+        .with_ctx("backupDir", dir.display().to_string())?;
+    Ok(backup_id)
+}
+
+fn read_startup_backup(path: &Path) -> AppResult<StartupBackupFile> {
+    let content = fs::read(path)
+        .with_context(|| format!("读取启动项备份失败: {}", path.display()))
+        .with_code(
+            AppManagerErrorCode::StartupBackupListFailed.as_str(),
+            "读取启动项备份失败",
+        )?;
+    serde_json::from_slice(&content)
+        .with_context(|| format!("解析启动项备份失败: {}", path.display()))
+        .with_code(
+            AppManagerErrorCode::StartupBackupInvalid.as_str(),
+            "启动项备份内容无效",
+        )
+}
+
+/// Backup ids in the directory, newest first.
+fn collect_startup_backup_ids(dir: &Path) -> AppResult<Vec<String>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => {
+            return Err(error)
+                .with_context(|| format!("读取启动项备份目录失败: {}", dir.display()))
+                .with_code(
+                    AppManagerErrorCode::StartupBackupListFailed.as_str(),
+                    "读取启动项备份失败",
+                )
+                .with_ctx("backupDir", dir.display().to_string());
+        }
+    };
+
+    let mut backups = entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            if path.extension()?.to_str()? != STARTUP_BACKUP_FILE_EXTENSION {
+                return None;
+            }
+            let backup_id = path.file_stem()?.to_str()?.to_string();
+            let (revision, created_at) = parse_startup_backup_id(backup_id.as_str())?;
+            Some((created_at, revision, backup_id))
+        })
+        .collect::<Vec<_>>();
+    backups.sort_by(|left, right| right.cmp(left));
+    Ok(backups
+        .into_iter()
+        .map(|(_, _, backup_id)| backup_id)
+        .collect())
+}
+
+fn list_startup_backups(dir: &Path) -> AppResult<Vec<AppManagerBackupMetaDto>> {
+    Ok(collect_startup_backup_ids(dir)?
+        .into_iter()
+        .filter_map(|backup_id| {
+            let backup = read_startup_backup(&startup_backup_path(dir, backup_id.as_str())).ok()?;
+            Some(backup.meta(backup_id))
+        })
+        .collect())
+}
+
+fn prune_startup_backups(dir: &Path, keep: usize) -> AppResult<()> {
+    for backup_id in collect_startup_backup_ids(dir)?.into_iter().skip(keep) {
+        let _ = fs::remove_file(startup_backup_path(dir, backup_id.as_str()));
+    }
+    Ok(())
+}
+
+/// Runs after every startup change; a failed backup is only logged because the change itself
+/// already succeeded.
+pub(super) fn record_startup_auto_backup(app: &dyn LauncherHost, cache: &AppIndexCache) {
+    let backup = StartupBackupFile::from_items(&cache.items, cache.revision, now_unix_millis());
+    let result = startup_backup_dir(app).and_then(|dir| {
+        write_startup_backup(dir.as_path(), &backup)?;
+        prune_startup_backups(dir.as_path(), STARTUP_BACKUP_KEEP)
+    });
+    if let Err(error) = result {
+        tracing::warn!(
+            event = "app_manager_startup_backup_failed",
+            error_code = error.code.as_str(),
+            error = error.message.as_str()
+        );
+    }
+}
+
+fn restore_startup_entries(
+    items: &[ManagedAppDto],
+    backup: &StartupBackupFile,
+    mut set_startup: impl FnMut(&ManagedAppDto, bool) -> AppResult<()>,
+) -> Vec<AppManagerBulkStartupItemDto> {
+    backup
+        .items
+        .iter()
+        .map(|entry| {
+            let result = |status, message: Option<&str>| AppManagerBulkStartupItemDto {
+                app_id: entry.app_id.clone(),
+                name: entry.name.clone(),
+                enabled: entry.enabled,
+                status,
+                message: message.map(ToString::to_string),
+            };
+            let Some(item) = items.iter().find(|item| item.id == entry.app_id) else {
+                return result(
+                    AppManagerBulkStartupStatus::Skipped,
+                    Some("应用不存在或索引已过期"),
+                );
+            };
+            if !item.startup_editable {
+                return result(
+                    AppManagerBulkStartupStatus::Skipped,
+                    Some("当前应用启动项为只读，无法修改"),
+                );
+            }
+            if item.startup_enabled == entry.enabled {
+                return result(AppManagerBulkStartupStatus::Unchanged, None);
+            }
+            match set_startup(item, entry.enabled) {
+                Ok(()) => result(AppManagerBulkStartupStatus::Updated, None),
+                Err(error) => result(
+                    AppManagerBulkStartupStatus::Failed,
+                    Some(error.message.as_str()),
+                ),
+            }
+        })
+        .collect()
+}
+
+pub fn list_managed_app_startup_auto_backups(
+    app: &dyn LauncherHost,
+) -> AppResult<Vec<AppManagerBackupMetaDto>> {
+    list_startup_backups(startup_backup_dir(app)?.as_path())
+}
+
+pub fn restore_managed_app_startup_auto_backup(
+    app: &dyn LauncherHost,
+    backup_id: String,
+) -> AppResult<AppManagerBulkStartupResultDto> {
+    let backup_id = backup_id.trim();
+    if parse_startup_backup_id(backup_id).is_none() {
+        return Err(app_error(
+            AppManagerErrorCode::StartupBackupInvalid,
+            "启动项备份标识无效",
+        )
+        .with_context("backupId", backup_id));
+    }
+    let path = startup_backup_path(startup_backup_dir(app)?.as_path(), backup_id);
+    if !path.is_file() {
+        return Err(app_error(
+            AppManagerErrorCode::StartupBackupNotFound,
+            "启动项备份不存在",
+        )
+        .with_context("backupId", backup_id));
+    }
+    let backup = read_startup_backup(path.as_path())?;
+
+    let cache = load_or_refresh_index(app, true)?;
+    let items = restore_startup_entries(&cache.items, &backup, |item, enabled| {
+        platform_set_startup(item.id.as_str(), Path::new(item.path.as_str()), enabled)
+    });
+    let count_status = |status| items.iter().filter(|item| item.status == status).count() as u32;
+    let updated_count = count_status(AppManagerBulkStartupStatus::Updated);
+    let failed_count = count_status(AppManagerBulkStartupStatus::Failed);
+    if updated_count > 0 {
+        let cache = load_or_refresh_index(app, true)?;
+        record_startup_auto_backup(app, &cache);
+    }
+
+    Ok(AppManagerBulkStartupResultDto {
+        backup_id: backup_id.to_string(),
+        updated_count,
+        failed_count,
+        items,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_backup_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "rtool-app-manager-startup-backups-{name}-{}",
+            uuid::Uuid::new_v4()
+        ))
+    }
+
+    fn startup_app(path: &str, enabled: bool, editable: bool) -> ManagedAppDto {
+        let mut item = build_scan_path_item(Path::new(path));
+        item.startup_enabled = enabled;
+        item.startup_editable = editable;
+        item
+    }
+
+    #[test]
+    fn backup_ids_must_match_the_generated_shape() {
+        assert_eq!(
+            parse_startup_backup_id("startup_3_1700000000000"),
+            Some((3, 1_700_000_000_000))
+        );
+        assert_eq!(parse_startup_backup_id("startup_+3_1"), None);
+        assert_eq!(parse_startup_backup_id("startup_3_1/../x"), None);
+        assert_eq!(parse_startup_backup_id("startup_3"), None);
+        assert_eq!(parse_startup_backup_id("backup_3_1"), None);
+    }
+
+    #[test]
+    fn prune_keeps_the_newest_backups_and_ignores_other_files() {
+        let dir = temp_backup_dir("prune");
+        let items = vec![
+            startup_app("/Applications/A.app", true, true),
+            startup_app("/Applications/B.app", false, true),
+            startup_app("/Applications/C.app", true, false),
+        ];
+        for created_at in 1..=(STARTUP_BACKUP_KEEP as i64 + 2) {
+            let backup = StartupBackupFile::from_items(&items, 4, created_at);
+            write_startup_backup(dir.as_path(), &backup).expect("write backup");
+        }
+        fs::write(dir.join("notes.json"), "{}").expect("write other");
+
+        prune_startup_backups(dir.as_path(), STARTUP_BACKUP_KEEP).expect("prune");
+        let backups = list_startup_backups(dir.as_path()).expect("list");
+        let other_kept = dir.join("notes.json").is_file();
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(
+            backups
+                .iter()
+                .map(|backup| backup.backup_id.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "startup_4_7",
+                "startup_4_6",
+                "startup_4_5",
+                "startup_4_4",
+                "startup_4_3"
+            ]
+        );
+        assert_eq!(backups[0].item_count, 2);
+        assert_eq!(backups[0].enabled_count, 1);
+        assert!(other_kept);
+    }
+
+    #[test]
+    fn restore_only_touches_editable_apps_whose_state_differs() {
+        let changed = startup_app("/Applications/Changed.app", false, true);
+        let same = startup_app("/Applications/Same.app", true, true);
+        let readonly = startup_app("/Applications/Readonly.app", false, false);
+        let failing = startup_app("/Applications/Failing.app", true, true);
+        let mut backup =
+            StartupBackupFile::from_items(&[changed.clone(), same.clone(), failing.clone()], 1, 1);
+        for entry in &mut backup.items {
+            if entry.app_id != same.id {
+                entry.enabled = !entry.enabled;
+            }
+        }
+        backup.items.push(StartupBackupEntry {
+            app_id: readonly.id.clone(),
+            name: readonly.name.clone(),
+            enabled: true,
+        });
+        backup.items.push(StartupBackupEntry {
+            app_id: "missing".to_string(),
+            name: "Missing".to_string(),
+            enabled: true,
+        });
+
+        let current = vec![changed.clone(), same, readonly, failing.clone()];
+        let mut calls = Vec::new();
+        let results = restore_startup_entries(&current, &backup, |item, enabled| {
+            calls.push((item.id.clone(), enabled));
+            if item.id == failing.id {
+                return Err(app_error(AppManagerErrorCode::StartupUpdateFailed, "失败"));
+            }
+            Ok(())
+        });
+
+        assert_eq!(
+            results.iter().map(|item| item.status).collect::<Vec<_>>(),
+            vec![
+                AppManagerBulkStartupStatus::Updated,
+                AppManagerBulkStartupStatus::Unchanged,
+                AppManagerBulkStartupStatus::Failed,
+                AppManagerBulkStartupStatus::Skipped,
+                AppManagerBulkStartupStatus::Skipped,
+            ]
+        );
+        assert_eq!(calls, vec![(changed.id, true), (failing.id, false)]);
+    }
+}
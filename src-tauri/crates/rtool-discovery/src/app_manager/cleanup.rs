@@ -203,12 +203,24 @@ fn delete_residue_item(
     delete_path_with_mode(Path::new(item_path), delete_mode)
 }
 
+fn requires_permanent_confirmation(
+    item: &AppManagerResidueItemDto,
+    delete_mode: AppManagerCleanupDeleteMode,
+    confirmed_item_ids: &HashSet<&str>,
+) -> bool {
+    delete_mode == AppManagerCleanupDeleteMode::Permanent
+        && (item.scope == AppManagerScope::System || item.risk_level == AppManagerRiskLevel::High)
+        && !confirmed_item_ids.contains(item.item_id.as_str())
+}
+
 pub(super) fn execute_cleanup_plan(
     app_item: &ManagedAppDto,
     scan_result: &AppManagerResidueScanResultDto,
     input: AppManagerCleanupInputDto,
 ) -> AppResult<AppManagerCleanupResultDto> {
-    let delete_mode = input.delete_mode;
+    let delete_mode = input
+        .delete_mode
+        .unwrap_or(AppManagerCleanupDeleteMode::Trash);
     let skip_on_error = input.skip_on_error.unwrap_or(true);
     let mut released_size_bytes = 0u64;
     let main_app_size_bytes =
@@ -233,7 +245,7 @@ pub(super) fn execute_cleanup_plan(
             let confirmed_fingerprint = input.confirmed_fingerprint.clone().ok_or_else(|| {
                 app_error(AppManagerErrorCode::FingerprintMissing, "缺少应用确认指纹")
             })?;
-            if confirmed_fingerprint != app_item.fingerprint {
+            if !fingerprint_matches(app_item, confirmed_fingerprint.as_str()) {
                 return Err(app_error(
                     AppManagerErrorCode::FingerprintMismatch,
                     "应用信息已变化，请刷新后重试",
@@ -286,12 +298,30 @@ pub(super) fn execute_cleanup_plan(
         .iter()
         .map(|value| value.as_str())
         .collect::<HashSet<_>>();
+    let confirmed_item_ids = input
+        .confirmed_item_ids
+        .iter()
+        .map(|value| value.as_str())
+        .collect::<HashSet<_>>();
     for group in &scan_result.groups {
         for item in &group.items {
             if !selected.contains(item.item_id.as_str()) {
                 continue;
             }
 
+            if item.user_skip {
+                skipped.push(AppManagerCleanupItemResultDto {
+                    item_id: item.item_id.clone(),
+                    path: item.path.clone(),
+                    kind: item.kind,
+                    status: AppManagerCleanupStatus::Skipped,
+                    reason_code: AppManagerCleanupReasonCode::UserSkip,
+                    message: "已标记为跳过".to_string(),
+                    size_bytes: Some(item.size_bytes),
+                });
+                continue;
+            }
+
             if item
                 .readonly_reason_code
                 .is_some_and(|reason| reason == AppReadonlyReasonCode::ManagedByPolicy)
@@ -308,6 +338,19 @@ pub(super) fn execute_cleanup_plan(
                 continue;
             }
 
+            if requires_permanent_confirmation(item, delete_mode, &confirmed_item_ids) {
+                skipped.push(AppManagerCleanupItemResultDto {
+                    item_id: item.item_id.clone(),
+                    path: item.path.clone(),
+                    kind: item.kind,
+                    status: AppManagerCleanupStatus::Skipped,
+                    reason_code: AppManagerCleanupReasonCode::RequiresConfirmation,
+                    message: "永久删除系统级或高风险项需要逐项确认，已跳过".to_string(),
+                    size_bytes: Some(item.size_bytes),
+                });
+                continue;
+            }
+
             let is_registry_item = matches!(
                 item.kind,
                 AppManagerResidueKind::RegistryKey | AppManagerResidueKind::RegistryValue
@@ -382,3 +425,323 @@ pub(super) fn execute_cleanup_plan(
         failed,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn residue_item(item_id: &str, path: &Path) -> AppManagerResidueItemDto {
+        AppManagerResidueItemDto {
+            item_id: item_id.to_string(),
+            path: path.to_string_lossy().to_string(),
+            path_type: AppManagerPathType::File,
+            kind: AppManagerResidueKind::Cache,
+            scope: AppManagerScope::User,
+            size_bytes: 4,
+            match_reason: AppManagerResidueMatchReason::RelatedRoot,
+            confidence: AppManagerResidueConfidence::Exact,
+            evidence: Vec::new(),
+            risk_level: AppManagerRiskLevel::Low,
+            recommended: true,
+            readonly: false,
+            readonly_reason_code: None,
+            annotation: None,
+            user_skip: false,
+        }
+    }
+
+    fn scan_result(
+        app_id: &str,
+        items: Vec<AppManagerResidueItemDto>,
+    ) -> AppManagerResidueScanResultDto {
+        AppManagerResidueScanResultDto {
+            app_id: app_id.to_string(),
+            scan_mode: AppManagerResidueScanMode::Deep,
+            total_size_bytes: 0,
+            groups: vec![AppManagerResidueGroupDto {
+                group_id: "group".to_string(),
+                label: "Cache".to_string(),
+                scope: AppManagerScope::User,
+                kind: AppManagerResidueKind::Cache,
+                total_size_bytes: 0,
+                total_items: u32::try_from(items.len()).unwrap_or(u32::MAX),
+                recommended_items: 0,
+                items,
+            }],
+            warnings: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn annotation_parse_trims_and_clears_empty_notes() {
+        assert_eq!(ResidueAnnotation::parse("   ", false).expect("empty"), None);
+        assert_eq!(
+            ResidueAnnotation::parse("  keep for now ", true).expect("note"),
+            Some(ResidueAnnotation {
+                note: Some("keep for now".to_string()),
+                skip: true,
+            })
+        );
+        let too_long = "x".repeat(RESIDUE_ANNOTATION_MAX_CHARS + 1);
+        assert_eq!(
+            ResidueAnnotation::parse(too_long.as_str(), false)
+                .expect_err("too long")
+                .code,
+            AppManagerErrorCode::ResidueAnnotationInvalid.as_str()
+        );
+    }
+
+    #[test]
+    fn user_skipped_item_is_reported_and_kept() {
+        let root =
+            std::env::temp_dir().join(format!("rtool-cleanup-skip-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&root).expect("create temp root");
+        let kept = root.join("kept.cache");
+        fs::write(&kept, b"keep").expect("write residue file");
+
+        let app_item = build_scan_path_item(root.as_path());
+        let mut result = scan_result(app_item.id.as_str(), vec![residue_item("kept", &kept)]);
+        let annotations = HashMap::from([(
+            "kept".to_string(),
+            ResidueAnnotation {
+                note: Some("shared cache".to_string()),
+                skip: true,
+            },
+        )]);
+        apply_residue_annotations(&mut result, &annotations);
+        assert_eq!(
+            result.groups[0].items[0].annotation.as_deref(),
+            Some("shared cache")
+        );
+
+        let cleanup = execute_cleanup_plan(
+            &app_item,
+            &result,
+            AppManagerCleanupInputDto {
+                app_id: app_item.id.clone(),
+                selected_item_ids: vec!["kept".to_string()],
+                delete_mode: Some(AppManagerCleanupDeleteMode::Permanent),
+                include_main_app: false,
+                skip_on_error: Some(true),
+                confirmed_fingerprint: None,
+                confirmed_item_ids: Vec::new(),
+            },
+        )
+        .expect("cleanup result");
+
+        assert!(kept.exists());
+        assert!(cleanup.deleted.is_empty());
+        assert_eq!(cleanup.skipped.len(), 1);
+        assert_eq!(
+            cleanup.skipped[0].reason_code,
+            AppManagerCleanupReasonCode::UserSkip
+        );
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn permanent_delete_of_risky_items_requires_confirmation() {
+        let root =
+            std::env::temp_dir().join(format!("rtool-cleanup-confirm-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&root).expect("create temp root");
+        let cache = root.join("user.cache");
+        let system = root.join("system.plist");
+        let confirmed = root.join("confirmed.plist");
+        for path in [&cache, &system, &confirmed] {
+            fs::write(path, b"data").expect("write residue file");
+        }
+
+        let mut system_item = residue_item("system", &system);
+        system_item.scope = AppManagerScope::System;
+        let mut confirmed_item = residue_item("confirmed", &confirmed);
+        confirmed_item.risk_level = AppManagerRiskLevel::High;
+
+        let app_item = build_scan_path_item(root.as_path());
+        let result = scan_result(
+            app_item.id.as_str(),
+            vec![residue_item("cache", &cache), system_item, confirmed_item],
+        );
+        let cleanup = execute_cleanup_plan(
+            &app_item,
+            &result,
+            AppManagerCleanupInputDto {
+                app_id: app_item.id.clone(),
+                selected_item_ids: vec![
+                    "cache".to_string(),
+                    "system".to_string(),
+                    "confirmed".to_string(),
+                ],
+                delete_mode: Some(AppManagerCleanupDeleteMode::Permanent),
+                include_main_app: false,
+                skip_on_error: Some(true),
+                confirmed_fingerprint: None,
+                confirmed_item_ids: vec!["confirmed".to_string()],
+            },
+        )
+        .expect("cleanup result");
+
+        assert!(!cache.exists());
+        assert!(system.exists());
+        assert!(!confirmed.exists());
+        assert_eq!(cleanup.skipped.len(), 1);
+        assert_eq!(cleanup.skipped[0].item_id, "system");
+        assert_eq!(
+            cleanup.skipped[0].reason_code,
+            AppManagerCleanupReasonCode::RequiresConfirmation
+        );
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn residue_annotations_survive_scan_cache_expiry_and_rescans() {
+        let root =
+            std::env::temp_dir().join(format!("rtool-cleanup-annotate-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&root).expect("create temp root");
+        let kept = root.join("kept.cache");
+        fs::write(&kept, b"keep").expect("write residue file");
+        let app_item = build_scan_path_item(root.as_path());
+        let app_id = app_item.id.clone();
+        let rescan = || scan_result(app_id.as_str(), vec![residue_item("kept", &kept)]);
+        let cache_key = format!("{app_id}|deep");
+
+        set_residue_annotation(
+            app_id.as_str(),
+            "kept",
+            Some(ResidueAnnotation {
+                note: None,
+                skip: true,
+            }),
+        );
+        {
+            let mut stale = ResidueScanCacheEntry::new(rescan());
+            stale.refreshed_at = Instant::now()
+                .checked_sub(RESIDUE_SCAN_CACHE_TTL + Duration::from_secs(1))
+                .expect("stale instant");
+            residue_scan_cache()
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .insert(cache_key.clone(), stale);
+        }
+        cleanup_stale_scan_cache();
+        let expired = !residue_scan_cache()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .contains_key(cache_key.as_str());
+
+        let rescanned = ResidueScanCacheEntry::new(rescan()).annotated_result();
+        let cleanup = execute_cleanup_plan(
+            &app_item,
+            &rescanned,
+            AppManagerCleanupInputDto {
+                app_id: app_id.clone(),
+                selected_item_ids: vec!["kept".to_string()],
+                delete_mode: Some(AppManagerCleanupDeleteMode::Permanent),
+                include_main_app: false,
+                skip_on_error: Some(true),
+                confirmed_fingerprint: None,
+                confirmed_item_ids: Vec::new(),
+            },
+        )
+        .expect("cleanup result");
+        let kept_after_cleanup = kept.exists();
+        set_residue_annotation(app_id.as_str(), "kept", None);
+        let cleared = annotate_scan_result(rescan());
+        let _ = fs::remove_dir_all(&root);
+
+        assert!(expired);
+        assert!(rescanned.groups[0].items[0].user_skip);
+        assert!(kept_after_cleanup);
+        assert_eq!(cleanup.skipped.len(), 1);
+        assert!(!cleared.groups[0].items[0].user_skip);
+    }
+
+    struct PathScanOnlyHost;
+
+    impl LauncherHost for PathScanOnlyHost {
+        fn emit(&self, _event: &str, _payload: serde_json::Value) -> AppResult<()> {
+            Ok(())
+        }
+
+        fn get_webview_window(&self, _label: &str) -> Option<Box<dyn crate::host::LauncherWindow>> {
+            None
+        }
+
+        fn open_path(&self, _path: &Path) -> AppResult<()> {
+            Err(AppError::new(
+                "test_host_unsupported",
+                "测试宿主不支持该操作",
+            ))
+        }
+
+        fn app_data_dir(&self) -> AppResult<PathBuf> {
+            Err(AppError::new(
+                "test_host_unsupported",
+                "测试宿主不支持该操作",
+            ))
+        }
+
+        fn package_info(&self) -> crate::host::AppPackageInfo {
+            crate::host::AppPackageInfo {
+                name: "rtool".to_string(),
+                version: "test".to_string(),
+            }
+        }
+
+        fn resolved_locale(&self) -> Option<String> {
+            None
+        }
+
+        fn apply_clipboard_window_mode(
+            &self,
+            _compact: bool,
+            _source: &str,
+        ) -> AppResult<rtool_contracts::models::ClipboardWindowModeAppliedDto> {
+            Err(AppError::new(
+                "test_host_unsupported",
+                "测试宿主不支持该操作",
+            ))
+        }
+    }
+
+    #[test]
+    fn path_scanned_items_can_be_cleaned_up() {
+        let root =
+            std::env::temp_dir().join(format!("rtool-cleanup-path-scan-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&root).expect("create temp root");
+        let stale = root.join("stale.cache");
+        fs::write(&stale, b"data").expect("write residue file");
+        let app_item = build_scan_path_item(root.as_path());
+        remember_scan_path_item(&app_item);
+        residue_scan_cache()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(
+                format!("{}|deep", app_item.id),
+                ResidueScanCacheEntry::new(scan_result(
+                    app_item.id.as_str(),
+                    vec![residue_item("stale", &stale)],
+                )),
+            );
+        let input = |app_id: &str| AppManagerCleanupInputDto {
+            app_id: app_id.to_string(),
+            selected_item_ids: vec!["stale".to_string()],
+            delete_mode: Some(AppManagerCleanupDeleteMode::Permanent),
+            include_main_app: false,
+            skip_on_error: Some(true),
+            confirmed_fingerprint: None,
+            confirmed_item_ids: Vec::new(),
+        };
+
+        let cleanup = cleanup_managed_app_residue(&PathScanOnlyHost, input(app_item.id.as_str()))
+            .expect("cleanup result");
+        let unknown = cleanup_managed_app_residue(&PathScanOnlyHost, input("path.unknown"))
+            .expect_err("unknown path scan");
+        let stale_exists = stale.exists();
+        let _ = fs::remove_dir_all(&root);
+
+        assert!(is_scan_path_app_id(app_item.id.as_str()));
+        assert!(!stale_exists);
+        assert_eq!(cleanup.deleted.len(), 1);
+        assert_eq!(unknown.code, AppManagerErrorCode::NotFound.as_str());
+    }
+}
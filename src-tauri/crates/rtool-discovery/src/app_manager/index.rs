@@ -71,12 +71,17 @@ pub(super) fn build_self_item(app: &dyn LauncherHost) -> Option<ManagedAppDto> {
         size_accuracy: size_snapshot.size_accuracy,
         size_source: size_resolution.size_source,
         size_computed_at: size_snapshot.size_computed_at,
+        size_accuracy_used: None,
         startup_enabled,
         startup_scope,
         startup_editable,
         readonly_reason_code,
         uninstall_supported: false,
         uninstall_kind: None,
+        package_manager: None,
+        package_id: None,
+        folder_path: None,
+        folder_name: None,
         capabilities: build_app_capabilities(
             cfg!(target_os = "macos") || cfg!(target_os = "windows"),
             false,
@@ -90,6 +95,34 @@ pub(super) fn build_self_item(app: &dyn LauncherHost) -> Option<ManagedAppDto> {
     Some(item)
 }
 
+/// Tags apps that share a subfolder of an application root (e.g. `/Applications/Adobe`)
+/// with that folder; a lone app in a subfolder is left ungrouped.
+#[cfg(any(target_os = "macos", test))]
+pub(super) fn assign_app_folders(items: &mut [ManagedAppDto], roots: &[PathBuf]) {
+    let folder_of = |item: &ManagedAppDto| {
+        let folder = Path::new(item.path.as_str()).parent()?;
+        let root = folder.parent()?;
+        roots
+            .iter()
+            .any(|candidate| candidate.as_path() == root)
+            .then(|| folder.to_path_buf())
+    };
+
+    let mut counts = HashMap::<PathBuf, usize>::new();
+    for folder in items.iter().filter_map(folder_of) {
+        *counts.entry(folder).or_default() += 1;
+    }
+    for item in items.iter_mut() {
+        let Some(folder) = folder_of(item).filter(|folder| counts.get(folder) > Some(&1)) else {
+            continue;
+        };
+        item.folder_name = folder
+            .file_name()
+            .map(|value| value.to_string_lossy().to_string());
+        item.folder_path = Some(folder.to_string_lossy().to_string());
+    }
+}
+
 pub(super) fn collect_platform_apps(app: &dyn LauncherHost) -> Vec<ManagedAppDto> {
     #[cfg(target_os = "macos")]
     {
@@ -106,6 +139,63 @@ pub(super) fn collect_platform_apps(app: &dyn LauncherHost) -> Vec<ManagedAppDto
     }
 }
 
+/// Builds per-app items on a bounded worker pool while keeping the first `max_items`
+/// successes in input order, so the result matches a sequential walk.
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+pub(super) fn build_items_bounded<T, R>(
+    inputs: &[T],
+    max_items: usize,
+    build: impl Fn(&T) -> Option<R> + Sync,
+) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+{
+    let workers = std::thread::available_parallelism()
+        .map(std::num::NonZeroUsize::get)
+        .unwrap_or(1)
+        .min(INDEX_BUILD_MAX_PARALLELISM);
+    build_items_with_workers(inputs, max_items, workers, &build)
+}
+
+#[cfg(any(target_os = "macos", target_os = "windows", test))]
+fn build_items_with_workers<T, R>(
+    inputs: &[T],
+    max_items: usize,
+    workers: usize,
+    build: &(impl Fn(&T) -> Option<R> + Sync),
+) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+{
+    let mut items = Vec::new();
+    let mut remaining = inputs;
+    while items.len() < max_items && !remaining.is_empty() {
+        let (batch, rest) = remaining.split_at((max_items - items.len()).min(remaining.len()));
+        remaining = rest;
+        if workers <= 1 || batch.len() <= 1 {
+            items.extend(batch.iter().filter_map(build));
+            continue;
+        }
+        let chunk_size = batch.len().div_ceil(workers);
+        let built = std::thread::scope(|scope| {
+            let handles = batch
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    scope.spawn(move || chunk.iter().filter_map(build).collect::<Vec<_>>())
+                })
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().unwrap_or_default())
+                .collect::<Vec<_>>()
+        });
+        items.extend(built);
+    }
+    items
+}
+
 pub(super) fn collect_index_source_fingerprint() -> String {
     #[cfg(target_os = "macos")]
     {
@@ -120,3 +210,68 @@ pub(super) fn collect_index_source_fingerprint() -> String {
         "unsupported-platform".to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app_at(path: &str) -> ManagedAppDto {
+        build_scan_path_item(Path::new(path))
+    }
+
+    #[test]
+    fn bounded_build_matches_sequential_order_and_cap() {
+        let inputs = (0..97).collect::<Vec<u32>>();
+        let build = |value: &u32| (value % 7 != 3).then(|| format!("app-{value}"));
+        for max_items in [0, 1, 10, 60, 500] {
+            let sequential = inputs
+                .iter()
+                .filter_map(build)
+                .take(max_items)
+                .collect::<Vec<_>>();
+            for workers in [1, 3, 8] {
+                assert_eq!(
+                    build_items_with_workers(inputs.as_slice(), max_items, workers, &build),
+                    sequential
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn apps_sharing_a_root_subfolder_are_grouped() {
+        let mut items = vec![
+            app_at("/Applications/Adobe/Photoshop.app"),
+            app_at("/Applications/Adobe/Illustrator.app"),
+            app_at("/Applications/Utilities/Terminal.app"),
+            app_at("/Applications/Safari.app"),
+            app_at("/Users/me/Applications/Tools/A.app"),
+            app_at("/Users/me/Applications/Tools/B.app"),
+            app_at("/Volumes/Ext/Adobe/C.app"),
+            app_at("/Volumes/Ext/Adobe/D.app"),
+        ];
+        let roots = [
+            PathBuf::from("/Applications"),
+            PathBuf::from("/Users/me/Applications"),
+        ];
+
+        assign_app_folders(&mut items, &roots);
+        let folders = items
+            .iter()
+            .map(|item| (item.folder_path.as_deref(), item.folder_name.as_deref()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            folders,
+            vec![
+                (Some("/Applications/Adobe"), Some("Adobe")),
+                (Some("/Applications/Adobe"), Some("Adobe")),
+                (None, None),
+                (None, None),
+                (Some("/Users/me/Applications/Tools"), Some("Tools")),
+                (Some("/Users/me/Applications/Tools"), Some("Tools")),
+                (None, None),
+                (None, None),
+            ]
+        );
+    }
+}
@@ -40,7 +40,7 @@ pub(crate) fn collect_macos_source_fingerprint() -> String {
 
 #[cfg(target_os = "macos")]
 pub(crate) fn collect_macos_apps(app: &dyn LauncherHost) -> Vec<ManagedAppDto> {
-    let mut items = Vec::new();
+    let mut candidates = Vec::new();
     let mut queue = VecDeque::new();
     let mut seen = HashSet::new();
     for root in mac_application_roots() {
@@ -48,20 +48,12 @@ pub(crate) fn collect_macos_apps(app: &dyn LauncherHost) -> Vec<ManagedAppDto> {
     }
 
     while let Some((dir, depth)) = queue.pop_front() {
-        if items.len() >= MAC_SCAN_MAX_ITEMS {
-            break;
-        }
-
         let entries = match fs::read_dir(&dir) {
             Ok(entries) => entries,
             Err(_) => continue,
         };
 
         for entry in entries.flatten() {
-            if items.len() >= MAC_SCAN_MAX_ITEMS {
-                break;
-            }
-
             let path = entry.path();
             let file_type = match entry.file_type() {
                 Ok(file_type) => file_type,
@@ -71,19 +63,14 @@ pub(crate) fn collect_macos_apps(app: &dyn LauncherHost) -> Vec<ManagedAppDto> {
                 continue;
             }
 
-            let path_key = normalize_path_key(path.to_string_lossy().as_ref());
-            if seen.contains(&path_key) {
-                continue;
-            }
-
             if path
                 .extension()
                 .and_then(|value| value.to_str())
                 .is_some_and(|value| value.eq_ignore_ascii_case("app"))
             {
-                if let Some(item) = build_macos_app_item(app, &path) {
-                    seen.insert(path_key);
-                    items.push(item);
+                let path_key = normalize_path_key(path.to_string_lossy().as_ref());
+                if seen.insert(path_key) {
+                    candidates.push(path);
                 }
                 continue;
             }
@@ -102,6 +89,10 @@ pub(crate) fn collect_macos_apps(app: &dyn LauncherHost) -> Vec<ManagedAppDto> {
         }
     }
 
+    let mut items = build_items_bounded(candidates.as_slice(), MAC_SCAN_MAX_ITEMS, |path| {
+        build_macos_app_item(app, path)
+    });
+    assign_app_folders(items.as_mut_slice(), &mac_application_roots());
     items
 }
 
@@ -162,12 +153,17 @@ pub(crate) fn build_macos_app_item(
         size_accuracy: size_snapshot.size_accuracy,
         size_source: size_resolution.size_source,
         size_computed_at: size_snapshot.size_computed_at,
+        size_accuracy_used: None,
         startup_enabled,
         startup_scope,
         startup_editable,
         readonly_reason_code,
         uninstall_supported: true,
         uninstall_kind: Some(AppManagerUninstallKind::FinderTrash),
+        package_manager: None,
+        package_id: None,
+        folder_path: None,
+        folder_name: None,
         capabilities: build_app_capabilities(true, true, true),
         identity,
         risk_level: AppManagerRiskLevel::Medium,
@@ -10,12 +10,13 @@ pub(super) struct AppIndexCache {
     pub(super) building: bool,
     pub(super) index_state: AppManagerIndexState,
     pub(super) last_error: Option<String>,
+    pub(super) build_duration_ms: Option<u64>,
     pub(super) disk_bootstrapped: bool,
 }
 
 #[derive(Debug, Clone)]
 pub(super) struct AppIndexRefreshMeta {
-    pub(super) cache: AppIndexCache,
+    pub(super) cache: Arc<AppIndexCache>,
     pub(super) changed_count: u32,
     pub(super) rebuilt: bool,
 }
@@ -28,14 +29,111 @@ struct PersistedAppIndexCache {
     items: Vec<ManagedAppDto>,
 }
 
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(super) struct ResidueAnnotation {
+    pub(super) note: Option<String>,
+    pub(super) skip: bool,
+}
+
+impl ResidueAnnotation {
+    pub(super) fn parse(note: &str, skip: bool) -> AppResult<Option<Self>> {
+        let note = note.trim();
+        if note.chars().count() > RESIDUE_ANNOTATION_MAX_CHARS {
+            return Err(app_error(
+                AppManagerErrorCode::ResidueAnnotationInvalid,
+                "备注内容过长",
+            )
+            .with_context("maxChars", RESIDUE_ANNOTATION_MAX_CHARS.to_string()));
+        }
+        if note.is_empty() && !skip {
+            return Ok(None);
+        }
+        Ok(Some(Self {
+            note: (!note.is_empty()).then(|| note.to_string()),
+            skip,
+        }))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(super) struct ResidueScanCacheEntry {
     pub(super) refreshed_at: Instant,
     pub(super) result: AppManagerResidueScanResultDto,
 }
 
+impl ResidueScanCacheEntry {
+    pub(super) fn new(result: AppManagerResidueScanResultDto) -> Self {
+        Self {
+            refreshed_at: Instant::now(),
+            result,
+        }
+    }
+
+    pub(super) fn annotated_result(&self) -> AppManagerResidueScanResultDto {
+        annotate_scan_result(self.result.clone())
+    }
+}
+
+/// Annotations outlive scan cache entries, which expire and are rebuilt on every rescan.
+pub(super) fn annotate_scan_result(
+    mut result: AppManagerResidueScanResultDto,
+) -> AppManagerResidueScanResultDto {
+    let store = residue_annotation_store()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(annotations) = store.get(result.app_id.as_str()) {
+        apply_residue_annotations(&mut result, annotations);
+    }
+    result
+}
+
+pub(super) fn set_residue_annotation(
+    app_id: &str,
+    item_id: &str,
+    annotation: Option<ResidueAnnotation>,
+) {
+    let mut store = residue_annotation_store()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    match annotation {
+        Some(value) => {
+            store
+                .entry(app_id.to_string())
+                .or_default()
+                .insert(item_id.to_string(), value);
+        }
+        None => {
+            if let Some(annotations) = store.get_mut(app_id) {
+                annotations.remove(item_id);
+                if annotations.is_empty() {
+                    store.remove(app_id);
+                }
+            }
+        }
+    }
+}
+
+pub(super) fn apply_residue_annotations(
+    result: &mut AppManagerResidueScanResultDto,
+    annotations: &HashMap<String, ResidueAnnotation>,
+) {
+    if annotations.is_empty() {
+        return;
+    }
+    for item in result
+        .groups
+        .iter_mut()
+        .flat_map(|group| group.items.iter_mut())
+    {
+        let annotation = annotations.get(item.item_id.as_str());
+        item.annotation = annotation.and_then(|value| value.note.clone());
+        item.user_skip = annotation.is_some_and(|value| value.skip);
+    }
+}
+
+/// Readers share the current cache through an `Arc`; writers copy it on write.
 pub(super) struct AppIndexRuntime {
-    pub(super) cache: Mutex<AppIndexCache>,
+    pub(super) cache: Mutex<Arc<AppIndexCache>>,
     pub(super) condvar: Condvar,
 }
 
@@ -161,11 +259,13 @@ pub(super) fn refresh_index_with_meta(
             .cache
             .lock()
             .unwrap_or_else(|poisoned| poisoned.into_inner());
-        try_bootstrap_index_from_disk(app, &mut guard);
+        if !guard.disk_bootstrapped {
+            try_bootstrap_index_from_disk(app, Arc::make_mut(&mut guard));
+        }
         let stale = force_refresh || guard.is_stale();
         if !stale {
             return Ok(AppIndexRefreshMeta {
-                cache: guard.clone(),
+                cache: Arc::clone(&guard),
                 changed_count: 0,
                 rebuilt: false,
             });
@@ -177,9 +277,9 @@ pub(super) fn refresh_index_with_meta(
             && guard.source_fingerprint == source_fingerprint
             && !guard.items.is_empty();
         if fingerprint_unchanged {
-            guard.refreshed_at = Some(Instant::now());
+            Arc::make_mut(&mut guard).refreshed_at = Some(Instant::now());
             return Ok(AppIndexRefreshMeta {
-                cache: guard.clone(),
+                cache: Arc::clone(&guard),
                 changed_count: 0,
                 rebuilt: false,
             });
@@ -193,32 +293,39 @@ pub(super) fn refresh_index_with_meta(
             continue;
         }
 
-        guard.building = true;
-        guard.index_state = AppManagerIndexState::Building;
-        let previous_items = guard.items.clone();
+        let previous = {
+            let cache = Arc::make_mut(&mut guard);
+            cache.building = true;
+            cache.index_state = AppManagerIndexState::Building;
+            Arc::clone(&guard)
+        };
         drop(guard);
 
+        let build_started_at = Instant::now();
         let rebuild_result = build_app_index(app);
+        let build_duration_ms = build_started_at.elapsed().as_millis() as u64;
         let indexed_at = now_unix_seconds();
         let mut guard = runtime
             .cache
             .lock()
             .unwrap_or_else(|poisoned| poisoned.into_inner());
-        guard.building = false;
+        let cache = Arc::make_mut(&mut guard);
+        cache.building = false;
+        cache.build_duration_ms = Some(build_duration_ms);
         match rebuild_result {
             Ok(items) => {
-                let changed_count = count_item_changes(previous_items.as_slice(), items.as_slice());
+                let changed_count = count_item_changes(previous.items.as_slice(), items.as_slice());
                 let changed = changed_count > 0;
-                guard.items = items;
-                guard.indexed_at = indexed_at;
-                guard.refreshed_at = Some(Instant::now());
-                guard.source_fingerprint = source_fingerprint;
-                guard.index_state = AppManagerIndexState::Ready;
-                guard.last_error = None;
-                if changed || guard.revision == 0 {
-                    guard.revision = guard.revision.saturating_add(1);
+                cache.items = items;
+                cache.indexed_at = indexed_at;
+                cache.refreshed_at = Some(Instant::now());
+                cache.source_fingerprint = source_fingerprint;
+                cache.index_state = AppManagerIndexState::Ready;
+                cache.last_error = None;
+                if changed || cache.revision == 0 {
+                    cache.revision = cache.revision.saturating_add(1);
                 }
-                let cache_snapshot = guard.clone();
+                let cache_snapshot = Arc::clone(&guard);
                 runtime.condvar.notify_all();
                 persist_index_to_disk(app, &cache_snapshot);
                 return Ok(AppIndexRefreshMeta {
@@ -228,10 +335,10 @@ pub(super) fn refresh_index_with_meta(
                 });
             }
             Err(error) => {
-                guard.refreshed_at = Some(Instant::now());
-                guard.index_state = AppManagerIndexState::Degraded;
-                guard.last_error = Some(error.to_string());
-                let cache_snapshot = guard.clone();
+                cache.refreshed_at = Some(Instant::now());
+                cache.index_state = AppManagerIndexState::Degraded;
+                cache.last_error = Some(error.to_string());
+                let cache_snapshot = Arc::clone(&guard);
                 runtime.condvar.notify_all();
                 if cache_snapshot.items.is_empty() {
                     return Err(error);
@@ -246,9 +353,18 @@ pub(super) fn refresh_index_with_meta(
     }
 }
 
+pub(super) fn read_index_snapshot() -> Arc<AppIndexCache> {
+    Arc::clone(
+        &app_index_runtime()
+            .cache
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()),
+    )
+}
+
 pub(super) fn load_or_refresh_index(
     app: &dyn LauncherHost,
     force_refresh: bool,
-) -> AppResult<AppIndexCache> {
+) -> AppResult<Arc<AppIndexCache>> {
     refresh_index_with_meta(app, force_refresh).map(|value| value.cache)
 }
@@ -54,11 +54,13 @@ pub(crate) fn collect_windows_source_fingerprint() -> String {
 #[cfg(target_os = "windows")]
 pub(crate) fn collect_windows_apps(app: &dyn LauncherHost) -> Vec<ManagedAppDto> {
     let uninstall_entries = windows_list_uninstall_entries();
+    let package_roots = PackageManagerRoots::from_env();
     let mut seen_path_keys = HashSet::new();
     let mut seen_identity_keys = HashSet::new();
     let mut items = windows_collect_apps_from_uninstall_entries(
         app,
         uninstall_entries.as_slice(),
+        &package_roots,
         &mut seen_path_keys,
         &mut seen_identity_keys,
     );
@@ -72,6 +74,7 @@ pub(crate) fn collect_windows_apps(app: &dyn LauncherHost) -> Vec<ManagedAppDto>
             &mut seen_identity_keys,
             app,
             uninstall_entries.as_slice(),
+            &package_roots,
         );
         if items.len() >= WIN_SCAN_MAX_ITEMS {
             break;
@@ -84,12 +87,13 @@ pub(crate) fn collect_windows_apps(app: &dyn LauncherHost) -> Vec<ManagedAppDto>
 pub(crate) fn windows_collect_apps_from_uninstall_entries(
     app: &dyn LauncherHost,
     entries: &[WindowsUninstallEntry],
+    package_roots: &PackageManagerRoots,
     seen_path_keys: &mut HashSet<String>,
     seen_identity_keys: &mut HashSet<String>,
 ) -> Vec<ManagedAppDto> {
-    let mut items = Vec::new();
+    let mut pending = Vec::new();
     for entry in entries {
-        if items.len() >= WIN_SCAN_MAX_ITEMS {
+        if pending.len() >= WIN_SCAN_MAX_ITEMS {
             break;
         }
         let Some(path) = windows_discovery_path_from_uninstall_entry(entry) else {
@@ -103,13 +107,16 @@ pub(crate) fn windows_collect_apps_from_uninstall_entries(
         if !seen_identity_keys.insert(identity_key) {
             continue;
         }
-        items.push(windows_build_item_from_uninstall_entry(
+        pending.push((entry, path));
+    }
+    build_items_bounded(pending.as_slice(), WIN_SCAN_MAX_ITEMS, |(entry, path)| {
+        Some(windows_build_item_from_uninstall_entry(
             app,
             entry,
             path.as_path(),
-        ));
-    }
-    items
+            package_roots,
+        ))
+    })
 }
 
 #[cfg(target_os = "windows")]
@@ -122,15 +129,17 @@ pub(crate) fn scan_windows_root(
     seen_identity_keys: &mut HashSet<String>,
     app: &dyn LauncherHost,
     uninstall_entries: &[WindowsUninstallEntry],
+    package_roots: &PackageManagerRoots,
 ) {
     if !root.exists() {
         return;
     }
 
+    let mut pending = Vec::new();
     let mut queue = VecDeque::new();
     queue.push_back((root.to_path_buf(), 0usize));
     while let Some((dir, depth)) = queue.pop_front() {
-        if items.len() >= max_items {
+        if items.len() + pending.len() >= max_items {
             break;
         }
         let entries = match fs::read_dir(&dir) {
@@ -139,7 +148,7 @@ pub(crate) fn scan_windows_root(
         };
 
         for entry in entries.flatten() {
-            if items.len() >= max_items {
+            if items.len() + pending.len() >= max_items {
                 break;
             }
 
@@ -188,11 +197,23 @@ pub(crate) fn scan_windows_root(
             if path_key.is_empty() || seen_path_keys.contains(path_key.as_str()) {
                 continue;
             }
-            let item =
-                windows_build_item_from_uninstall_entry(app, &uninstall_match, path.as_path());
             seen_identity_keys.insert(identity_key);
             seen_path_keys.insert(path_key);
-            items.push(item);
+            pending.push((uninstall_match, path));
         }
     }
+
+    let remaining = max_items.saturating_sub(items.len());
+    items.extend(build_items_bounded(
+        pending.as_slice(),
+        remaining,
+        |(entry, path)| {
+            Some(windows_build_item_from_uninstall_entry(
+                app,
+                entry,
+                path.as_path(),
+                package_roots,
+            ))
+        },
+    ));
 }
@@ -133,6 +133,7 @@ pub(crate) fn windows_build_item_from_uninstall_entry(
     app: &dyn LauncherHost,
     entry: &WindowsUninstallEntry,
     path: &Path,
+    package_roots: &PackageManagerRoots,
 ) -> ManagedAppDto {
     let path_str = path.to_string_lossy().to_string();
     let size_resolution = windows_size_measurement_path(entry, path);
@@ -174,6 +175,21 @@ pub(crate) fn windows_build_item_from_uninstall_entry(
         platform_detect_startup_state(id.as_str(), path);
     let readonly_reason_code = startup_readonly_reason_code(startup_scope, startup_editable);
     let aliases = collect_app_path_aliases_from_parts(name.as_str(), path_str.as_str(), None);
+    let package_match = detect_package_manager_from_path(path, package_roots).or_else(|| {
+        entry.install_location.as_deref().and_then(|location| {
+            detect_package_manager_from_path(Path::new(location), package_roots)
+        })
+    });
+    let (package_manager, package_id) = match package_match {
+        Some(matched) => (Some(matched.manager), matched.package_id),
+        None => (
+            detect_installer_kind(
+                entry.registry_key.as_str(),
+                entry.uninstall_string.as_deref(),
+            ),
+            None,
+        ),
+    };
 
     let mut item = ManagedAppDto {
         id,
@@ -190,12 +206,17 @@ pub(crate) fn windows_build_item_from_uninstall_entry(
         size_accuracy,
         size_source,
         size_computed_at,
+        size_accuracy_used: None,
         startup_enabled,
         startup_scope,
         startup_editable,
         readonly_reason_code,
         uninstall_supported: true,
         uninstall_kind: Some(AppManagerUninstallKind::RegistryCommand),
+        package_manager,
+        package_id,
+        folder_path: None,
+        folder_name: None,
         capabilities: build_app_capabilities(true, true, true),
         identity: build_app_identity(
             entry.registry_key.as_str(),
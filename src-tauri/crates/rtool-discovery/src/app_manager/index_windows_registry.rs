@@ -174,6 +174,25 @@ pub(crate) fn windows_query_registry_values(root: &str) -> Vec<(String, String)>
         .collect()
 }
 
+#[cfg(target_os = "windows")]
+pub(crate) fn windows_query_registry_subkey_names(root: &str) -> Vec<String> {
+    let output = match Command::new("reg").args(["query", root]).output() {
+        Ok(output) => output,
+        Err(error) => {
+            tracing::debug!(
+                event = "app_manager_windows_reg_query_failed",
+                root = root,
+                error = error.to_string()
+            );
+            return Vec::new();
+        }
+    };
+    if !output.status.success() {
+        return Vec::new();
+    }
+    parse_reg_query_subkey_names(String::from_utf8_lossy(&output.stdout).as_ref())
+}
+
 #[cfg(target_os = "windows")]
 pub(crate) fn windows_registry_value_exists(root: &str, value_name: &str) -> bool {
     Command::new("reg")
@@ -2,23 +2,31 @@ use crate::host::LauncherHost;
 use anyhow::Context;
 #[cfg(target_os = "macos")]
 use regex::Regex;
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+use rtool_contracts::models::AppManagerGhostStartupReason;
 use rtool_contracts::models::{
-    AppManagerActionCode, AppManagerActionResultDto, AppManagerCapabilitiesDto,
-    AppManagerCleanupDeleteMode, AppManagerCleanupInputDto, AppManagerCleanupItemResultDto,
-    AppManagerCleanupReasonCode, AppManagerCleanupResultDto, AppManagerCleanupStatus,
-    AppManagerDetailQueryDto, AppManagerExportScanInputDto, AppManagerExportScanResultDto,
-    AppManagerIconKind, AppManagerIdentityDto, AppManagerIdentitySource, AppManagerIndexState,
+    AppManagerActionCode, AppManagerActionResultDto, AppManagerAnnotateResidueInputDto,
+    AppManagerBackupMetaDto, AppManagerBulkStartupItemDto, AppManagerBulkStartupResultDto,
+    AppManagerBulkStartupStatus, AppManagerCapabilitiesDto, AppManagerCleanupDeleteMode,
+    AppManagerCleanupInputDto, AppManagerCleanupItemResultDto, AppManagerCleanupReasonCode,
+    AppManagerCleanupResultDto, AppManagerCleanupStatus, AppManagerClearExportsInputDto,
+    AppManagerClearExportsResultDto, AppManagerDetailQueryDto, AppManagerExportFileDto,
+    AppManagerExportFormat, AppManagerExportListDto, AppManagerExportScanInputDto,
+    AppManagerExportScanResultDto, AppManagerGhostStartupItemDto, AppManagerIconKind,
+    AppManagerIdentityDto, AppManagerIdentitySource, AppManagerIndexState,
     AppManagerIndexUpdateReason, AppManagerIndexUpdatedPayloadDto, AppManagerPageDto,
     AppManagerPathType, AppManagerPlatform, AppManagerQueryDto, AppManagerResidueConfidence,
     AppManagerResidueGroupDto, AppManagerResidueItemDto, AppManagerResidueKind,
     AppManagerResidueMatchReason, AppManagerResidueScanInputDto, AppManagerResidueScanMode,
     AppManagerResidueScanResultDto, AppManagerResolveSizesInputDto,
     AppManagerResolveSizesResultDto, AppManagerResolvedSizeDto, AppManagerRiskLevel,
-    AppManagerScanWarningCode, AppManagerScanWarningDetailCode, AppManagerScanWarningDto,
-    AppManagerScope, AppManagerSizeAccuracy, AppManagerSizeSource, AppManagerSnapshotMetaDto,
-    AppManagerSource, AppManagerStartupScope, AppManagerStartupUpdateInputDto,
-    AppManagerUninstallInputDto, AppManagerUninstallKind, AppReadonlyReasonCode, AppRelatedRootDto,
-    AppSizeSummaryDto, ManagedAppDetailDto, ManagedAppDto,
+    AppManagerScanPathInputDto, AppManagerScanWarningCode, AppManagerScanWarningDetailCode,
+    AppManagerScanWarningDto, AppManagerScope, AppManagerSizeAccuracy, AppManagerSizeAccuracyMode,
+    AppManagerSizeSource, AppManagerSnapshotMetaDto, AppManagerSource, AppManagerStartupEntryDto,
+    AppManagerStartupEntryKind, AppManagerStartupScope, AppManagerStartupUpdateInputDto,
+    AppManagerUninstallHelpTarget, AppManagerUninstallInputDto, AppManagerUninstallKind,
+    AppReadonlyReasonCode, AppRelatedRootDto, AppSizeSummaryDto, ManagedAppDetailDto,
+    ManagedAppDto,
 };
 use rtool_contracts::{AppError, AppResult, ResultExt};
 use rtool_platform::icon::{resolve_application_icon, resolve_builtin_icon};
@@ -30,7 +38,7 @@ use std::fs;
 use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
 use std::process::Command;
-use std::sync::{Condvar, Mutex, OnceLock};
+use std::sync::{Arc, Condvar, Mutex, OnceLock};
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 #[path = "api/mod.rs"]
@@ -47,8 +55,13 @@ mod index;
 mod index_runtime;
 #[path = "naming.rs"]
 mod naming;
+#[cfg(any(target_os = "windows", test))]
+#[path = "package_manager.rs"]
+mod package_manager;
 #[path = "residue.rs"]
 mod residue;
+#[path = "scan_path.rs"]
+mod scan_path;
 #[path = "size.rs"]
 mod size;
 #[path = "startup.rs"]
@@ -63,7 +76,10 @@ use identity::*;
 use index::*;
 use index_runtime::*;
 use naming::*;
+#[cfg(target_os = "windows")]
+use package_manager::*;
 use residue::*;
+use scan_path::*;
 use size::*;
 use startup::*;
 use uninstall::*;
@@ -72,6 +88,7 @@ const INDEX_CACHE_TTL: Duration = Duration::from_secs(30);
 const INDEX_DISK_CACHE_FILE: &str = "app_manager_index_cache.json";
 const INDEX_DISK_CACHE_PREFIX: &str = "app_manager_index_cache";
 const RESIDUE_SCAN_CACHE_TTL: Duration = Duration::from_secs(120);
+const RESIDUE_ANNOTATION_MAX_CHARS: usize = 500;
 const DEFAULT_LIMIT: usize = 100;
 const MAX_LIMIT: usize = 300;
 #[cfg(target_os = "macos")]
@@ -81,10 +98,19 @@ const MAC_STARTUP_CACHE_TTL: Duration = Duration::from_secs(20);
 #[cfg(target_os = "windows")]
 const WIN_SCAN_MAX_ITEMS: usize = 700;
 const STARTUP_LABEL_PREFIX: &str = "com.rtool.startup";
+#[cfg(target_os = "windows")]
+const WINDOWS_STARTUP_VALUE_PREFIX: &str = "RToolStartup_";
 const EXPORT_DIR_NAME: &str = "rtool-app-scan-exports";
+const STARTUP_BACKUP_DIR_NAME: &str = "startup_backups";
+const STARTUP_BACKUP_KEEP: usize = 5;
 const SIZE_ESTIMATE_MAX_DEPTH: usize = 3;
 const SIZE_ESTIMATE_MAX_DIRS: usize = 2_000;
+const SIZE_STANDARD_MAX_DEPTH: usize = 6;
+const SIZE_STANDARD_MAX_DIRS: usize = 5_000;
 const SIZE_WARNING_LIMIT: usize = 24;
+const SIZE_RESOLVE_MAX_PARALLELISM: usize = 8;
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+const INDEX_BUILD_MAX_PARALLELISM: usize = 8;
 
 #[allow(dead_code)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -94,6 +120,7 @@ pub(super) enum AppManagerErrorCode {
     ExportDirFailed,
     ExportSerializeFailed,
     ExportWriteFailed,
+    ExportListFailed,
     FingerprintMismatch,
     UninstallUnsupported,
     UninstallSelfForbidden,
@@ -111,6 +138,8 @@ pub(super) enum AppManagerErrorCode {
     StartupWriteFailed,
     StartupDeleteFailed,
     StartupUpdateFailed,
+    StartupEntryNotFound,
+    GhostStartupNotFound,
     CleanupDeleteFailed,
     CleanupModeInvalid,
     CleanupNotFound,
@@ -118,6 +147,16 @@ pub(super) enum AppManagerErrorCode {
     CleanupNotSupported,
     FingerprintMissing,
     CleanupFailed,
+    ScanPathInvalid,
+    ScanPathNotFound,
+    ScanPathForbidden,
+    ScanPathOutOfScope,
+    ResidueItemNotFound,
+    ResidueAnnotationInvalid,
+    StartupBackupWriteFailed,
+    StartupBackupListFailed,
+    StartupBackupInvalid,
+    StartupBackupNotFound,
 }
 
 impl AppManagerErrorCode {
@@ -128,6 +167,7 @@ impl AppManagerErrorCode {
             Self::ExportDirFailed => "app_manager_export_dir_failed",
             Self::ExportSerializeFailed => "app_manager_export_serialize_failed",
             Self::ExportWriteFailed => "app_manager_export_write_failed",
+            Self::ExportListFailed => "app_manager_export_list_failed",
             Self::FingerprintMismatch => "app_manager_fingerprint_mismatch",
             Self::UninstallUnsupported => "app_manager_uninstall_unsupported",
             Self::UninstallSelfForbidden => "app_manager_uninstall_self_forbidden",
@@ -145,6 +185,8 @@ impl AppManagerErrorCode {
             Self::StartupWriteFailed => "app_manager_startup_write_failed",
             Self::StartupDeleteFailed => "app_manager_startup_delete_failed",
             Self::StartupUpdateFailed => "app_manager_startup_update_failed",
+            Self::StartupEntryNotFound => "app_manager_startup_entry_not_found",
+            Self::GhostStartupNotFound => "app_manager_ghost_startup_not_found",
             Self::CleanupDeleteFailed => "app_manager_cleanup_delete_failed",
             Self::CleanupModeInvalid => "app_manager_cleanup_mode_invalid",
             Self::CleanupNotFound => "app_manager_cleanup_not_found",
@@ -152,6 +194,16 @@ impl AppManagerErrorCode {
             Self::CleanupNotSupported => "app_manager_cleanup_not_supported",
             Self::FingerprintMissing => "app_manager_fingerprint_missing",
             Self::CleanupFailed => "app_manager_cleanup_failed",
+            Self::ScanPathInvalid => "app_manager_scan_path_invalid",
+            Self::ScanPathNotFound => "app_manager_scan_path_not_found",
+            Self::ScanPathForbidden => "app_manager_scan_path_forbidden",
+            Self::ScanPathOutOfScope => "app_manager_scan_path_out_of_scope",
+            Self::ResidueItemNotFound => "app_manager_residue_item_not_found",
+            Self::ResidueAnnotationInvalid => "app_manager_residue_annotation_invalid",
+            Self::StartupBackupWriteFailed => "app_manager_startup_backup_write_failed",
+            Self::StartupBackupListFailed => "app_manager_startup_backup_list_failed",
+            Self::StartupBackupInvalid => "app_manager_startup_backup_invalid",
+            Self::StartupBackupNotFound => "app_manager_startup_backup_not_found",
         }
     }
 }
@@ -0,0 +1,350 @@
+use super::*;
+use rtool_contracts::models::AppManagerPackageManager;
+
+const WINGET_SOURCE_MARKER: &str = "_Microsoft.Winget.Source";
+const SCOOP_MANIFEST_FILE: &str = "manifest.json";
+const CHOCOLATEY_MANIFEST_EXT: &str = "nuspec";
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PackageManagerRoots {
+    winget_apps: Option<PathBuf>,
+    winget_packages: Option<PathBuf>,
+    scoop_apps: Option<PathBuf>,
+    chocolatey_lib: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PackageManagerMatch {
+    pub(crate) manager: AppManagerPackageManager,
+    pub(crate) package_id: Option<String>,
+}
+
+impl PackageManagerRoots {
+    #[cfg(target_os = "windows")]
+    pub(crate) fn from_env() -> Self {
+        let local_app_data = std::env::var_os("LOCALAPPDATA").map(PathBuf::from);
+        let scoop_root = std::env::var_os("SCOOP").map(PathBuf::from).or_else(|| {
+            std::env::var_os("USERPROFILE").map(|value| PathBuf::from(value).join("scoop"))
+        });
+        Self {
+            winget_apps: local_app_data
+                .as_ref()
+                .map(|dir| dir.join("Microsoft").join("WindowsApps")),
+            winget_packages: local_app_data
+                .as_ref()
+                .map(|dir| dir.join("Microsoft").join("WinGet").join("Packages")),
+            scoop_apps: scoop_root.map(|dir| dir.join("apps")),
+            chocolatey_lib: std::env::var_os("ChocolateyInstall")
+                .map(|dir| PathBuf::from(dir).join("lib")),
+        }
+    }
+}
+
+fn path_components_lower(path: &Path) -> Vec<String> {
+    path.components()
+        .map(|component| {
+            component
+                .as_os_str()
+                .to_string_lossy()
+                .trim_end_matches(['\\', '/'])
+                .to_ascii_lowercase()
+        })
+        .filter(|component| !component.is_empty())
+        .collect()
+}
+
+fn relative_components(path: &Path, root: &Path) -> Option<Vec<String>> {
+    let root_parts = path_components_lower(root);
+    let path_parts = path_components_lower(path);
+    if root_parts.is_empty() || path_parts.len() <= root_parts.len() {
+        return None;
+    }
+    if path_parts[..root_parts.len()] != root_parts[..] {
+        return None;
+    }
+
+    let rest = path
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy().to_string())
+        .filter(|component| !component.trim_end_matches(['\\', '/']).is_empty())
+        .skip(root_parts.len())
+        .collect();
+    Some(rest)
+}
+
+fn detect_scoop(path: &Path, apps_root: &Path) -> Option<PackageManagerMatch> {
+    let rest = relative_components(path, apps_root)?;
+    let [name, version, ..] = rest.as_slice() else {
+        return None;
+    };
+    let manifest = apps_root.join(name).join(version).join(SCOOP_MANIFEST_FILE);
+    if !manifest.is_file() {
+        return None;
+    }
+    Some(PackageManagerMatch {
+        manager: AppManagerPackageManager::Scoop,
+        package_id: Some(name.clone()),
+    })
+}
+
+fn detect_chocolatey(path: &Path, lib_root: &Path) -> Option<PackageManagerMatch> {
+    let rest = relative_components(path, lib_root)?;
+    let package_id = rest.first()?;
+    let manifest = lib_root
+        .join(package_id)
+        .join(format!("{package_id}.{CHOCOLATEY_MANIFEST_EXT}"));
+    if !manifest.is_file() {
+        return None;
+    }
+    Some(PackageManagerMatch {
+        manager: AppManagerPackageManager::Chocolatey,
+        package_id: Some(package_id.clone()),
+    })
+}
+
+fn detect_winget_package(path: &Path, packages_root: &Path) -> Option<PackageManagerMatch> {
+    let rest = relative_components(path, packages_root)?;
+    let folder = rest.first()?;
+    let package_id = folder
+        .split_once(WINGET_SOURCE_MARKER)
+        .map(|(id, _)| id)
+        .unwrap_or(folder.as_str())
+        .trim();
+    if package_id.is_empty() {
+        return None;
+    }
+    Some(PackageManagerMatch {
+        manager: AppManagerPackageManager::Winget,
+        package_id: Some(package_id.to_string()),
+    })
+}
+
+fn detect_winget_app_alias(path: &Path, apps_root: &Path) -> Option<PackageManagerMatch> {
+    relative_components(path, apps_root)?;
+    Some(PackageManagerMatch {
+        manager: AppManagerPackageManager::Winget,
+        package_id: None,
+    })
+}
+
+pub(crate) fn detect_package_manager_from_path(
+    path: &Path,
+    roots: &PackageManagerRoots,
+) -> Option<PackageManagerMatch> {
+    roots
+        .scoop_apps
+        .as_deref()
+        .and_then(|root| detect_scoop(path, root))
+        .or_else(|| {
+            roots
+                .chocolatey_lib
+                .as_deref()
+                .and_then(|root| detect_chocolatey(path, root))
+        })
+        .or_else(|| {
+            roots
+                .winget_packages
+                .as_deref()
+                .and_then(|root| detect_winget_package(path, root))
+        })
+        .or_else(|| {
+            roots
+                .winget_apps
+                .as_deref()
+                .and_then(|root| detect_winget_app_alias(path, root))
+        })
+}
+
+pub(crate) fn detect_installer_kind(
+    registry_key: &str,
+    uninstall_command: Option<&str>,
+) -> Option<AppManagerPackageManager> {
+    let key = registry_key.trim().to_ascii_lowercase();
+    let command = uninstall_command.unwrap_or_default().to_ascii_lowercase();
+    if command.contains("msiexec") {
+        return Some(AppManagerPackageManager::Msi);
+    }
+    if key.ends_with("_is1") || command.contains("unins000.exe") {
+        return Some(AppManagerPackageManager::Inno);
+    }
+    if command.contains("uninst.exe") || command.contains("uninstall.exe") {
+        return Some(AppManagerPackageManager::Nsis);
+    }
+    None
+}
+
+pub(crate) fn winget_uninstall_args(package_id: &str) -> Vec<String> {
+    [
+        "uninstall",
+        "--id",
+        package_id,
+        "--exact",
+        "--silent",
+        "--accept-source-agreements",
+    ]
+    .into_iter()
+    .map(ToString::to_string)
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TempTree {
+        root: PathBuf,
+    }
+
+    impl TempTree {
+        fn new(label: &str) -> Self {
+            let root = std::env::temp_dir().join(format!(
+                "rtool-package-manager-{label}-{}",
+                uuid::Uuid::new_v4()
+            ));
+            fs::create_dir_all(&root).expect("create temp root");
+            Self { root }
+        }
+
+        fn touch(&self, relative: &str) -> PathBuf {
+            let path = self.root.join(relative);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).expect("create parent dir");
+            }
+            fs::write(&path, b"").expect("write file");
+            path
+        }
+    }
+
+    impl Drop for TempTree {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.root);
+        }
+    }
+
+    fn roots_for(tree: &TempTree) -> PackageManagerRoots {
+        PackageManagerRoots {
+            winget_apps: Some(tree.root.join("LocalAppData/Microsoft/WindowsApps")),
+            winget_packages: Some(tree.root.join("LocalAppData/Microsoft/WinGet/Packages")),
+            scoop_apps: Some(tree.root.join("scoop/apps")),
+            chocolatey_lib: Some(tree.root.join("chocolatey/lib")),
+        }
+    }
+
+    #[test]
+    fn detects_scoop_app_with_manifest() {
+        let tree = TempTree::new("scoop");
+        tree.touch("scoop/apps/7zip/current/manifest.json");
+        let exe = tree.touch("scoop/apps/7zip/current/7zFM.exe");
+
+        let matched = detect_package_manager_from_path(&exe, &roots_for(&tree));
+        assert_eq!(
+            matched,
+            Some(PackageManagerMatch {
+                manager: AppManagerPackageManager::Scoop,
+                package_id: Some("7zip".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn ignores_scoop_path_without_manifest() {
+        let tree = TempTree::new("scoop-missing");
+        let exe = tree.touch("scoop/apps/7zip/current/7zFM.exe");
+
+        assert_eq!(
+            detect_package_manager_from_path(&exe, &roots_for(&tree)),
+            None
+        );
+    }
+
+    #[test]
+    fn detects_chocolatey_package_with_nuspec() {
+        let tree = TempTree::new("choco");
+        tree.touch("chocolatey/lib/git/git.nuspec");
+        let exe = tree.touch("chocolatey/lib/git/tools/git.exe");
+
+        let matched = detect_package_manager_from_path(&exe, &roots_for(&tree));
+        assert_eq!(
+            matched,
+            Some(PackageManagerMatch {
+                manager: AppManagerPackageManager::Chocolatey,
+                package_id: Some("git".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn detects_winget_package_id_from_folder_name() {
+        let tree = TempTree::new("winget");
+        let exe = tree.touch(
+            "LocalAppData/Microsoft/WinGet/Packages/BurntSushi.ripgrep.MSVC_Microsoft.Winget.Source_8wekyb3d8bbwe/rg.exe",
+        );
+
+        let matched = detect_package_manager_from_path(&exe, &roots_for(&tree));
+        assert_eq!(
+            matched,
+            Some(PackageManagerMatch {
+                manager: AppManagerPackageManager::Winget,
+                package_id: Some("BurntSushi.ripgrep.MSVC".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn detects_winget_app_alias_without_package_id() {
+        let tree = TempTree::new("winget-alias");
+        let exe = tree.touch("LocalAppData/Microsoft/WindowsApps/python.exe");
+
+        let matched = detect_package_manager_from_path(&exe, &roots_for(&tree));
+        assert_eq!(
+            matched,
+            Some(PackageManagerMatch {
+                manager: AppManagerPackageManager::Winget,
+                package_id: None,
+            })
+        );
+    }
+
+    #[test]
+    fn unrelated_path_has_no_package_manager() {
+        let tree = TempTree::new("unrelated");
+        let exe = tree.touch("Program Files/Vendor/app.exe");
+
+        assert_eq!(
+            detect_package_manager_from_path(&exe, &roots_for(&tree)),
+            None
+        );
+    }
+
+    #[test]
+    fn detects_installer_kind_from_uninstall_entry() {
+        assert_eq!(
+            detect_installer_kind("{GUID}", Some("MsiExec.exe /X{GUID}")),
+            Some(AppManagerPackageManager::Msi)
+        );
+        assert_eq!(
+            detect_installer_kind(r"HKLM\Uninstall\App_is1", Some(r#""C:\App\unins000.exe""#)),
+            Some(AppManagerPackageManager::Inno)
+        );
+        assert_eq!(
+            detect_installer_kind(r"HKLM\Uninstall\App", Some(r#""C:\App\uninst.exe" /S"#)),
+            Some(AppManagerPackageManager::Nsis)
+        );
+        assert_eq!(detect_installer_kind(r"HKLM\Uninstall\App", None), None);
+    }
+
+    #[test]
+    fn winget_uninstall_args_target_exact_package_id() {
+        assert_eq!(
+            winget_uninstall_args("Git.Git"),
+            vec![
+                "uninstall",
+                "--id",
+                "Git.Git",
+                "--exact",
+                "--silent",
+                "--accept-source-agreements",
+            ]
+        );
+    }
+}
@@ -329,11 +329,8 @@ pub(super) fn detect_path_type(
     AppManagerPathType::Directory
 }
 
-pub(super) fn build_app_detail(app: ManagedAppDto) -> ManagedAppDetailDto {
-    let app_size_resolution = resolve_managed_app_size_path(&app);
-    let size_snapshot = resolve_app_size_snapshot(app_size_resolution.path.as_path());
-    let app_size_bytes = size_snapshot.size_bytes.or(app.size_bytes);
-    let related_roots = collect_related_root_specs(&app)
+pub(super) fn build_related_roots(app: &ManagedAppDto) -> Vec<AppRelatedRootDto> {
+    collect_related_root_specs(app)
         .into_iter()
         .map(|root| {
             let exists = root.path.exists();
@@ -368,10 +365,30 @@ pub(super) fn build_app_detail(app: ManagedAppDto) -> ManagedAppDetailDto {
                 kind: root.kind,
                 exists,
                 readonly,
+                cleanable: root.kind.is_cleanable() && !readonly,
                 readonly_reason_code,
             }
         })
-        .collect::<Vec<_>>();
+        .collect()
+}
+
+pub(super) fn build_app_detail(
+    mut app: ManagedAppDto,
+    size_accuracy: Option<AppManagerSizeAccuracyMode>,
+) -> ManagedAppDetailDto {
+    let app_size_bytes = match size_accuracy {
+        Some(mode) => {
+            apply_size_accuracy_mode(&mut app, mode);
+            app.size_bytes
+        }
+        None => {
+            let app_size_resolution = resolve_managed_app_size_path(&app);
+            resolve_app_size_snapshot(app_size_resolution.path.as_path())
+                .size_bytes
+                .or(app.size_bytes)
+        }
+    };
+    let related_roots = build_related_roots(&app);
 
     ManagedAppDetailDto {
         install_path: app.path.clone(),
@@ -379,6 +396,7 @@ pub(super) fn build_app_detail(app: ManagedAppDto) -> ManagedAppDetailDto {
             app_bytes: app_size_bytes,
             residue_bytes: None,
             total_bytes: app_size_bytes,
+            size_accuracy_used: app.size_accuracy_used,
         },
         related_roots,
         app,
@@ -835,6 +853,25 @@ fn windows_collect_registry_residue_candidates(item: &ManagedAppDto) -> Vec<Resi
         });
     }
 
+    let software_root = r"HKCU\Software";
+    let software_subkeys = windows_query_registry_subkey_names(software_root);
+    let aliases = collect_app_path_aliases(item);
+    for subkey in match_software_subkeys_by_alias(software_subkeys.as_slice(), aliases.as_slice()) {
+        candidates.push(ResidueCandidate {
+            path: PathBuf::from(format!("{software_root}\\{subkey}")),
+            scope: AppManagerScope::User,
+            kind: AppManagerResidueKind::RegistryKey,
+            exists: true,
+            filesystem: false,
+            match_reason: AppManagerResidueMatchReason::UserSoftwareRegistry,
+            confidence: AppManagerResidueConfidence::Medium,
+            evidence: vec![format!("user_software_registry_alias:{subkey}")],
+            risk_level: AppManagerRiskLevel::Medium,
+            recommended: false,
+            readonly_reason_code: None,
+        });
+    }
+
     let app_path_key = normalize_path_key(item.path.as_str());
     for root in [
         r"HKCU\Software\Microsoft\Windows\CurrentVersion\Run",
@@ -874,6 +911,70 @@ fn windows_collect_registry_residue_candidates(item: &ManagedAppDto) -> Vec<Resi
     candidates
 }
 
+#[cfg(any(target_os = "windows", test))]
+const WINDOWS_SHARED_SOFTWARE_SUBKEYS: [&str; 7] = [
+    "classes",
+    "clients",
+    "microsoft",
+    "policies",
+    "registeredapplications",
+    "wow6432node",
+    "appdatalow",
+];
+
+/// Reads the direct child key names from non-recursive `reg query` output.
+#[cfg(any(target_os = "windows", test))]
+pub(super) fn parse_reg_query_subkey_names(stdout: &str) -> Vec<String> {
+    let mut root: Option<&str> = None;
+    let mut names = Vec::new();
+    for raw_line in stdout.lines() {
+        let line = raw_line.trim_end();
+        if raw_line.starts_with(' ') || !line.starts_with("HKEY_") {
+            continue;
+        }
+        let Some(root) = root else {
+            root = Some(line);
+            continue;
+        };
+        let Some(name) = line
+            .get(root.len()..)
+            .filter(|_| line[..root.len()].eq_ignore_ascii_case(root))
+            .and_then(|rest| rest.strip_prefix('\\'))
+        else {
+            continue;
+        };
+        if !name.is_empty() && !name.contains('\\') {
+            names.push(name.to_string());
+        }
+    }
+    names
+}
+
+#[cfg(any(target_os = "windows", test))]
+fn compact_name_key(value: &str) -> String {
+    normalize_name_key(value).replace(' ', "")
+}
+
+#[cfg(any(target_os = "windows", test))]
+pub(super) fn match_software_subkeys_by_alias(
+    subkeys: &[String],
+    aliases: &[String],
+) -> Vec<String> {
+    let alias_keys = aliases
+        .iter()
+        .map(|alias| compact_name_key(alias))
+        .filter(|key| key.chars().count() >= 3)
+        .collect::<HashSet<_>>();
+    subkeys
+        .iter()
+        .filter(|subkey| {
+            let key = compact_name_key(subkey);
+            !WINDOWS_SHARED_SOFTWARE_SUBKEYS.contains(&key.as_str()) && alias_keys.contains(&key)
+        })
+        .cloned()
+        .collect()
+}
+
 fn group_label(kind: AppManagerResidueKind, scope: AppManagerScope) -> String {
     let kind_label = match kind {
         AppManagerResidueKind::Install => "安装目录",
@@ -1102,6 +1203,8 @@ pub(super) fn build_residue_scan_result(
                 scope: candidate.scope,
                 kind: candidate.kind,
                 total_size_bytes: 0,
+                total_items: 0,
+                recommended_items: 0,
                 items: Vec::new(),
             });
         group.total_size_bytes = group.total_size_bytes.saturating_add(size_bytes);
@@ -1123,6 +1226,8 @@ pub(super) fn build_residue_scan_result(
             recommended: candidate.recommended && !readonly,
             readonly,
             readonly_reason_code,
+            annotation: None,
+            user_skip: false,
         });
     }
 
@@ -1137,6 +1242,10 @@ pub(super) fn build_residue_scan_result(
         group
             .items
             .sort_by(|left, right| left.path.cmp(&right.path));
+        group.total_items = u32::try_from(group.items.len()).unwrap_or(u32::MAX);
+        group.recommended_items =
+            u32::try_from(group.items.iter().filter(|entry| entry.recommended).count())
+                .unwrap_or(u32::MAX);
     }
 
     AppManagerResidueScanResultDto {
@@ -1147,3 +1256,41 @@ pub(super) fn build_residue_scan_result(
         warnings,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOFTWARE_DUMP: &str = "\r
+HKEY_CURRENT_USER\\Software\r
+    (Default)    REG_SZ    \r
+HKEY_CURRENT_USER\\Software\\7-Zip\r
+HKEY_CURRENT_USER\\Software\\Classes\r
+HKEY_CURRENT_USER\\Software\\Microsoft\r
+HKEY_CURRENT_USER\\Software\\Notepad++\r
+HKEY_CURRENT_USER\\Software\\Sublime Text\r
+HKEY_CURRENT_USER\\Software\\Sublime Text\\Settings\r
+";
+
+    #[test]
+    fn parses_direct_subkeys_from_reg_query_dump() {
+        assert_eq!(
+            parse_reg_query_subkey_names(SOFTWARE_DUMP),
+            vec!["7-Zip", "Classes", "Microsoft", "Notepad++", "Sublime Text",]
+        );
+    }
+
+    #[test]
+    fn matches_software_subkeys_by_normalized_alias() {
+        let subkeys = parse_reg_query_subkey_names(SOFTWARE_DUMP);
+        let aliases = vec![
+            "SublimeText".to_string(),
+            "7zip".to_string(),
+            "Microsoft".to_string(),
+        ];
+        assert_eq!(
+            match_software_subkeys_by_alias(subkeys.as_slice(), aliases.as_slice()),
+            vec!["7-Zip", "Sublime Text"]
+        );
+    }
+}
@@ -0,0 +1,414 @@
+use super::*;
+
+const SCAN_PATH_ID_PREFIX: &str = "path";
+const SCAN_PATH_ICON: &str = "i-noto:file-folder";
+
+#[cfg(target_os = "macos")]
+const SYSTEM_CRITICAL_ROOTS: &[&str] = &[
+    "/System",
+    "/bin",
+    "/sbin",
+    "/usr",
+    "/dev",
+    "/private/etc",
+    "/private/var/db",
+    "/Library/Apple",
+];
+#[cfg(target_os = "windows")]
+const SYSTEM_CRITICAL_ROOTS: &[&str] = &[
+    r"C:\Windows",
+    r"C:\Recovery",
+    r"C:\System Volume Information",
+    r"C:\$Recycle.Bin",
+];
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+const SYSTEM_CRITICAL_ROOTS: &[&str] = &[
+    "/bin", "/boot", "/dev", "/etc", "/lib", "/lib64", "/proc", "/sbin", "/sys", "/usr", "/var",
+];
+
+const HOME_CONTAINER_DIRS: &[&str] = &[
+    "Desktop",
+    "Documents",
+    "Downloads",
+    "Library",
+    "Library/Application Support",
+    "Library/Caches",
+    "Library/Containers",
+    "Library/Group Containers",
+    "Library/Logs",
+    "Library/Preferences",
+    "AppData",
+    "AppData/Local",
+    "AppData/LocalLow",
+    "AppData/Roaming",
+    ".cache",
+    ".config",
+    ".local",
+    ".local/share",
+];
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct ScanPathScope {
+    home: Option<PathBuf>,
+    install_roots: Vec<PathBuf>,
+    critical_roots: Vec<PathBuf>,
+}
+
+impl ScanPathScope {
+    pub(super) fn from_env() -> Self {
+        #[cfg(target_os = "macos")]
+        let install_roots = vec![PathBuf::from("/Applications")];
+        #[cfg(target_os = "windows")]
+        let install_roots = ["ProgramFiles", "ProgramFiles(x86)", "ProgramData"]
+            .into_iter()
+            .filter_map(std::env::var_os)
+            .map(PathBuf::from)
+            .collect::<Vec<_>>();
+        #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+        let install_roots = vec![PathBuf::from("/opt")];
+
+        let critical_roots = SYSTEM_CRITICAL_ROOTS.iter().map(PathBuf::from);
+        #[cfg(target_os = "windows")]
+        let critical_roots =
+            critical_roots.chain(std::env::var_os("SystemRoot").map(PathBuf::from));
+
+        Self {
+            home: home_dir().map(canonicalize_or_keep),
+            install_roots: install_roots
+                .into_iter()
+                .map(canonicalize_or_keep)
+                .collect(),
+            critical_roots: critical_roots.map(canonicalize_or_keep).collect(),
+        }
+    }
+
+    fn is_container(&self, path: &Path) -> bool {
+        let home_containers = self.home.iter().flat_map(|home| {
+            std::iter::once(home.clone())
+                .chain(HOME_CONTAINER_DIRS.iter().map(|dir| home.join(dir)))
+        });
+        home_containers
+            .chain(self.install_roots.iter().cloned())
+            .any(|root| path_key(root.as_path()) == path_key(path))
+    }
+
+    fn is_critical(&self, path: &Path) -> bool {
+        path.parent().is_none()
+            || self
+                .critical_roots
+                .iter()
+                .any(|root| path_is_within(path, root))
+    }
+
+    fn is_in_scope(&self, path: &Path) -> bool {
+        self.home
+            .iter()
+            .chain(self.install_roots.iter())
+            .any(|root| path_key(root.as_path()) != path_key(path) && path_is_within(path, root))
+    }
+}
+
+fn canonicalize_or_keep(path: PathBuf) -> PathBuf {
+    fs::canonicalize(&path)
+        .map(strip_verbatim_prefix)
+        .unwrap_or(path)
+}
+
+fn strip_verbatim_prefix(path: PathBuf) -> PathBuf {
+    let raw = path.to_string_lossy().to_string();
+    if let Some(rest) = raw.strip_prefix(r"\\?\UNC\") {
+        return PathBuf::from(format!(r"\\{rest}"));
+    }
+    if let Some(rest) = raw.strip_prefix(r"\\?\") {
+        return PathBuf::from(rest);
+    }
+    path
+}
+
+fn path_key(path: &Path) -> String {
+    normalize_path_key(path.to_string_lossy().as_ref())
+        .trim_end_matches(['/', '\\'])
+        .to_string()
+}
+
+fn path_is_within(path: &Path, root: &Path) -> bool {
+    let root_key = path_key(root);
+    if root_key.is_empty() {
+        return false;
+    }
+    let key = path_key(path);
+    key == root_key
+        || key
+            .strip_prefix(root_key.as_str())
+            .is_some_and(|rest| rest.starts_with(['/', '\\']))
+}
+
+pub(super) fn resolve_scan_path(raw_path: &str, scope: &ScanPathScope) -> AppResult<PathBuf> {
+    let trimmed = raw_path.trim();
+    if trimmed.is_empty() {
+        return Err(app_error(
+            AppManagerErrorCode::ScanPathInvalid,
+            "扫描路径不能为空",
+        ));
+    }
+    let path = PathBuf::from(trimmed);
+    if !path.is_absolute() {
+        return Err(app_error(
+            AppManagerErrorCode::ScanPathInvalid,
+            "扫描路径必须是绝对路径",
+        )
+        .with_context("path", trimmed));
+    }
+    if !path.exists() {
+        return Err(
+            app_error(AppManagerErrorCode::ScanPathNotFound, "扫描路径不存在")
+                .with_context("path", trimmed),
+        );
+    }
+
+    let canonical = fs::canonicalize(&path)
+        .map(strip_verbatim_prefix)
+        .with_context(|| format!("解析扫描路径失败: {}", path.display()))
+        .with_code(
+            AppManagerErrorCode::ScanPathInvalid.as_str(),
+            "无法解析扫描路径",
+        )
+        .with_ctx("path", trimmed)?;
+    if !canonical.is_dir() {
+        return Err(
+            app_error(AppManagerErrorCode::ScanPathInvalid, "扫描路径必须是目录")
+                .with_context("path", canonical.display().to_string()),
+        );
+    }
+    if scope.is_critical(canonical.as_path()) {
+        return Err(app_error(
+            AppManagerErrorCode::ScanPathForbidden,
+            "不允许扫描系统关键目录",
+        )
+        .with_context("path", canonical.display().to_string()));
+    }
+    if scope.is_container(canonical.as_path()) {
+        return Err(app_error(
+            AppManagerErrorCode::ScanPathForbidden,
+            "不允许扫描用户目录或应用根目录本身",
+        )
+        .with_context("path", canonical.display().to_string()));
+    }
+    if !scope.is_in_scope(canonical.as_path()) {
+        return Err(app_error(
+            AppManagerErrorCode::ScanPathOutOfScope,
+            "扫描路径不在允许的范围内",
+        )
+        .with_context("path", canonical.display().to_string()));
+    }
+    Ok(canonical)
+}
+
+pub(super) fn is_scan_path_app_id(app_id: &str) -> bool {
+    app_id
+        .strip_prefix(SCAN_PATH_ID_PREFIX)
+        .is_some_and(|rest| rest.starts_with('.'))
+}
+
+pub(super) fn remember_scan_path_item(item: &ManagedAppDto) {
+    scan_path_items()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(item.id.clone(), item.clone());
+}
+
+pub(super) fn find_scan_path_item(app_id: &str) -> AppResult<ManagedAppDto> {
+    scan_path_items()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(app_id)
+        .cloned()
+        .ok_or_else(|| {
+            app_error(AppManagerErrorCode::NotFound, "扫描路径已失效，请重新扫描")
+                .with_context("appId", app_id)
+        })
+}
+
+pub(super) fn build_scan_path_item(path: &Path) -> ManagedAppDto {
+    let path_value = path.to_string_lossy().to_string();
+    let name = path
+        .file_name()
+        .map(|value| value.to_string_lossy().to_string())
+        .unwrap_or_else(|| path_value.clone());
+    let path_key = normalize_path_key(path_value.as_str());
+    let aliases = collect_app_path_aliases_from_parts(name.as_str(), path_value.as_str(), None);
+    let identity = build_app_identity(path_key.clone(), aliases, AppManagerIdentitySource::Path);
+    let icon = resolve_builtin_icon(SCAN_PATH_ICON);
+
+    let mut item = ManagedAppDto {
+        id: stable_app_id(SCAN_PATH_ID_PREFIX, path_key.as_str()),
+        name,
+        path: path_value,
+        bundle_or_app_id: None,
+        version: None,
+        publisher: None,
+        platform: AppManagerPlatform::current(),
+        source: AppManagerSource::Application,
+        icon_kind: AppManagerIconKind::from_raw(icon.kind.as_str()),
+        icon_value: icon.value,
+        size_bytes: None,
+        size_accuracy: AppManagerSizeAccuracy::Estimated,
+        size_source: AppManagerSizeSource::Path,
+        size_computed_at: None,
+        size_accuracy_used: None,
+        startup_enabled: false,
+        startup_scope: AppManagerStartupScope::None,
+        startup_editable: false,
+        readonly_reason_code: None,
+        uninstall_supported: false,
+        uninstall_kind: None,
+        package_manager: None,
+        package_id: None,
+        folder_path: None,
+        folder_name: None,
+        capabilities: build_app_capabilities(false, false, true),
+        identity,
+        risk_level: AppManagerRiskLevel::Medium,
+        fingerprint: String::new(),
+    };
+    item.fingerprint = fingerprint_for_app(&item);
+    item
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TempScope {
+        root: PathBuf,
+        scope: ScanPathScope,
+    }
+
+    impl TempScope {
+        fn new(label: &str) -> Self {
+            let root = std::env::temp_dir()
+                .join(format!("rtool-scan-path-{label}-{}", uuid::Uuid::new_v4()));
+            for dir in ["home/Library", "apps", "system"] {
+                fs::create_dir_all(root.join(dir)).expect("create temp dir");
+            }
+            let root = canonicalize_or_keep(root);
+            let scope = ScanPathScope {
+                home: Some(root.join("home")),
+                install_roots: vec![root.join("apps")],
+                critical_roots: vec![root.join("system")],
+            };
+            Self { root, scope }
+        }
+
+        fn mkdir(&self, relative: &str) -> PathBuf {
+            let path = self.root.join(relative);
+            fs::create_dir_all(&path).expect("create dir");
+            path
+        }
+    }
+
+    impl Drop for TempScope {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.root);
+        }
+    }
+
+    fn error_code(result: AppResult<PathBuf>) -> String {
+        result.expect_err("path should be rejected").code.clone()
+    }
+
+    #[test]
+    fn accepts_directory_under_home_or_install_root() {
+        let temp = TempScope::new("accept");
+        let home_app = temp.mkdir("home/tools/Foo Editor");
+        let installed_app = temp.mkdir("apps/Bar");
+
+        let resolved = resolve_scan_path(home_app.to_string_lossy().as_ref(), &temp.scope)
+            .expect("home path should be accepted");
+        assert_eq!(resolved, home_app);
+        let resolved = resolve_scan_path(installed_app.to_string_lossy().as_ref(), &temp.scope)
+            .expect("install path should be accepted");
+        assert_eq!(resolved, installed_app);
+    }
+
+    #[test]
+    fn rejects_empty_relative_missing_and_file_paths() {
+        let temp = TempScope::new("invalid");
+        let file = temp.root.join("home/notes.txt");
+        fs::write(&file, b"").expect("write file");
+
+        assert_eq!(
+            error_code(resolve_scan_path("  ", &temp.scope)),
+            AppManagerErrorCode::ScanPathInvalid.as_str()
+        );
+        assert_eq!(
+            error_code(resolve_scan_path("relative/app", &temp.scope)),
+            AppManagerErrorCode::ScanPathInvalid.as_str()
+        );
+        assert_eq!(
+            error_code(resolve_scan_path(
+                temp.root.join("home/missing").to_string_lossy().as_ref(),
+                &temp.scope
+            )),
+            AppManagerErrorCode::ScanPathNotFound.as_str()
+        );
+        assert_eq!(
+            error_code(resolve_scan_path(
+                file.to_string_lossy().as_ref(),
+                &temp.scope
+            )),
+            AppManagerErrorCode::ScanPathInvalid.as_str()
+        );
+    }
+
+    #[test]
+    fn rejects_critical_and_container_paths() {
+        let temp = TempScope::new("forbidden");
+        let system_child = temp.mkdir("system/core");
+
+        for path in [
+            system_child,
+            temp.root.join("home"),
+            temp.root.join("home/Library"),
+            temp.root.join("apps"),
+        ] {
+            assert_eq!(
+                error_code(resolve_scan_path(
+                    path.to_string_lossy().as_ref(),
+                    &temp.scope
+                )),
+                AppManagerErrorCode::ScanPathForbidden.as_str()
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_paths_outside_user_scope() {
+        let temp = TempScope::new("scope");
+        let outside = temp.mkdir("elsewhere/App");
+
+        assert_eq!(
+            error_code(resolve_scan_path(
+                outside.to_string_lossy().as_ref(),
+                &temp.scope
+            )),
+            AppManagerErrorCode::ScanPathOutOfScope.as_str()
+        );
+    }
+
+    #[test]
+    fn scan_path_item_uses_directory_name_as_alias() {
+        let item = build_scan_path_item(Path::new("/opt/Foo Editor"));
+
+        assert_eq!(item.name, "Foo Editor");
+        assert!(item.id.starts_with("path."));
+        assert!(
+            item.identity
+                .aliases
+                .iter()
+                .any(|alias| alias == "Foo Editor")
+        );
+        assert!(item.capabilities.residue_scan);
+        assert!(!item.uninstall_supported);
+    }
+}
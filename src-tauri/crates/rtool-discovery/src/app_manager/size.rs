@@ -2,6 +2,9 @@ use super::*;
 use std::collections::VecDeque;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+use std::time::{Duration, Instant};
 
 const APP_SIZE_CACHE_TTL: Duration = Duration::from_secs(15 * 60);
 
@@ -16,6 +19,7 @@ pub(super) struct PathSizeWarning {
 pub(super) struct PathSizeComputation {
     pub(super) size_bytes: u64,
     pub(super) warnings: Vec<PathSizeWarning>,
+    pub(super) cancelled: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -75,6 +79,7 @@ impl AppIndexCache {
             building: false,
             index_state: AppManagerIndexState::Ready,
             last_error: None,
+            build_duration_ms: None,
             disk_bootstrapped: false,
         }
     }
@@ -90,7 +95,7 @@ impl AppIndexCache {
 pub(super) fn app_index_runtime() -> &'static AppIndexRuntime {
     static RUNTIME: OnceLock<AppIndexRuntime> = OnceLock::new();
     RUNTIME.get_or_init(|| AppIndexRuntime {
-        cache: Mutex::new(AppIndexCache::new()),
+        cache: Mutex::new(Arc::new(AppIndexCache::new())),
         condvar: Condvar::new(),
     })
 }
@@ -100,6 +105,20 @@ pub(super) fn residue_scan_cache() -> &'static Mutex<HashMap<String, ResidueScan
     CACHE.get_or_init(|| Mutex::new(HashMap::new()))
 }
 
+/// Items built by path scans; they are not part of the app index.
+pub(super) fn scan_path_items() -> &'static Mutex<HashMap<String, ManagedAppDto>> {
+    static ITEMS: OnceLock<Mutex<HashMap<String, ManagedAppDto>>> = OnceLock::new();
+    ITEMS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Residue annotations keyed by app id, then residue item id.
+pub(super) fn residue_annotation_store()
+-> &'static Mutex<HashMap<String, HashMap<String, ResidueAnnotation>>> {
+    static STORE: OnceLock<Mutex<HashMap<String, HashMap<String, ResidueAnnotation>>>> =
+        OnceLock::new();
+    STORE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
 fn app_size_cache() -> &'static Mutex<HashMap<String, AppSizeCacheEntry>> {
     static CACHE: OnceLock<Mutex<HashMap<String, AppSizeCacheEntry>>> = OnceLock::new();
     CACHE.get_or_init(|| Mutex::new(HashMap::new()))
@@ -252,8 +271,36 @@ pub(super) fn startup_label(app_id: &str) -> String {
     format!("{STARTUP_LABEL_PREFIX}.{short}")
 }
 
-pub(super) fn fingerprint_for_app(item: &ManagedAppDto) -> String {
-    let content = format!(
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum AppFingerprintAlgorithm {
+    DefaultHash,
+    Blake3,
+}
+
+impl AppFingerprintAlgorithm {
+    pub(super) fn as_str(self) -> &'static str {
+        match self {
+            Self::DefaultHash => "default_hash",
+            Self::Blake3 => "blake3",
+        }
+    }
+
+    /// v1 fingerprints are `DefaultHasher` hex (at most 16 chars); v2 is a full blake3 hex digest.
+    pub(super) fn detect(fingerprint: &str) -> Self {
+        if fingerprint.len() == BLAKE3_FINGERPRINT_HEX_LEN {
+            Self::Blake3
+        } else {
+            Self::DefaultHash
+        }
+    }
+}
+
+pub(super) const APP_FINGERPRINT_ALGORITHM: AppFingerprintAlgorithm =
+    AppFingerprintAlgorithm::Blake3;
+const BLAKE3_FINGERPRINT_HEX_LEN: usize = 64;
+
+fn fingerprint_content(item: &ManagedAppDto) -> String {
+    format!(
         "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}",
         item.id,
         item.name,
@@ -265,8 +312,43 @@ pub(super) fn fingerprint_for_app(item: &ManagedAppDto) -> String {
         item.size_accuracy.as_str(),
         item.size_source.as_str(),
         item.size_computed_at.unwrap_or(0)
-    );
-    stable_hash(content.as_str())
+    )
+}
+
+pub(super) fn fingerprint_for_app_v1(item: &ManagedAppDto) -> String {
+    stable_hash(fingerprint_content(item).as_str())
+}
+
+pub(super) fn fingerprint_for_app_v2(item: &ManagedAppDto) -> String {
+    blake3::hash(fingerprint_content(item).as_bytes())
+        .to_hex()
+        .to_string()
+}
+
+pub(super) fn fingerprint_for_app(item: &ManagedAppDto) -> String {
+    match APP_FINGERPRINT_ALGORITHM {
+        AppFingerprintAlgorithm::DefaultHash => fingerprint_for_app_v1(item),
+        AppFingerprintAlgorithm::Blake3 => fingerprint_for_app_v2(item),
+    }
+}
+
+pub(super) fn fingerprint_matches(item: &ManagedAppDto, confirmed_fingerprint: &str) -> bool {
+    let algorithm = AppFingerprintAlgorithm::detect(confirmed_fingerprint);
+    let expected = match algorithm {
+        AppFingerprintAlgorithm::DefaultHash => {
+            static LEGACY_WARNED: AtomicBool = AtomicBool::new(false);
+            if !LEGACY_WARNED.swap(true, AtomicOrdering::Relaxed) {
+                tracing::warn!(
+                    event = "app_manager_legacy_fingerprint_in_use",
+                    algorithm = algorithm.as_str(),
+                    preferred = APP_FINGERPRINT_ALGORITHM.as_str()
+                );
+            }
+            fingerprint_for_app_v1(item)
+        }
+        AppFingerprintAlgorithm::Blake3 => fingerprint_for_app_v2(item),
+    };
+    expected == confirmed_fingerprint
 }
 
 pub(super) fn make_action_result(
@@ -280,6 +362,7 @@ pub(super) fn make_action_result(
         code,
         message: message.into(),
         detail,
+        uninstall_help_target: None,
     }
 }
 
@@ -385,6 +468,7 @@ pub(super) fn walk_path_size_bytes(
     max_depth: Option<usize>,
     max_dirs: Option<usize>,
     collect_warnings: bool,
+    cancel: Option<&AtomicBool>,
 ) -> Option<PathSizeComputation> {
     if !path.exists() {
         return None;
@@ -396,6 +480,7 @@ pub(super) fn walk_path_size_bytes(
             Ok(meta) => Some(PathSizeComputation {
                 size_bytes: meta.len(),
                 warnings,
+                cancelled: false,
             }),
             Err(error) => {
                 if collect_warnings {
@@ -408,6 +493,7 @@ pub(super) fn walk_path_size_bytes(
                     return Some(PathSizeComputation {
                         size_bytes: 0,
                         warnings,
+                        cancelled: false,
                     });
                 }
                 None
@@ -419,7 +505,9 @@ pub(super) fn walk_path_size_bytes(
     let mut queue = VecDeque::new();
     queue.push_back((path.to_path_buf(), 0usize));
     let mut visited_dirs = 0usize;
-    while let Some((dir, depth)) = queue.pop_front() {
+    let mut cancelled = false;
+    let is_cancelled = || cancel.is_some_and(|flag| flag.load(AtomicOrdering::Relaxed));
+    'walk: while let Some((dir, depth)) = queue.pop_front() {
         if max_dirs.is_some_and(|limit| visited_dirs >= limit) {
             if collect_warnings {
                 append_path_size_warning(
@@ -449,6 +537,10 @@ pub(super) fn walk_path_size_bytes(
         };
 
         for entry_result in entries {
+            if is_cancelled() {
+                cancelled = true;
+                break 'walk;
+            }
             let entry = match entry_result {
                 Ok(entry) => entry,
                 Err(error) => {
@@ -514,6 +606,7 @@ pub(super) fn walk_path_size_bytes(
     Some(PathSizeComputation {
         size_bytes: total,
         warnings,
+        cancelled,
     })
 }
 
@@ -524,14 +617,266 @@ pub(super) fn try_get_path_size_bytes(path: &Path) -> Option<u64> {
         Some(SIZE_ESTIMATE_MAX_DEPTH),
         Some(SIZE_ESTIMATE_MAX_DIRS),
         false,
+        None,
     )
     .map(|value| value.size_bytes)
 }
 
+/// Measures `path` with the walk budget of `mode`; `Exact` is unbounded and can be slow.
+pub(super) fn measure_path_size_with_mode(
+    path: &Path,
+    mode: AppManagerSizeAccuracyMode,
+) -> Option<(u64, AppManagerSizeAccuracy)> {
+    match mode {
+        AppManagerSizeAccuracyMode::Quick => try_get_path_size_bytes(path)
+            .map(|size_bytes| (size_bytes, AppManagerSizeAccuracy::Estimated)),
+        AppManagerSizeAccuracyMode::Standard => walk_path_size_bytes(
+            path,
+            Some(SIZE_STANDARD_MAX_DEPTH),
+            Some(SIZE_STANDARD_MAX_DIRS),
+            false,
+            None,
+        )
+        .map(|value| (value.size_bytes, AppManagerSizeAccuracy::Estimated)),
+        AppManagerSizeAccuracyMode::Exact => exact_path_size_bytes(path)
+            .map(|size_bytes| (size_bytes, AppManagerSizeAccuracy::Exact)),
+    }
+}
+
+/// Re-measures the app with `mode`, leaving the cached size untouched when the path is unreadable.
+/// Returns whether a new size was measured.
+pub(super) fn apply_size_accuracy_mode(
+    item: &mut ManagedAppDto,
+    mode: AppManagerSizeAccuracyMode,
+) -> bool {
+    let size_resolution = resolve_managed_app_size_path(item);
+    let Some((size_bytes, size_accuracy)) =
+        measure_path_size_with_mode(size_resolution.path.as_path(), mode)
+    else {
+        return false;
+    };
+    item.size_bytes = Some(size_bytes);
+    item.size_accuracy = size_accuracy;
+    item.size_source = size_resolution.size_source;
+    item.size_computed_at = Some(now_unix_seconds());
+    item.size_accuracy_used = Some(mode);
+    true
+}
+
 pub(super) fn exact_path_size_bytes(path: &Path) -> Option<u64> {
-    walk_path_size_bytes(path, None, None, false).map(|value| value.size_bytes)
+    walk_path_size_bytes(path, None, None, false, None).map(|value| value.size_bytes)
 }
 
 pub(super) fn exact_path_size_bytes_with_warnings(path: &Path) -> Option<PathSizeComputation> {
-    walk_path_size_bytes(path, None, None, true)
+    walk_path_size_bytes(path, None, None, true, None)
+}
+
+/// Full walk that stops between directory entries once `cancel` is set, returning the partial total.
+pub(super) fn exact_path_size_bytes_cancellable(
+    path: &Path,
+    cancel: &AtomicBool,
+) -> Option<PathSizeComputation> {
+    walk_path_size_bytes(path, None, None, false, Some(cancel))
+}
+
+/// Cancels that arrive before their resolution registers are kept this long.
+const SIZE_CANCEL_PENDING_TTL: Duration = Duration::from_secs(30);
+
+#[derive(Debug)]
+struct SizeCancelEntry {
+    flag: Arc<AtomicBool>,
+    registrations: usize,
+    pending_since: Option<Instant>,
+}
+
+fn size_cancel_registry() -> &'static Mutex<HashMap<String, SizeCancelEntry>> {
+    static REGISTRY: OnceLock<Mutex<HashMap<String, SizeCancelEntry>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn prune_pending_size_cancels(registry: &mut HashMap<String, SizeCancelEntry>, now: Instant) {
+    registry.retain(|_, entry| {
+        entry.registrations > 0
+            || entry
+                .pending_since
+                .is_some_and(|since| now.duration_since(since) < SIZE_CANCEL_PENDING_TTL)
+    });
+}
+
+pub(super) fn register_size_cancel_token(token: &str) -> Arc<AtomicBool> {
+    register_size_cancel_token_at(token, Instant::now())
+}
+
+fn register_size_cancel_token_at(token: &str, now: Instant) -> Arc<AtomicBool> {
+    let mut registry = size_cancel_registry()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    prune_pending_size_cancels(&mut registry, now);
+    let entry = registry
+        .entry(token.to_string())
+        .or_insert_with(|| SizeCancelEntry {
+            flag: Arc::new(AtomicBool::new(false)),
+            registrations: 0,
+            pending_since: None,
+        });
+    entry.registrations += 1;
+    entry.pending_since = None;
+    entry.flag.clone()
+}
+
+pub(super) fn release_size_cancel_token(token: &str) {
+    let mut registry = size_cancel_registry()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let Some(entry) = registry.get_mut(token) else {
+        return;
+    };
+    entry.registrations = entry.registrations.saturating_sub(1);
+    if entry.registrations == 0 {
+        registry.remove(token);
+    }
+}
+
+/// Returns whether a running resolution was flagged; unknown tokens are remembered briefly
+/// so a cancel racing ahead of its resolution still applies once that resolution registers.
+pub(super) fn cancel_size_token(token: &str) -> bool {
+    cancel_size_token_at(token, Instant::now())
+}
+
+fn cancel_size_token_at(token: &str, now: Instant) -> bool {
+    let mut registry = size_cancel_registry()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    prune_pending_size_cancels(&mut registry, now);
+    match registry.get(token) {
+        Some(entry) => {
+            entry.flag.store(true, AtomicOrdering::Relaxed);
+            entry.registrations > 0
+        }
+        None => {
+            registry.insert(
+                token.to_string(),
+                SizeCancelEntry {
+                    flag: Arc::new(AtomicBool::new(true)),
+                    registrations: 0,
+                    pending_since: Some(now),
+                },
+            );
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cancelled_walk_returns_partial_result() {
+        let root = std::env::temp_dir().join(format!("rtool-size-cancel-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(root.join("nested")).expect("create temp dir");
+        fs::write(root.join("a.bin"), [0u8; 16]).expect("write file");
+        fs::write(root.join("nested").join("b.bin"), [0u8; 32]).expect("write file");
+
+        let cancel = AtomicBool::new(false);
+        let full = exact_path_size_bytes_cancellable(root.as_path(), &cancel).expect("full walk");
+        assert!(!full.cancelled);
+        assert_eq!(full.size_bytes, 48);
+
+        cancel.store(true, AtomicOrdering::Relaxed);
+        let partial =
+            exact_path_size_bytes_cancellable(root.as_path(), &cancel).expect("cancelled walk");
+        assert!(partial.cancelled);
+        assert_eq!(partial.size_bytes, 0);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn size_accuracy_modes_widen_the_walk_budget() {
+        let root = std::env::temp_dir().join(format!("rtool-size-mode-{}", uuid::Uuid::new_v4()));
+        let mut dir = root.clone();
+        fs::create_dir_all(&dir).expect("create temp dir");
+        for level in 0..8 {
+            fs::write(dir.join("f.bin"), vec![0u8; 1 << level]).expect("write file");
+            dir = dir.join(format!("d{level}"));
+            fs::create_dir_all(&dir).expect("create nested dir");
+        }
+
+        let measure = |mode| measure_path_size_with_mode(root.as_path(), mode).expect("measure");
+        assert_eq!(
+            measure(AppManagerSizeAccuracyMode::Quick),
+            (0b1111, AppManagerSizeAccuracy::Estimated)
+        );
+        assert_eq!(
+            measure(AppManagerSizeAccuracyMode::Standard),
+            (0b111_1111, AppManagerSizeAccuracy::Estimated)
+        );
+        assert_eq!(
+            measure(AppManagerSizeAccuracyMode::Exact),
+            (0b1111_1111, AppManagerSizeAccuracy::Exact)
+        );
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn cancel_size_token_only_flags_registered_tokens() {
+        let flag = register_size_cancel_token("size-test-token");
+        assert!(!cancel_size_token("size-test-missing"));
+        assert!(cancel_size_token("size-test-token"));
+        assert!(flag.load(AtomicOrdering::Relaxed));
+        release_size_cancel_token("size-test-token");
+        assert!(!cancel_size_token("size-test-token"));
+    }
+
+    #[test]
+    fn cancel_before_register_applies_until_the_pending_ttl_expires() {
+        let now = Instant::now();
+        assert!(!cancel_size_token_at("size-test-early", now));
+        let flag = register_size_cancel_token_at("size-test-early", now);
+        assert!(flag.load(AtomicOrdering::Relaxed));
+        release_size_cancel_token("size-test-early");
+
+        assert!(!cancel_size_token_at("size-test-stale", now));
+        let flag = register_size_cancel_token_at("size-test-stale", now + SIZE_CANCEL_PENDING_TTL);
+        assert!(!flag.load(AtomicOrdering::Relaxed));
+        release_size_cancel_token("size-test-stale");
+    }
+
+    #[test]
+    fn size_cancel_token_stays_registered_until_every_registration_releases() {
+        let first = register_size_cancel_token("size-test-shared");
+        let second = register_size_cancel_token("size-test-shared");
+        release_size_cancel_token("size-test-shared");
+
+        assert!(cancel_size_token("size-test-shared"));
+        assert!(first.load(AtomicOrdering::Relaxed));
+        assert!(second.load(AtomicOrdering::Relaxed));
+
+        release_size_cancel_token("size-test-shared");
+        assert!(!cancel_size_token("size-test-shared"));
+        release_size_cancel_token("size-test-shared");
+    }
+
+    #[test]
+    fn fingerprint_matches_accepts_both_v1_and_v2_hashes() {
+        let item = build_scan_path_item(Path::new("/opt/rtool-fingerprint/Demo.app"));
+        assert_eq!(item.fingerprint.len(), BLAKE3_FINGERPRINT_HEX_LEN);
+        assert_eq!(item.fingerprint, fingerprint_for_app_v2(&item));
+
+        assert!(fingerprint_matches(&item, item.fingerprint.as_str()));
+        assert!(fingerprint_matches(
+            &item,
+            fingerprint_for_app_v1(&item).as_str()
+        ));
+
+        let mut changed = item.clone();
+        changed.version = Some("2.0.0".to_string());
+        assert!(!fingerprint_matches(&changed, item.fingerprint.as_str()));
+        assert!(!fingerprint_matches(
+            &changed,
+            fingerprint_for_app_v1(&item).as_str()
+        ));
+    }
 }
@@ -87,6 +87,67 @@ pub(super) fn platform_detect_startup_state(
     }
 }
 
+pub(super) fn platform_locate_startup_entry(
+    app_id: &str,
+    app_path: &Path,
+) -> Option<(AppManagerStartupScope, AppManagerStartupEntryKind, String)> {
+    #[cfg(target_os = "macos")]
+    {
+        let system_roots = [
+            Path::new("/Library/LaunchAgents"),
+            Path::new("/Library/LaunchDaemons"),
+        ];
+        if let Some(path) = system_roots
+            .iter()
+            .find_map(|root| mac_find_plist_referencing(root, app_path))
+        {
+            return Some((
+                AppManagerStartupScope::System,
+                AppManagerStartupEntryKind::File,
+                path.to_string_lossy().to_string(),
+            ));
+        }
+        let user_path = mac_startup_file_path(app_id)
+            .filter(|path| path.exists())
+            .or_else(|| {
+                home_dir()
+                    .map(|home| home.join("Library").join("LaunchAgents"))
+                    .and_then(|root| mac_find_plist_referencing(root.as_path(), app_path))
+            })?;
+        Some((
+            AppManagerStartupScope::User,
+            AppManagerStartupEntryKind::File,
+            user_path.to_string_lossy().to_string(),
+        ))
+    }
+    #[cfg(target_os = "windows")]
+    {
+        let system_key = r"HKLM\Software\Microsoft\Windows\CurrentVersion\Run";
+        let user_key = r"HKCU\Software\Microsoft\Windows\CurrentVersion\Run";
+        if windows_run_registry_contains(system_key, app_path) {
+            return Some((
+                AppManagerStartupScope::System,
+                AppManagerStartupEntryKind::RegistryKey,
+                system_key.to_string(),
+            ));
+        }
+        if windows_startup_enabled(app_id) || windows_run_registry_contains(user_key, app_path) {
+            return Some((
+                AppManagerStartupScope::User,
+                AppManagerStartupEntryKind::RegistryKey,
+                user_key.to_string(),
+            ));
+        }
+        None
+    }
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    {
+        let _ = app_id;
+        let _ = app_path;
+        None
+    }
+}
+
 pub(super) fn platform_set_startup(app_id: &str, app_path: &Path, enabled: bool) -> AppResult<()> {
     #[cfg(target_os = "macos")]
     {
@@ -178,6 +239,28 @@ pub(super) fn mac_collect_plist_blobs(root: &Path) -> Vec<String> {
     blobs
 }
 
+#[cfg(target_os = "macos")]
+fn mac_find_plist_referencing(root: &Path, app_path: &Path) -> Option<PathBuf> {
+    let target = app_path.to_string_lossy().to_ascii_lowercase();
+    let escaped_target = xml_escape(app_path.to_string_lossy().as_ref()).to_ascii_lowercase();
+    let entries = fs::read_dir(root).ok()?;
+    entries
+        .flatten()
+        .take(500)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .and_then(|value| value.to_str())
+                .is_some_and(|value| value.eq_ignore_ascii_case("plist"))
+        })
+        .find(|path| {
+            mac_read_plist_text(path.as_path()).is_some_and(|content| {
+                let content = content.to_ascii_lowercase();
+                content.contains(target.as_str()) || content.contains(escaped_target.as_str())
+            })
+        })
+}
+
 #[cfg(target_os = "macos")]
 pub(super) fn mac_read_plist_text(path: &Path) -> Option<String> {
     if let Ok(content) = fs::read_to_string(path) {
@@ -293,7 +376,7 @@ pub(super) fn mac_set_startup(app_id: &str, app_path: &Path, enabled: bool) -> A
 #[cfg(target_os = "windows")]
 pub(super) fn windows_startup_value_name(app_id: &str) -> String {
     format!(
-        "RToolStartup_{}",
+        "{WINDOWS_STARTUP_VALUE_PREFIX}{}",
         stable_hash(app_id).chars().take(10).collect::<String>()
     )
 }
@@ -457,3 +540,267 @@ fn windows_get_startup_snapshot() -> WindowsStartupSnapshot {
         .unwrap_or_else(|poisoned| poisoned.into_inner());
     cache.clone()
 }
+
+#[cfg(any(target_os = "windows", test))]
+pub(super) fn startup_command_target(command: &str) -> Option<String> {
+    let trimmed = command.trim();
+    if let Some(rest) = trimmed.strip_prefix('"') {
+        let target = rest[..rest.find('"')?].trim();
+        return (!target.is_empty()).then(|| target.to_string());
+    }
+    if let Some(index) = trimmed.to_ascii_lowercase().find(".exe") {
+        return Some(trimmed[..index + ".exe".len()].to_string());
+    }
+    trimmed.split_whitespace().next().map(ToString::to_string)
+}
+
+#[cfg(any(target_os = "macos", test))]
+fn xml_unescape(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(any(target_os = "macos", test))]
+pub(super) fn plist_program_target(content: &str) -> Option<String> {
+    let Some(arguments) = content.split("<key>ProgramArguments</key>").nth(1) else {
+        let program = content.split("<key>Program</key>").nth(1)?;
+        let value = program
+            .split("<string>")
+            .nth(1)?
+            .split("</string>")
+            .next()?;
+        return Some(xml_unescape(value.trim()));
+    };
+    let array = arguments.split("</array>").next()?;
+    let values = array
+        .split("<string>")
+        .skip(1)
+        .filter_map(|part| part.split("</string>").next())
+        .map(|value| xml_unescape(value.trim()))
+        .collect::<Vec<_>>();
+    // Entries written by rtool for bundles launch through `/usr/bin/open -a <app>`.
+    match values.as_slice() {
+        [open, flag, target, ..] if open == "/usr/bin/open" && flag == "-a" => Some(target.clone()),
+        [program, ..] => Some(program.clone()),
+        [] => None,
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn mac_ghost_startup_entries() -> Vec<(AppManagerGhostStartupItemDto, PathBuf)> {
+    let mut roots = Vec::new();
+    if let Some(home) = home_dir() {
+        roots.push((
+            home.join("Library").join("LaunchAgents"),
+            AppManagerStartupScope::User,
+        ));
+    }
+    roots.push((
+        PathBuf::from("/Library/LaunchAgents"),
+        AppManagerStartupScope::System,
+    ));
+
+    let mut items = Vec::new();
+    for (root, scope) in roots {
+        let Ok(entries) = fs::read_dir(&root) else {
+            continue;
+        };
+        for plist_path in entries.flatten().take(500).map(|entry| entry.path()) {
+            if !plist_path
+                .extension()
+                .and_then(|value| value.to_str())
+                .is_some_and(|value| value.eq_ignore_ascii_case("plist"))
+            {
+                continue;
+            }
+            let Some(content) = mac_read_plist_text(plist_path.as_path()) else {
+                continue;
+            };
+            if !content.contains(STARTUP_LABEL_PREFIX) {
+                continue;
+            }
+            let Some(target) = plist_program_target(content.as_str()) else {
+                continue;
+            };
+            if target.is_empty() || Path::new(target.as_str()).exists() {
+                continue;
+            }
+            let label = plist_path
+                .file_stem()
+                .map(|value| value.to_string_lossy().to_string())
+                .unwrap_or_default();
+            items.push((
+                AppManagerGhostStartupItemDto {
+                    label,
+                    path: target,
+                    scope,
+                    reason: AppManagerGhostStartupReason::PathNotFound,
+                },
+                plist_path,
+            ));
+        }
+    }
+    items
+}
+
+#[cfg(target_os = "windows")]
+fn windows_ghost_startup_entries() -> Vec<(AppManagerGhostStartupItemDto, &'static str)> {
+    let prefix = WINDOWS_STARTUP_VALUE_PREFIX.to_ascii_lowercase();
+    let roots = [
+        (
+            r"HKCU\Software\Microsoft\Windows\CurrentVersion\Run",
+            AppManagerStartupScope::User,
+        ),
+        (
+            r"HKLM\Software\Microsoft\Windows\CurrentVersion\Run",
+            AppManagerStartupScope::System,
+        ),
+    ];
+
+    let mut items = Vec::new();
+    for (root, scope) in roots {
+        for (name, value) in windows_query_registry_values(root) {
+            if !name.to_ascii_lowercase().starts_with(prefix.as_str()) {
+                continue;
+            }
+            let Some(target) = startup_command_target(value.as_str()) else {
+                continue;
+            };
+            if Path::new(target.as_str()).exists() {
+                continue;
+            }
+            items.push((
+                AppManagerGhostStartupItemDto {
+                    label: name,
+                    path: target,
+                    scope,
+                    reason: AppManagerGhostStartupReason::PathNotFound,
+                },
+                root,
+            ));
+        }
+    }
+    items
+}
+
+pub(super) fn platform_scan_ghost_startup_items() -> Vec<AppManagerGhostStartupItemDto> {
+    #[cfg(target_os = "macos")]
+    {
+        mac_ghost_startup_entries()
+            .into_iter()
+            .map(|(item, _)| item)
+            .collect()
+    }
+    #[cfg(target_os = "windows")]
+    {
+        windows_ghost_startup_entries()
+            .into_iter()
+            .map(|(item, _)| item)
+            .collect()
+    }
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    {
+        Vec::new()
+    }
+}
+
+pub(super) fn ghost_startup_not_found(label: &str) -> AppError {
+    app_error(
+        AppManagerErrorCode::GhostStartupNotFound,
+        "未找到该失效启动项",
+    )
+    .with_context("label", label.to_string())
+}
+
+pub(super) fn platform_remove_ghost_startup_item(label: &str) -> AppResult<()> {
+    #[cfg(target_os = "macos")]
+    {
+        let (_, plist_path) = mac_ghost_startup_entries()
+            .into_iter()
+            .find(|(item, _)| item.label == label)
+            .ok_or_else(|| ghost_startup_not_found(label))?;
+        fs::remove_file(plist_path.as_path())
+            .with_context(|| format!("删除失效启动项失败: {}", plist_path.display()))
+            .with_code(
+                AppManagerErrorCode::StartupDeleteFailed.as_str(),
+                "删除启动项失败",
+            )
+            .with_ctx("startupFile", plist_path.display().to_string())?;
+        mac_startup_cache()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .refreshed_at = None;
+        Ok(())
+    }
+    #[cfg(target_os = "windows")]
+    {
+        let (_, root) = windows_ghost_startup_entries()
+            .into_iter()
+            .find(|(item, _)| item.label.eq_ignore_ascii_case(label))
+            .ok_or_else(|| ghost_startup_not_found(label))?;
+        let status = Command::new("reg")
+            .args(["delete", root, "/v", label, "/f"])
+            .status()
+            .with_context(|| format!("删除注册表失效启动项失败: {}", label))
+            .with_code(
+                AppManagerErrorCode::StartupDeleteFailed.as_str(),
+                "删除启动项失败",
+            )
+            .with_ctx("valueName", label.to_string())?;
+        if !status.success() {
+            return Err(
+                app_error(AppManagerErrorCode::StartupDeleteFailed, "删除启动项失败")
+                    .with_context("status", status.to_string())
+                    .with_context("valueName", label.to_string()),
+            );
+        }
+        windows_invalidate_startup_snapshot();
+        Ok(())
+    }
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    {
+        Err(ghost_startup_not_found(label))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn startup_command_target_handles_quoted_and_bare_commands() {
+        assert_eq!(
+            startup_command_target(r#""C:\Program Files\Foo\foo.exe" --minimized"#).as_deref(),
+            Some(r"C:\Program Files\Foo\foo.exe")
+        );
+        assert_eq!(
+            startup_command_target(r"C:\Tools\bar.EXE /silent").as_deref(),
+            Some(r"C:\Tools\bar.EXE")
+        );
+        assert_eq!(startup_command_target("   "), None);
+    }
+
+    #[test]
+    fn plist_program_target_unwraps_open_launcher() {
+        let bundle = "<key>ProgramArguments</key>\n  <array><string>/usr/bin/open</string><string>-a</string><string>/Applications/A &amp; B.app</string></array>";
+        let binary = "<key>ProgramArguments</key><array><string>/opt/tool/bin/tool</string><string>--daemon</string></array>";
+        let program = "<key>Program</key><string>/usr/local/bin/agent</string>";
+
+        assert_eq!(
+            plist_program_target(bundle).as_deref(),
+            Some("/Applications/A & B.app")
+        );
+        assert_eq!(
+            plist_program_target(binary).as_deref(),
+            Some("/opt/tool/bin/tool")
+        );
+        assert_eq!(
+            plist_program_target(program).as_deref(),
+            Some("/usr/local/bin/agent")
+        );
+    }
+}
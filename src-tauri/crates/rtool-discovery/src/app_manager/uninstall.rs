@@ -1,4 +1,6 @@
 use super::*;
+#[cfg(target_os = "windows")]
+use rtool_contracts::models::AppManagerPackageManager;
 
 pub(super) fn platform_uninstall(item: &ManagedAppDto) -> AppResult<()> {
     #[cfg(target_os = "macos")]
@@ -19,7 +21,9 @@ pub(super) fn platform_uninstall(item: &ManagedAppDto) -> AppResult<()> {
     }
 }
 
-pub(super) fn platform_open_uninstall_help(item: &ManagedAppDto) -> AppResult<()> {
+pub(super) fn platform_open_uninstall_help(
+    item: &ManagedAppDto,
+) -> AppResult<AppManagerUninstallHelpTarget> {
     #[cfg(target_os = "macos")]
     {
         if item.path.trim().is_empty() {
@@ -32,16 +36,20 @@ pub(super) fn platform_open_uninstall_help(item: &ManagedAppDto) -> AppResult<()
             "open",
             &["-R", item.path.as_str()],
             AppManagerErrorCode::OpenHelpFailed,
-        )
+        )?;
+        Ok(AppManagerUninstallHelpTarget::FinderReveal)
     }
     #[cfg(target_os = "windows")]
     {
-        let _ = item;
+        if windows_launch_registered_uninstaller(item) {
+            return Ok(AppManagerUninstallHelpTarget::RegisteredUninstaller);
+        }
         open_with_command(
             "cmd",
             &["/C", "start", "", "ms-settings:appsfeatures"],
             AppManagerErrorCode::OpenHelpFailed,
-        )
+        )?;
+        Ok(AppManagerUninstallHelpTarget::AppsAndFeatures)
     }
     #[cfg(not(any(target_os = "macos", target_os = "windows")))]
     {
@@ -53,6 +61,41 @@ pub(super) fn platform_open_uninstall_help(item: &ManagedAppDto) -> AppResult<()
     }
 }
 
+/// Starts the interactive uninstaller registered for the app without waiting for it to exit.
+#[cfg(target_os = "windows")]
+fn windows_launch_registered_uninstaller(item: &ManagedAppDto) -> bool {
+    let entries = windows_list_uninstall_entries();
+    let Some(entry) = windows_find_best_uninstall_entry(
+        item.name.as_str(),
+        Path::new(item.path.as_str()),
+        entries.as_slice(),
+    ) else {
+        return false;
+    };
+    let Some(command) = entry
+        .uninstall_string
+        .as_deref()
+        .or(entry.quiet_uninstall_string.as_deref())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    else {
+        return false;
+    };
+
+    match Command::new("cmd").args(["/C", command]).spawn() {
+        Ok(_) => true,
+        Err(error) => {
+            tracing::warn!(
+                event = "app_manager_uninstall_help_launch_failed",
+                app_name = item.name.as_str(),
+                command = command,
+                error = error.to_string()
+            );
+            false
+        }
+    }
+}
+
 pub(super) fn platform_open_permission_help(item: &ManagedAppDto) -> AppResult<()> {
     #[cfg(target_os = "macos")]
     {
@@ -155,6 +198,20 @@ pub(super) fn mac_uninstall(item: &ManagedAppDto) -> AppResult<()> {
 
 #[cfg(target_os = "windows")]
 pub(super) fn windows_uninstall(item: &ManagedAppDto) -> AppResult<()> {
+    if item.package_manager == Some(AppManagerPackageManager::Winget)
+        && let Some(package_id) = item.package_id.as_deref()
+    {
+        if windows_winget_uninstall(package_id) {
+            return Ok(());
+        }
+
+        tracing::warn!(
+            event = "app_manager_winget_uninstall_failed",
+            app_name = item.name.as_str(),
+            package_id = package_id
+        );
+    }
+
     let entries = windows_list_uninstall_entries();
     let matched = windows_find_best_uninstall_entry(
         item.name.as_str(),
@@ -190,6 +247,14 @@ pub(super) fn windows_uninstall(item: &ManagedAppDto) -> AppResult<()> {
     )
 }
 
+#[cfg(target_os = "windows")]
+fn windows_winget_uninstall(package_id: &str) -> bool {
+    Command::new("winget")
+        .args(winget_uninstall_args(package_id))
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
 #[cfg(target_os = "windows")]
 pub(super) fn windows_execute_uninstall_command(command: &str) -> bool {
     let direct_status = Command::new("cmd").args(["/C", command]).status();
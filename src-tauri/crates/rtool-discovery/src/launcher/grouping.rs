@@ -107,6 +107,9 @@ fn normalized_item_corpus(item: &LauncherItemDto) -> String {
         | LauncherActionDto::OpenApplication { path } => {
             parts.push(path.to_ascii_lowercase());
         }
+        LauncherActionDto::CopyClipboardItem { item_id } => {
+            parts.push(item_id.to_ascii_lowercase());
+        }
     }
 
     parts.join(" ")
@@ -179,6 +182,9 @@ mod tests {
             icon_kind: "iconify".to_string(),
             icon_value: "i-noto:card-index-dividers".to_string(),
             action,
+            undoable: false,
+            title_match_ranges: Vec::new(),
+            matched_via_alias: false,
         }
     }
 
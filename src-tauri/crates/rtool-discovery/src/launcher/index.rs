@@ -9,7 +9,7 @@ use rtool_contracts::models::{
     LauncherRuntimeStatusDto, LauncherSearchSettingsDto, LauncherUpdateSearchSettingsInputDto,
 };
 use rtool_contracts::{AppError, AppResult};
-use rtool_data::db::{DbConn, get_app_setting, set_app_setting};
+use rtool_data::db::{DbConn, db_write_permit, get_app_setting, set_app_setting};
 use rtool_data::db_error::DbResult;
 use rtool_kernel::i18n::t;
 use serde::{Deserialize, Serialize};
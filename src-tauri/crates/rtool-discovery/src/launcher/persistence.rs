@@ -19,6 +19,7 @@ pub(super) async fn read_meta(db_conn: &DbConn, key: &str) -> DbResult<Option<St
 }
 
 pub(super) async fn write_meta(db_conn: &DbConn, key: &str, value: &str) -> DbResult<()> {
+    let _write_permit = db_write_permit().await;
     db_conn
         .execute(
             "INSERT INTO launcher_index_meta (key, value) VALUES (?1, ?2)
@@ -36,6 +37,7 @@ pub(super) async fn upsert_entries_batched(
 ) -> DbResult<()> {
     const UPSERT_BATCH_SIZE: usize = 2_000;
     for chunk in entries.chunks(UPSERT_BATCH_SIZE) {
+        let _write_permit = db_write_permit().await;
         let transaction = db_conn.transaction().await?;
         for entry in chunk {
             transaction
@@ -89,6 +91,7 @@ pub(super) async fn delete_stale_entries_for_root(
     root: &str,
     scan_token: &str,
 ) -> DbResult<()> {
+    let _write_permit = db_write_permit().await;
     db_conn
         .execute(
             "DELETE FROM launcher_index_entries
@@ -101,6 +104,7 @@ pub(super) async fn delete_stale_entries_for_root(
 }
 
 pub(super) async fn purge_removed_roots(db_conn: &DbConn, roots: &[String]) -> DbResult<()> {
+    let _write_permit = db_write_permit().await;
     if roots.is_empty() {
         db_conn
             .execute("DELETE FROM launcher_index_entries", ())
@@ -89,6 +89,9 @@ fn map_index_row_to_item(
                 icon_kind: icon.kind,
                 icon_value: icon.value,
                 action: LauncherActionDto::OpenApplication { path },
+                undoable: false,
+                title_match_ranges: Vec::new(),
+                matched_via_alias: false,
             }
         }
         IndexedEntryKind::Directory => {
@@ -105,6 +108,9 @@ fn map_index_row_to_item(
                 icon_kind: icon.kind,
                 icon_value: icon.value,
                 action: LauncherActionDto::OpenDirectory { path },
+                undoable: false,
+                title_match_ranges: Vec::new(),
+                matched_via_alias: false,
             }
         }
         IndexedEntryKind::File => {
@@ -122,6 +128,9 @@ fn map_index_row_to_item(
                 icon_kind: icon.kind,
                 icon_value: icon.value,
                 action: LauncherActionDto::OpenFile { path },
+                undoable: false,
+                title_match_ranges: Vec::new(),
+                matched_via_alias: false,
             }
         }
     };
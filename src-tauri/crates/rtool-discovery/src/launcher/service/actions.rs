@@ -27,6 +27,11 @@ pub fn execute_launcher_action(
         LauncherActionDto::OpenDirectory { path }
         | LauncherActionDto::OpenFile { path }
         | LauncherActionDto::OpenApplication { path } => execute_open_path_action(app, path),
+        LauncherActionDto::CopyClipboardItem { item_id } => Err(AppError::new(
+            "launcher_action_unsupported",
+            "当前启动器动作需由剪贴板服务执行",
+        )
+        .with_context("itemId", item_id.clone())),
     }
 }
 
@@ -40,6 +45,11 @@ fn execute_builtin_tool_action(app: &dyn LauncherHost, tool_id: &str) -> AppResu
     execute_builtin_route_action(app, &route)
 }
 
+pub fn is_launcher_window_visible(app: &dyn LauncherHost, window_label: &str) -> bool {
+    app.get_webview_window(window_label)
+        .is_some_and(|window| window.is_visible())
+}
+
 fn execute_builtin_window_action(app: &dyn LauncherHost, window_label: &str) -> AppResult<String> {
     let screenshot_payload = prepare_window_open(app, window_label)?;
     open_window(app, window_label)?;
@@ -47,6 +57,18 @@ fn execute_builtin_window_action(app: &dyn LauncherHost, window_label: &str) ->
     Ok(format!("window:{window_label}"))
 }
 
+pub fn hide_launcher_window(app: &dyn LauncherHost, window_label: &str) -> AppResult<String> {
+    let window = app.get_webview_window(window_label).ok_or_else(|| {
+        AppError::new("launcher_window_not_found", "目标窗口不存在")
+            .with_context("label", window_label)
+    })?;
+    window
+        .hide()
+        .with_context(|| format!("隐藏窗口失败: {window_label}"))
+        .with_code("launcher_window_hide_failed", "关闭窗口失败")?;
+    Ok(format!("close:{window_label}"))
+}
+
 fn execute_open_path_action(app: &dyn LauncherHost, path: &str) -> AppResult<String> {
     open_path(app, Path::new(path))?;
     Ok(format!("path:{path}"))
@@ -173,6 +195,14 @@ mod tests {
             Ok(())
         }
 
+        fn hide(&self) -> AppResult<()> {
+            Ok(())
+        }
+
+        fn is_visible(&self) -> bool {
+            false
+        }
+
         fn set_focus(&self) -> AppResult<()> {
             Ok(())
         }
@@ -237,6 +267,7 @@ mod tests {
         ) -> AppResult<ClipboardWindowModeAppliedDto> {
             Ok(ClipboardWindowModeAppliedDto {
                 compact: false,
+                content_filter: Default::default(),
                 applied_width_logical: 0.0,
                 applied_height_logical: 0.0,
                 scale_factor: 1.0,
@@ -1,5 +1,12 @@
 mod actions;
+mod ranking;
 mod search;
 
-pub use actions::execute_launcher_action;
-pub use search::{LauncherSearchDiagnostics, LauncherSearchResult, search_launcher_async};
+pub use actions::{execute_launcher_action, hide_launcher_window, is_launcher_window_visible};
+pub use ranking::{
+    LauncherHistoryEntry, LauncherRankedItem, record_launcher_launch_async, score_launcher_result,
+};
+pub use search::{
+    LauncherSearchDiagnostics, LauncherSearchResult, launcher_title_match_ranges,
+    search_launcher_async,
+};
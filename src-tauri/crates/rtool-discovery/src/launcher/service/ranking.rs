@@ -0,0 +1,250 @@
+use rtool_contracts::models::LauncherItemDto;
+use rtool_data::db::{DbConn, db_write_permit};
+use rtool_data::db_error::DbResult;
+use std::collections::{HashMap, HashSet};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const MILLIS_PER_DAY: f64 = 86_400_000.0;
+const RECENCY_DECAY_PER_DAY: f64 = 0.01;
+/// `item.score` points worth one unit of history boost. An exact title match (140) leads a
+/// substring match (95) by 4.5 units, which a handful of launches cannot make up.
+const MATCH_POINTS_PER_UNIT: f64 = 10.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LauncherHistoryEntry {
+    pub launch_count: u32,
+    pub last_launched_at: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct LauncherRankedItem {
+    pub item: LauncherItemDto,
+    tokens: Vec<String>,
+}
+
+impl LauncherRankedItem {
+    pub fn new(item: LauncherItemDto) -> Self {
+        let tokens = format!("{} {}", item.title, item.subtitle)
+            .to_lowercase()
+            .split(|ch: char| ch.is_whitespace() || matches!(ch, '/' | '\\' | '-' | '_' | '.'))
+            .filter(|token| !token.is_empty())
+            .map(str::to_string)
+            .collect();
+        Self { item, tokens }
+    }
+
+    pub fn tokens(&self) -> &[String] {
+        &self.tokens
+    }
+}
+
+pub fn score_launcher_result(
+    query: &str,
+    item: &LauncherRankedItem,
+    history: &HashMap<String, LauncherHistoryEntry>,
+) -> f64 {
+    score_launcher_result_at(query, item, history, now_unix_millis())
+}
+
+fn score_launcher_result_at(
+    query: &str,
+    item: &LauncherRankedItem,
+    history: &HashMap<String, LauncherHistoryEntry>,
+    now_ms: i64,
+) -> f64 {
+    // `item.score` also carries the category weight, which is the same for every item it is
+    // compared against because results are ranked within their category.
+    let matched = fuzzy_overlap_ratio(query, item.tokens())
+        + f64::from(item.item.score) / MATCH_POINTS_PER_UNIT;
+    let Some(entry) = history.get(&item.item.id) else {
+        return matched;
+    };
+
+    let days_since_last_launch =
+        (now_ms.saturating_sub(entry.last_launched_at)).max(0) as f64 / MILLIS_PER_DAY;
+    let recency = (-RECENCY_DECAY_PER_DAY * days_since_last_launch).exp();
+    let frequency = (1.0 + f64::from(entry.launch_count)).log2();
+    matched + recency + frequency
+}
+
+fn fuzzy_overlap_ratio(query: &str, tokens: &[String]) -> f64 {
+    let query_chars: Vec<char> = query
+        .to_lowercase()
+        .chars()
+        .filter(|ch| !ch.is_whitespace())
+        .collect();
+    if query_chars.is_empty() {
+        return 1.0;
+    }
+
+    let available: HashSet<char> = tokens.iter().flat_map(|token| token.chars()).collect();
+    let matched = query_chars
+        .iter()
+        .filter(|ch| available.contains(ch))
+        .count();
+    matched as f64 / query_chars.len() as f64
+}
+
+pub(super) async fn load_launch_history(
+    db_conn: &DbConn,
+) -> DbResult<HashMap<String, LauncherHistoryEntry>> {
+    let mut rows = db_conn
+        .query(
+            "SELECT item_id, launch_count, last_launched_at FROM launcher_items_history",
+            (),
+        )
+        .await?;
+    let mut history = HashMap::new();
+    while let Some(row) = rows.next().await? {
+        let launch_count = row.get::<i64>(1)?;
+        history.insert(
+            row.get::<String>(0)?,
+            LauncherHistoryEntry {
+                launch_count: u32::try_from(launch_count.max(0)).unwrap_or(u32::MAX),
+                last_launched_at: row.get::<i64>(2)?,
+            },
+        );
+    }
+    Ok(history)
+}
+
+pub async fn record_launcher_launch_async(db_conn: &DbConn, item_id: &str) -> DbResult<()> {
+    let _write_permit = db_write_permit().await;
+    db_conn
+        .execute(
+            "INSERT INTO launcher_items_history (item_id, launch_count, last_launched_at)
+             VALUES (?1, 1, ?2)
+             ON CONFLICT(item_id) DO UPDATE SET
+                launch_count = launch_count + 1,
+                last_launched_at = excluded.last_launched_at",
+            (item_id, now_unix_millis()),
+        )
+        .await?;
+    Ok(())
+}
+
+fn now_unix_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .ok()
+        .and_then(|duration| i64::try_from(duration.as_millis()).ok())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rtool_contracts::models::LauncherActionDto;
+
+    const NOW_MS: i64 = 1_700_000_000_000;
+
+    fn ranked(id: &str, title: &str) -> LauncherRankedItem {
+        ranked_with_score(id, title, 0)
+    }
+
+    fn ranked_with_score(id: &str, title: &str, score: i32) -> LauncherRankedItem {
+        LauncherRankedItem::new(LauncherItemDto {
+            id: id.to_string(),
+            title: title.to_string(),
+            subtitle: "/Applications".to_string(),
+            category: "application".to_string(),
+            group: String::new(),
+            source: None,
+            shortcut: None,
+            score,
+            icon_kind: "iconify".to_string(),
+            icon_value: "i-noto:rocket".to_string(),
+            action: LauncherActionDto::OpenApplication {
+                path: format!("/Applications/{title}.app"),
+            },
+            undoable: false,
+            title_match_ranges: Vec::new(),
+            matched_via_alias: false,
+        })
+    }
+
+    #[test]
+    fn tokens_are_precomputed_from_title_and_subtitle() {
+        let item = ranked("app.code", "Visual Studio-Code");
+        assert_eq!(item.tokens(), &["visual", "studio", "code", "applications"]);
+    }
+
+    #[test]
+    fn fuzzy_score_is_character_overlap_ratio() {
+        let item = ranked("app.code", "Code");
+        let history = HashMap::new();
+
+        assert_eq!(
+            score_launcher_result_at("code", &item, &history, NOW_MS),
+            1.0
+        );
+        assert_eq!(
+            score_launcher_result_at("cozy", &item, &history, NOW_MS),
+            0.5
+        );
+        assert_eq!(score_launcher_result_at("", &item, &history, NOW_MS), 1.0);
+    }
+
+    #[test]
+    fn history_adds_recency_and_frequency_boost() {
+        let item = ranked("app.code", "Code");
+        let mut history = HashMap::new();
+        history.insert(
+            "app.code".to_string(),
+            LauncherHistoryEntry {
+                launch_count: 3,
+                last_launched_at: NOW_MS - 10 * 86_400_000,
+            },
+        );
+
+        let expected = 1.0 + (-0.1_f64).exp() + 2.0;
+        let score = score_launcher_result_at("code", &item, &history, NOW_MS);
+        assert!((score - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn recent_launch_outranks_stale_launch() {
+        let fresh = ranked("app.fresh", "Code");
+        let stale = ranked("app.stale", "Code");
+        let mut history = HashMap::new();
+        history.insert(
+            "app.fresh".to_string(),
+            LauncherHistoryEntry {
+                launch_count: 1,
+                last_launched_at: NOW_MS,
+            },
+        );
+        history.insert(
+            "app.stale".to_string(),
+            LauncherHistoryEntry {
+                launch_count: 1,
+                last_launched_at: NOW_MS - 365 * 86_400_000,
+            },
+        );
+
+        assert!(
+            score_launcher_result_at("code", &fresh, &history, NOW_MS)
+                > score_launcher_result_at("code", &stale, &history, NOW_MS)
+        );
+    }
+
+    #[test]
+    fn exact_match_without_history_beats_launched_substring_match() {
+        // Scores as `score_item` assigns them to applications for the query "code".
+        let exact = ranked_with_score("app.code", "Code", 160 + 140);
+        let substring = ranked_with_score("app.xcode", "Xcode", 160 + 95);
+        let mut history = HashMap::new();
+        history.insert(
+            "app.xcode".to_string(),
+            LauncherHistoryEntry {
+                launch_count: 1,
+                last_launched_at: NOW_MS,
+            },
+        );
+
+        assert!(
+            score_launcher_result_at("code", &exact, &history, NOW_MS)
+                > score_launcher_result_at("code", &substring, &history, NOW_MS)
+        );
+    }
+}
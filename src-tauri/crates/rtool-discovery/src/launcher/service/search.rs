@@ -1,10 +1,15 @@
+use super::ranking::{
+    LauncherHistoryEntry, LauncherRankedItem, load_launch_history, score_launcher_result,
+};
 use crate::host::LauncherHost;
 use crate::launcher::grouping::with_launcher_group;
 use crate::launcher::icon::resolve_builtin_icon;
 use crate::launcher::index::search_indexed_items_async;
-use rtool_contracts::models::{LauncherActionDto, LauncherItemDto};
+use rtool_contracts::models::{LauncherActionDto, LauncherItemDto, LauncherMatchRangeDto};
 use rtool_data::db::DbConn;
 use rtool_kernel::i18n::{DEFAULT_RESOLVED_LOCALE, ResolvedAppLocale, t};
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::time::Instant;
 
 const DEFAULT_RESULT_LIMIT: usize = 60;
@@ -86,7 +91,14 @@ pub async fn search_launcher_async(
     let result_limit = clamp_result_limit(limit);
     let (items, diagnostics) =
         build_search_candidates(app, db_conn, &normalized, &locale, result_limit).await;
-    let matched = collect_matched_items(items, &query_pattern, locale_kind, result_limit);
+    let history = load_launch_history(db_conn).await.unwrap_or_else(|error| {
+        tracing::warn!(
+            event = "launcher_history_load_failed",
+            error = error.to_string()
+        );
+        HashMap::new()
+    });
+    let matched = collect_matched_items(items, &query_pattern, locale_kind, &history, result_limit);
 
     LauncherSearchResult {
         items: matched,
@@ -142,22 +154,33 @@ fn collect_matched_items(
     items: Vec<LauncherItemDto>,
     query: &QueryPattern<'_>,
     locale_kind: LocaleKind,
+    history: &HashMap<String, LauncherHistoryEntry>,
     result_limit: usize,
 ) -> Vec<LauncherItemDto> {
-    let mut matched: Vec<LauncherItemDto> = items
+    let mut matched: Vec<(f64, LauncherItemDto)> = items
         .into_iter()
         .filter_map(|item| score_item(item, query, locale_kind))
+        .map(|item| {
+            let ranked = LauncherRankedItem::new(item);
+            let weighted = score_launcher_result(query.text, &ranked, history);
+            (weighted, ranked.item)
+        })
         .collect();
 
-    matched.sort_by(|left, right| {
-        right
-            .score
-            .cmp(&left.score)
-            .then_with(|| category_rank(&left.category).cmp(&category_rank(&right.category)))
+    // Results stay grouped by source; history-weighted score orders each group.
+    matched.sort_by(|(left_weight, left), (right_weight, right)| {
+        category_rank(&left.category)
+            .cmp(&category_rank(&right.category))
+            .then_with(|| {
+                right_weight
+                    .partial_cmp(left_weight)
+                    .unwrap_or(Ordering::Equal)
+            })
+            .then_with(|| right.score.cmp(&left.score))
             .then_with(|| left.title.cmp(&right.title))
     });
     matched.truncate(result_limit);
-    matched
+    matched.into_iter().map(|(_, item)| item).collect()
 }
 
 fn should_hide_item_without_query(item: &LauncherItemDto) -> bool {
@@ -295,6 +318,7 @@ fn build_builtin_item(
     action: LauncherActionDto,
 ) -> LauncherItemDto {
     let payload = resolve_builtin_icon(icon);
+    let undoable = action.builtin_window_label().is_some();
     with_launcher_group(LauncherItemDto {
         id: id.to_string(),
         title,
@@ -307,6 +331,9 @@ fn build_builtin_item(
         icon_kind: payload.kind,
         icon_value: payload.value,
         action,
+        undoable,
+        title_match_ranges: Vec::new(),
+        matched_via_alias: false,
     })
 }
 
@@ -336,6 +363,8 @@ fn score_item(
 
     let tail = title_score.min(subtitle_score) / 4;
     item.score = base + best + tail + alias_score / 5;
+    item.title_match_ranges = calculate_match_ranges(&item.title, query);
+    item.matched_via_alias = alias_score > title_score.max(subtitle_score);
     Some(item)
 }
 
@@ -417,6 +446,57 @@ fn calculate_match_score(source: &str, query: &QueryPattern<'_>) -> i32 {
     score
 }
 
+/// Ranges of `title` matched by `query`, using the same rules that score launcher results.
+pub fn launcher_title_match_ranges(title: &str, query: &str) -> Vec<LauncherMatchRangeDto> {
+    let normalized = normalize_query(query);
+    calculate_match_ranges(title, &QueryPattern::new(&normalized))
+}
+
+fn calculate_match_ranges(source: &str, query: &QueryPattern<'_>) -> Vec<LauncherMatchRangeDto> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    // Lowercasing may expand a char, so each folded char remembers its source index.
+    let (folded, origins): (Vec<char>, Vec<usize>) = source
+        .chars()
+        .enumerate()
+        .flat_map(|(index, ch)| ch.to_lowercase().map(move |lower| (lower, index)))
+        .unzip();
+    let locate = |needle: &str| {
+        let needle: Vec<char> = needle.chars().collect();
+        if needle.is_empty() || needle.len() > folded.len() {
+            return None;
+        }
+        let start = folded
+            .windows(needle.len())
+            .position(|window| window == needle.as_slice())?;
+        Some(LauncherMatchRangeDto {
+            start: u32::try_from(origins[start]).unwrap_or(u32::MAX),
+            end: u32::try_from(origins[start + needle.len() - 1] + 1).unwrap_or(u32::MAX),
+        })
+    };
+
+    if let Some(range) = locate(query.text) {
+        return vec![range];
+    }
+
+    let mut ranges: Vec<LauncherMatchRangeDto> = query
+        .tokens
+        .iter()
+        .filter_map(|token| locate(token))
+        .collect();
+    ranges.sort_by_key(|range| range.start);
+    let mut merged: Vec<LauncherMatchRangeDto> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
 fn normalize_query(value: &str) -> String {
     value.trim().to_lowercase()
 }
@@ -463,6 +543,9 @@ mod tests {
             icon_kind: "iconify".to_string(),
             icon_value: "i-noto:card-index-dividers".to_string(),
             action,
+            undoable: false,
+            title_match_ranges: Vec::new(),
+            matched_via_alias: false,
         }
     }
 
@@ -548,6 +631,44 @@ mod tests {
         assert!(calculate_alias_score(&item, &en_query, LocaleKind::En) > 0);
     }
 
+    #[test]
+    fn matched_items_stay_grouped_and_history_orders_each_group() {
+        let open_app = |title: &str| LauncherActionDto::OpenApplication {
+            path: format!("/Applications/{title}.app"),
+        };
+        let mut code = sample_item("app.code", "Code", "Editor", open_app("Code"));
+        code.category = "application".to_string();
+        let mut codex = sample_item("app.codex", "Codex", "Editor", open_app("Codex"));
+        codex.category = "application".to_string();
+        let tools = sample_item(
+            "builtin.tools",
+            "Code tools",
+            "Toolbox",
+            LauncherActionDto::OpenBuiltinRoute {
+                route: "/tools".to_string(),
+            },
+        );
+
+        let mut history = HashMap::new();
+        history.insert(
+            "app.codex".to_string(),
+            LauncherHistoryEntry {
+                launch_count: 4,
+                last_launched_at: 0,
+            },
+        );
+
+        let matched = collect_matched_items(
+            vec![code, codex, tools],
+            &QueryPattern::new("code"),
+            LocaleKind::Other,
+            &history,
+            10,
+        );
+        let ids: Vec<&str> = matched.iter().map(|item| item.id.as_str()).collect();
+        assert_eq!(ids, vec!["builtin.tools", "app.codex", "app.code"]);
+    }
+
     #[test]
     fn match_score_prefers_exact_then_prefix() {
         let exact = calculate_match_score("base64", &QueryPattern::new("base64"));
@@ -557,4 +678,65 @@ mod tests {
         assert!(exact > prefix);
         assert!(prefix > contains);
     }
+
+    fn ranges(values: &[(u32, u32)]) -> Vec<LauncherMatchRangeDto> {
+        values
+            .iter()
+            .map(|&(start, end)| LauncherMatchRangeDto { start, end })
+            .collect()
+    }
+
+    #[test]
+    fn match_ranges_follow_scoring_rules() {
+        let phrase = QueryPattern::new("studio code");
+        assert_eq!(
+            calculate_match_ranges("Visual Studio Code", &phrase),
+            ranges(&[(7, 18)])
+        );
+
+        let tokens = QueryPattern::new("code vis");
+        assert_eq!(
+            calculate_match_ranges("Visual Studio Code", &tokens),
+            ranges(&[(0, 3), (14, 18)])
+        );
+
+        let overlapping = QueryPattern::new("base se64");
+        assert_eq!(
+            calculate_match_ranges("Base64", &overlapping),
+            ranges(&[(0, 6)])
+        );
+
+        assert!(calculate_match_ranges("Regex", &QueryPattern::new("json")).is_empty());
+        assert_eq!(
+            launcher_title_match_ranges("剪贴板历史", "  历史 "),
+            ranges(&[(3, 5)])
+        );
+    }
+
+    #[test]
+    fn scored_item_reports_title_ranges_and_alias_matches() {
+        let regex = || {
+            sample_item(
+                "builtin.tool.regex",
+                "Regex",
+                "Pattern tester",
+                LauncherActionDto::OpenBuiltinTool {
+                    tool_id: "regex".to_string(),
+                },
+            )
+        };
+
+        let by_title = score_item(regex(), &QueryPattern::new("reg"), LocaleKind::Zh).unwrap();
+        assert_eq!(by_title.title_match_ranges, ranges(&[(0, 3)]));
+        assert!(!by_title.matched_via_alias);
+
+        let by_alias = score_item(
+            regex(),
+            &QueryPattern::new("regular expression"),
+            LocaleKind::Zh,
+        )
+        .unwrap();
+        assert!(by_alias.title_match_ranges.is_empty());
+        assert!(by_alias.matched_via_alias);
+    }
 }
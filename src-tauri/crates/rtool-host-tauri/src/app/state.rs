@@ -1,5 +1,8 @@
+use crate::features::launcher::undo::LauncherUndoHistory;
+use crate::platform::native_ui::shortcuts::ShortcutBootstrapOutcome;
+use crate::platform::native_ui::window_auto_hide::WindowAutoHideState;
 use rtool_app::{ApplicationServices, LocaleStateDto, ResolvedAppLocale};
-use rtool_kernel::{RuntimeOrchestrator, RuntimeState, RuntimeWorkerStatus};
+use rtool_kernel::{ClipboardPauseState, RuntimeOrchestrator, RuntimeState, RuntimeWorkerStatus};
 use std::path::PathBuf;
 use std::time::Instant;
 
@@ -9,6 +12,9 @@ pub struct AppContext {
     pub app_services: ApplicationServices,
     pub runtime_state: RuntimeState,
     pub runtime_orchestrator: RuntimeOrchestrator,
+    pub(crate) launcher_undo: LauncherUndoHistory,
+    pub(crate) window_auto_hide: WindowAutoHideState,
+    pub(crate) shortcut_bootstrap: ShortcutBootstrapOutcome,
 }
 
 impl AppContext {
@@ -36,6 +42,26 @@ impl AppContext {
         self.runtime_state.set_clipboard_window_compact(compact);
     }
 
+    pub fn clipboard_paste_target(&self) -> Option<String> {
+        self.runtime_state.clipboard_paste_target()
+    }
+
+    pub fn set_clipboard_paste_target(&self, app_name: Option<String>) {
+        self.runtime_state.set_clipboard_paste_target(app_name);
+    }
+
+    pub fn clipboard_pause(&self, now_ms: i64) -> Option<ClipboardPauseState> {
+        self.runtime_state.clipboard_pause(now_ms)
+    }
+
+    pub fn set_clipboard_pause(&self, pause: Option<ClipboardPauseState>) {
+        self.runtime_state.set_clipboard_pause(pause);
+    }
+
+    pub fn clear_clipboard_pause_if(&self, expected: ClipboardPauseState) -> bool {
+        self.runtime_state.clear_clipboard_pause_if(expected)
+    }
+
     pub fn screenshot_shortcut_id(&self) -> Option<u32> {
         self.runtime_state.screenshot_shortcut_id()
     }
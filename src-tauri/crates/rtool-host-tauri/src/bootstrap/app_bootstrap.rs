@@ -5,8 +5,9 @@ use crate::constants::{
     MAIN_WINDOW_LABEL, SHORTCUT_CLIPBOARD_WINDOW, SHORTCUT_CLIPBOARD_WINDOW_COMPACT,
     SHORTCUT_LAUNCHER_FALLBACK, SHORTCUT_LAUNCHER_PRIMARY, SHORTCUT_SCREENSHOT_DEFAULT,
 };
-use crate::platform::native_ui::shortcuts;
+use crate::platform::native_ui::{shortcuts, window_auto_hide};
 use rtool_contracts::models::SettingsDto;
+use std::cell::Cell;
 use tauri::Manager;
 use tauri_plugin_global_shortcut::ShortcutState;
 
@@ -51,50 +52,68 @@ impl AppBootstrap {
             .ok()
             .map(|shortcut| shortcut.id());
 
-        let shortcut_builder = tauri_plugin_global_shortcut::Builder::new()
-            .with_shortcuts([
+        let shortcut_tiers: [&[&str]; 4] = [
+            &[
                 SHORTCUT_LAUNCHER_PRIMARY,
                 SHORTCUT_LAUNCHER_FALLBACK,
                 SHORTCUT_CLIPBOARD_WINDOW,
                 SHORTCUT_CLIPBOARD_WINDOW_COMPACT,
                 screenshot_shortcut.as_str(),
-            ])
+            ],
+            &[
+                SHORTCUT_LAUNCHER_PRIMARY,
+                SHORTCUT_CLIPBOARD_WINDOW,
+                SHORTCUT_CLIPBOARD_WINDOW_COMPACT,
+                screenshot_shortcut.as_str(),
+            ],
+            &[SHORTCUT_LAUNCHER_PRIMARY, SHORTCUT_CLIPBOARD_WINDOW],
+            &[SHORTCUT_LAUNCHER_PRIMARY],
+        ];
+        let accepted_tier = Cell::new(0usize);
+
+        let shortcut_builder = tauri_plugin_global_shortcut::Builder::new()
+            .with_shortcuts(shortcut_tiers[0].iter().copied())
             .or_else(|error| {
                 app_setup::log_warn_fallback(&format!(
                     "failed to register global shortcuts with fallback: {}",
                     error
                 ));
+                accepted_tier.set(1);
                 tauri_plugin_global_shortcut::Builder::new()
-                    .with_shortcuts([
-                        SHORTCUT_LAUNCHER_PRIMARY,
-                        SHORTCUT_CLIPBOARD_WINDOW,
-                        SHORTCUT_CLIPBOARD_WINDOW_COMPACT,
-                        screenshot_shortcut.as_str(),
-                    ])
+                    .with_shortcuts(shortcut_tiers[1].iter().copied())
             })
             .or_else(|error| {
                 app_setup::log_warn_fallback(&format!(
                     "failed to register compact clipboard/screenshot shortcut, fallback to basic clipboard shortcut: {}",
                     error
                 ));
+                accepted_tier.set(2);
                 tauri_plugin_global_shortcut::Builder::new()
-                    .with_shortcuts([SHORTCUT_LAUNCHER_PRIMARY, SHORTCUT_CLIPBOARD_WINDOW])
+                    .with_shortcuts(shortcut_tiers[2].iter().copied())
             })
             .or_else(|error| {
                 app_setup::log_warn_fallback(&format!(
                     "failed to register clipboard shortcut, fallback to launcher only: {}",
                     error
                 ));
+                accepted_tier.set(3);
                 tauri_plugin_global_shortcut::Builder::new()
-                    .with_shortcuts([SHORTCUT_LAUNCHER_PRIMARY])
+                    .with_shortcuts(shortcut_tiers[3].iter().copied())
             })
             .unwrap_or_else(|error| {
                 app_setup::log_error_fallback(&format!(
                     "failed to register global shortcuts: {}",
                     error
                 ));
+                accepted_tier.set(shortcut_tiers.len());
                 tauri_plugin_global_shortcut::Builder::new()
             });
+        let shortcut_bootstrap = shortcuts::ShortcutBootstrapOutcome::new(
+            shortcut_tiers
+                .get(accepted_tier.get())
+                .copied()
+                .unwrap_or_default(),
+        );
 
         let shortcut_plugin = shortcut_builder
             .with_handler(move |app, shortcut, event| {
@@ -115,9 +134,18 @@ impl AppBootstrap {
             .plugin(tauri_plugin_dialog::init())
             .plugin(shortcut_plugin)
             .setup(move |app| {
-                app_setup::setup(app, startup_settings.clone(), screenshot_shortcut_id)
+                app_setup::setup(
+                    app,
+                    startup_settings.clone(),
+                    screenshot_shortcut_id,
+                    shortcut_bootstrap.clone(),
+                )
             })
             .on_window_event(|window, event| {
+                if let tauri::WindowEvent::Focused(focused) = event {
+                    window_auto_hide::handle_focus_changed(window, *focused);
+                    return;
+                }
                 if window.label() != MAIN_WINDOW_LABEL {
                     return;
                 }
@@ -1,21 +1,23 @@
 use crate::app::state::AppState;
 use crate::constants::{
-    RUNTIME_WORKER_APP_MANAGER, RUNTIME_WORKER_CLIPBOARD, RUNTIME_WORKER_LAUNCHER,
-    RUNTIME_WORKER_SCREENSHOT, SHORTCUT_SCREENSHOT_DEFAULT, TRAY_ICON_ID,
+    MAIN_ROUTE_CHANGED_EVENT, RUNTIME_WORKER_APP_MANAGER, RUNTIME_WORKER_CLIPBOARD,
+    RUNTIME_WORKER_LAUNCHER, RUNTIME_WORKER_SCREENSHOT, SHORTCUT_SCREENSHOT_DEFAULT, TRAY_ICON_ID,
 };
+use crate::features::launcher::undo::LauncherUndoHistory;
 use crate::platform::clipboard_watcher::start_clipboard_watcher;
+use crate::platform::native_ui::window_auto_hide::WindowAutoHideState;
 use crate::platform::native_ui::{apply_locale_to_native_ui, apply_window_chrome, shortcuts, tray};
 use rtool_app::{
-    AppLocaleState, ApplicationServices, BootstrapApplicationService, LocaleApplicationService,
-    ScreenshotApplicationService, SettingsApplicationService,
+    AppLocaleState, ApplicationServices, BootstrapApplicationService, ClipboardApplicationService,
+    LocaleApplicationService, ScreenshotApplicationService, SettingsApplicationService,
 };
 use rtool_contracts::models::SettingsDto;
 use rtool_kernel::{RuntimeOrchestrator, RuntimeState};
 use std::error::Error;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tauri::Manager;
 use tauri::tray::TrayIconBuilder;
+use tauri::{Listener, Manager};
 
 fn locale_state_from_settings(settings: &SettingsDto) -> AppLocaleState {
     LocaleApplicationService.state_from_settings(settings)
@@ -78,10 +80,35 @@ fn start_screenshot_session_sweeper(orchestrator: &RuntimeOrchestrator) {
     });
 }
 
+fn start_clipboard_preview_repair(clipboard: ClipboardApplicationService) {
+    tauri::async_runtime::spawn(async move {
+        if let Err(error) = clipboard.repair_previews().await {
+            tracing::warn!(
+                event = "clipboard_preview_repair_failed",
+                error_code = error.code.as_str()
+            );
+        }
+    });
+}
+
+#[derive(serde::Deserialize)]
+struct MainRouteChangedPayload {
+    route: String,
+}
+
+fn start_main_route_tracking(app_handle: &tauri::AppHandle, launcher_undo: LauncherUndoHistory) {
+    app_handle.listen_any(MAIN_ROUTE_CHANGED_EVENT, move |event| {
+        if let Ok(payload) = serde_json::from_str::<MainRouteChangedPayload>(event.payload()) {
+            launcher_undo.set_main_route(payload.route);
+        }
+    });
+}
+
 pub(crate) fn setup(
     app: &mut tauri::App,
     startup_settings: Option<SettingsDto>,
     screenshot_shortcut_id: Option<u32>,
+    shortcut_bootstrap: shortcuts::ShortcutBootstrapOutcome,
 ) -> Result<(), Box<dyn Error>> {
     let setup_started_at = Instant::now();
     let bootstrap_service = BootstrapApplicationService;
@@ -182,7 +209,11 @@ pub(crate) fn setup(
 
     app.manage(crate::platform::native_ui::window_factory::WindowWarmupState::default());
 
-    match start_clipboard_watcher(app_handle.clone(), app_services.clipboard.clone()) {
+    match start_clipboard_watcher(
+        app_handle.clone(),
+        app_services.clipboard.clone(),
+        runtime_state.clone(),
+    ) {
         Ok(()) => runtime_orchestrator.mark_running(RUNTIME_WORKER_CLIPBOARD),
         Err(error) => {
             runtime_orchestrator.mark_error(
@@ -197,12 +228,18 @@ pub(crate) fn setup(
         }
     }
     app_services.start_background_workers();
+    start_clipboard_preview_repair(app_services.clipboard.clone());
+    let launcher_undo = LauncherUndoHistory::default();
+    start_main_route_tracking(&app_handle, launcher_undo.clone());
 
     app.manage(AppState {
         db_path,
         app_services,
         runtime_state,
         runtime_orchestrator: runtime_orchestrator.clone(),
+        launcher_undo,
+        window_auto_hide: WindowAutoHideState::new(settings.window_auto_hide),
+        shortcut_bootstrap,
     });
 
     crate::platform::native_ui::window_factory::warmup_secondary_windows(app_handle.clone());
@@ -1,10 +1,12 @@
 use rtool_kernel::WorkerId;
+use std::time::Duration;
 
 pub(crate) const CLIPBOARD_WINDOW_OPENED_EVENT: &str = "rtool://clipboard-window/opened";
 pub(crate) const SCREENSHOT_WINDOW_OPENED_EVENT: &str = "rtool://screenshot-window/opened";
 pub(crate) const SCREENSHOT_PIN_WINDOW_OPENED_EVENT: &str = "rtool://screenshot-pin-window/opened";
 pub(crate) const SCREENSHOT_OPERATION_RESULT_EVENT: &str = "rtool://screenshot/operation-result";
 pub(crate) const LAUNCHER_OPENED_EVENT: &str = "rtool://launcher/opened";
+pub(crate) const MAIN_ROUTE_CHANGED_EVENT: &str = "rtool://main/route-changed";
 
 pub(crate) const SHORTCUT_LAUNCHER_PRIMARY: &str = "CommandOrControl+K";
 pub(crate) const SHORTCUT_LAUNCHER_FALLBACK: &str = "Alt+Space";
@@ -38,10 +40,13 @@ pub(crate) const CLIPBOARD_MIN_HEIGHT_LOGICAL: f64 = 520.0;
 pub(crate) const TRAY_ICON_ID: &str = "main-tray";
 pub(crate) const TRAY_MENU_ID_TOOLS: &str = "tray.tools";
 pub(crate) const TRAY_MENU_ID_CLIPBOARD: &str = "tray.clipboard";
+pub(crate) const TRAY_MENU_ID_CLIPBOARD_PAUSE: &str = "tray.clipboardPause";
 pub(crate) const TRAY_MENU_ID_QUIT: &str = "tray.quit";
 
 pub(crate) const CLIPBOARD_PLUGIN_UPDATE_EVENT: &str =
     "plugin:clipboard://clipboard-monitor/update";
+pub(crate) const CLIPBOARD_PASTE_FOCUS_DELAY: Duration = Duration::from_millis(120);
+pub(crate) const CLIPBOARD_PAUSE_MAX_MINUTES: u32 = 24 * 60;
 
 pub(crate) const RUNTIME_WORKER_CLIPBOARD: WorkerId = WorkerId::Clipboard;
 pub(crate) const RUNTIME_WORKER_APP_MANAGER: WorkerId = WorkerId::AppManager;
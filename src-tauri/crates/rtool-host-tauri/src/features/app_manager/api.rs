@@ -5,7 +5,11 @@ use serde::Serialize;
 use serde_json::Value;
 use tauri::State;
 
-use super::operations::{run_app_manager_operation, run_reveal_path};
+use super::operations::{
+    run_app_manager_operation, run_background_index_refresh, run_cancel_resolve_sizes,
+    run_reveal_path,
+};
+use super::reveal::reveal_startup_entry;
 use super::types::{APP_MANAGER_COMMAND_CONTEXT, AppManagerRequest};
 
 async fn dispatch_operation<T, F>(
@@ -77,6 +81,19 @@ pub(crate) async fn handle_app_manager(
             )
             .await
         }
+        AppManagerRequest::GetSnapshotMeta => {
+            dispatch_operation(
+                app,
+                state,
+                request_id,
+                window_label,
+                "get_snapshot_meta",
+                "app_manager_get_snapshot_meta",
+                false,
+                move |service, _host| service.get_snapshot_meta(),
+            )
+            .await
+        }
         AppManagerRequest::ResolveSizes(payload) => {
             dispatch_operation(
                 app,
@@ -90,6 +107,15 @@ pub(crate) async fn handle_app_manager(
             )
             .await
         }
+        AppManagerRequest::CancelResolveSizes(payload) => {
+            let cancelled = run_cancel_resolve_sizes(
+                state.app_services.app_manager,
+                payload.cancel_token,
+                request_id,
+                window_label,
+            )?;
+            APP_MANAGER_COMMAND_CONTEXT.serialize("cancel_resolve_sizes", cancelled)
+        }
         AppManagerRequest::GetDetailCore(payload) => {
             dispatch_operation(
                 app,
@@ -103,6 +129,19 @@ pub(crate) async fn handle_app_manager(
             )
             .await
         }
+        AppManagerRequest::ListRelatedRoots(payload) => {
+            dispatch_operation(
+                app,
+                state,
+                request_id,
+                window_label,
+                "list_related_roots",
+                "app_manager_list_related_roots",
+                false,
+                move |service, host| service.list_related_roots(&host, payload.app_id),
+            )
+            .await
+        }
         AppManagerRequest::GetDetailHeavy(payload) => {
             dispatch_operation(
                 app,
@@ -116,7 +155,25 @@ pub(crate) async fn handle_app_manager(
             )
             .await
         }
+        AppManagerRequest::ScanPath(payload) => {
+            dispatch_operation(
+                app,
+                state,
+                request_id,
+                window_label,
+                "scan_path",
+                "app_manager_scan_path",
+                false,
+                move |service, _host| service.scan_path(payload.input),
+            )
+            .await
+        }
         AppManagerRequest::Cleanup(payload) => {
+            let mut input = payload.input;
+            if input.delete_mode.is_none() {
+                let settings = state.app_services.settings.load_or_init().await?;
+                input.delete_mode = Some(settings.app_manager.default_delete_mode);
+            }
             dispatch_operation(
                 app,
                 state,
@@ -125,7 +182,20 @@ pub(crate) async fn handle_app_manager(
                 "cleanup",
                 "app_manager_cleanup",
                 true,
-                move |service, host| service.cleanup(&host, payload.input),
+                move |service, host| service.cleanup(&host, input),
+            )
+            .await
+        }
+        AppManagerRequest::AnnotateResidueItem(payload) => {
+            dispatch_operation(
+                app,
+                state,
+                request_id,
+                window_label,
+                "annotate_residue_item",
+                "app_manager_annotate_residue_item",
+                false,
+                move |service, host| service.annotate_residue_item(&host, payload.input),
             )
             .await
         }
@@ -142,6 +212,32 @@ pub(crate) async fn handle_app_manager(
             )
             .await
         }
+        AppManagerRequest::ListExports => {
+            dispatch_operation(
+                app,
+                state,
+                request_id,
+                window_label,
+                "list_exports",
+                "app_manager_list_exports",
+                false,
+                move |service, _host| service.list_exports(),
+            )
+            .await
+        }
+        AppManagerRequest::ClearExports(payload) => {
+            dispatch_operation(
+                app,
+                state,
+                request_id,
+                window_label,
+                "clear_exports",
+                "app_manager_clear_exports",
+                false,
+                move |service, _host| service.clear_exports(payload.input),
+            )
+            .await
+        }
         AppManagerRequest::RefreshIndex => {
             dispatch_operation(
                 app,
@@ -155,6 +251,10 @@ pub(crate) async fn handle_app_manager(
             )
             .await
         }
+        AppManagerRequest::RefreshIndexAsync => APP_MANAGER_COMMAND_CONTEXT.serialize(
+            "refresh_index_async",
+            run_background_index_refresh(app, state, request_id, window_label)?,
+        ),
         AppManagerRequest::SetStartup(payload) => {
             dispatch_operation(
                 app,
@@ -207,6 +307,75 @@ pub(crate) async fn handle_app_manager(
             )
             .await
         }
+        AppManagerRequest::RevealStartupEntry(payload) => {
+            dispatch_operation(
+                app,
+                state,
+                request_id,
+                window_label,
+                "reveal_startup_entry",
+                "app_manager_reveal_startup_entry",
+                false,
+                move |service, host| {
+                    let entry = service.locate_startup_entry(&host, payload.app_id)?;
+                    reveal_startup_entry(&entry)?;
+                    Ok(entry)
+                },
+            )
+            .await
+        }
+        AppManagerRequest::ScanGhostStartupItems => {
+            dispatch_operation(
+                app,
+                state,
+                request_id,
+                window_label,
+                "scan_ghost_startup_items",
+                "app_manager_scan_ghost_startup_items",
+                false,
+                move |service, _host| service.scan_ghost_startup_items(),
+            )
+            .await
+        }
+        AppManagerRequest::RemoveGhostStartupItem(payload) => {
+            dispatch_operation(
+                app,
+                state,
+                request_id,
+                window_label,
+                "remove_ghost_startup_item",
+                "app_manager_remove_ghost_startup_item",
+                false,
+                move |service, _host| service.remove_ghost_startup_item(payload.label),
+            )
+            .await
+        }
+        AppManagerRequest::ListAutoBackups => {
+            dispatch_operation(
+                app,
+                state,
+                request_id,
+                window_label,
+                "list_auto_backups",
+                "app_manager_list_auto_backups",
+                false,
+                move |service, host| service.list_startup_auto_backups(&host),
+            )
+            .await
+        }
+        AppManagerRequest::RestoreAutoBackup(payload) => {
+            dispatch_operation(
+                app,
+                state,
+                request_id,
+                window_label,
+                "restore_auto_backup",
+                "app_manager_restore_auto_backup",
+                true,
+                move |service, host| service.restore_startup_auto_backup(&host, payload.backup_id),
+            )
+            .await
+        }
         AppManagerRequest::RevealPath(payload) => {
             run_reveal_path(payload.path, request_id, window_label)?;
             APP_MANAGER_COMMAND_CONTEXT.serialize("reveal_path", Value::Null)
@@ -2,13 +2,17 @@ use crate::app::state::AppState;
 use crate::host::launcher::TauriLauncherHost;
 use crate::shared::command_runtime::run_command_sync;
 use rtool_app::AppManagerApplicationService;
+use rtool_contracts::models::AppManagerSnapshotMetaDto;
 use rtool_contracts::{AppError, AppResult, InvokeError};
 use std::path::Path;
-use tauri::State;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::{Emitter, State};
 
 use super::reveal::reveal_path;
 use super::runtime::run_app_manager_command;
-use super::watcher::trigger_app_manager_watcher_refresh;
+use super::watcher::{APP_MANAGER_INDEX_UPDATED_EVENT, trigger_app_manager_watcher_refresh};
+
+static BACKGROUND_INDEX_REFRESH_RUNNING: AtomicBool = AtomicBool::new(false);
 
 pub(super) async fn run_app_manager_operation<T, F>(
     app: tauri::AppHandle,
@@ -41,6 +45,55 @@ where
     result
 }
 
+pub(super) fn run_background_index_refresh(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+    request_id: Option<String>,
+    window_label: Option<String>,
+) -> Result<AppManagerSnapshotMetaDto, InvokeError> {
+    let service = state.app_services.app_manager;
+    let orchestrator = state.runtime_orchestrator.clone();
+    run_command_sync(
+        "app_manager_refresh_index_async",
+        request_id.clone(),
+        window_label.clone(),
+        move || {
+            let pending = service.pending_index_refresh();
+            if BACKGROUND_INDEX_REFRESH_RUNNING.swap(true, Ordering::SeqCst) {
+                return Ok::<_, AppError>(pending);
+            }
+
+            tauri::async_runtime::spawn(async move {
+                let app_handle = app.clone();
+                let result = run_app_manager_command(
+                    app,
+                    service,
+                    orchestrator,
+                    request_id,
+                    window_label,
+                    "app_manager_refresh_index_background",
+                    move |service, host| service.rebuild_index(&host),
+                )
+                .await;
+                BACKGROUND_INDEX_REFRESH_RUNNING.store(false, Ordering::SeqCst);
+                match result {
+                    Ok(payload) => {
+                        let _ = app_handle.emit(APP_MANAGER_INDEX_UPDATED_EVENT, payload);
+                    }
+                    Err(error) => {
+                        tracing::warn!(
+                            event = "app_manager_background_refresh_failed",
+                            code = error.code.as_str(),
+                            message = error.message.as_str()
+                        );
+                    }
+                }
+            });
+            Ok(pending)
+        },
+    )
+}
+
 pub(super) fn run_reveal_path(
     path: String,
     request_id: Option<String>,
@@ -63,3 +116,17 @@ pub(super) fn run_reveal_path(
         },
     )
 }
+
+pub(super) fn run_cancel_resolve_sizes(
+    service: AppManagerApplicationService,
+    cancel_token: String,
+    request_id: Option<String>,
+    window_label: Option<String>,
+) -> Result<bool, InvokeError> {
+    run_command_sync(
+        "app_manager_cancel_resolve_sizes",
+        request_id,
+        window_label,
+        move || service.cancel_resolve_sizes(cancel_token),
+    )
+}
@@ -1,4 +1,5 @@
 use anyhow::Context;
+use rtool_contracts::models::{AppManagerStartupEntryDto, AppManagerStartupEntryKind};
 use rtool_contracts::{AppError, AppResult, ResultExt};
 use std::path::{Path, PathBuf};
 use std::process::Command;
@@ -54,9 +55,80 @@ pub(super) fn reveal_path(path: &Path) -> AppResult<()> {
     }
 }
 
+pub(super) fn reveal_startup_entry(entry: &AppManagerStartupEntryDto) -> AppResult<()> {
+    match entry.kind {
+        AppManagerStartupEntryKind::File => reveal_path(Path::new(entry.location.as_str())),
+        AppManagerStartupEntryKind::RegistryKey => reveal_registry_key(entry.location.as_str()),
+    }
+}
+
+#[cfg(any(target_os = "windows", test))]
+fn regedit_last_key(key: &str) -> String {
+    let expanded = if let Some(rest) = key.strip_prefix(r"HKCU\") {
+        format!(r"HKEY_CURRENT_USER\{rest}")
+    } else if let Some(rest) = key.strip_prefix(r"HKLM\") {
+        format!(r"HKEY_LOCAL_MACHINE\{rest}")
+    } else {
+        key.to_string()
+    };
+    format!(r"Computer\{expanded}")
+}
+
+#[cfg(target_os = "windows")]
+fn reveal_registry_key(key: &str) -> AppResult<()> {
+    // regedit reopens at its last remembered key, so point that at the target before launching.
+    let last_key = regedit_last_key(key);
+    let status = Command::new("reg")
+        .args([
+            "add",
+            r"HKCU\Software\Microsoft\Windows\CurrentVersion\Applets\Regedit",
+            "/v",
+            "LastKey",
+            "/t",
+            "REG_SZ",
+            "/d",
+            last_key.as_str(),
+            "/f",
+        ])
+        .status()
+        .with_context(|| format!("failed to preset regedit location for {key}"))
+        .with_code(
+            "app_manager_reveal_failed",
+            "定位失败：无法启动注册表编辑器",
+        )?;
+    if !status.success() {
+        return Err(AppError::new(
+            "app_manager_reveal_failed",
+            "定位失败：无法启动注册表编辑器",
+        )
+        .with_context("registryKey", key.to_string())
+        .with_context("status", status.to_string()));
+    }
+
+    Command::new("regedit")
+        .arg("/m")
+        .spawn()
+        .with_context(|| format!("failed to launch regedit for {key}"))
+        .with_code(
+            "app_manager_reveal_failed",
+            "定位失败：无法启动注册表编辑器",
+        )
+        .with_ctx("registryKey", key.to_string())?;
+    Ok(())
+}
+
+#[cfg(not(target_os = "windows"))]
+fn reveal_registry_key(key: &str) -> AppResult<()> {
+    Err(AppError::new(
+        "app_manager_reveal_not_supported",
+        "定位失败：当前平台不支持注册表项",
+    )
+    .with_context("registryKey", key.to_string()))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::reveal_path;
+    use super::{regedit_last_key, reveal_path};
     use std::time::{SystemTime, UNIX_EPOCH};
 
     #[test]
@@ -80,4 +152,16 @@ mod tests {
                 .any(|item| item.key == "path" && item.value == missing_display)
         );
     }
+
+    #[test]
+    fn regedit_last_key_expands_hive_abbreviations() {
+        assert_eq!(
+            regedit_last_key(r"HKCU\Software\Microsoft\Windows\CurrentVersion\Run"),
+            r"Computer\HKEY_CURRENT_USER\Software\Microsoft\Windows\CurrentVersion\Run"
+        );
+        assert_eq!(
+            regedit_last_key(r"HKLM\Software\Microsoft\Windows\CurrentVersion\Run"),
+            r"Computer\HKEY_LOCAL_MACHINE\Software\Microsoft\Windows\CurrentVersion\Run"
+        );
+    }
 }
@@ -1,7 +1,8 @@
 use crate::shared::command_response::CommandPayloadContext;
 use rtool_contracts::models::{
-    AppManagerCleanupInputDto, AppManagerDetailQueryDto, AppManagerExportScanInputDto,
-    AppManagerQueryDto, AppManagerResidueScanInputDto, AppManagerResolveSizesInputDto,
+    AppManagerAnnotateResidueInputDto, AppManagerCleanupInputDto, AppManagerClearExportsInputDto,
+    AppManagerDetailQueryDto, AppManagerExportScanInputDto, AppManagerQueryDto,
+    AppManagerResidueScanInputDto, AppManagerResolveSizesInputDto, AppManagerScanPathInputDto,
     AppManagerStartupUpdateInputDto, AppManagerUninstallInputDto,
 };
 use serde::Deserialize;
@@ -24,24 +25,48 @@ pub(crate) struct AppManagerResolveSizesPayload {
     pub(super) input: AppManagerResolveSizesInputDto,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AppManagerCancelResolveSizesPayload {
+    pub(super) cancel_token: String,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct AppManagerResidueInputPayload {
     pub(super) input: AppManagerResidueScanInputDto,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AppManagerScanPathPayload {
+    pub(super) input: AppManagerScanPathInputDto,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct AppManagerCleanupPayload {
     pub(super) input: AppManagerCleanupInputDto,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AppManagerAnnotateResiduePayload {
+    pub(super) input: AppManagerAnnotateResidueInputDto,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct AppManagerExportPayload {
     pub(super) input: AppManagerExportScanInputDto,
 }
 
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub(crate) struct AppManagerClearExportsPayload {
+    pub(super) input: AppManagerClearExportsInputDto,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct AppManagerStartupPayload {
@@ -60,28 +85,53 @@ pub(crate) struct AppManagerHelpPayload {
     pub(super) app_id: String,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AppManagerGhostStartupPayload {
+    pub(super) label: String,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct AppManagerRevealPayload {
     pub(super) path: String,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AppManagerRestoreAutoBackupPayload {
+    pub(super) backup_id: String,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(tag = "kind", content = "payload", rename_all = "snake_case")]
 pub(crate) enum AppManagerRequest {
     List(AppManagerListPayload),
     ListSnapshotMeta,
+    GetSnapshotMeta,
     ResolveSizes(AppManagerResolveSizesPayload),
+    CancelResolveSizes(AppManagerCancelResolveSizesPayload),
     GetDetailCore(AppManagerDetailPayload),
+    ListRelatedRoots(AppManagerHelpPayload),
     GetDetailHeavy(AppManagerResidueInputPayload),
+    ScanPath(AppManagerScanPathPayload),
     Cleanup(AppManagerCleanupPayload),
+    AnnotateResidueItem(AppManagerAnnotateResiduePayload),
     ExportScanResult(AppManagerExportPayload),
+    ListExports,
+    ClearExports(AppManagerClearExportsPayload),
     RefreshIndex,
+    RefreshIndexAsync,
     SetStartup(AppManagerStartupPayload),
     Uninstall(AppManagerUninstallPayload),
     OpenUninstallHelp(AppManagerHelpPayload),
     OpenPermissionHelp(AppManagerHelpPayload),
     RevealPath(AppManagerRevealPayload),
+    RevealStartupEntry(AppManagerHelpPayload),
+    ScanGhostStartupItems,
+    RemoveGhostStartupItem(AppManagerGhostStartupPayload),
+    ListAutoBackups,
+    RestoreAutoBackup(AppManagerRestoreAutoBackupPayload),
 }
 
 pub const APP_MANAGER_COMMAND_CONTEXT: CommandPayloadContext = CommandPayloadContext::new(
@@ -11,6 +11,8 @@ use tauri::Emitter;
 use tokio::sync::Notify;
 use tokio::time::sleep;
 
+pub(super) const APP_MANAGER_INDEX_UPDATED_EVENT: &str = "rtool://app-manager/index-updated";
+
 fn app_manager_watcher_started() -> &'static AtomicBool {
     static STARTED: OnceLock<AtomicBool> = OnceLock::new();
     STARTED.get_or_init(|| AtomicBool::new(false))
@@ -75,7 +77,7 @@ pub(super) fn ensure_app_manager_watcher_started(
             .await;
             match poll_result {
                 Ok(Some(payload)) => {
-                    let _ = app_handle.emit("rtool://app-manager/index-updated", payload);
+                    let _ = app_handle.emit(APP_MANAGER_INDEX_UPDATED_EVENT, payload);
                     wait_for = Duration::from_secs(budget.app_manager_poll_min_secs);
                 }
                 Ok(None) => {
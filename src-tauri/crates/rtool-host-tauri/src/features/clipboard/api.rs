@@ -1,9 +1,13 @@
 use crate::app::state::AppState;
+use crate::constants::{CLIPBOARD_PASTE_FOCUS_DELAY, CLIPBOARD_WINDOW_LABEL};
 use crate::features::clipboard::events::emit_clipboard_sync;
+use crate::features::clipboard::paste::send_paste_keystroke;
+use crate::features::clipboard::pause::{clipboard_watcher_status, set_clipboard_paused};
 use crate::features::clipboard::system_clipboard::{
     copy_files_to_clipboard_with_verify, decode_data_url_image_bytes,
     parse_file_paths_from_plain_text,
 };
+use crate::platform::clipboard_watcher::read_image_signature;
 use crate::shared::command_response::CommandPayloadContext;
 use crate::shared::command_runtime::{run_blocking, run_command_async, run_command_sync};
 use crate::shared::request_context::InvokeMeta;
@@ -12,8 +16,10 @@ use arboard::{Clipboard as ArboardClipboard, ImageData};
 use image::ImageReader;
 use rtool_app::services::ClipboardApplicationService;
 use rtool_contracts::models::{
-    ClipboardFilterDto, ClipboardImageExportResultDto, ClipboardItemDto, ClipboardSyncPayload,
-    ClipboardWindowModeAppliedDto,
+    ClipboardFilterDto, ClipboardImageExportResultDto, ClipboardItemDetailDto, ClipboardItemDto,
+    ClipboardMigrateResultDto, ClipboardPreviewRepairResultDto, ClipboardSearchResultDto,
+    ClipboardSizeSummaryDto, ClipboardStorageStatsDto, ClipboardSyncPayload,
+    ClipboardTextTransformDto, ClipboardWatcherStatusDto, ClipboardWindowModeAppliedDto,
 };
 use rtool_contracts::{AppError, AppResult, InvokeError, ResultExt};
 use serde::Deserialize;
@@ -21,7 +27,7 @@ use serde_json::Value;
 use std::borrow::Cow;
 use std::io::Cursor;
 use std::path::PathBuf;
-use tauri::{AppHandle, State};
+use tauri::{AppHandle, Manager, State};
 use tauri_plugin_dialog::DialogExt;
 
 fn default_filter() -> ClipboardFilterDto {
@@ -30,6 +36,7 @@ fn default_filter() -> ClipboardFilterDto {
         item_type: None,
         only_pinned: Some(false),
         limit: Some(100),
+        content_filter: None,
     }
 }
 
@@ -104,12 +111,105 @@ fn emit_clipboard_touch_sync(app: &AppHandle, touched: ClipboardItemDto, reason:
     );
 }
 
+fn write_text_or_files_to_clipboard(
+    clipboard_plugin: &tauri_plugin_clipboard::Clipboard,
+    item: ClipboardItemDto,
+) -> AppResult<()> {
+    if item.item_type == "file" {
+        let file_paths = parse_file_paths_from_plain_text(&item.plain_text)?;
+        return copy_files_to_clipboard_with_verify(clipboard_plugin, &file_paths);
+    }
+
+    let mut clipboard = ArboardClipboard::new().map_err(map_arboard_error)?;
+    clipboard
+        .set_text(item.plain_text)
+        .map_err(map_arboard_error)
+}
+
+/// Items recorded without a preview can only be "copied back" while the system
+/// clipboard still holds the original image.
+fn ensure_original_image_on_clipboard(
+    clipboard_plugin: &tauri_plugin_clipboard::Clipboard,
+    item: &ClipboardItemDto,
+) -> AppResult<()> {
+    let current_signature = clipboard_plugin
+        .read_image_binary()
+        .ok()
+        .and_then(|bytes| read_image_signature(&bytes));
+    if current_signature.is_some_and(|signature| item.content_key == format!("image:{signature}")) {
+        return Ok(());
+    }
+    Err(AppError::new(
+        "clipboard_image_original_unavailable",
+        "图片过大未保存预览，且原图已不在剪贴板中",
+    )
+    .with_context("itemId", item.id.clone()))
+}
+
+async fn write_image_to_clipboard(
+    clipboard_plugin: &tauri_plugin_clipboard::Clipboard,
+    item: ClipboardItemDto,
+) -> AppResult<()> {
+    if item.preview_skipped {
+        return ensure_original_image_on_clipboard(clipboard_plugin, &item);
+    }
+    let id = item.id;
+    let preview_path = item.preview_path;
+    let preview_data_url = item.preview_data_url;
+    let (width, height, bytes) = run_blocking("clipboard_copy_image_back_decode", move || {
+        let image = decode_clipboard_image(preview_path, preview_data_url)?;
+        let rgba = image.to_rgba8();
+        let (width, height) = rgba.dimensions();
+        Ok((width, height, rgba.into_raw()))
+    })
+    .await?;
+
+    let image_data = ImageData {
+        width: width as usize,
+        height: height as usize,
+        bytes: Cow::Owned(bytes),
+    };
+
+    let mut clipboard = ArboardClipboard::new().map_err(map_arboard_error)?;
+    clipboard
+        .set_image(image_data)
+        .with_context(|| format!("写入图片到剪贴板失败: id={id}"))
+        .with_code("clipboard_set_image_failed", "写入图片到剪贴板失败")
+        .with_ctx("itemId", id)
+}
+
+pub(crate) async fn copy_clipboard_item_back(
+    app: &AppHandle,
+    clipboard_plugin: &tauri_plugin_clipboard::Clipboard,
+    clipboard_service: ClipboardApplicationService,
+    id: String,
+    reason: &str,
+) -> AppResult<()> {
+    let item = fetch_clipboard_item_or_not_found(clipboard_service.clone(), id.clone()).await?;
+    if item.item_type == "image" {
+        write_image_to_clipboard(clipboard_plugin, item).await?;
+    } else {
+        write_text_or_files_to_clipboard(clipboard_plugin, item)?;
+    }
+
+    let touched = touch_clipboard_item(clipboard_service, id).await?;
+    emit_clipboard_touch_sync(app, touched, reason);
+    Ok(())
+}
+
 #[derive(Debug, Clone, Deserialize, Default)]
 #[serde(rename_all = "camelCase", default)]
 pub(crate) struct ClipboardListPayload {
     filter: Option<ClipboardFilterDto>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ClipboardSearchPayload {
+    query: String,
+    limit: Option<u32>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct ClipboardPinPayload {
@@ -117,12 +217,25 @@ pub(crate) struct ClipboardPinPayload {
     pinned: bool,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ClipboardReorderPinnedPayload {
+    item_ids: Vec<String>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct ClipboardIdPayload {
     id: String,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ClipboardCopyTransformedPayload {
+    id: String,
+    transform: ClipboardTextTransformDto,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct ClipboardSaveTextPayload {
@@ -135,20 +248,44 @@ pub(crate) struct ClipboardWindowModePayload {
     compact: bool,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ClipboardSetPausedPayload {
+    paused: bool,
+    duration_minutes: Option<u32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ClipboardMigrateStoragePayload {
+    new_dir: String,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(tag = "kind", content = "payload", rename_all = "snake_case")]
 pub(crate) enum ClipboardRequest {
     List(ClipboardListPayload),
+    Search(ClipboardSearchPayload),
+    GetItem(ClipboardIdPayload),
     Pin(ClipboardPinPayload),
+    ReorderPinned(ClipboardReorderPinnedPayload),
     Delete(ClipboardIdPayload),
     ClearAll,
     SaveText(ClipboardSaveTextPayload),
     WindowSetMode(ClipboardWindowModePayload),
     WindowApplyMode(ClipboardWindowModePayload),
     CopyBack(ClipboardIdPayload),
+    CopyTransformed(ClipboardCopyTransformedPayload),
+    CopyAndPaste(ClipboardIdPayload),
     CopyFilePaths(ClipboardIdPayload),
     CopyImageBack(ClipboardIdPayload),
     ExportImage(ClipboardIdPayload),
+    StorageStats,
+    SizeSummary,
+    Repair,
+    MigrateStorage(ClipboardMigrateStoragePayload),
+    SetPaused(ClipboardSetPausedPayload),
+    WatcherStatus,
 }
 
 const CLIPBOARD_COMMAND_CONTEXT: CommandPayloadContext = CommandPayloadContext::new(
@@ -175,6 +312,39 @@ async fn clipboard_list(
     .await
 }
 
+async fn clipboard_search(
+    state: State<'_, AppState>,
+    query: String,
+    limit: Option<u32>,
+    request_id: Option<String>,
+    window_label: Option<String>,
+) -> Result<ClipboardSearchResultDto, InvokeError> {
+    let service = state.app_services.clipboard.clone();
+    run_command_async(
+        "clipboard_search",
+        request_id,
+        window_label,
+        move || async move { service.search(&query, limit).await },
+    )
+    .await
+}
+
+async fn clipboard_get_item(
+    state: State<'_, AppState>,
+    id: String,
+    request_id: Option<String>,
+    window_label: Option<String>,
+) -> Result<ClipboardItemDetailDto, InvokeError> {
+    let service = state.app_services.clipboard.clone();
+    run_command_async(
+        "clipboard_get_item",
+        request_id,
+        window_label,
+        move || async move { service.get_item_detail(id).await },
+    )
+    .await
+}
+
 async fn clipboard_pin(
     app: AppHandle,
     state: State<'_, AppState>,
@@ -189,16 +359,58 @@ async fn clipboard_pin(
         window_label,
         move || async move {
             let service = state.app_services.clipboard.clone();
-            let updated = service.pin(id, pinned).await?;
+            let result = service.pin(id, pinned).await?;
             emit_clipboard_sync(
                 &app,
                 ClipboardSyncPayload {
-                    upsert: vec![updated],
+                    upsert: vec![result.item],
                     removed_ids: Vec::new(),
                     clear_all: false,
                     reason: Some("pin".to_string()),
                 },
             );
+            if !result.unpinned.is_empty() {
+                emit_clipboard_sync(
+                    &app,
+                    ClipboardSyncPayload {
+                        upsert: result.unpinned,
+                        removed_ids: Vec::new(),
+                        clear_all: false,
+                        reason: Some("pin_limit".to_string()),
+                    },
+                );
+            }
+            Ok::<(), AppError>(())
+        },
+    )
+    .await
+}
+
+async fn clipboard_reorder_pinned(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    item_ids: Vec<String>,
+    request_id: Option<String>,
+    window_label: Option<String>,
+) -> Result<(), InvokeError> {
+    run_command_async(
+        "clipboard_reorder_pinned",
+        request_id,
+        window_label,
+        move || async move {
+            let service = state.app_services.clipboard.clone();
+            let reordered = service.reorder_pinned(item_ids).await?;
+            if !reordered.is_empty() {
+                emit_clipboard_sync(
+                    &app,
+                    ClipboardSyncPayload {
+                        upsert: reordered,
+                        removed_ids: Vec::new(),
+                        clear_all: false,
+                        reason: Some("reorder_pinned".to_string()),
+                    },
+                );
+            }
             Ok::<(), AppError>(())
         },
     )
@@ -347,15 +559,7 @@ async fn clipboard_copy_back(
         move || async move {
             let item =
                 fetch_clipboard_item_or_not_found(clipboard_service.clone(), id.clone()).await?;
-            if item.item_type == "file" {
-                let file_paths = parse_file_paths_from_plain_text(&item.plain_text)?;
-                copy_files_to_clipboard_with_verify(clipboard_plugin.inner(), &file_paths)?;
-            } else {
-                let mut clipboard = ArboardClipboard::new().map_err(map_arboard_error)?;
-                clipboard
-                    .set_text(item.plain_text)
-                    .map_err(map_arboard_error)?;
-            }
+            write_text_or_files_to_clipboard(clipboard_plugin.inner(), item)?;
 
             let touched = touch_clipboard_item(clipboard_service, id.clone()).await?;
             emit_clipboard_touch_sync(&app, touched, "copy_back");
@@ -365,6 +569,81 @@ async fn clipboard_copy_back(
     .await
 }
 
+async fn clipboard_copy_transformed(
+    state: State<'_, AppState>,
+    id: String,
+    transform: ClipboardTextTransformDto,
+    request_id: Option<String>,
+    window_label: Option<String>,
+) -> Result<String, InvokeError> {
+    let clipboard_service = state.app_services.clipboard.clone();
+    run_command_async(
+        "clipboard_copy_transformed",
+        request_id,
+        window_label,
+        move || async move {
+            let text = clipboard_service.transform_item_text(id, transform).await?;
+            let mut clipboard = ArboardClipboard::new().map_err(map_arboard_error)?;
+            clipboard
+                .set_text(text.clone())
+                .map_err(map_arboard_error)?;
+            Ok::<String, AppError>(text)
+        },
+    )
+    .await
+}
+
+fn hide_clipboard_window(app: &AppHandle) -> AppResult<()> {
+    let Some(window) = app.get_webview_window(CLIPBOARD_WINDOW_LABEL) else {
+        return Ok(());
+    };
+    window.hide().map_err(|error| {
+        AppError::new("clipboard_window_hide_failed", "隐藏剪贴板窗口失败").with_source(error)
+    })
+}
+
+async fn clipboard_copy_and_paste(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    clipboard_plugin: State<'_, tauri_plugin_clipboard::Clipboard>,
+    id: String,
+    request_id: Option<String>,
+    window_label: Option<String>,
+) -> Result<(), InvokeError> {
+    let clipboard_service = state.app_services.clipboard.clone();
+    let settings = state.app_services.settings.clone();
+    let paste_target = state.clipboard_paste_target();
+    run_command_async(
+        "clipboard_copy_and_paste",
+        request_id,
+        window_label,
+        move || async move {
+            if !settings.load_or_init().await?.clipboard.paste_after_copy {
+                return Err(AppError::new(
+                    "clipboard_paste_after_copy_disabled",
+                    "未开启复制后自动粘贴",
+                ));
+            }
+            copy_clipboard_item_back(
+                &app,
+                clipboard_plugin.inner(),
+                clipboard_service,
+                id,
+                "copy_and_paste",
+            )
+            .await?;
+            hide_clipboard_window(&app)?;
+
+            tokio::time::sleep(CLIPBOARD_PASTE_FOCUS_DELAY).await;
+            run_blocking("clipboard_paste_keystroke", move || {
+                send_paste_keystroke(paste_target.as_deref())
+            })
+            .await
+        },
+    )
+    .await
+}
+
 async fn clipboard_copy_file_paths(
     app: AppHandle,
     state: State<'_, AppState>,
@@ -415,30 +694,8 @@ async fn clipboard_copy_image_back(
             if item.item_type != "image" {
                 return Err(AppError::new("clipboard_not_image", "当前条目不是图片类型"));
             }
-
-            let preview_path = item.preview_path.clone();
-            let preview_data_url = item.preview_data_url.clone();
-            let (width, height, bytes) =
-                run_blocking("clipboard_copy_image_back_decode", move || {
-                    let image = decode_clipboard_image(preview_path, preview_data_url)?;
-                    let rgba = image.to_rgba8();
-                    let (width, height) = rgba.dimensions();
-                    Ok((width, height, rgba.into_raw()))
-                })
-                .await?;
-
-            let image_data = ImageData {
-                width: width as usize,
-                height: height as usize,
-                bytes: Cow::Owned(bytes),
-            };
-
-            let mut clipboard = ArboardClipboard::new().map_err(map_arboard_error)?;
-            clipboard
-                .set_image(image_data)
-                .with_context(|| format!("写入图片到剪贴板失败: id={id}"))
-                .with_code("clipboard_set_image_failed", "写入图片到剪贴板失败")
-                .with_ctx("itemId", id.clone())?;
+            let clipboard_plugin = app.state::<tauri_plugin_clipboard::Clipboard>();
+            write_image_to_clipboard(&clipboard_plugin, item).await?;
 
             let touched = touch_clipboard_item(clipboard_service, id.clone()).await?;
             emit_clipboard_touch_sync(&app, touched, "copy_image_back");
@@ -517,6 +774,115 @@ async fn clipboard_export_image(
     .await
 }
 
+async fn clipboard_storage_stats(
+    state: State<'_, AppState>,
+    request_id: Option<String>,
+    window_label: Option<String>,
+) -> Result<ClipboardStorageStatsDto, InvokeError> {
+    let service = state.app_services.clipboard.clone();
+    let preview_dir = service.preview_dir();
+    run_command_async(
+        "clipboard_storage_stats",
+        request_id,
+        window_label,
+        move || async move { service.storage_stats(preview_dir.as_deref()).await },
+    )
+    .await
+}
+
+async fn clipboard_get_size_summary(
+    state: State<'_, AppState>,
+    request_id: Option<String>,
+    window_label: Option<String>,
+) -> Result<ClipboardSizeSummaryDto, InvokeError> {
+    let service = state.app_services.clipboard.clone();
+    let preview_dir = service.preview_dir();
+    run_command_async(
+        "clipboard_get_size_summary",
+        request_id,
+        window_label,
+        move || async move { service.size_summary(preview_dir.as_deref()).await },
+    )
+    .await
+}
+
+async fn clipboard_repair(
+    state: State<'_, AppState>,
+    request_id: Option<String>,
+    window_label: Option<String>,
+) -> Result<ClipboardPreviewRepairResultDto, InvokeError> {
+    let service = state.app_services.clipboard.clone();
+    run_command_async(
+        "clipboard_repair",
+        request_id,
+        window_label,
+        move || async move { service.repair_previews().await },
+    )
+    .await
+}
+
+async fn clipboard_migrate_storage(
+    state: State<'_, AppState>,
+    new_dir: String,
+    request_id: Option<String>,
+    window_label: Option<String>,
+) -> Result<ClipboardMigrateResultDto, InvokeError> {
+    let clipboard = state.app_services.clipboard.clone();
+    let settings = state.app_services.settings.clone();
+    run_command_async(
+        "clipboard_migrate_storage",
+        request_id,
+        window_label,
+        move || async move {
+            let new_dir = new_dir.trim();
+            if new_dir.is_empty() {
+                return Err(AppError::new(
+                    "clipboard_storage_dir_invalid",
+                    "剪贴板存储目录不能为空",
+                ));
+            }
+            clipboard
+                .migrate_storage_dir(&PathBuf::from(new_dir), async move |new_path: String| {
+                    settings
+                        .update_clipboard_storage_dir(Some(new_path.as_str()))
+                        .await
+                        .map(|_| ())
+                })
+                .await
+        },
+    )
+    .await
+}
+
+fn clipboard_set_paused(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    paused: bool,
+    duration_minutes: Option<u32>,
+    request_id: Option<String>,
+    window_label: Option<String>,
+) -> Result<ClipboardWatcherStatusDto, InvokeError> {
+    run_command_sync(
+        "clipboard_set_paused",
+        request_id,
+        window_label,
+        move || set_clipboard_paused(&app, state.inner(), paused, duration_minutes),
+    )
+}
+
+fn clipboard_get_watcher_status(
+    state: State<'_, AppState>,
+    request_id: Option<String>,
+    window_label: Option<String>,
+) -> Result<ClipboardWatcherStatusDto, InvokeError> {
+    run_command_sync(
+        "clipboard_watcher_status",
+        request_id,
+        window_label,
+        move || Ok::<_, InvokeError>(clipboard_watcher_status(state.inner())),
+    )
+}
+
 pub(crate) async fn handle_clipboard(
     app: AppHandle,
     state: State<'_, AppState>,
@@ -531,6 +897,21 @@ pub(crate) async fn handle_clipboard(
             "list",
             clipboard_list(state, payload.filter, request_id, window_label).await?,
         ),
+        ClipboardRequest::Search(payload) => CLIPBOARD_COMMAND_CONTEXT.serialize(
+            "search",
+            clipboard_search(
+                state,
+                payload.query,
+                payload.limit,
+                request_id,
+                window_label,
+            )
+            .await?,
+        ),
+        ClipboardRequest::GetItem(payload) => CLIPBOARD_COMMAND_CONTEXT.serialize(
+            "get_item",
+            clipboard_get_item(state, payload.id, request_id, window_label).await?,
+        ),
         ClipboardRequest::Pin(payload) => {
             clipboard_pin(
                 app,
@@ -543,6 +924,11 @@ pub(crate) async fn handle_clipboard(
             .await?;
             Ok(Value::Null)
         }
+        ClipboardRequest::ReorderPinned(payload) => {
+            clipboard_reorder_pinned(app, state, payload.item_ids, request_id, window_label)
+                .await?;
+            Ok(Value::Null)
+        }
         ClipboardRequest::Delete(payload) => {
             clipboard_delete(app, state, payload.id, request_id, window_label).await?;
             Ok(Value::Null)
@@ -575,6 +961,29 @@ pub(crate) async fn handle_clipboard(
             .await?;
             Ok(Value::Null)
         }
+        ClipboardRequest::CopyTransformed(payload) => CLIPBOARD_COMMAND_CONTEXT.serialize(
+            "copy_transformed",
+            clipboard_copy_transformed(
+                state,
+                payload.id,
+                payload.transform,
+                request_id,
+                window_label,
+            )
+            .await?,
+        ),
+        ClipboardRequest::CopyAndPaste(payload) => {
+            clipboard_copy_and_paste(
+                app,
+                state,
+                clipboard_plugin,
+                payload.id,
+                request_id,
+                window_label,
+            )
+            .await?;
+            Ok(Value::Null)
+        }
         ClipboardRequest::CopyFilePaths(payload) => {
             clipboard_copy_file_paths(app, state, payload.id, request_id, window_label).await?;
             Ok(Value::Null)
@@ -587,5 +996,36 @@ pub(crate) async fn handle_clipboard(
             "export_image",
             clipboard_export_image(app, state, payload.id, request_id, window_label).await?,
         ),
+        ClipboardRequest::StorageStats => CLIPBOARD_COMMAND_CONTEXT.serialize(
+            "storage_stats",
+            clipboard_storage_stats(state, request_id, window_label).await?,
+        ),
+        ClipboardRequest::SizeSummary => CLIPBOARD_COMMAND_CONTEXT.serialize(
+            "size_summary",
+            clipboard_get_size_summary(state, request_id, window_label).await?,
+        ),
+        ClipboardRequest::Repair => CLIPBOARD_COMMAND_CONTEXT.serialize(
+            "repair",
+            clipboard_repair(state, request_id, window_label).await?,
+        ),
+        ClipboardRequest::MigrateStorage(payload) => CLIPBOARD_COMMAND_CONTEXT.serialize(
+            "migrate_storage",
+            clipboard_migrate_storage(state, payload.new_dir, request_id, window_label).await?,
+        ),
+        ClipboardRequest::SetPaused(payload) => CLIPBOARD_COMMAND_CONTEXT.serialize(
+            "set_paused",
+            clipboard_set_paused(
+                app,
+                state,
+                payload.paused,
+                payload.duration_minutes,
+                request_id,
+                window_label,
+            )?,
+        ),
+        ClipboardRequest::WatcherStatus => CLIPBOARD_COMMAND_CONTEXT.serialize(
+            "watcher_status",
+            clipboard_get_watcher_status(state, request_id, window_label)?,
+        ),
     }
 }
@@ -1,7 +1,9 @@
-use rtool_contracts::models::ClipboardSyncPayload;
+use crate::platform::native_ui::tray::refresh_tray_tooltip;
+use rtool_contracts::models::{ClipboardSyncPayload, ClipboardWatcherStatusDto};
 use tauri::{AppHandle, Emitter, Runtime};
 
 const CLIPBOARD_SYNC_EVENT: &str = "rtool://clipboard/sync";
+const CLIPBOARD_WATCHER_STATUS_EVENT: &str = "rtool://clipboard/watcher-status";
 
 pub fn emit_clipboard_sync<R: Runtime>(app: &AppHandle<R>, payload: ClipboardSyncPayload) {
     if let Err(error) = app.emit(CLIPBOARD_SYNC_EVENT, payload) {
@@ -11,4 +13,18 @@ pub fn emit_clipboard_sync<R: Runtime>(app: &AppHandle<R>, payload: ClipboardSyn
             error = error.to_string()
         );
     }
+    refresh_tray_tooltip(app);
+}
+
+pub fn emit_clipboard_watcher_status<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: ClipboardWatcherStatusDto,
+) {
+    if let Err(error) = app.emit(CLIPBOARD_WATCHER_STATUS_EVENT, payload) {
+        tracing::warn!(
+            event = "clipboard_event_emit_failed",
+            event_name = CLIPBOARD_WATCHER_STATUS_EVENT,
+            error = error.to_string()
+        );
+    }
 }
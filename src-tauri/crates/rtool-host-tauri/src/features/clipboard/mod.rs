@@ -1,3 +1,5 @@
 pub(crate) mod api;
 pub(crate) mod events;
+pub(crate) mod paste;
+pub(crate) mod pause;
 pub(crate) mod system_clipboard;
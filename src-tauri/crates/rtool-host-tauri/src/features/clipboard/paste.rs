@@ -0,0 +1,108 @@
+use rtool_contracts::{AppError, AppResult};
+
+#[cfg(any(target_os = "macos", test))]
+use crate::features::clipboard::system_clipboard::escape_applescript_string;
+
+#[cfg(any(target_os = "macos", test))]
+pub(crate) fn build_macos_paste_script(target_app: Option<&str>) -> String {
+    let keystroke = "tell application \"System Events\" to keystroke \"v\" using command down";
+    match target_app.map(str::trim).filter(|value| !value.is_empty()) {
+        Some(app_name) => format!(
+            "tell application \"{}\" to activate\ndelay 0.05\n{keystroke}",
+            escape_applescript_string(app_name)
+        ),
+        None => keystroke.to_string(),
+    }
+}
+
+fn paste_failed(reason: impl Into<String>) -> AppError {
+    AppError::new("clipboard_paste_failed", "模拟粘贴失败").with_context("reason", reason)
+}
+
+#[cfg(target_os = "macos")]
+pub(crate) fn send_paste_keystroke(target_app: Option<&str>) -> AppResult<()> {
+    let output = std::process::Command::new("osascript")
+        .arg("-e")
+        .arg(build_macos_paste_script(target_app))
+        .output()
+        .map_err(|error| paste_failed("osascript_spawn_failed").with_source(error))?;
+    if output.status.success() {
+        return Ok(());
+    }
+
+    Err(paste_failed("osascript_failed")
+        .with_context("exitCode", format!("{:?}", output.status.code())))
+}
+
+#[cfg(target_os = "windows")]
+pub(crate) fn send_paste_keystroke(_target_app: Option<&str>) -> AppResult<()> {
+    use windows_sys::Win32::UI::Input::KeyboardAndMouse::{
+        INPUT, INPUT_0, INPUT_KEYBOARD, KEYBDINPUT, KEYEVENTF_KEYUP, SendInput, VK_CONTROL,
+    };
+
+    const VK_V: u16 = 0x56;
+
+    let key = |virtual_key: u16, flags: u32| INPUT {
+        r#type: INPUT_KEYBOARD,
+        Anonymous: INPUT_0 {
+            ki: KEYBDINPUT {
+                wVk: virtual_key,
+                wScan: 0,
+                dwFlags: flags,
+                time: 0,
+                dwExtraInfo: 0,
+            },
+        },
+    };
+    let inputs = [
+        key(VK_CONTROL, 0),
+        key(VK_V, 0),
+        key(VK_V, KEYEVENTF_KEYUP),
+        key(VK_CONTROL, KEYEVENTF_KEYUP),
+    ];
+    let sent = unsafe {
+        SendInput(
+            inputs.len() as u32,
+            inputs.as_ptr(),
+            std::mem::size_of::<INPUT>() as i32,
+        )
+    };
+    if sent as usize == inputs.len() {
+        return Ok(());
+    }
+
+    Err(paste_failed("send_input_blocked").with_context("sent", sent.to_string()))
+}
+
+#[cfg(all(not(target_os = "macos"), not(target_os = "windows")))]
+pub(crate) fn send_paste_keystroke(_target_app: Option<&str>) -> AppResult<()> {
+    let output = std::process::Command::new("xdotool")
+        .args(["key", "--clearmodifiers", "ctrl+v"])
+        .output()
+        .map_err(|error| paste_failed("xdotool_unavailable").with_source(error))?;
+    if output.status.success() {
+        return Ok(());
+    }
+
+    Err(paste_failed("xdotool_failed")
+        .with_context("exitCode", format!("{:?}", output.status.code())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn macos_paste_script_activates_target_app_first() {
+        let script = build_macos_paste_script(Some("Visual \"Studio\" Code"));
+        assert!(script.starts_with("tell application \"Visual \\\"Studio\\\" Code\" to activate"));
+        assert!(script.ends_with("keystroke \"v\" using command down"));
+    }
+
+    #[test]
+    fn macos_paste_script_skips_activation_without_target() {
+        let script = build_macos_paste_script(Some("  "));
+        assert!(!script.contains("activate"));
+        assert!(script.contains("keystroke \"v\" using command down"));
+    }
+}
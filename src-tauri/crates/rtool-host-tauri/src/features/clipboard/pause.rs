@@ -0,0 +1,121 @@
+use crate::app::state::AppState;
+use crate::constants::{CLIPBOARD_PAUSE_MAX_MINUTES, RUNTIME_WORKER_CLIPBOARD};
+use crate::features::clipboard::events::emit_clipboard_watcher_status;
+use crate::platform::native_ui::tray::refresh_tray_menu;
+use rtool_contracts::models::ClipboardWatcherStatusDto;
+use rtool_contracts::{AppError, AppResult};
+use rtool_kernel::ClipboardPauseState;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Runtime};
+
+pub(crate) fn now_ms() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .ok()
+        .and_then(|value| i64::try_from(value.as_millis()).ok())
+        .unwrap_or_default()
+}
+
+fn resolve_resume_at(paused_at: i64, duration_minutes: Option<u32>) -> AppResult<Option<i64>> {
+    let Some(minutes) = duration_minutes else {
+        return Ok(None);
+    };
+    if minutes == 0 || minutes > CLIPBOARD_PAUSE_MAX_MINUTES {
+        return Err(
+            AppError::new("clipboard_pause_duration_invalid", "暂停时长超出允许范围")
+                .with_context("durationMinutes", minutes.to_string())
+                .with_context("maxMinutes", CLIPBOARD_PAUSE_MAX_MINUTES.to_string()),
+        );
+    }
+    Ok(Some(paused_at + i64::from(minutes) * 60_000))
+}
+
+pub(crate) fn clipboard_watcher_status(state: &AppState) -> ClipboardWatcherStatusDto {
+    let running = state
+        .worker_snapshot()
+        .iter()
+        .any(|status| status.worker == RUNTIME_WORKER_CLIPBOARD && status.running);
+    let pause = state.clipboard_pause(now_ms());
+    ClipboardWatcherStatusDto {
+        running,
+        paused: pause.is_some(),
+        paused_at: pause.map(|value| value.paused_at),
+        resume_at: pause.and_then(|value| value.resume_at),
+    }
+}
+
+fn notify_pause_changed<R: Runtime>(app: &AppHandle<R>, state: &AppState) {
+    refresh_tray_menu(app, &state.resolved_locale());
+    emit_clipboard_watcher_status(app, clipboard_watcher_status(state));
+}
+
+fn schedule_auto_resume<R: Runtime>(
+    app: AppHandle<R>,
+    state: AppState,
+    pause: ClipboardPauseState,
+) {
+    let Some(resume_at) = pause.resume_at else {
+        return;
+    };
+    let delay = Duration::from_millis(u64::try_from(resume_at - pause.paused_at).unwrap_or(0));
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(delay).await;
+        if !state.clear_clipboard_pause_if(pause) {
+            return;
+        }
+        tracing::info!(
+            event = "clipboard_watcher_auto_resumed",
+            paused_at = pause.paused_at
+        );
+        notify_pause_changed(&app, &state);
+    });
+}
+
+pub(crate) fn set_clipboard_paused<R: Runtime>(
+    app: &AppHandle<R>,
+    state: &AppState,
+    paused: bool,
+    duration_minutes: Option<u32>,
+) -> AppResult<ClipboardWatcherStatusDto> {
+    if paused {
+        let paused_at = now_ms();
+        let pause = ClipboardPauseState {
+            paused_at,
+            resume_at: resolve_resume_at(paused_at, duration_minutes)?,
+        };
+        state.set_clipboard_pause(Some(pause));
+        schedule_auto_resume(app.clone(), state.clone(), pause);
+        tracing::info!(
+            event = "clipboard_watcher_paused",
+            duration_minutes = duration_minutes.unwrap_or_default()
+        );
+    } else {
+        state.set_clipboard_pause(None);
+        tracing::info!(event = "clipboard_watcher_resumed");
+    }
+
+    notify_pause_changed(app, state);
+    Ok(clipboard_watcher_status(state))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pause_without_duration_never_expires() {
+        assert_eq!(resolve_resume_at(1_000, None).expect("no duration"), None);
+    }
+
+    #[test]
+    fn pause_duration_is_bounded() {
+        assert_eq!(
+            resolve_resume_at(1_000, Some(5)).expect("five minutes"),
+            Some(1_000 + 5 * 60_000)
+        );
+        for minutes in [0, CLIPBOARD_PAUSE_MAX_MINUTES + 1] {
+            let error = resolve_resume_at(1_000, Some(minutes)).expect_err("out of range");
+            assert_eq!(error.code, "clipboard_pause_duration_invalid");
+        }
+    }
+}
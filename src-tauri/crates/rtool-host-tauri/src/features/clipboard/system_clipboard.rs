@@ -96,7 +96,7 @@ pub(crate) fn ensure_expected_and_actual_file_paths(
 }
 
 #[cfg(any(target_os = "macos", test))]
-fn escape_applescript_string(value: &str) -> String {
+pub(crate) fn escape_applescript_string(value: &str) -> String {
     value.replace('\\', "\\\\").replace('"', "\\\"")
 }
 
@@ -1,16 +1,18 @@
 use std::future::Future;
 
+use crate::features::clipboard::api::copy_clipboard_item_back;
+use crate::features::launcher::undo::LauncherUndoAction;
 use crate::host::launcher::TauriLauncherHost;
 use crate::shared::command_response::CommandPayloadContext;
 use crate::shared::command_runtime::{run_blocking_command, run_command_async};
 use crate::shared::request_context::InvokeMeta;
 use rtool_contracts::models::{
-    ActionResultDto, LauncherActionDto, LauncherUpdateSearchSettingsInputDto,
+    ActionResultDto, LauncherActionDto, LauncherUndoResultDto, LauncherUpdateSearchSettingsInputDto,
 };
-use rtool_contracts::{AppResult, InvokeError};
+use rtool_contracts::{AppError, AppResult, InvokeError};
 use serde::Deserialize;
 use serde_json::Value;
-use tauri::State;
+use tauri::{Manager, State};
 
 use crate::app::state::AppState;
 
@@ -25,6 +27,8 @@ pub(crate) struct LauncherSearchPayload {
 #[serde(rename_all = "camelCase")]
 pub(crate) struct LauncherExecutePayload {
     action: LauncherActionDto,
+    #[serde(default)]
+    item_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -43,6 +47,7 @@ pub(crate) enum LauncherRequest {
     GetStatus,
     RebuildIndex,
     ResetSearchSettings,
+    Undo,
 }
 
 const LAUNCHER_COMMAND_CONTEXT: CommandPayloadContext = CommandPayloadContext::new(
@@ -61,6 +66,7 @@ fn request_kind(request: &LauncherRequest) -> &'static str {
         LauncherRequest::GetStatus => "get_status",
         LauncherRequest::RebuildIndex => "rebuild_index",
         LauncherRequest::ResetSearchSettings => "reset_search_settings",
+        LauncherRequest::Undo => "undo",
     }
 }
 
@@ -73,6 +79,7 @@ fn request_command_name(request: &LauncherRequest) -> &'static str {
         LauncherRequest::GetStatus => "launcher_get_status",
         LauncherRequest::RebuildIndex => "launcher_rebuild_index",
         LauncherRequest::ResetSearchSettings => "launcher_reset_search_settings",
+        LauncherRequest::Undo => "launcher_undo_last_action",
     }
 }
 
@@ -156,37 +163,135 @@ pub(crate) async fn handle_launcher(
     let command_name = request_command_name(&request);
 
     match request {
-        LauncherRequest::Search(payload) => LAUNCHER_COMMAND_CONTEXT.serialize(
-            kind,
-            run_launcher_with_host_async(
-                app,
-                state,
-                request_id,
-                window_label,
-                command_name,
-                move |launcher_service, host| async move {
-                    Ok(launcher_service
-                        .search(&host, &payload.query, payload.limit)
-                        .await)
-                },
+        LauncherRequest::Search(payload) => {
+            let clipboard_service = state.app_services.clipboard.clone();
+            LAUNCHER_COMMAND_CONTEXT.serialize(
+                kind,
+                run_launcher_with_host_async(
+                    app,
+                    state,
+                    request_id,
+                    window_label,
+                    command_name,
+                    move |launcher_service, host| async move {
+                        if let Some(keyword) =
+                            rtool_app::LauncherApplicationService::clipboard_query(&payload.query)
+                        {
+                            return launcher_service
+                                .search_clipboard(
+                                    &clipboard_service,
+                                    &payload.query,
+                                    keyword,
+                                    payload.limit,
+                                )
+                                .await;
+                        }
+                        Ok(launcher_service
+                            .search(&host, &payload.query, payload.limit)
+                            .await)
+                    },
+                )
+                .await?,
             )
-            .await?,
-        ),
-        LauncherRequest::Execute(payload) => LAUNCHER_COMMAND_CONTEXT.serialize(
-            kind,
-            run_launcher_with_host_blocking(
-                app,
-                state,
-                request_id,
-                window_label,
-                command_name,
-                move |launcher_service, host| {
-                    let message = launcher_service.execute(&host, &payload.action)?;
-                    Ok(ActionResultDto { ok: true, message })
-                },
+        }
+        LauncherRequest::Execute(LauncherExecutePayload {
+            action: LauncherActionDto::CopyClipboardItem { item_id },
+            ..
+        }) => {
+            let clipboard_service = state.app_services.clipboard.clone();
+            LAUNCHER_COMMAND_CONTEXT.serialize(
+                kind,
+                run_command_async(command_name, request_id, window_label, move || async move {
+                    let clipboard_plugin = app.state::<tauri_plugin_clipboard::Clipboard>();
+                    copy_clipboard_item_back(
+                        &app,
+                        clipboard_plugin.inner(),
+                        clipboard_service,
+                        item_id,
+                        "launcher_copy",
+                    )
+                    .await?;
+                    Ok::<ActionResultDto, AppError>(ActionResultDto {
+                        ok: true,
+                        message: "已复制到剪贴板".to_string(),
+                    })
+                })
+                .await?,
             )
-            .await?,
-        ),
+        }
+        LauncherRequest::Execute(payload) => {
+            let undo_history = state.launcher_undo.clone();
+            LAUNCHER_COMMAND_CONTEXT.serialize(
+                kind,
+                run_launcher_with_host_blocking(
+                    app,
+                    state,
+                    request_id,
+                    window_label,
+                    command_name,
+                    move |launcher_service, host| {
+                        let window_was_visible = payload
+                            .action
+                            .builtin_window_label()
+                            .map(|label| launcher_service.is_window_visible(&host, label));
+                        let previous_route = undo_history.main_route();
+                        let message = launcher_service.execute(&host, &payload.action)?;
+                        if let Some(inverse_action) = window_was_visible.and_then(|visible| {
+                            LauncherUndoAction::for_action(
+                                &payload.action,
+                                visible,
+                                previous_route.as_deref(),
+                            )
+                        }) {
+                            undo_history.record(message.clone(), inverse_action);
+                        }
+                        if let Some(item_id) = payload.item_id {
+                            tauri::async_runtime::spawn(async move {
+                                if let Err(error) = launcher_service.record_launch(&item_id).await {
+                                    tracing::warn!(
+                                        event = "launcher_history_record_failed",
+                                        item_id = item_id,
+                                        error = error.to_string()
+                                    );
+                                }
+                            });
+                        }
+                        Ok(ActionResultDto { ok: true, message })
+                    },
+                )
+                .await?,
+            )
+        }
+        LauncherRequest::Undo => {
+            let undo_history = state.launcher_undo.clone();
+            LAUNCHER_COMMAND_CONTEXT.serialize(
+                kind,
+                run_launcher_with_host_blocking(
+                    app,
+                    state,
+                    request_id,
+                    window_label,
+                    command_name,
+                    move |launcher_service, host| {
+                        let entry = undo_history.pop().ok_or_else(|| {
+                            AppError::new("launcher_undo_empty", "没有可撤销的操作")
+                        })?;
+                        let message = match entry.inverse_action {
+                            LauncherUndoAction::HideWindow { window_label } => {
+                                launcher_service.hide_window(&host, &window_label)?
+                            }
+                            LauncherUndoAction::RestoreRoute { route } => launcher_service
+                                .execute(&host, &LauncherActionDto::OpenBuiltinRoute { route })?,
+                        };
+                        Ok(LauncherUndoResultDto {
+                            undone_action_id: entry.action_id,
+                            message,
+                        })
+                    },
+                )
+                .await?,
+            )
+        }
         LauncherRequest::GetSearchSettings => LAUNCHER_COMMAND_CONTEXT.serialize(
             kind,
             run_launcher_async(
@@ -269,6 +374,7 @@ mod tests {
                 action: LauncherActionDto::OpenBuiltinRoute {
                     route: "/tools".to_string(),
                 },
+                item_id: None,
             })),
             "execute"
         );
@@ -293,6 +399,7 @@ mod tests {
             request_kind(&LauncherRequest::ResetSearchSettings),
             "reset_search_settings"
         );
+        assert_eq!(request_kind(&LauncherRequest::Undo), "undo");
     }
 
     #[test]
@@ -309,6 +416,7 @@ mod tests {
                 action: LauncherActionDto::OpenBuiltinRoute {
                     route: "/settings".to_string(),
                 },
+                item_id: None,
             })),
             "launcher_execute"
         );
@@ -336,5 +444,9 @@ mod tests {
             request_command_name(&LauncherRequest::ResetSearchSettings),
             "launcher_reset_search_settings"
         );
+        assert_eq!(
+            request_command_name(&LauncherRequest::Undo),
+            "launcher_undo_last_action"
+        );
     }
 }
@@ -0,0 +1,175 @@
+use rtool_contracts::models::LauncherActionDto;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+const LAUNCHER_UNDO_HISTORY_LIMIT: usize = 20;
+
+/// Internal inverse of a launcher action; never accepted from the frontend.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum LauncherUndoAction {
+    HideWindow { window_label: String },
+    RestoreRoute { route: String },
+}
+
+impl LauncherUndoAction {
+    /// Reopening an already visible window leaves nothing to hide; a route change
+    /// on a visible main window is reverted by navigating back to the previous route.
+    pub(crate) fn for_action(
+        action: &LauncherActionDto,
+        window_was_visible: bool,
+        previous_route: Option<&str>,
+    ) -> Option<Self> {
+        let window_label = action.builtin_window_label()?;
+        if !window_was_visible {
+            return Some(Self::HideWindow {
+                window_label: window_label.to_string(),
+            });
+        }
+
+        let route = action.builtin_route()?;
+        previous_route
+            .filter(|previous| *previous != route)
+            .map(|previous| Self::RestoreRoute {
+                route: previous.to_string(),
+            })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct LauncherUndoEntry {
+    pub action_id: String,
+    pub inverse_action: LauncherUndoAction,
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct LauncherUndoHistory {
+    entries: Arc<Mutex<VecDeque<LauncherUndoEntry>>>,
+    main_route: Arc<Mutex<Option<String>>>,
+}
+
+impl LauncherUndoHistory {
+    pub(crate) fn record(&self, action_id: String, inverse_action: LauncherUndoAction) {
+        let mut guard = match self.entries.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        if guard.len() >= LAUNCHER_UNDO_HISTORY_LIMIT {
+            guard.pop_front();
+        }
+        guard.push_back(LauncherUndoEntry {
+            action_id,
+            inverse_action,
+        });
+    }
+
+    pub(crate) fn pop(&self) -> Option<LauncherUndoEntry> {
+        match self.entries.lock() {
+            Ok(mut guard) => guard.pop_back(),
+            Err(poisoned) => poisoned.into_inner().pop_back(),
+        }
+    }
+
+    pub(crate) fn set_main_route(&self, route: String) {
+        match self.main_route.lock() {
+            Ok(mut guard) => *guard = Some(route),
+            Err(poisoned) => *poisoned.into_inner() = Some(route),
+        }
+    }
+
+    pub(crate) fn main_route(&self) -> Option<String> {
+        match self.main_route.lock() {
+            Ok(guard) => guard.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hide(window_label: &str) -> LauncherUndoAction {
+        LauncherUndoAction::HideWindow {
+            window_label: window_label.to_string(),
+        }
+    }
+
+    #[test]
+    fn inverse_hides_only_windows_that_were_hidden_before() {
+        let open_clipboard = LauncherActionDto::OpenBuiltinWindow {
+            window_label: "clipboard_history".to_string(),
+        };
+        assert_eq!(
+            LauncherUndoAction::for_action(&open_clipboard, false, None),
+            Some(hide("clipboard_history"))
+        );
+        assert_eq!(
+            LauncherUndoAction::for_action(&open_clipboard, true, Some("/tools")),
+            None
+        );
+
+        let open_path = LauncherActionDto::OpenFile {
+            path: "/tmp/a.txt".to_string(),
+        };
+        assert_eq!(
+            LauncherUndoAction::for_action(&open_path, false, None),
+            None
+        );
+    }
+
+    #[test]
+    fn inverse_restores_previous_route_on_visible_main_window() {
+        let open_tool = LauncherActionDto::OpenBuiltinTool {
+            tool_id: "json".to_string(),
+        };
+        assert_eq!(
+            LauncherUndoAction::for_action(&open_tool, false, Some("/settings")),
+            Some(hide("main"))
+        );
+        assert_eq!(
+            LauncherUndoAction::for_action(&open_tool, true, Some("/settings")),
+            Some(LauncherUndoAction::RestoreRoute {
+                route: "/settings".to_string()
+            })
+        );
+        assert_eq!(
+            LauncherUndoAction::for_action(&open_tool, true, Some("/tools/json")),
+            None
+        );
+        assert_eq!(LauncherUndoAction::for_action(&open_tool, true, None), None);
+    }
+
+    #[test]
+    fn history_pops_latest_first() {
+        let history = LauncherUndoHistory::default();
+        history.record("route:/tools".to_string(), hide("main"));
+        history.record(
+            "window:clipboard_history".to_string(),
+            hide("clipboard_history"),
+        );
+
+        let latest = history.pop().expect("latest entry");
+        assert_eq!(latest.action_id, "window:clipboard_history");
+        assert_eq!(latest.inverse_action, hide("clipboard_history"));
+        let previous = history.pop().expect("previous entry");
+        assert_eq!(previous.action_id, "route:/tools");
+        assert!(history.pop().is_none());
+    }
+
+    #[test]
+    fn record_drops_oldest_entry_beyond_limit() {
+        let history = LauncherUndoHistory::default();
+        for index in 0..(LAUNCHER_UNDO_HISTORY_LIMIT + 3) {
+            history.record(format!("route:{index}"), hide("main"));
+        }
+
+        let mut count = 0;
+        let mut last_id = String::new();
+        while let Some(entry) = history.pop() {
+            count += 1;
+            last_id = entry.action_id;
+        }
+        assert_eq!(count, LAUNCHER_UNDO_HISTORY_LIMIT);
+        assert_eq!(last_id, "route:3");
+    }
+}
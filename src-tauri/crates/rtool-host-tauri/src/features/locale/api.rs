@@ -1,14 +1,22 @@
 use crate::app::state::AppState;
 use crate::shared::command_response::CommandPayloadContext;
-use crate::shared::command_runtime::run_command_async;
+use crate::shared::command_runtime::{run_command_async, run_command_sync};
 use crate::shared::request_context::InvokeMeta;
 use rtool_app::{LocaleApplicationService, LocaleStateDto};
+use rtool_contracts::models::{
+    AppExportLocaleResultDto, AppImportLocaleResultDto, I18nLocaleStatsDto,
+};
 use rtool_contracts::{AppError, InvokeError};
 use serde::Deserialize;
 use serde_json::Value;
-use tauri::{AppHandle, Emitter, State};
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use tauri::{AppHandle, Emitter, Manager, State};
+use tokio::sync::Mutex;
 
 const LOCALE_SYNC_EVENT: &str = "rtool://settings/locale_sync";
+const LOCALE_IMPORT_SUCCEEDED_EVENT: &str = "rtool://settings/locale_import_succeeded";
+const LOCALE_IMPORT_FAILED_EVENT: &str = "rtool://settings/locale_import_failed";
 
 const LOCALE_COMMAND_CONTEXT: CommandPayloadContext = CommandPayloadContext::new(
     "locale",
@@ -17,6 +25,12 @@ const LOCALE_COMMAND_CONTEXT: CommandPayloadContext = CommandPayloadContext::new
     "未知语言命令",
 );
 
+/// Serializes locale changes so persistence, runtime state and native UI stay in step.
+pub(crate) fn locale_update_lock() -> &'static Mutex<()> {
+    static STORE: OnceLock<Mutex<()>> = OnceLock::new();
+    STORE.get_or_init(|| Mutex::new(()))
+}
+
 async fn app_get_locale(
     state: State<'_, AppState>,
     request_id: Option<String>,
@@ -28,6 +42,7 @@ async fn app_get_locale(
         window_label,
         move || async move {
             let locale_service = LocaleApplicationService;
+            let _guard = locale_update_lock().lock().await;
             let settings = state.app_services.settings.load_or_init().await?;
             let preference = locale_service
                 .normalize_preference(settings.locale.preference.as_str())
@@ -63,11 +78,13 @@ async fn app_set_locale(
                         .with_context("preference", preference.clone())
                 })?;
 
+            let _guard = locale_update_lock().lock().await;
             state
                 .app_services
                 .settings
                 .update_locale_preference(canonical_preference.as_str())
                 .await?;
+            // "system" is resolved again here so an OS locale change since launch is picked up.
             let resolved = locale_service.resolve(canonical_preference.as_str());
             let next = state.update_locale(canonical_preference, resolved.clone());
             crate::platform::native_ui::apply_locale_to_native_ui(&app, &resolved);
@@ -80,17 +97,207 @@ async fn app_set_locale(
     .await
 }
 
+async fn app_format_bytes(
+    state: State<'_, AppState>,
+    bytes: u64,
+    request_id: Option<String>,
+    window_label: Option<String>,
+) -> Result<String, InvokeError> {
+    run_command_async(
+        "app_format_bytes",
+        request_id,
+        window_label,
+        move || async move {
+            let settings = state.app_services.settings.load_or_init().await?;
+            let formatted = LocaleApplicationService.format_bytes(
+                state.resolved_locale().as_str(),
+                bytes,
+                settings.locale.byte_unit.as_str(),
+            );
+            Ok::<_, AppError>(formatted)
+        },
+    )
+    .await
+}
+
+fn app_format_number(
+    state: State<'_, AppState>,
+    value: i64,
+    request_id: Option<String>,
+    window_label: Option<String>,
+) -> Result<String, InvokeError> {
+    run_command_sync("app_format_number", request_id, window_label, move || {
+        Ok::<_, AppError>(
+            LocaleApplicationService.format_number(state.resolved_locale().as_str(), value),
+        )
+    })
+}
+
+async fn app_export_locale(
+    app: AppHandle,
+    locale: String,
+    output_path: Option<String>,
+    request_id: Option<String>,
+    window_label: Option<String>,
+) -> Result<AppExportLocaleResultDto, InvokeError> {
+    run_command_async(
+        "app_export_locale",
+        request_id,
+        window_label,
+        move || async move {
+            let locale = locale.trim().to_string();
+            let output_path = match output_path.filter(|value| !value.trim().is_empty()) {
+                Some(value) => PathBuf::from(value),
+                None => app
+                    .path()
+                    .download_dir()
+                    .map_err(|error| {
+                        AppError::new("i18n_locale_export_dir_unavailable", "无法访问下载目录")
+                            .with_source(error)
+                    })?
+                    .join(format!("rtool-locale-{locale}.json")),
+            };
+            LocaleApplicationService.export_locale(locale.as_str(), output_path.as_path())
+        },
+    )
+    .await
+}
+
+async fn i18n_get_locale_stats(
+    locale: String,
+    request_id: Option<String>,
+    window_label: Option<String>,
+) -> Result<I18nLocaleStatsDto, InvokeError> {
+    run_command_async(
+        "i18n_get_locale_stats",
+        request_id,
+        window_label,
+        move || async move { LocaleApplicationService.locale_stats(locale.as_str()) },
+    )
+    .await
+}
+
+async fn i18n_list_locale_stats(
+    request_id: Option<String>,
+    window_label: Option<String>,
+) -> Result<Vec<I18nLocaleStatsDto>, InvokeError> {
+    run_command_async(
+        "i18n_list_locale_stats",
+        request_id,
+        window_label,
+        move || async move { Ok::<_, AppError>(LocaleApplicationService.list_locale_stats()) },
+    )
+    .await
+}
+
+async fn app_import_locale_file(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    payload: ImportLocalePayload,
+    request_id: Option<String>,
+    window_label: Option<String>,
+) -> Result<AppImportLocaleResultDto, InvokeError> {
+    run_command_async(
+        "app_import_locale_file",
+        request_id,
+        window_label,
+        move || async move {
+            let _guard = locale_update_lock().lock().await;
+            let result = app
+                .path()
+                .app_data_dir()
+                .map_err(|error| {
+                    AppError::new("i18n_locale_import_dir_unavailable", "无法访问应用数据目录")
+                        .with_source(error)
+                })
+                .and_then(|app_data_dir| {
+                    LocaleApplicationService.import_locale_file(
+                        app_data_dir.as_path(),
+                        payload.locale.trim(),
+                        payload.namespace.as_deref(),
+                        PathBuf::from(payload.file_path.trim()).as_path(),
+                    )
+                });
+
+            match &result {
+                Ok(imported) => {
+                    crate::platform::native_ui::apply_locale_to_native_ui(
+                        &app,
+                        &state.resolved_locale(),
+                    );
+                    if let Err(error) = app.emit(LOCALE_SYNC_EVENT, state.locale_snapshot()) {
+                        tracing::warn!(event = "locale_sync_emit_failed", detail = %error);
+                    }
+                    if let Err(error) = app.emit(LOCALE_IMPORT_SUCCEEDED_EVENT, imported) {
+                        tracing::warn!(event = "locale_import_emit_failed", detail = %error);
+                    }
+                }
+                Err(error) => {
+                    let payload = serde_json::json!({
+                        "code": error.code,
+                        "message": error.message,
+                    });
+                    if let Err(emit_error) = app.emit(LOCALE_IMPORT_FAILED_EVENT, payload) {
+                        tracing::warn!(event = "locale_import_emit_failed", detail = %emit_error);
+                    }
+                }
+            }
+            result
+        },
+    )
+    .await
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct SetLocalePayload {
     preference: String,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct FormatBytesPayload {
+    bytes: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct FormatNumberPayload {
+    value: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ExportLocalePayload {
+    locale: String,
+    output_path: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ImportLocalePayload {
+    locale: String,
+    namespace: Option<String>,
+    file_path: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct LocaleStatsPayload {
+    locale: String,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(tag = "kind", content = "payload", rename_all = "snake_case")]
 pub(crate) enum LocaleRequest {
     Get,
     Set(SetLocalePayload),
+    FormatBytes(FormatBytesPayload),
+    FormatNumber(FormatNumberPayload),
+    Export(ExportLocalePayload),
+    Import(ImportLocalePayload),
+    Stats(LocaleStatsPayload),
+    ListStats,
 }
 
 pub(crate) async fn handle_locale(
@@ -110,5 +317,36 @@ pub(crate) async fn handle_locale(
             "set",
             app_set_locale(app, state, payload.preference, request_id, window_label).await?,
         ),
+        LocaleRequest::FormatBytes(payload) => LOCALE_COMMAND_CONTEXT.serialize(
+            "format_bytes",
+            app_format_bytes(state, payload.bytes, request_id, window_label).await?,
+        ),
+        LocaleRequest::FormatNumber(payload) => LOCALE_COMMAND_CONTEXT.serialize(
+            "format_number",
+            app_format_number(state, payload.value, request_id, window_label)?,
+        ),
+        LocaleRequest::Export(payload) => LOCALE_COMMAND_CONTEXT.serialize(
+            "export",
+            app_export_locale(
+                app,
+                payload.locale,
+                payload.output_path,
+                request_id,
+                window_label,
+            )
+            .await?,
+        ),
+        LocaleRequest::Import(payload) => LOCALE_COMMAND_CONTEXT.serialize(
+            "import",
+            app_import_locale_file(app, state, payload, request_id, window_label).await?,
+        ),
+        LocaleRequest::Stats(payload) => LOCALE_COMMAND_CONTEXT.serialize(
+            "stats",
+            i18n_get_locale_stats(payload.locale, request_id, window_label).await?,
+        ),
+        LocaleRequest::ListStats => LOCALE_COMMAND_CONTEXT.serialize(
+            "list_stats",
+            i18n_list_locale_stats(request_id, window_label).await?,
+        ),
     }
 }
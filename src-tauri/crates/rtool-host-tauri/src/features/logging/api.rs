@@ -1,9 +1,9 @@
 use crate::shared::command_response::CommandPayloadContext;
-use crate::shared::command_runtime::{run_command_async, run_command_sync};
+use crate::shared::command_runtime::{run_blocking_command, run_command_async, run_command_sync};
 use crate::shared::request_context::InvokeMeta;
 use rtool_app::LoggingApplicationService;
 use rtool_contracts::InvokeError;
-use rtool_contracts::models::{LogConfigDto, LogPageDto, LogQueryDto};
+use rtool_contracts::models::{LogConfigDto, LogExportResultDto, LogPageDto, LogQueryDto};
 use serde::Deserialize;
 use serde_json::Value;
 
@@ -66,7 +66,7 @@ async fn logging_export_jsonl(
     output_path: Option<String>,
     request_id: Option<String>,
     window_label: Option<String>,
-) -> Result<String, InvokeError> {
+) -> Result<LogExportResultDto, InvokeError> {
     let normalized = query.unwrap_or_default();
     let service = LoggingApplicationService;
     run_command_async(
@@ -78,6 +78,40 @@ async fn logging_export_jsonl(
     .await
 }
 
+async fn logging_export_cbor(
+    query: Option<LogQueryDto>,
+    output_path: Option<String>,
+    request_id: Option<String>,
+    window_label: Option<String>,
+) -> Result<LogExportResultDto, InvokeError> {
+    let normalized = query.unwrap_or_default();
+    let service = LoggingApplicationService;
+    run_command_async(
+        "logging_export_cbor",
+        request_id,
+        window_label,
+        move || async move { service.export_cbor(normalized, output_path).await },
+    )
+    .await
+}
+
+async fn logging_convert_cbor_to_jsonl(
+    cbor_path: String,
+    output_path: String,
+    request_id: Option<String>,
+    window_label: Option<String>,
+) -> Result<String, InvokeError> {
+    let service = LoggingApplicationService;
+    run_blocking_command(
+        "logging_convert_cbor_to_jsonl",
+        request_id,
+        window_label,
+        "logging_convert_cbor_to_jsonl",
+        move || service.convert_cbor_to_jsonl(cbor_path, output_path),
+    )
+    .await
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct ClientLogPayload {
@@ -107,6 +141,13 @@ pub(crate) struct LoggingExportPayload {
     output_path: Option<String>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct LoggingConvertCborPayload {
+    cbor_path: String,
+    output_path: String,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(tag = "kind", content = "payload", rename_all = "snake_case")]
 pub(crate) enum LoggingRequest {
@@ -115,6 +156,8 @@ pub(crate) enum LoggingRequest {
     GetConfig,
     UpdateConfig(LoggingConfigPayload),
     ExportJsonl(LoggingExportPayload),
+    ExportCbor(LoggingExportPayload),
+    ConvertCborToJsonl(LoggingConvertCborPayload),
 }
 
 const LOGGING_COMMAND_CONTEXT: CommandPayloadContext = CommandPayloadContext::new(
@@ -159,5 +202,20 @@ pub(crate) async fn handle_logging(
             logging_export_jsonl(payload.query, payload.output_path, request_id, window_label)
                 .await?,
         ),
+        LoggingRequest::ExportCbor(payload) => LOGGING_COMMAND_CONTEXT.serialize(
+            "export_cbor",
+            logging_export_cbor(payload.query, payload.output_path, request_id, window_label)
+                .await?,
+        ),
+        LoggingRequest::ConvertCborToJsonl(payload) => LOGGING_COMMAND_CONTEXT.serialize(
+            "convert_cbor_to_jsonl",
+            logging_convert_cbor_to_jsonl(
+                payload.cbor_path,
+                payload.output_path,
+                request_id,
+                window_label,
+            )
+            .await?,
+        ),
     }
 }
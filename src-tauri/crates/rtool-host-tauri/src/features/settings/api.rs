@@ -4,12 +4,17 @@ use crate::shared::command_response::CommandPayloadContext;
 use crate::shared::command_runtime::run_command_async;
 use crate::shared::request_context::InvokeMeta;
 use rtool_app::LocaleApplicationService;
-use rtool_contracts::models::{ClipboardSyncPayload, SettingsDto, SettingsUpdateInputDto};
+use rtool_contracts::models::{
+    ClipboardSyncPayload, DbMaintenanceResultDto, SettingsDto, SettingsExportResultDto,
+    SettingsImportResultDto, SettingsUpdateInputDto, ShortcutStatusDto,
+};
 use rtool_contracts::{AppError, InvokeError};
 use serde::Deserialize;
 use serde_json::Value;
 use tauri::{AppHandle, Emitter, State};
 
+use super::bundle::{export_settings_bundle, import_settings_bundle};
+
 const SETTINGS_SYNC_EVENT: &str = "rtool://settings/sync";
 
 const SETTINGS_COMMAND_CONTEXT: CommandPayloadContext = CommandPayloadContext::new(
@@ -49,6 +54,110 @@ async fn app_get_settings(
     .await
 }
 
+pub(super) async fn apply_settings_update(
+    app: &AppHandle,
+    state: &AppState,
+    input: SettingsUpdateInputDto,
+) -> Result<SettingsDto, AppError> {
+    let mut normalized_input = input;
+    normalize_screenshot_shortcut_update(&mut normalized_input)?;
+
+    // Held from the snapshot through the native UI update so locale writes cannot interleave.
+    let locale_guard = match normalized_input.locale {
+        Some(_) => Some(
+            crate::features::locale::api::locale_update_lock()
+                .lock()
+                .await,
+        ),
+        None => None,
+    };
+    let previous_locale = state.locale_snapshot();
+    let previous_settings = state.app_services.settings.load_or_init().await?;
+    let previous_screenshot_shortcut = previous_settings.screenshot.shortcut.clone();
+    let requested_screenshot_shortcut = normalized_input
+        .screenshot
+        .as_ref()
+        .and_then(|value| value.shortcut.as_ref())
+        .cloned();
+
+    let mut rebound_shortcut: Option<(String, String)> = None;
+    if let Some(next_shortcut) = requested_screenshot_shortcut
+        && next_shortcut != previous_screenshot_shortcut
+    {
+        crate::platform::native_ui::shortcuts::rebind_screenshot_shortcut(
+            app,
+            previous_screenshot_shortcut.as_str(),
+            next_shortcut.as_str(),
+        )?;
+        rebound_shortcut = Some((previous_screenshot_shortcut.clone(), next_shortcut));
+    }
+
+    let settings = match state.app_services.settings.update(normalized_input).await {
+        Ok(value) => value,
+        Err(error) => {
+            if let Some((previous_shortcut, applied_shortcut)) = rebound_shortcut
+                && let Err(rebind_error) =
+                    crate::platform::native_ui::shortcuts::rebind_screenshot_shortcut(
+                        app,
+                        applied_shortcut.as_str(),
+                        previous_shortcut.as_str(),
+                    )
+            {
+                tracing::warn!(
+                    event = "screenshot_shortcut_rollback_failed",
+                    previous_shortcut,
+                    applied_shortcut,
+                    error = rebind_error.to_string()
+                );
+            }
+            return Err(error);
+        }
+    };
+    crate::platform::native_ui::apply_window_chrome(
+        app,
+        settings.theme.transparent_window_background,
+    );
+
+    if previous_locale.preference != settings.locale.preference {
+        let resolved = LocaleApplicationService.resolve(settings.locale.preference.as_str());
+        state.update_locale(settings.locale.preference.clone(), resolved.clone());
+        crate::platform::native_ui::apply_locale_to_native_ui(app, &resolved);
+    }
+    drop(locale_guard);
+
+    let clipboard_update = state
+        .app_services
+        .clipboard
+        .apply_settings(&settings.clipboard)
+        .await?;
+    if !clipboard_update.removed_ids.is_empty() {
+        emit_clipboard_sync(
+            app,
+            ClipboardSyncPayload {
+                upsert: Vec::new(),
+                removed_ids: clipboard_update.removed_ids,
+                clear_all: false,
+                reason: Some("settings_clipboard_prune".to_string()),
+            },
+        );
+    }
+
+    state.window_auto_hide.update(settings.window_auto_hide);
+
+    if previous_settings.tray != settings.tray {
+        crate::platform::native_ui::tray::refresh_tray_tooltip(app);
+    }
+
+    if let Err(error) = app.emit(SETTINGS_SYNC_EVENT, settings.clone()) {
+        tracing::warn!(
+            event = "settings_sync_emit_failed",
+            detail = %error
+        );
+    }
+
+    Ok(settings)
+}
+
 async fn app_update_settings(
     app: AppHandle,
     state: State<'_, AppState>,
@@ -60,105 +169,110 @@ async fn app_update_settings(
         "app_update_settings",
         request_id,
         window_label,
-        move || async move {
-            let mut normalized_input = input;
-            normalize_screenshot_shortcut_update(&mut normalized_input)?;
-
-            let previous_locale = state.locale_snapshot();
-            let previous_settings = state.app_services.settings.load_or_init().await?;
-            let previous_screenshot_shortcut = previous_settings.screenshot.shortcut.clone();
-            let requested_screenshot_shortcut = normalized_input
-                .screenshot
-                .as_ref()
-                .and_then(|value| value.shortcut.as_ref())
-                .cloned();
-
-            let mut rebound_shortcut: Option<(String, String)> = None;
-            if let Some(next_shortcut) = requested_screenshot_shortcut
-                && next_shortcut != previous_screenshot_shortcut
-            {
-                crate::platform::native_ui::shortcuts::rebind_screenshot_shortcut(
-                    &app,
-                    previous_screenshot_shortcut.as_str(),
-                    next_shortcut.as_str(),
-                )?;
-                rebound_shortcut = Some((previous_screenshot_shortcut.clone(), next_shortcut));
-            }
-
-            let settings = match state.app_services.settings.update(normalized_input).await {
-                Ok(value) => value,
-                Err(error) => {
-                    if let Some((previous_shortcut, applied_shortcut)) = rebound_shortcut
-                        && let Err(rebind_error) =
-                            crate::platform::native_ui::shortcuts::rebind_screenshot_shortcut(
-                                &app,
-                                applied_shortcut.as_str(),
-                                previous_shortcut.as_str(),
-                            )
-                    {
-                        tracing::warn!(
-                            event = "screenshot_shortcut_rollback_failed",
-                            previous_shortcut,
-                            applied_shortcut,
-                            error = rebind_error.to_string()
-                        );
-                    }
-                    return Err(error);
-                }
-            };
-            crate::platform::native_ui::apply_window_chrome(
-                &app,
-                settings.theme.transparent_window_background,
-            );
-
-            if previous_locale.preference != settings.locale.preference {
-                let resolved =
-                    LocaleApplicationService.resolve(settings.locale.preference.as_str());
-                state.update_locale(settings.locale.preference.clone(), resolved.clone());
-                crate::platform::native_ui::apply_locale_to_native_ui(&app, &resolved);
-            }
+        move || async move { apply_settings_update(&app, state.inner(), input).await },
+    )
+    .await
+}
 
-            let clipboard_update = state
-                .app_services
-                .clipboard
-                .apply_settings(&settings.clipboard)
-                .await?;
-            if !clipboard_update.removed_ids.is_empty() {
-                emit_clipboard_sync(
-                    &app,
-                    ClipboardSyncPayload {
-                        upsert: Vec::new(),
-                        removed_ids: clipboard_update.removed_ids,
-                        clear_all: false,
-                        reason: Some("settings_clipboard_prune".to_string()),
-                    },
-                );
-            }
+async fn settings_export_all(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    output_path: Option<String>,
+    request_id: Option<String>,
+    window_label: Option<String>,
+) -> Result<SettingsExportResultDto, InvokeError> {
+    run_command_async(
+        "settings_export_all",
+        request_id,
+        window_label,
+        move || async move { export_settings_bundle(&app, state.inner(), output_path).await },
+    )
+    .await
+}
 
-            if let Err(error) = app.emit(SETTINGS_SYNC_EVENT, settings.clone()) {
-                tracing::warn!(
-                    event = "settings_sync_emit_failed",
-                    detail = %error
-                );
-            }
+async fn settings_import_all(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    input_path: String,
+    request_id: Option<String>,
+    window_label: Option<String>,
+) -> Result<SettingsImportResultDto, InvokeError> {
+    run_command_async(
+        "settings_import_all",
+        request_id,
+        window_label,
+        move || async move { import_settings_bundle(&app, state.inner(), input_path).await },
+    )
+    .await
+}
 
-            Ok::<SettingsDto, AppError>(settings)
+async fn settings_shortcuts_status(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    request_id: Option<String>,
+    window_label: Option<String>,
+) -> Result<Vec<ShortcutStatusDto>, InvokeError> {
+    let settings_service = state.app_services.settings.clone();
+    run_command_async(
+        "settings_shortcuts_status",
+        request_id,
+        window_label,
+        move || async move {
+            let settings = settings_service.load_or_init().await?;
+            Ok::<_, AppError>(crate::platform::native_ui::shortcuts::shortcuts_status(
+                &app,
+                &state.shortcut_bootstrap,
+                settings.screenshot.shortcut.as_str(),
+            ))
         },
     )
     .await
 }
 
+async fn settings_db_maintenance(
+    state: State<'_, AppState>,
+    request_id: Option<String>,
+    window_label: Option<String>,
+) -> Result<DbMaintenanceResultDto, InvokeError> {
+    let maintenance_service = state.app_services.maintenance.clone();
+    let db_path = state.db_path.clone();
+    run_command_async(
+        "settings_db_maintenance",
+        request_id,
+        window_label,
+        move || async move { maintenance_service.vacuum_database(db_path.as_path()).await },
+    )
+    .await
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct UpdateSettingsPayload {
     input: SettingsUpdateInputDto,
 }
 
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub(crate) struct ExportAllSettingsPayload {
+    output_path: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ImportAllSettingsPayload {
+    input_path: String,
+}
+
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, Deserialize)]
 #[serde(tag = "kind", content = "payload", rename_all = "snake_case")]
 pub(crate) enum SettingsRequest {
     Get,
     Update(UpdateSettingsPayload),
+    ExportAll(ExportAllSettingsPayload),
+    ImportAll(ImportAllSettingsPayload),
+    ShortcutsStatus,
+    DbMaintenance,
 }
 
 pub(crate) async fn handle_settings(
@@ -178,5 +292,21 @@ pub(crate) async fn handle_settings(
             "update",
             app_update_settings(app, state, payload.input, request_id, window_label).await?,
         ),
+        SettingsRequest::ExportAll(payload) => SETTINGS_COMMAND_CONTEXT.serialize(
+            "export_all",
+            settings_export_all(app, state, payload.output_path, request_id, window_label).await?,
+        ),
+        SettingsRequest::ImportAll(payload) => SETTINGS_COMMAND_CONTEXT.serialize(
+            "import_all",
+            settings_import_all(app, state, payload.input_path, request_id, window_label).await?,
+        ),
+        SettingsRequest::ShortcutsStatus => SETTINGS_COMMAND_CONTEXT.serialize(
+            "shortcuts_status",
+            settings_shortcuts_status(app, state, request_id, window_label).await?,
+        ),
+        SettingsRequest::DbMaintenance => SETTINGS_COMMAND_CONTEXT.serialize(
+            "db_maintenance",
+            settings_db_maintenance(state, request_id, window_label).await?,
+        ),
     }
 }
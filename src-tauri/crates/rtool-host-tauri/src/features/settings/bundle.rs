@@ -0,0 +1,389 @@
+use crate::app::state::AppState;
+use anyhow::Context;
+use rtool_contracts::models::{
+    AppManagerSettingsDto, AppManagerSettingsUpdateInputDto, LauncherSearchSettingsDto,
+    LauncherUpdateSearchSettingsInputDto, LayoutSettingsDto, LayoutSettingsUpdateInputDto,
+    LocaleSettingsDto, LocaleSettingsUpdateInputDto, LogConfigDto, SettingsBundleDto,
+    SettingsClipboardDto, SettingsClipboardUpdateInputDto, SettingsExportResultDto,
+    SettingsImportResultDto, SettingsImportSectionResultDto, SettingsScreenshotDto,
+    SettingsScreenshotUpdateInputDto, SettingsUpdateInputDto, ThemeSettingsDto,
+    ThemeSettingsUpdateInputDto, TraySettingsDto, TraySettingsUpdateInputDto,
+    WindowAutoHideSettingsDto, WindowAutoHideSettingsUpdateInputDto,
+};
+use rtool_contracts::{AppError, AppResult, ResultExt};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager};
+
+use super::api::apply_settings_update;
+
+const SETTINGS_BUNDLE_VERSION: u32 = 1;
+const SETTINGS_EXPORT_DIR_NAME: &str = "settings_exports";
+
+fn now_ms() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .ok()
+        .and_then(|value| i64::try_from(value.as_millis()).ok())
+        .unwrap_or_default()
+}
+
+fn to_section_value<T: Serialize>(section: &str, value: &T) -> AppResult<Value> {
+    serde_json::to_value(value)
+        .with_context(|| format!("序列化设置分组失败: section={section}"))
+        .with_code("settings_export_serialize_failed", "序列化设置失败")
+        .with_ctx("section", section)
+}
+
+fn parse_section<T: DeserializeOwned>(section: &str, value: Value) -> AppResult<T> {
+    serde_json::from_value(value)
+        .with_context(|| format!("解析设置分组失败: section={section}"))
+        .with_code("settings_import_section_invalid", "设置分组格式无效")
+        .with_ctx("section", section)
+}
+
+fn section_result(section: &str, result: AppResult<()>) -> SettingsImportSectionResultDto {
+    match result {
+        Ok(()) => SettingsImportSectionResultDto {
+            section: section.to_string(),
+            ok: true,
+            error_code: None,
+            message: None,
+        },
+        Err(error) => {
+            tracing::warn!(
+                event = "settings_import_section_failed",
+                section,
+                error_code = error.code.as_str(),
+                error = error.to_string()
+            );
+            SettingsImportSectionResultDto {
+                section: section.to_string(),
+                ok: false,
+                error_code: Some(error.code.clone()),
+                message: Some(error.message.clone()),
+            }
+        }
+    }
+}
+
+fn resolve_export_path(app: &AppHandle, output_path: Option<String>) -> AppResult<PathBuf> {
+    if let Some(path) = output_path
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    {
+        return Ok(PathBuf::from(path));
+    }
+
+    let app_data_dir = app.path().app_data_dir().map_err(|error| {
+        AppError::new("settings_export_dir_unavailable", "无法访问设置导出目录").with_source(error)
+    })?;
+    Ok(app_data_dir
+        .join(SETTINGS_EXPORT_DIR_NAME)
+        .join(format!("rtool-settings-{}.json", now_ms())))
+}
+
+pub(super) async fn export_settings_bundle(
+    app: &AppHandle,
+    state: &AppState,
+    output_path: Option<String>,
+) -> AppResult<SettingsExportResultDto> {
+    let settings = state.app_services.settings.load_or_init().await?;
+    let logging = state.app_services.logging.get_config()?;
+    let launcher_search = state.app_services.launcher.get_search_settings().await?;
+
+    let bundle = SettingsBundleDto {
+        version: SETTINGS_BUNDLE_VERSION,
+        exported_at: now_ms(),
+        theme: Some(to_section_value("theme", &settings.theme)?),
+        layout: Some(to_section_value("layout", &settings.layout)?),
+        locale: Some(to_section_value("locale", &settings.locale)?),
+        clipboard: Some(to_section_value("clipboard", &settings.clipboard)?),
+        screenshot: Some(to_section_value("screenshot", &settings.screenshot)?),
+        logging: Some(to_section_value("logging", &logging)?),
+        launcher_search: Some(to_section_value("launcherSearch", &launcher_search)?),
+        tray: Some(to_section_value("tray", &settings.tray)?),
+        window_auto_hide: Some(to_section_value(
+            "windowAutoHide",
+            &settings.window_auto_hide,
+        )?),
+        app_manager: Some(to_section_value("appManager", &settings.app_manager)?),
+    };
+    let content = serde_json::to_string_pretty(&bundle)
+        .with_context(|| "序列化设置导出内容失败".to_string())
+        .with_code("settings_export_serialize_failed", "序列化设置失败")?;
+
+    let target_path = resolve_export_path(app, output_path)?;
+    if let Some(parent) = target_path.parent() {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .with_context(|| format!("创建设置导出目录失败: {}", parent.display()))
+            .with_code("settings_export_dir_create_failed", "创建设置导出目录失败")
+            .with_ctx("outputDir", parent.display().to_string())?;
+    }
+    tokio::fs::write(&target_path, content)
+        .await
+        .with_context(|| format!("写入设置导出文件失败: {}", target_path.display()))
+        .with_code("settings_export_write_failed", "写入设置导出文件失败")
+        .with_ctx("targetPath", target_path.display().to_string())?;
+
+    Ok(SettingsExportResultDto {
+        file_path: target_path.to_string_lossy().to_string(),
+        sections: [
+            "theme",
+            "layout",
+            "locale",
+            "clipboard",
+            "screenshot",
+            "logging",
+            "launcherSearch",
+            "tray",
+            "windowAutoHide",
+            "appManager",
+        ]
+        .into_iter()
+        .map(ToString::to_string)
+        .collect(),
+    })
+}
+
+async fn read_settings_bundle(input_path: &str) -> AppResult<SettingsBundleDto> {
+    let path = input_path.trim();
+    if path.is_empty() {
+        return Err(AppError::new(
+            "settings_import_path_invalid",
+            "导入失败：文件路径不能为空",
+        ));
+    }
+
+    let content = tokio::fs::read_to_string(path)
+        .await
+        .with_context(|| format!("读取设置导入文件失败: {path}"))
+        .with_code("settings_import_read_failed", "读取设置导入文件失败")
+        .with_ctx("inputPath", path)?;
+    let bundle = serde_json::from_str::<SettingsBundleDto>(content.as_str())
+        .with_context(|| format!("解析设置导入文件失败: {path}"))
+        .with_code("settings_import_parse_failed", "设置导入文件格式无效")
+        .with_ctx("inputPath", path)?;
+
+    if bundle.version == 0 || bundle.version > SETTINGS_BUNDLE_VERSION {
+        return Err(AppError::new(
+            "settings_import_version_unsupported",
+            "不支持的设置文件版本",
+        )
+        .with_context("version", bundle.version.to_string()));
+    }
+    Ok(bundle)
+}
+
+async fn import_app_section(
+    app: &AppHandle,
+    state: &AppState,
+    input: SettingsUpdateInputDto,
+) -> AppResult<()> {
+    apply_settings_update(app, state, input).await.map(|_| ())
+}
+
+pub(super) async fn import_settings_bundle(
+    app: &AppHandle,
+    state: &AppState,
+    input_path: String,
+) -> AppResult<SettingsImportResultDto> {
+    let bundle = read_settings_bundle(input_path.as_str()).await?;
+    let mut sections = Vec::new();
+
+    if let Some(value) = bundle.theme {
+        let result = match parse_section::<ThemeSettingsDto>("theme", value) {
+            Ok(theme) => {
+                let input = SettingsUpdateInputDto {
+                    theme: Some(ThemeSettingsUpdateInputDto {
+                        preference: Some(theme.preference),
+                        transparent_window_background: Some(theme.transparent_window_background),
+                    }),
+                    ..Default::default()
+                };
+                import_app_section(app, state, input).await
+            }
+            Err(error) => Err(error),
+        };
+        sections.push(section_result("theme", result));
+    }
+
+    if let Some(value) = bundle.layout {
+        let result = match parse_section::<LayoutSettingsDto>("layout", value) {
+            Ok(layout) => {
+                let input = SettingsUpdateInputDto {
+                    layout: Some(LayoutSettingsUpdateInputDto {
+                        preference: Some(layout.preference),
+                    }),
+                    ..Default::default()
+                };
+                import_app_section(app, state, input).await
+            }
+            Err(error) => Err(error),
+        };
+        sections.push(section_result("layout", result));
+    }
+
+    if let Some(value) = bundle.locale {
+        let result = match parse_section::<LocaleSettingsDto>("locale", value) {
+            Ok(locale) => {
+                let input = SettingsUpdateInputDto {
+                    locale: Some(LocaleSettingsUpdateInputDto {
+                        preference: Some(locale.preference),
+                        byte_unit: Some(locale.byte_unit),
+                    }),
+                    ..Default::default()
+                };
+                import_app_section(app, state, input).await
+            }
+            Err(error) => Err(error),
+        };
+        sections.push(section_result("locale", result));
+    }
+
+    if let Some(value) = bundle.clipboard {
+        let result = match parse_section::<SettingsClipboardDto>("clipboard", value) {
+            Ok(clipboard) => {
+                let input = SettingsUpdateInputDto {
+                    clipboard: Some(SettingsClipboardUpdateInputDto {
+                        max_items: Some(clipboard.max_items),
+                        size_cleanup_enabled: Some(clipboard.size_cleanup_enabled),
+                        max_total_size_mb: Some(clipboard.max_total_size_mb),
+                        paste_after_copy: Some(clipboard.paste_after_copy),
+                        capture_text: Some(clipboard.capture_text),
+                        capture_images: Some(clipboard.capture_images),
+                        capture_files: Some(clipboard.capture_files),
+                        perceptual_deduplicate: Some(clipboard.perceptual_deduplicate),
+                        image_preview_max_width: Some(
+                            clipboard.image_preview_max_width.unwrap_or(0),
+                        ),
+                        image_preview_max_height: Some(
+                            clipboard.image_preview_max_height.unwrap_or(0),
+                        ),
+                        max_preview_pixels: Some(clipboard.max_preview_pixels.unwrap_or(0)),
+                        preview_failure_policy: Some(clipboard.preview_failure_policy),
+                        compact_content_filter: Some(clipboard.compact_content_filter),
+                        regular_content_filter: Some(clipboard.regular_content_filter),
+                        max_pinned_items: Some(clipboard.max_pinned_items),
+                    }),
+                    ..Default::default()
+                };
+                import_app_section(app, state, input).await
+            }
+            Err(error) => Err(error),
+        };
+        sections.push(section_result("clipboard", result));
+    }
+
+    if let Some(value) = bundle.screenshot {
+        let result = match parse_section::<SettingsScreenshotDto>("screenshot", value) {
+            Ok(screenshot) => {
+                let input = SettingsUpdateInputDto {
+                    screenshot: Some(SettingsScreenshotUpdateInputDto {
+                        shortcut: Some(screenshot.shortcut),
+                        auto_save_enabled: Some(screenshot.auto_save_enabled),
+                        max_items: Some(screenshot.max_items),
+                        max_total_size_mb: Some(screenshot.max_total_size_mb),
+                        pin_max_instances: Some(screenshot.pin_max_instances),
+                    }),
+                    ..Default::default()
+                };
+                import_app_section(app, state, input).await
+            }
+            Err(error) => Err(error),
+        };
+        sections.push(section_result("screenshot", result));
+    }
+
+    if let Some(value) = bundle.logging {
+        let result = match parse_section::<LogConfigDto>("logging", value) {
+            Ok(config) => state
+                .app_services
+                .logging
+                .update_config(config)
+                .await
+                .map(|_| ()),
+            Err(error) => Err(error),
+        };
+        sections.push(section_result("logging", result));
+    }
+
+    if let Some(value) = bundle.launcher_search {
+        let result = match parse_section::<LauncherSearchSettingsDto>("launcherSearch", value) {
+            Ok(search) => state
+                .app_services
+                .launcher
+                .update_search_settings(LauncherUpdateSearchSettingsInputDto {
+                    roots: Some(search.roots),
+                    exclude_patterns: Some(search.exclude_patterns),
+                    max_scan_depth: Some(search.max_scan_depth),
+                    max_items_per_root: Some(search.max_items_per_root),
+                    max_total_items: Some(search.max_total_items),
+                    refresh_interval_secs: Some(search.refresh_interval_secs),
+                })
+                .await
+                .map(|_| ()),
+            Err(error) => Err(error),
+        };
+        sections.push(section_result("launcherSearch", result));
+    }
+
+    if let Some(value) = bundle.tray {
+        let result = match parse_section::<TraySettingsDto>("tray", value) {
+            Ok(tray) => {
+                let input = SettingsUpdateInputDto {
+                    tray: Some(TraySettingsUpdateInputDto {
+                        live_tooltip: Some(tray.live_tooltip),
+                    }),
+                    ..Default::default()
+                };
+                import_app_section(app, state, input).await
+            }
+            Err(error) => Err(error),
+        };
+        sections.push(section_result("tray", result));
+    }
+
+    if let Some(value) = bundle.window_auto_hide {
+        let result = match parse_section::<WindowAutoHideSettingsDto>("windowAutoHide", value) {
+            Ok(window_auto_hide) => {
+                let input = SettingsUpdateInputDto {
+                    window_auto_hide: Some(WindowAutoHideSettingsUpdateInputDto {
+                        launcher_enabled: Some(window_auto_hide.launcher_enabled),
+                        launcher_delay_ms: Some(window_auto_hide.launcher_delay_ms),
+                        clipboard_enabled: Some(window_auto_hide.clipboard_enabled),
+                        clipboard_delay_ms: Some(window_auto_hide.clipboard_delay_ms),
+                    }),
+                    ..Default::default()
+                };
+                import_app_section(app, state, input).await
+            }
+            Err(error) => Err(error),
+        };
+        sections.push(section_result("windowAutoHide", result));
+    }
+
+    if let Some(value) = bundle.app_manager {
+        let result = match parse_section::<AppManagerSettingsDto>("appManager", value) {
+            Ok(app_manager) => {
+                let input = SettingsUpdateInputDto {
+                    app_manager: Some(AppManagerSettingsUpdateInputDto {
+                        default_delete_mode: Some(app_manager.default_delete_mode),
+                    }),
+                    ..Default::default()
+                };
+                import_app_section(app, state, input).await
+            }
+            Err(error) => Err(error),
+        };
+        sections.push(section_result("appManager", result));
+    }
+
+    let settings = state.app_services.settings.load_or_init().await?;
+    Ok(SettingsImportResultDto { sections, settings })
+}
@@ -1 +1,2 @@
 pub(crate) mod api;
+mod bundle;
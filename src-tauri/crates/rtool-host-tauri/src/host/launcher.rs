@@ -26,12 +26,28 @@ impl TauriLauncherWindow {
 
 impl LauncherWindow for TauriLauncherWindow {
     fn show(&self) -> AppResult<()> {
+        if self.window.label() == crate::constants::CLIPBOARD_WINDOW_LABEL && !self.is_visible() {
+            crate::platform::native_ui::clipboard_window::remember_clipboard_paste_target(
+                self.window.app_handle(),
+            );
+        }
         self.window.show().map_err(|error| {
             AppError::new("launcher_window_show_failed", "打开窗口失败")
                 .with_context("detail", error.to_string())
         })
     }
 
+    fn hide(&self) -> AppResult<()> {
+        self.window.hide().map_err(|error| {
+            AppError::new("launcher_window_hide_failed", "关闭窗口失败")
+                .with_context("detail", error.to_string())
+        })
+    }
+
+    fn is_visible(&self) -> bool {
+        self.window.is_visible().unwrap_or(false)
+    }
+
     fn set_focus(&self) -> AppResult<()> {
         self.window.set_focus().map_err(|error| {
             AppError::new("launcher_window_focus_failed", "打开窗口失败")
@@ -0,0 +1,82 @@
+/// Fixed-window rate guard for watcher saves. Once a window exceeds `max_saves`,
+/// only one save per window is let through until a window stays under the limit.
+#[derive(Debug)]
+pub(super) struct ClipboardBurstGuard {
+    window_ms: i64,
+    max_saves: u32,
+    window_started_at: i64,
+    window_count: u32,
+    suppressed: bool,
+    last_sampled_at: i64,
+    skipped_count: u64,
+}
+
+impl ClipboardBurstGuard {
+    pub(super) fn new(window_ms: u64, max_saves: u32) -> Self {
+        Self {
+            window_ms: i64::try_from(window_ms).unwrap_or(i64::MAX).max(1),
+            max_saves: max_saves.max(1),
+            window_started_at: 0,
+            window_count: 0,
+            suppressed: false,
+            last_sampled_at: 0,
+            skipped_count: 0,
+        }
+    }
+
+    pub(super) fn allow(&mut self, now: i64) -> bool {
+        if now.saturating_sub(self.window_started_at) >= self.window_ms {
+            if self.suppressed && self.window_count <= self.max_saves {
+                self.suppressed = false;
+                tracing::info!(
+                    event = "clipboard_high_frequency_resumed",
+                    skipped_count = self.skipped_count
+                );
+                self.skipped_count = 0;
+            }
+            self.window_started_at = now;
+            self.window_count = 0;
+        }
+        self.window_count = self.window_count.saturating_add(1);
+
+        if !self.suppressed && self.window_count > self.max_saves {
+            self.suppressed = true;
+            self.last_sampled_at = now;
+            tracing::warn!(
+                event = "clipboard_high_frequency_suppressed",
+                window_ms = self.window_ms,
+                max_saves = self.max_saves
+            );
+        }
+        if !self.suppressed {
+            return true;
+        }
+
+        if now.saturating_sub(self.last_sampled_at) >= self.window_ms {
+            self.last_sampled_at = now;
+            return true;
+        }
+        self.skipped_count += 1;
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn suppresses_bursts_samples_periodically_and_recovers() {
+        let mut guard = ClipboardBurstGuard::new(1000, 3);
+        let allowed = (0..10).filter(|step| guard.allow(step * 10)).count();
+        assert_eq!(allowed, 3);
+
+        assert!(guard.allow(1100));
+        assert!(!guard.allow(1110));
+        assert!(guard.allow(2120));
+
+        assert!(guard.allow(3200));
+        assert!(guard.allow(3210));
+        assert!(guard.allow(3220));
+    }
+}
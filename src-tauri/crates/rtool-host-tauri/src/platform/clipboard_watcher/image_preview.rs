@@ -1,10 +1,11 @@
-use image::ImageReader;
+use image::imageops::FilterType;
+use image::{ImageFormat, ImageReader};
 use std::error::Error;
 use std::io::Cursor;
 use std::path::Path;
 use xcap::Window;
 
-pub(super) fn current_source_app() -> Option<String> {
+pub(crate) fn current_source_app() -> Option<String> {
     let windows = Window::all().ok()?;
     for window in windows {
         let Ok(is_focused) = window.is_focused() else {
@@ -42,15 +43,149 @@ pub(super) fn read_image_dimensions_from_header(bytes: &[u8]) -> Option<(u32, u3
     reader.into_dimensions().ok()
 }
 
+/// Signature of an encoded image as the watcher would record it.
+pub(crate) fn read_image_signature(bytes: &[u8]) -> Option<String> {
+    let (width, height) = read_image_dimensions_from_header(bytes)?;
+    Some(build_image_signature(
+        width as usize,
+        height as usize,
+        bytes,
+    ))
+}
+
+pub(super) fn exceeds_preview_pixel_limit(
+    width: usize,
+    height: usize,
+    max_pixels: Option<u64>,
+) -> bool {
+    max_pixels.is_some_and(|limit| (width as u64).saturating_mul(height as u64) > limit)
+}
+
+/// Returns the bounding box to downscale into when the image exceeds either limit.
+fn preview_resize_bounds(
+    width: u32,
+    height: u32,
+    max_width: Option<u32>,
+    max_height: Option<u32>,
+) -> Option<(u32, u32)> {
+    let exceeds = max_width.is_some_and(|limit| width > limit)
+        || max_height.is_some_and(|limit| height > limit);
+    exceeds.then(|| {
+        (
+            max_width.unwrap_or(width).min(width),
+            max_height.unwrap_or(height).min(height),
+        )
+    })
+}
+
+/// Writes the preview PNG, downscaling it proportionally when it exceeds the
+/// configured limits. The signature is always derived from the original bytes.
+/// A failed write leaves no partial file behind.
 pub(super) fn save_clipboard_image_preview(
     preview_dir: &Path,
     signature: &str,
     bytes: &[u8],
+    max_width: Option<u32>,
+    max_height: Option<u32>,
 ) -> Result<String, Box<dyn Error>> {
     std::fs::create_dir_all(preview_dir)?;
 
     let preview_path = preview_dir.join(format!("{}.png", signature));
-    std::fs::write(&preview_path, bytes)?;
+    let bounds = read_image_dimensions_from_header(bytes)
+        .and_then(|(width, height)| preview_resize_bounds(width, height, max_width, max_height));
+    let written = match bounds {
+        Some((bound_width, bound_height)) => {
+            let resized = image::load_from_memory(bytes)?.resize(
+                bound_width,
+                bound_height,
+                FilterType::Lanczos3,
+            );
+            let mut encoded = Vec::new();
+            resized.write_to(&mut Cursor::new(&mut encoded), ImageFormat::Png)?;
+            std::fs::write(&preview_path, encoded)
+        }
+        None => std::fs::write(&preview_path, bytes),
+    };
+    if let Err(error) = written {
+        let _ = std::fs::remove_file(&preview_path);
+        return Err(error.into());
+    }
 
     Ok(preview_path.to_string_lossy().to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn preview_resize_bounds_only_apply_when_a_limit_is_exceeded() {
+        assert_eq!(preview_resize_bounds(800, 600, None, None), None);
+        assert_eq!(preview_resize_bounds(800, 600, Some(800), Some(600)), None);
+        assert_eq!(
+            preview_resize_bounds(1600, 900, Some(800), None),
+            Some((800, 900))
+        );
+        assert_eq!(
+            preview_resize_bounds(1000, 3000, Some(1200), Some(1000)),
+            Some((1000, 1000))
+        );
+    }
+
+    fn temp_preview_dir(label: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!(
+            "rtool-image-preview-{label}-{}",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|value| value.as_nanos())
+                .unwrap_or_default()
+        ))
+    }
+
+    fn encoded_png(width: u32, height: u32) -> Vec<u8> {
+        let mut encoded = Vec::new();
+        image::DynamicImage::new_rgba8(width, height)
+            .write_to(&mut Cursor::new(&mut encoded), ImageFormat::Png)
+            .expect("encode png");
+        encoded
+    }
+
+    #[test]
+    fn failed_preview_write_leaves_no_file_behind() {
+        let dir = temp_preview_dir("failed");
+
+        // The header still reports dimensions, but the pixel data is cut off.
+        let mut truncated = encoded_png(64, 64);
+        truncated.truncate(truncated.len() - 16);
+        assert_eq!(
+            read_image_dimensions_from_header(&truncated),
+            Some((64, 64))
+        );
+        let decode_failed =
+            save_clipboard_image_preview(&dir, "truncated", &truncated, Some(32), None);
+        assert!(decode_failed.is_err());
+        assert!(!dir.join("truncated.png").exists());
+
+        std::fs::create_dir_all(dir.join("blocked.png")).expect("block preview path");
+        let write_failed =
+            save_clipboard_image_preview(&dir, "blocked", &encoded_png(4, 4), None, None);
+        assert!(write_failed.is_err());
+
+        let saved = save_clipboard_image_preview(&dir, "ok", &encoded_png(4, 4), None, None)
+            .expect("save preview");
+        assert!(std::path::Path::new(&saved).is_file());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn preview_pixel_limit_only_skips_images_above_it() {
+        assert!(!exceeds_preview_pixel_limit(20_000, 20_000, None));
+        assert!(!exceeds_preview_pixel_limit(4000, 3000, Some(12_000_000)));
+        assert!(exceeds_preview_pixel_limit(4001, 3000, Some(12_000_000)));
+        assert!(exceeds_preview_pixel_limit(
+            usize::MAX,
+            2,
+            Some(u64::MAX - 1)
+        ));
+    }
+}
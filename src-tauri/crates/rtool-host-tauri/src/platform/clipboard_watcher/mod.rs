@@ -1,9 +1,14 @@
+mod burst_guard;
 mod image_preview;
 mod processor;
+mod sync_debounce;
+
+pub(crate) use image_preview::{current_source_app, read_image_signature};
 
 use crate::constants::CLIPBOARD_PLUGIN_UPDATE_EVENT;
 use rtool_app::ClipboardApplicationService;
 use rtool_contracts::{AppError, AppResult};
+use rtool_kernel::RuntimeState;
 use std::sync::Arc;
 use tauri::{AppHandle, Listener, Manager, Runtime};
 use tokio::sync::Mutex;
@@ -11,6 +16,7 @@ use tokio::sync::Mutex;
 pub(crate) fn start_clipboard_watcher<R: Runtime>(
     app_handle: AppHandle<R>,
     service: ClipboardApplicationService,
+    runtime_state: RuntimeState,
 ) -> AppResult<()> {
     let clipboard = app_handle.state::<tauri_plugin_clipboard::Clipboard>();
     clipboard
@@ -23,6 +29,7 @@ pub(crate) fn start_clipboard_watcher<R: Runtime>(
     let processor = Arc::new(Mutex::new(processor::ClipboardProcessor::new(
         app_handle.clone(),
         service,
+        runtime_state,
     )));
 
     let processor_ref = Arc::clone(&processor);
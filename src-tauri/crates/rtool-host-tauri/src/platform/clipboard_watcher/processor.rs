@@ -1,47 +1,62 @@
+use super::burst_guard::ClipboardBurstGuard;
 use super::image_preview::{
-    build_image_signature, current_source_app, read_image_dimensions_from_header,
-    save_clipboard_image_preview,
+    build_image_signature, current_source_app, exceeds_preview_pixel_limit,
+    read_image_dimensions_from_header, save_clipboard_image_preview,
 };
-use crate::features::clipboard::events::emit_clipboard_sync;
-use rtool_app::{ClipboardApplicationService, sanitize_for_log};
-use rtool_contracts::models::ClipboardSyncPayload;
-use std::path::PathBuf;
+use super::sync_debounce::ClipboardSyncDebouncer;
+use crate::features::clipboard::pause::now_ms;
+use crate::shared::command_runtime::run_blocking;
+use rtool_app::{ClipboardApplicationService, ClipboardImagePreview, sanitize_for_log};
+use rtool_contracts::models::{
+    ClipboardPreviewFailurePolicy, ClipboardSettingsDto, ClipboardSyncPayload,
+};
+use rtool_kernel::{RuntimeBudget, RuntimeState};
+use std::error::Error;
+use std::sync::Arc;
+use std::time::Duration;
 use tauri::{AppHandle, Manager, Runtime};
 
 pub(super) struct ClipboardProcessor<R: Runtime> {
     app_handle: AppHandle<R>,
     service: ClipboardApplicationService,
-    preview_dir: Option<PathBuf>,
+    runtime_state: RuntimeState,
+    sync: ClipboardSyncDebouncer<R>,
+    burst_guard: ClipboardBurstGuard,
     last_seen: String,
     last_image_signature: String,
 }
 
 impl<R: Runtime> ClipboardProcessor<R> {
-    pub(super) fn new(app_handle: AppHandle<R>, service: ClipboardApplicationService) -> Self {
-        let preview_dir = match app_handle.path().app_data_dir() {
-            Ok(value) => Some(value.join("clipboard_previews")),
-            Err(error) => {
-                tracing::warn!(
-                    event = "clipboard_preview_dir_resolve_failed",
-                    error = error.to_string()
-                );
-                None
-            }
-        };
-
+    pub(super) fn new(
+        app_handle: AppHandle<R>,
+        service: ClipboardApplicationService,
+        runtime_state: RuntimeState,
+    ) -> Self {
+        let budget = RuntimeBudget::global();
         Self {
+            sync: ClipboardSyncDebouncer::new(
+                app_handle.clone(),
+                Duration::from_millis(budget.clipboard_sync_debounce_ms),
+            ),
+            burst_guard: ClipboardBurstGuard::new(
+                budget.clipboard_burst_window_ms,
+                budget.clipboard_burst_max_saves,
+            ),
             app_handle,
             service,
-            preview_dir,
+            runtime_state,
             last_seen: String::new(),
             last_image_signature: String::new(),
         }
     }
 
-    async fn handle_text(&mut self, text: String, source_app: Option<String>) -> bool {
+    async fn handle_text(&mut self, text: String, source_app: Option<String>) {
         let trimmed = text.trim().to_string();
         if trimmed.is_empty() || trimmed == self.last_seen {
-            return true;
+            return;
+        }
+        if !self.burst_guard.allow(now_ms()) {
+            return;
         }
 
         self.last_seen = trimmed.clone();
@@ -49,15 +64,12 @@ impl<R: Runtime> ClipboardProcessor<R> {
 
         match self.service.save_text(trimmed, source_app).await {
             Ok(result) => {
-                emit_clipboard_sync(
-                    &self.app_handle,
-                    ClipboardSyncPayload {
-                        upsert: vec![result.item],
-                        removed_ids: result.removed_ids,
-                        clear_all: false,
-                        reason: Some("watcher_save_text".to_string()),
-                    },
-                );
+                self.sync.push(ClipboardSyncPayload {
+                    upsert: vec![result.item],
+                    removed_ids: result.removed_ids,
+                    clear_all: false,
+                    reason: Some("watcher_save_text".to_string()),
+                });
             }
             Err(error) => {
                 tracing::warn!(
@@ -72,22 +84,14 @@ impl<R: Runtime> ClipboardProcessor<R> {
                 );
             }
         }
-        true
     }
 
-    async fn handle_files(&mut self, files_uris: Vec<String>, source_app: Option<String>) -> bool {
-        let normalized_files: Vec<String> = files_uris
-            .into_iter()
-            .map(|value| value.trim().to_string())
-            .filter(|value| !value.is_empty())
-            .collect();
-        if normalized_files.is_empty() {
-            return false;
-        }
-
-        let serialized = normalized_files.join("\n");
+    async fn handle_files(&mut self, serialized: String, source_app: Option<String>) {
         if serialized == self.last_seen {
-            return true;
+            return;
+        }
+        if !self.burst_guard.allow(now_ms()) {
+            return;
         }
 
         self.last_seen = serialized.clone();
@@ -95,15 +99,12 @@ impl<R: Runtime> ClipboardProcessor<R> {
 
         match self.service.save_text(serialized, source_app).await {
             Ok(result) => {
-                emit_clipboard_sync(
-                    &self.app_handle,
-                    ClipboardSyncPayload {
-                        upsert: vec![result.item],
-                        removed_ids: result.removed_ids,
-                        clear_all: false,
-                        reason: Some("watcher_save_files".to_string()),
-                    },
-                );
+                self.sync.push(ClipboardSyncPayload {
+                    upsert: vec![result.item],
+                    removed_ids: result.removed_ids,
+                    clear_all: false,
+                    reason: Some("watcher_save_files".to_string()),
+                });
             }
             Err(error) => {
                 tracing::warn!(
@@ -118,31 +119,59 @@ impl<R: Runtime> ClipboardProcessor<R> {
                 );
             }
         }
-        true
-    }
-
-    async fn handle_image(&mut self, png_bytes: &[u8], source_app: Option<String>) {
-        let (width_u32, height_u32) =
-            if let Some(dimensions) = read_image_dimensions_from_header(png_bytes) {
-                dimensions
-            } else {
-                match image::load_from_memory(png_bytes) {
-                    Ok(decoded) => (decoded.width(), decoded.height()),
-                    Err(error) => {
-                        tracing::warn!(
-                            event = "clipboard_image_decode_failed",
-                            error = error.to_string()
-                        );
-                        return;
-                    }
-                }
-            };
-        let width = width_u32 as usize;
-        let height = height_u32 as usize;
-        let signature = build_image_signature(width, height, png_bytes);
+    }
+
+    async fn handle_image(&mut self, png_bytes: Vec<u8>, source_app: Option<String>) {
+        let Some((width, height)) = image_dimensions(&png_bytes) else {
+            return;
+        };
+        let signature = build_image_signature(width, height, &png_bytes);
         if signature == self.last_image_signature {
             return;
         }
+        if !self.burst_guard.allow(now_ms()) {
+            return;
+        }
+
+        let png_bytes: Arc<[u8]> = png_bytes.into();
+        let perceptual_hash = if self.service.get_settings().perceptual_deduplicate {
+            let dhash_bytes = Arc::clone(&png_bytes);
+            run_blocking("clipboard_image_dhash", move || {
+                Ok(ClipboardApplicationService::compute_image_dhash(
+                    &dhash_bytes,
+                ))
+            })
+            .await
+            .unwrap_or_else(|error| {
+                tracing::warn!(
+                    event = "clipboard_image_dhash_failed",
+                    error_code = error.code.as_str()
+                );
+                None
+            })
+        } else {
+            None
+        };
+        if let Some(hash) = perceptual_hash.as_deref() {
+            match self.service.find_perceptual_duplicate(hash).await {
+                Ok(Some(existing_id)) => {
+                    tracing::debug!(
+                        event = "clipboard_image_perceptual_dedup",
+                        existing_id = existing_id.as_str()
+                    );
+                    self.last_image_signature = signature;
+                    self.last_seen.clear();
+                    return;
+                }
+                Ok(None) => {}
+                Err(error) => {
+                    tracing::warn!(
+                        event = "clipboard_image_perceptual_lookup_failed",
+                        error_code = error.code.as_str()
+                    );
+                }
+            }
+        }
 
         if let Err(error) = self.service.ensure_disk_space_for_new_item() {
             tracing::warn!(
@@ -161,35 +190,75 @@ impl<R: Runtime> ClipboardProcessor<R> {
         self.last_image_signature = signature.clone();
         self.last_seen.clear();
 
-        let preview_path = self.preview_dir.as_ref().and_then(|dir| {
-            match save_clipboard_image_preview(dir, &signature, png_bytes) {
-                Ok(path) => Some(path),
-                Err(error) => {
-                    tracing::warn!(
-                        event = "clipboard_preview_save_failed",
-                        signature = %signature,
-                        error = error.to_string()
-                    );
-                    None
-                }
+        let settings = self.service.get_settings();
+        let preview = if exceeds_preview_pixel_limit(width, height, settings.max_preview_pixels) {
+            tracing::info!(
+                event = "clipboard_preview_skipped",
+                signature = %signature,
+                width,
+                height
+            );
+            ClipboardImagePreview::Skipped
+        } else {
+            // Downscaling decodes and re-encodes the whole image; keep it off the async runtime.
+            let preview_dir = self.service.preview_dir();
+            let preview_signature = signature.clone();
+            let (max_width, max_height) = (
+                settings.image_preview_max_width,
+                settings.image_preview_max_height,
+            );
+            let saved = run_blocking("clipboard_image_preview_save", move || {
+                Ok(preview_dir
+                    .ok_or_else(|| "clipboard preview directory unavailable".to_string())
+                    .and_then(|dir| {
+                        save_clipboard_image_preview(
+                            &dir,
+                            &preview_signature,
+                            &png_bytes,
+                            max_width,
+                            max_height,
+                        )
+                        .map_err(|error| error.to_string())
+                    }))
+            })
+            .await
+            .map_err(Box::<dyn Error>::from)
+            .and_then(|saved| saved.map_err(Box::<dyn Error>::from));
+            if let Err(error) = &saved {
+                tracing::warn!(
+                    event = "clipboard_preview_save_failed",
+                    signature = %signature,
+                    skip_item = settings.preview_failure_policy
+                        == ClipboardPreviewFailurePolicy::SkipItem,
+                    error = error.to_string()
+                );
             }
-        });
+            let Some(preview) = resolve_image_preview(saved, settings.preview_failure_policy)
+            else {
+                return;
+            };
+            preview
+        };
 
         match self
             .service
-            .save_watcher_image(width, height, &signature, preview_path, source_app)
+            .save_watcher_image(
+                width,
+                height,
+                &signature,
+                preview,
+                source_app,
+                perceptual_hash.as_deref(),
+            )
             .await
         {
             Ok(result) => {
-                emit_clipboard_sync(
-                    &self.app_handle,
-                    ClipboardSyncPayload {
-                        upsert: vec![result.item],
-                        removed_ids: result.removed_ids,
-                        clear_all: false,
-                        reason: Some("watcher_save_image".to_string()),
-                    },
-                );
+                self.sync.push(ClipboardSyncPayload {
+                    upsert: vec![result.item],
+                    removed_ids: result.removed_ids,
+                    clear_all: false,
+                    reason: Some("watcher_save_image".to_string()),
+                });
             }
             Err(error) => {
                 tracing::warn!(
@@ -206,33 +275,318 @@ impl<R: Runtime> ClipboardProcessor<R> {
         }
     }
 
-    pub(super) async fn handle_update_event(&mut self) {
-        let source_app = current_source_app();
-        let files_uris_result = {
-            let clipboard = self.app_handle.state::<tauri_plugin_clipboard::Clipboard>();
-            clipboard.read_files_uris()
-        };
-        if let Ok(files_uris) = files_uris_result
-            && self.handle_files(files_uris, source_app.clone()).await
+    fn remember_while_paused(&mut self) {
+        let clipboard = self.app_handle.state::<tauri_plugin_clipboard::Clipboard>();
+        if let Some(serialized) = clipboard
+            .read_files_uris()
+            .ok()
+            .and_then(serialize_files_uris)
         {
+            self.last_seen = serialized;
+            self.last_image_signature.clear();
             return;
         }
+        if let Ok(png_bytes) = clipboard.read_image_binary() {
+            if let Some((width, height)) = image_dimensions(&png_bytes) {
+                self.last_image_signature = build_image_signature(width, height, &png_bytes);
+                self.last_seen.clear();
+            }
+            return;
+        }
+        if let Ok(text) = clipboard.read_text() {
+            let trimmed = text.trim();
+            if !trimmed.is_empty() {
+                self.last_seen = trimmed.to_string();
+                self.last_image_signature.clear();
+            }
+        }
+    }
 
-        let image_binary_result = {
-            let clipboard = self.app_handle.state::<tauri_plugin_clipboard::Clipboard>();
-            clipboard.read_image_binary()
-        };
-        if let Ok(image_binary) = image_binary_result {
-            self.handle_image(&image_binary, source_app.clone()).await;
+    pub(super) async fn handle_update_event(&mut self) {
+        if self.runtime_state.clipboard_pause(now_ms()).is_some() {
+            self.remember_while_paused();
+            return;
+        }
+
+        let read_order = clipboard_read_order(&self.service.get_settings());
+        if read_order.is_empty() {
             return;
         }
 
-        let text_result = {
+        let source_app = current_source_app();
+        let content = {
             let clipboard = self.app_handle.state::<tauri_plugin_clipboard::Clipboard>();
-            clipboard.read_text()
+            read_clipboard_content(&read_order, clipboard.inner())
         };
-        if let Ok(text) = text_result {
-            self.handle_text(text, source_app).await;
+        match content {
+            Some(ClipboardContent::Files(serialized)) => {
+                self.handle_files(serialized, source_app).await
+            }
+            Some(ClipboardContent::Image(png_bytes)) => {
+                self.handle_image(png_bytes, source_app).await
+            }
+            Some(ClipboardContent::Text(text)) => self.handle_text(text, source_app).await,
+            None => {}
+        }
+    }
+}
+
+trait ClipboardContentReader {
+    fn read_files_uris(&self) -> Result<Vec<String>, String>;
+    fn read_image_binary(&self) -> Result<Vec<u8>, String>;
+    fn read_text(&self) -> Result<String, String>;
+}
+
+impl ClipboardContentReader for tauri_plugin_clipboard::Clipboard {
+    fn read_files_uris(&self) -> Result<Vec<String>, String> {
+        tauri_plugin_clipboard::Clipboard::read_files_uris(self)
+    }
+
+    fn read_image_binary(&self) -> Result<Vec<u8>, String> {
+        tauri_plugin_clipboard::Clipboard::read_image_binary(self)
+    }
+
+    fn read_text(&self) -> Result<String, String> {
+        tauri_plugin_clipboard::Clipboard::read_text(self)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum ClipboardContent {
+    Files(String),
+    Image(Vec<u8>),
+    Text(String),
+}
+
+/// Reads the first available kind in `read_order`; disabled kinds are never read.
+fn read_clipboard_content(
+    read_order: &[ClipboardReadKind],
+    reader: &impl ClipboardContentReader,
+) -> Option<ClipboardContent> {
+    read_order.iter().find_map(|kind| match kind {
+        ClipboardReadKind::Files => reader
+            .read_files_uris()
+            .ok()
+            .and_then(serialize_files_uris)
+            .map(ClipboardContent::Files),
+        ClipboardReadKind::Image => reader.read_image_binary().ok().map(ClipboardContent::Image),
+        ClipboardReadKind::Text => reader.read_text().ok().map(ClipboardContent::Text),
+    })
+}
+
+/// Maps the preview write result to what gets recorded; `None` drops the item.
+fn resolve_image_preview(
+    saved: Result<String, Box<dyn Error>>,
+    policy: ClipboardPreviewFailurePolicy,
+) -> Option<ClipboardImagePreview> {
+    match (saved, policy) {
+        (Ok(path), _) => Some(ClipboardImagePreview::Saved(path)),
+        (Err(_), ClipboardPreviewFailurePolicy::KeepWithoutPreview) => {
+            Some(ClipboardImagePreview::Unavailable)
+        }
+        (Err(_), ClipboardPreviewFailurePolicy::SkipItem) => None,
+    }
+}
+
+fn serialize_files_uris(files_uris: Vec<String>) -> Option<String> {
+    let normalized_files: Vec<String> = files_uris
+        .into_iter()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .collect();
+    if normalized_files.is_empty() {
+        return None;
+    }
+    Some(normalized_files.join("\n"))
+}
+
+fn image_dimensions(png_bytes: &[u8]) -> Option<(usize, usize)> {
+    let (width, height) = match read_image_dimensions_from_header(png_bytes) {
+        Some(dimensions) => dimensions,
+        None => match image::load_from_memory(png_bytes) {
+            Ok(decoded) => (decoded.width(), decoded.height()),
+            Err(error) => {
+                tracing::warn!(
+                    event = "clipboard_image_decode_failed",
+                    error = error.to_string()
+                );
+                return None;
+            }
+        },
+    };
+    Some((width as usize, height as usize))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ClipboardReadKind {
+    Files,
+    Image,
+    Text,
+}
+
+fn clipboard_read_order(settings: &ClipboardSettingsDto) -> Vec<ClipboardReadKind> {
+    [
+        (ClipboardReadKind::Files, settings.capture_files),
+        (ClipboardReadKind::Image, settings.capture_images),
+        (ClipboardReadKind::Text, settings.capture_text),
+    ]
+    .into_iter()
+    .filter_map(|(kind, enabled)| enabled.then_some(kind))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    fn settings(
+        capture_text: bool,
+        capture_images: bool,
+        capture_files: bool,
+    ) -> ClipboardSettingsDto {
+        ClipboardSettingsDto {
+            max_items: 1000,
+            size_cleanup_enabled: true,
+            max_total_size_mb: 500,
+            capture_text,
+            capture_images,
+            capture_files,
+            perceptual_deduplicate: false,
+            clipboard_storage_dir: None,
+            image_preview_max_width: None,
+            image_preview_max_height: None,
+            max_preview_pixels: None,
+            preview_failure_policy: Default::default(),
+            compact_content_filter: Default::default(),
+            regular_content_filter: Default::default(),
+            max_pinned_items: 100,
+        }
+    }
+
+    #[test]
+    fn read_order_prefers_files_then_image_then_text() {
+        assert_eq!(
+            clipboard_read_order(&settings(true, true, true)),
+            vec![
+                ClipboardReadKind::Files,
+                ClipboardReadKind::Image,
+                ClipboardReadKind::Text
+            ]
+        );
+    }
+
+    #[test]
+    fn disabled_images_never_reach_image_save_path() {
+        let order = clipboard_read_order(&settings(true, false, true));
+        assert!(!order.contains(&ClipboardReadKind::Image));
+        assert_eq!(
+            order,
+            vec![ClipboardReadKind::Files, ClipboardReadKind::Text]
+        );
+    }
+
+    #[test]
+    fn preview_write_failure_follows_the_configured_policy() {
+        let failed = || Err(Box::<dyn Error>::from("disk full"));
+        assert_eq!(
+            resolve_image_preview(failed(), ClipboardPreviewFailurePolicy::KeepWithoutPreview),
+            Some(ClipboardImagePreview::Unavailable)
+        );
+        assert_eq!(
+            resolve_image_preview(failed(), ClipboardPreviewFailurePolicy::SkipItem),
+            None
+        );
+        assert_eq!(
+            resolve_image_preview(
+                Ok("/tmp/a.png".to_string()),
+                ClipboardPreviewFailurePolicy::SkipItem
+            ),
+            Some(ClipboardImagePreview::Saved("/tmp/a.png".to_string()))
+        );
+    }
+
+    #[test]
+    fn all_kinds_disabled_skips_clipboard_reads() {
+        assert!(clipboard_read_order(&settings(false, false, false)).is_empty());
+        let reader = FakeClipboard::with_all_kinds();
+        assert_eq!(read_clipboard_content(&[], &reader), None);
+        assert!(reader.reads.borrow().is_empty());
+    }
+
+    struct FakeClipboard {
+        files: Vec<String>,
+        image: Option<Vec<u8>>,
+        text: Option<String>,
+        reads: RefCell<Vec<ClipboardReadKind>>,
+    }
+
+    impl FakeClipboard {
+        fn with_all_kinds() -> Self {
+            Self {
+                files: vec!["file:///tmp/a.txt".to_string()],
+                image: Some(vec![1, 2, 3]),
+                text: Some("hello".to_string()),
+                reads: RefCell::new(Vec::new()),
+            }
+        }
+    }
+
+    impl ClipboardContentReader for FakeClipboard {
+        fn read_files_uris(&self) -> Result<Vec<String>, String> {
+            self.reads.borrow_mut().push(ClipboardReadKind::Files);
+            Ok(self.files.clone())
+        }
+
+        fn read_image_binary(&self) -> Result<Vec<u8>, String> {
+            self.reads.borrow_mut().push(ClipboardReadKind::Image);
+            self.image.clone().ok_or_else(|| "no image".to_string())
         }
+
+        fn read_text(&self) -> Result<String, String> {
+            self.reads.borrow_mut().push(ClipboardReadKind::Text);
+            self.text.clone().ok_or_else(|| "no text".to_string())
+        }
+    }
+
+    #[test]
+    fn update_dispatch_never_reads_disabled_kinds() {
+        let reader = FakeClipboard::with_all_kinds();
+        let order = clipboard_read_order(&settings(true, false, false));
+        assert_eq!(
+            read_clipboard_content(&order, &reader),
+            Some(ClipboardContent::Text("hello".to_string()))
+        );
+        assert_eq!(*reader.reads.borrow(), vec![ClipboardReadKind::Text]);
+
+        let reader = FakeClipboard::with_all_kinds();
+        let order = clipboard_read_order(&settings(false, true, false));
+        assert_eq!(
+            read_clipboard_content(&order, &reader),
+            Some(ClipboardContent::Image(vec![1, 2, 3]))
+        );
+        assert_eq!(*reader.reads.borrow(), vec![ClipboardReadKind::Image]);
+    }
+
+    #[test]
+    fn update_dispatch_falls_through_empty_files_to_next_enabled_kind() {
+        let reader = FakeClipboard {
+            files: vec!["  ".to_string()],
+            image: None,
+            ..FakeClipboard::with_all_kinds()
+        };
+        let order = clipboard_read_order(&settings(true, true, true));
+        assert_eq!(
+            read_clipboard_content(&order, &reader),
+            Some(ClipboardContent::Text("hello".to_string()))
+        );
+        assert_eq!(
+            *reader.reads.borrow(),
+            vec![
+                ClipboardReadKind::Files,
+                ClipboardReadKind::Image,
+                ClipboardReadKind::Text
+            ]
+        );
     }
 }
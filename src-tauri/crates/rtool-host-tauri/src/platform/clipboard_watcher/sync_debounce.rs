@@ -0,0 +1,192 @@
+use crate::features::clipboard::events::emit_clipboard_sync;
+use rtool_contracts::models::{ClipboardItemDto, ClipboardSyncPayload};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Runtime};
+
+#[derive(Debug, Default)]
+struct PendingClipboardSync {
+    upsert: Vec<ClipboardItemDto>,
+    removed_ids: Vec<String>,
+    clear_all: bool,
+    reason: Option<String>,
+    flush_scheduled: bool,
+}
+
+impl PendingClipboardSync {
+    fn merge(&mut self, payload: ClipboardSyncPayload) {
+        if payload.clear_all {
+            self.upsert.clear();
+            self.removed_ids.clear();
+            self.clear_all = true;
+        }
+
+        for removed_id in payload.removed_ids {
+            self.upsert.retain(|item| item.id != removed_id);
+            if !self.removed_ids.contains(&removed_id) {
+                self.removed_ids.push(removed_id);
+            }
+        }
+
+        for item in payload.upsert {
+            self.removed_ids.retain(|removed_id| *removed_id != item.id);
+            if let Some(existing) = self
+                .upsert
+                .iter_mut()
+                .find(|existing| existing.id == item.id)
+            {
+                *existing = item;
+            } else {
+                self.upsert.push(item);
+            }
+        }
+
+        if payload.reason.is_some() {
+            self.reason = payload.reason;
+        }
+    }
+
+    fn take_payload(&mut self) -> Option<ClipboardSyncPayload> {
+        if !self.clear_all && self.upsert.is_empty() && self.removed_ids.is_empty() {
+            return None;
+        }
+
+        let payload = ClipboardSyncPayload {
+            upsert: std::mem::take(&mut self.upsert),
+            removed_ids: std::mem::take(&mut self.removed_ids),
+            clear_all: self.clear_all,
+            reason: self.reason.take(),
+        };
+        self.clear_all = false;
+        Some(payload)
+    }
+}
+
+pub(super) struct ClipboardSyncDebouncer<R: Runtime> {
+    app_handle: AppHandle<R>,
+    window: Duration,
+    pending: Arc<Mutex<PendingClipboardSync>>,
+}
+
+impl<R: Runtime> ClipboardSyncDebouncer<R> {
+    pub(super) fn new(app_handle: AppHandle<R>, window: Duration) -> Self {
+        Self {
+            app_handle,
+            window,
+            pending: Arc::new(Mutex::new(PendingClipboardSync::default())),
+        }
+    }
+
+    pub(super) fn push(&self, payload: ClipboardSyncPayload) {
+        if self.window.is_zero() {
+            emit_clipboard_sync(&self.app_handle, payload);
+            return;
+        }
+
+        let should_schedule = {
+            let mut pending = self
+                .pending
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            pending.merge(payload);
+            !std::mem::replace(&mut pending.flush_scheduled, true)
+        };
+        if !should_schedule {
+            return;
+        }
+
+        let app_handle = self.app_handle.clone();
+        let pending = Arc::clone(&self.pending);
+        let window = self.window;
+        tauri::async_runtime::spawn(async move {
+            tokio::time::sleep(window).await;
+            let payload = {
+                let mut pending = pending
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner());
+                pending.flush_scheduled = false;
+                pending.take_payload()
+            };
+            if let Some(payload) = payload {
+                emit_clipboard_sync(&app_handle, payload);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(id: &str, plain_text: &str) -> ClipboardItemDto {
+        ClipboardItemDto {
+            id: id.to_string(),
+            content_key: format!("key-{id}"),
+            item_type: "text".to_string(),
+            plain_text: plain_text.to_string(),
+            source_app: None,
+            preview_path: None,
+            preview_data_url: None,
+            created_at: 0,
+            pinned: false,
+            pin_order: 0,
+            preview_missing: false,
+            preview_skipped: false,
+            content_size_bytes: None,
+            width: None,
+            height: None,
+            original_width: None,
+            original_height: None,
+        }
+    }
+
+    fn payload(upsert: Vec<ClipboardItemDto>, removed_ids: &[&str]) -> ClipboardSyncPayload {
+        ClipboardSyncPayload {
+            upsert,
+            removed_ids: removed_ids.iter().map(ToString::to_string).collect(),
+            clear_all: false,
+            reason: Some("watcher_save_text".to_string()),
+        }
+    }
+
+    #[test]
+    fn merges_upserts_and_keeps_latest_item_version() {
+        let mut pending = PendingClipboardSync::default();
+        pending.merge(payload(vec![item("a", "first")], &[]));
+        pending.merge(payload(vec![item("b", "second")], &["old"]));
+        pending.merge(payload(vec![item("a", "third")], &[]));
+
+        let merged = pending.take_payload().expect("merged payload");
+        let texts = merged
+            .upsert
+            .iter()
+            .map(|item| (item.id.as_str(), item.plain_text.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(texts, vec![("a", "third"), ("b", "second")]);
+        assert_eq!(merged.removed_ids, vec!["old".to_string()]);
+        assert!(pending.take_payload().is_none());
+    }
+
+    #[test]
+    fn removal_after_upsert_drops_pending_item() {
+        let mut pending = PendingClipboardSync::default();
+        pending.merge(payload(vec![item("a", "first")], &[]));
+        pending.merge(payload(vec![item("b", "second")], &["a"]));
+
+        let merged = pending.take_payload().expect("merged payload");
+        assert_eq!(merged.upsert.len(), 1);
+        assert_eq!(merged.upsert[0].id, "b");
+        assert_eq!(merged.removed_ids, vec!["a".to_string()]);
+    }
+
+    #[test]
+    fn upsert_after_removal_cancels_removal() {
+        let mut pending = PendingClipboardSync::default();
+        pending.merge(payload(Vec::new(), &["a"]));
+        pending.merge(payload(vec![item("a", "restored")], &[]));
+
+        let merged = pending.take_payload().expect("merged payload");
+        assert_eq!(merged.upsert[0].plain_text, "restored");
+        assert!(merged.removed_ids.is_empty());
+    }
+}
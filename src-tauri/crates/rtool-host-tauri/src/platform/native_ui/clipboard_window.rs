@@ -3,6 +3,7 @@ use crate::constants::{
     CLIPBOARD_COMPACT_WIDTH_LOGICAL, CLIPBOARD_MIN_HEIGHT_LOGICAL, CLIPBOARD_REGULAR_WIDTH_LOGICAL,
     CLIPBOARD_WINDOW_LABEL,
 };
+use crate::platform::clipboard_watcher::current_source_app;
 use crate::platform::native_ui::window_factory::ensure_webview_window;
 use anyhow::Context;
 use rtool_contracts::models::ClipboardWindowModeAppliedDto;
@@ -141,16 +142,61 @@ pub(crate) fn apply_clipboard_window_mode(
         position_y = next_y
     );
 
+    let content_filter = app
+        .try_state::<AppState>()
+        .map(|state| {
+            let settings = state.app_services.clipboard.get_settings();
+            if compact {
+                settings.compact_content_filter
+            } else {
+                settings.regular_content_filter
+            }
+        })
+        .unwrap_or_default();
+
     Ok(ClipboardWindowModeAppliedDto {
         compact,
+        content_filter,
         applied_width_logical,
         applied_height_logical,
         scale_factor,
     })
 }
 
+/// Records the app that had focus before an rtool window is shown, so copy-and-paste can
+/// return to it. A focused rtool window (e.g. the launcher) keeps the earlier target.
+pub(crate) fn remember_clipboard_paste_target(app: &AppHandle) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let source_app = current_source_app();
+    if source_app
+        .as_deref()
+        .is_some_and(|name| is_own_app_name(name, &app.package_info().name))
+    {
+        return;
+    }
+    state.set_clipboard_paste_target(source_app);
+}
+
+fn is_own_app_name(source_app: &str, package_name: &str) -> bool {
+    source_app.trim().eq_ignore_ascii_case(package_name.trim())
+}
+
 pub(crate) fn set_clipboard_window_compact_state(app: &AppHandle, compact: bool) {
     if let Some(state) = app.try_state::<AppState>() {
         state.set_clipboard_window_compact(compact);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn own_app_name_match_ignores_case_and_whitespace() {
+        assert!(is_own_app_name("rtool", "rtool"));
+        assert!(is_own_app_name(" RTool ", "rtool"));
+        assert!(!is_own_app_name("Notes", "rtool"));
+    }
+}
@@ -1,15 +1,19 @@
 use crate::app::state::AppState;
 use crate::constants::{
     CLIPBOARD_WINDOW_LABEL, CLIPBOARD_WINDOW_OPENED_EVENT, SCREENSHOT_WINDOW_LABEL,
-    SCREENSHOT_WINDOW_OPENED_EVENT, SHORTCUT_SCREENSHOT_DEFAULT,
+    SCREENSHOT_WINDOW_OPENED_EVENT, SHORTCUT_CLIPBOARD_WINDOW, SHORTCUT_CLIPBOARD_WINDOW_COMPACT,
+    SHORTCUT_LAUNCHER_FALLBACK, SHORTCUT_LAUNCHER_PRIMARY, SHORTCUT_SCREENSHOT_DEFAULT,
 };
 use crate::platform::native_ui::clipboard_window::{
-    apply_clipboard_window_mode, set_clipboard_window_compact_state,
+    apply_clipboard_window_mode, remember_clipboard_paste_target,
+    set_clipboard_window_compact_state,
 };
 use crate::platform::native_ui::window_factory::ensure_webview_window;
 use crate::platform::native_ui::windows::toggle_launcher_window;
 use rtool_app::ScreenshotApplicationService;
-use rtool_contracts::models::{ClipboardWindowOpenedPayload, ScreenshotWindowOpenedPayload};
+use rtool_contracts::models::{
+    ClipboardWindowOpenedPayload, ScreenshotWindowOpenedPayload, ShortcutStatusDto,
+};
 use rtool_contracts::{AppError, AppResult};
 use tauri::{AppHandle, Emitter, LogicalPosition, LogicalSize, Manager};
 use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut};
@@ -95,6 +99,107 @@ pub(crate) fn rebind_screenshot_shortcut(
     Ok(())
 }
 
+/// Accelerators the bootstrap fallback chain handed to the global shortcut plugin.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ShortcutBootstrapOutcome {
+    accepted: Vec<String>,
+}
+
+impl ShortcutBootstrapOutcome {
+    pub(crate) fn new(accepted: &[&str]) -> Self {
+        Self {
+            accepted: accepted.iter().map(|value| value.to_string()).collect(),
+        }
+    }
+}
+
+struct ShortcutBinding<'a> {
+    action: &'static str,
+    accelerator: &'a str,
+    effective: &'a str,
+}
+
+pub(crate) fn shortcuts_status(
+    app: &AppHandle,
+    outcome: &ShortcutBootstrapOutcome,
+    screenshot_shortcut: &str,
+) -> Vec<ShortcutStatusDto> {
+    let screenshot_effective = normalize_screenshot_shortcut(screenshot_shortcut)
+        .unwrap_or_else(|_| SHORTCUT_SCREENSHOT_DEFAULT.to_string());
+    let screenshot_accelerator = match screenshot_shortcut.trim() {
+        "" => SHORTCUT_SCREENSHOT_DEFAULT,
+        value => value,
+    };
+    let bindings = [
+        ShortcutBinding {
+            action: "launcher",
+            accelerator: SHORTCUT_LAUNCHER_PRIMARY,
+            effective: SHORTCUT_LAUNCHER_PRIMARY,
+        },
+        ShortcutBinding {
+            action: "launcher_fallback",
+            accelerator: SHORTCUT_LAUNCHER_FALLBACK,
+            effective: SHORTCUT_LAUNCHER_FALLBACK,
+        },
+        ShortcutBinding {
+            action: "clipboard_window",
+            accelerator: SHORTCUT_CLIPBOARD_WINDOW,
+            effective: SHORTCUT_CLIPBOARD_WINDOW,
+        },
+        ShortcutBinding {
+            action: "clipboard_window_compact",
+            accelerator: SHORTCUT_CLIPBOARD_WINDOW_COMPACT,
+            effective: SHORTCUT_CLIPBOARD_WINDOW_COMPACT,
+        },
+        ShortcutBinding {
+            action: "screenshot",
+            accelerator: screenshot_accelerator,
+            effective: screenshot_effective.as_str(),
+        },
+    ];
+    let global_shortcut = app.global_shortcut();
+    build_shortcut_statuses(&bindings, outcome, |accelerator| {
+        accelerator
+            .parse::<Shortcut>()
+            .is_ok_and(|shortcut| global_shortcut.is_registered(shortcut))
+    })
+}
+
+fn build_shortcut_statuses(
+    bindings: &[ShortcutBinding<'_>],
+    outcome: &ShortcutBootstrapOutcome,
+    is_registered: impl Fn(&str) -> bool,
+) -> Vec<ShortcutStatusDto> {
+    bindings
+        .iter()
+        .map(|binding| {
+            let registered = is_registered(binding.effective);
+            let invalid = binding.accelerator != binding.effective;
+            let dropped = !registered
+                && !outcome
+                    .accepted
+                    .iter()
+                    .any(|accepted| accepted == binding.effective);
+            let reason = if invalid {
+                Some("invalid_accelerator")
+            } else if registered {
+                None
+            } else if dropped {
+                Some("dropped_by_fallback")
+            } else {
+                Some("not_registered")
+            };
+            ShortcutStatusDto {
+                action: binding.action.to_string(),
+                accelerator: binding.accelerator.to_string(),
+                registered,
+                fell_back: invalid || dropped,
+                reason: reason.map(str::to_string),
+            }
+        })
+        .collect()
+}
+
 fn emit_clipboard_window_opened(app: &AppHandle, compact: bool) {
     if let Err(error) = app.emit(
         CLIPBOARD_WINDOW_OPENED_EVENT,
@@ -125,6 +230,7 @@ fn handle_clipboard_window_shortcut(app: &AppHandle, requested_compact: bool) {
 
     let is_visible = window.is_visible().unwrap_or(false);
     if !is_visible {
+        remember_clipboard_paste_target(app);
         tracing::info!(
             event = "clipboard_window_shortcut_action",
             action = "show",
@@ -308,3 +414,65 @@ pub(crate) fn handle_shortcut(
 
     toggle_launcher_window(app);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statuses_report_fallbacks_and_unregistered_shortcuts() {
+        let outcome = ShortcutBootstrapOutcome::new(&["CommandOrControl+K", "Alt+V"]);
+        let bindings = [
+            ShortcutBinding {
+                action: "launcher",
+                accelerator: "CommandOrControl+K",
+                effective: "CommandOrControl+K",
+            },
+            ShortcutBinding {
+                action: "launcher_fallback",
+                accelerator: "Alt+Space",
+                effective: "Alt+Space",
+            },
+            ShortcutBinding {
+                action: "clipboard_window",
+                accelerator: "Alt+V",
+                effective: "Alt+V",
+            },
+            ShortcutBinding {
+                action: "screenshot",
+                accelerator: "Bogus+",
+                effective: "Alt+Shift+S",
+            },
+        ];
+
+        let statuses = build_shortcut_statuses(&bindings, &outcome, |accelerator| {
+            matches!(accelerator, "CommandOrControl+K" | "Alt+Shift+S")
+        });
+        let summary = statuses
+            .iter()
+            .map(|status| {
+                (
+                    status.action.as_str(),
+                    status.registered,
+                    status.fell_back,
+                    status.reason.as_deref(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                ("launcher", true, false, None),
+                (
+                    "launcher_fallback",
+                    false,
+                    true,
+                    Some("dropped_by_fallback")
+                ),
+                ("clipboard_window", false, false, Some("not_registered")),
+                ("screenshot", true, true, Some("invalid_accelerator")),
+            ]
+        );
+        assert_eq!(statuses[3].accelerator, "Bogus+");
+    }
+}
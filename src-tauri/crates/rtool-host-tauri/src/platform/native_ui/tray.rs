@@ -1,15 +1,22 @@
 use crate::app::state::AppState;
 use crate::constants::{
-    TRAY_ICON_ID, TRAY_MENU_ID_CLIPBOARD, TRAY_MENU_ID_QUIT, TRAY_MENU_ID_TOOLS,
+    TRAY_ICON_ID, TRAY_MENU_ID_CLIPBOARD, TRAY_MENU_ID_CLIPBOARD_PAUSE, TRAY_MENU_ID_QUIT,
+    TRAY_MENU_ID_TOOLS,
 };
+use crate::features::clipboard::pause::{now_ms, set_clipboard_paused};
 use crate::host::launcher::TauriLauncherHost;
 use crate::platform::native_ui::windows::focus_main_window;
 use rtool_app::LocaleApplicationService;
 use rtool_contracts::models::LauncherActionDto;
-use tauri::menu::{Menu, MenuItem, PredefinedMenuItem};
+use tauri::menu::{CheckMenuItem, Menu, MenuItem, PredefinedMenuItem};
 use tauri::tray::{MouseButton, MouseButtonState, TrayIconEvent};
 use tauri::{AppHandle, Manager, Runtime};
 
+fn clipboard_paused<R: Runtime>(app: &AppHandle<R>) -> bool {
+    app.try_state::<AppState>()
+        .is_some_and(|state| state.clipboard_pause(now_ms()).is_some())
+}
+
 pub(crate) fn build_tray_menu<R: Runtime>(
     app: &AppHandle<R>,
     locale: &str,
@@ -25,11 +32,29 @@ pub(crate) fn build_tray_menu<R: Runtime>(
         true,
         None::<&str>,
     )?;
+    let pause_label = locale_service.translate(locale, "tray.clipboardPause");
+    let pause_item = CheckMenuItem::with_id(
+        app,
+        TRAY_MENU_ID_CLIPBOARD_PAUSE,
+        &pause_label,
+        true,
+        clipboard_paused(app),
+        None::<&str>,
+    )?;
     let separator = PredefinedMenuItem::separator(app)?;
     let quit_label = locale_service.translate(locale, "tray.quit");
     let quit_item = MenuItem::with_id(app, TRAY_MENU_ID_QUIT, &quit_label, true, None::<&str>)?;
 
-    Menu::with_items(app, &[&tools_item, &clipboard_item, &separator, &quit_item])
+    Menu::with_items(
+        app,
+        &[
+            &tools_item,
+            &clipboard_item,
+            &pause_item,
+            &separator,
+            &quit_item,
+        ],
+    )
 }
 
 pub(crate) fn refresh_tray_menu<R: Runtime>(app: &AppHandle<R>, locale: &str) {
@@ -48,18 +73,84 @@ pub(crate) fn refresh_tray_menu<R: Runtime>(app: &AppHandle<R>, locale: &str) {
         }
     }
 
-    if let Err(error) = tray.set_tooltip(Some(
-        LocaleApplicationService.translate(locale, "tray.tooltip"),
-    )) {
+    set_tray_tooltip(
+        app,
+        compose_tray_tooltip(locale, clipboard_paused(app), None),
+    );
+    refresh_tray_tooltip(app);
+
+    if let Err(error) = tray.set_title(Option::<&str>::None) {
+        tracing::warn!(event = "tray_title_clear_failed", error = error.to_string());
+    }
+}
+
+fn compose_tray_tooltip(locale: &str, paused: bool, clipboard_count: Option<u64>) -> String {
+    let locale_service = LocaleApplicationService;
+    let tooltip_key = if paused {
+        "tray.tooltipClipboardPaused"
+    } else {
+        "tray.tooltip"
+    };
+    let tooltip = locale_service.translate(locale, tooltip_key);
+    let Some(count) = clipboard_count else {
+        return tooltip;
+    };
+
+    let count_text = locale_service.format_number(locale, i64::try_from(count).unwrap_or(i64::MAX));
+    let count_label = locale_service
+        .translate(locale, "tray.tooltipClipboardCount")
+        .replace("{{count}}", count_text.as_str());
+    format!("{tooltip} · {count_label}")
+}
+
+fn set_tray_tooltip<R: Runtime>(app: &AppHandle<R>, tooltip: String) {
+    let Some(tray) = app.tray_by_id(TRAY_ICON_ID) else {
+        return;
+    };
+    if let Err(error) = tray.set_tooltip(Some(tooltip)) {
         tracing::warn!(
             event = "tray_tooltip_update_failed",
             error = error.to_string()
         );
     }
+}
 
-    if let Err(error) = tray.set_title(Option::<&str>::None) {
-        tracing::warn!(event = "tray_title_clear_failed", error = error.to_string());
-    }
+pub(crate) fn refresh_tray_tooltip<R: Runtime>(app: &AppHandle<R>) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let state = state.inner().clone();
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let live_tooltip = match state.app_services.settings.load_or_init().await {
+            Ok(settings) => settings.tray.live_tooltip,
+            Err(error) => {
+                tracing::warn!(
+                    event = "tray_tooltip_settings_failed",
+                    error_code = error.code.as_str()
+                );
+                false
+            }
+        };
+        let clipboard_count = if live_tooltip {
+            match state.app_services.clipboard.item_count().await {
+                Ok(count) => Some(count),
+                Err(error) => {
+                    tracing::warn!(
+                        event = "tray_tooltip_count_failed",
+                        error_code = error.code.as_str()
+                    );
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        let locale = state.resolved_locale();
+        let paused = state.clipboard_pause(now_ms()).is_some();
+        set_tray_tooltip(&app, compose_tray_tooltip(&locale, paused, clipboard_count));
+    });
 }
 
 fn run_tray_action(app: &AppHandle, action: LauncherActionDto, action_name: &str) {
@@ -87,6 +178,22 @@ fn run_tray_action(app: &AppHandle, action: LauncherActionDto, action_name: &str
     }
 }
 
+fn toggle_clipboard_pause(app: &AppHandle) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let paused = state.clipboard_pause(now_ms()).is_some();
+    if let Err(error) = set_clipboard_paused(app, state.inner(), !paused, None) {
+        tracing::warn!(
+            event = "tray_action_failed",
+            action = "clipboard_pause",
+            error_code = error.code.as_str(),
+            error_message = error.message.as_str(),
+            error_detail = error.causes.first().map(String::as_str).unwrap_or_default()
+        );
+    }
+}
+
 pub(crate) fn handle_tray_menu(app: &AppHandle, menu_id: &str) {
     match menu_id {
         TRAY_MENU_ID_TOOLS => run_tray_action(
@@ -103,6 +210,7 @@ pub(crate) fn handle_tray_menu(app: &AppHandle, menu_id: &str) {
             },
             "clipboard",
         ),
+        TRAY_MENU_ID_CLIPBOARD_PAUSE => toggle_clipboard_pause(app),
         TRAY_MENU_ID_QUIT => app.exit(0),
         _ => {}
     }
@@ -0,0 +1,127 @@
+use crate::app::state::AppState;
+use crate::constants::{CLIPBOARD_WINDOW_LABEL, LAUNCHER_WINDOW_LABEL};
+use rtool_contracts::models::WindowAutoHideSettingsDto;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tauri::{Manager, Runtime, Window};
+
+#[derive(Clone, Default)]
+pub(crate) struct WindowAutoHideState {
+    settings: Arc<RwLock<WindowAutoHideSettingsDto>>,
+    launcher_generation: Arc<AtomicU64>,
+    clipboard_generation: Arc<AtomicU64>,
+}
+
+impl WindowAutoHideState {
+    pub(crate) fn new(settings: WindowAutoHideSettingsDto) -> Self {
+        Self {
+            settings: Arc::new(RwLock::new(settings)),
+            ..Self::default()
+        }
+    }
+
+    pub(crate) fn update(&self, settings: WindowAutoHideSettingsDto) {
+        match self.settings.write() {
+            Ok(mut guard) => *guard = settings,
+            Err(poisoned) => *poisoned.into_inner() = settings,
+        }
+    }
+
+    fn settings(&self) -> WindowAutoHideSettingsDto {
+        match self.settings.read() {
+            Ok(guard) => *guard,
+            Err(poisoned) => *poisoned.into_inner(),
+        }
+    }
+
+    fn generation(&self, label: &str) -> Option<&AtomicU64> {
+        match label {
+            LAUNCHER_WINDOW_LABEL => Some(self.launcher_generation.as_ref()),
+            CLIPBOARD_WINDOW_LABEL => Some(self.clipboard_generation.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+fn auto_hide_delay(settings: &WindowAutoHideSettingsDto, label: &str) -> Option<Duration> {
+    let (enabled, delay_ms) = match label {
+        LAUNCHER_WINDOW_LABEL => (settings.launcher_enabled, settings.launcher_delay_ms),
+        CLIPBOARD_WINDOW_LABEL => (settings.clipboard_enabled, settings.clipboard_delay_ms),
+        _ => return None,
+    };
+    enabled.then(|| Duration::from_millis(u64::from(delay_ms)))
+}
+
+fn hide_if_still_blurred<R: Runtime>(window: &Window<R>) {
+    // A pinned (always-on-top) window is kept open on purpose.
+    if window.is_focused().unwrap_or(false)
+        || !window.is_visible().unwrap_or(false)
+        || window.is_always_on_top().unwrap_or(false)
+    {
+        return;
+    }
+    if let Err(error) = window.hide() {
+        tracing::warn!(
+            event = "window_auto_hide_failed",
+            window = window.label(),
+            error = error.to_string()
+        );
+    }
+}
+
+pub(crate) fn handle_focus_changed<R: Runtime>(window: &Window<R>, focused: bool) {
+    let Some(state) = window.try_state::<AppState>() else {
+        return;
+    };
+    let auto_hide = &state.window_auto_hide;
+    let Some(generation) = auto_hide.generation(window.label()) else {
+        return;
+    };
+    // Any focus change invalidates a pending hide for this window.
+    let current = generation.fetch_add(1, Ordering::SeqCst) + 1;
+    if focused {
+        return;
+    }
+    let Some(delay) = auto_hide_delay(&auto_hide.settings(), window.label()) else {
+        return;
+    };
+    if delay.is_zero() {
+        hide_if_still_blurred(window);
+        return;
+    }
+
+    let window = window.clone();
+    let auto_hide = auto_hide.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(delay).await;
+        let still_pending = auto_hide
+            .generation(window.label())
+            .is_some_and(|generation| generation.load(Ordering::SeqCst) == current);
+        if still_pending {
+            hide_if_still_blurred(&window);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn auto_hide_delay_follows_per_window_toggle() {
+        let settings = WindowAutoHideSettingsDto {
+            launcher_enabled: true,
+            launcher_delay_ms: 0,
+            clipboard_enabled: false,
+            clipboard_delay_ms: 300,
+        };
+
+        assert_eq!(
+            auto_hide_delay(&settings, LAUNCHER_WINDOW_LABEL),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(auto_hide_delay(&settings, CLIPBOARD_WINDOW_LABEL), None);
+        assert_eq!(auto_hide_delay(&settings, "main"), None);
+    }
+}
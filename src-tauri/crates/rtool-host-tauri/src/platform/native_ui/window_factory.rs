@@ -1,6 +1,4 @@
-use crate::constants::{
-    CLIPBOARD_WINDOW_LABEL, LAUNCHER_OPENED_EVENT, LAUNCHER_WINDOW_LABEL,
-};
+use crate::constants::{CLIPBOARD_WINDOW_LABEL, LAUNCHER_OPENED_EVENT, LAUNCHER_WINDOW_LABEL};
 use rtool_contracts::{AppError, AppResult};
 use std::collections::HashSet;
 use std::sync::Mutex;
@@ -2,7 +2,8 @@ use crate::constants::{
     CLIPBOARD_WINDOW_LABEL, LAUNCHER_OPENED_EVENT, LAUNCHER_WINDOW_LABEL, MAIN_WINDOW_LABEL,
     SCREENSHOT_PIN_WINDOW_LABELS, SCREENSHOT_WINDOW_LABEL,
 };
-use crate::platform::native_ui::window_factory::{ensure_webview_window, WindowWarmupState};
+use crate::platform::native_ui::clipboard_window::remember_clipboard_paste_target;
+use crate::platform::native_ui::window_factory::{WindowWarmupState, ensure_webview_window};
 use rtool_app::LocaleApplicationService;
 use tauri::{AppHandle, Emitter, Manager, Runtime};
 
@@ -69,6 +70,7 @@ pub(crate) fn toggle_launcher_window(app: &AppHandle) {
         return;
     }
 
+    remember_clipboard_paste_target(app);
     if let Some(state) = app.try_state::<WindowWarmupState>()
         && state.request_show_if_not_ready(LAUNCHER_WINDOW_LABEL)
     {
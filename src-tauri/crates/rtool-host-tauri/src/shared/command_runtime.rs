@@ -6,7 +6,9 @@ use std::sync::{Mutex, OnceLock};
 use std::time::Instant;
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use rtool_app::{RecordLogInput, record_log_event_best_effort, sanitize_for_log};
+use rtool_app::{
+    RecordLogInput, build_error_cause_summary, record_log_event_best_effort, sanitize_for_log,
+};
 use rtool_contracts::{AppError, AppResult, InvokeError};
 use rtool_kernel::RequestContext;
 
@@ -179,6 +181,7 @@ fn emit_runtime_summary(window: CommandRuntimeWindow, ended_at_ms: i64, trigger:
             "topCommands": make_top_commands(&window.by_command),
         })),
         raw_ref: None,
+        error_context: None,
     });
 }
 
@@ -271,6 +274,7 @@ pub(crate) fn command_start(
                 "sampleRate": COMMAND_START_SAMPLE_RATE,
             })),
             raw_ref: None,
+            error_context: None,
         });
     }
 
@@ -315,6 +319,7 @@ pub(crate) fn command_end_ok(command: &str, request_id: &str, started_at: Instan
                 "isSlowPath": duration_ms >= COMMAND_SLOW_DETAIL_MS,
             })),
             raw_ref: None,
+            error_context: None,
         });
     }
 }
@@ -364,15 +369,17 @@ where
             "errorCauses": causes,
             "errorCausesCount": error.causes.len(),
             "errorPrimaryCause": primary_cause,
-            "errorContext": error.context.iter().map(|item| serde_json::json!({
-                "key": item.key,
-                "value": sanitize_for_log(&item.value),
-            })).collect::<Vec<_>>(),
         })),
         raw_ref: None,
+        error_context: Some(error.context.clone()),
     });
 }
 
+fn attach_cause_summary(error: InvokeError) -> InvokeError {
+    let summary = build_error_cause_summary(&error.causes, &error.context);
+    error.with_cause_summary(summary)
+}
+
 pub(crate) fn run_command_sync<T, E, F>(
     command: &str,
     request_id: Option<String>,
@@ -390,7 +397,7 @@ where
         Ok(_) => command_end_ok(command, context.request_id(), started_at),
         Err(error) => command_end_error(command, context.request_id(), started_at, error),
     }
-    result.map_err(Into::into)
+    result.map_err(|error| attach_cause_summary(error.into()))
 }
 
 pub(crate) async fn run_command_async<T, E, Fut, F>(
@@ -411,7 +418,7 @@ where
         Ok(_) => command_end_ok(command, context.request_id(), started_at),
         Err(error) => command_end_error(command, context.request_id(), started_at, error),
     }
-    result.map_err(Into::into)
+    result.map_err(|error| attach_cause_summary(error.into()))
 }
 
 pub(crate) async fn run_blocking_command<T, F>(
@@ -5,6 +5,10 @@ use std::path::Path;
 pub const APP_LOCALE_PREFERENCE_KEY: &str = "app.locale.preference";
 pub const SYSTEM_LOCALE_PREFERENCE: &str = "system";
 pub const DEFAULT_RESOLVED_LOCALE: &str = "zh-CN";
+pub const TEXT_DIRECTION_LTR: &str = "ltr";
+pub const TEXT_DIRECTION_RTL: &str = "rtl";
+
+const RTL_LANGUAGES: [&str; 4] = ["ar", "he", "fa", "ur"];
 
 pub type AppLocalePreference = String;
 pub type ResolvedAppLocale = String;
@@ -13,13 +17,16 @@ pub type ResolvedAppLocale = String;
 pub struct AppLocaleState {
     pub preference: AppLocalePreference,
     pub resolved: ResolvedAppLocale,
+    pub text_direction: String,
 }
 
 impl AppLocaleState {
     pub fn new(preference: AppLocalePreference, resolved: ResolvedAppLocale) -> Self {
+        let text_direction = text_direction(resolved.as_str()).to_string();
         Self {
             preference,
             resolved,
+            text_direction,
         }
     }
 
@@ -27,6 +34,7 @@ impl AppLocaleState {
         LocaleStateDto {
             preference: self.preference,
             resolved: self.resolved,
+            text_direction: self.text_direction,
         }
     }
 }
@@ -36,6 +44,25 @@ impl AppLocaleState {
 pub struct LocaleStateDto {
     pub preference: AppLocalePreference,
     pub resolved: ResolvedAppLocale,
+    pub text_direction: String,
+}
+
+pub fn is_rtl(locale: &str) -> bool {
+    let language = locale
+        .trim()
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    RTL_LANGUAGES.contains(&language.as_str())
+}
+
+pub fn text_direction(locale: &str) -> &'static str {
+    if is_rtl(locale) {
+        TEXT_DIRECTION_RTL
+    } else {
+        TEXT_DIRECTION_LTR
+    }
 }
 
 pub fn resolve_system_locale() -> ResolvedAppLocale {
@@ -106,3 +133,27 @@ pub fn t(locale: &str, key: &str) -> String {
     tracing::warn!(event = "i18n_missing_key", locale = locale, key = key);
     key.to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_rtl_matches_rtl_languages_with_or_without_region() {
+        for locale in ["ar", "ar-SA", "he-IL", "fa", "ur_PK", "AR-eg"] {
+            assert!(is_rtl(locale), "{locale} should be rtl");
+        }
+        for locale in ["zh-CN", "en-US", "", "arn", "hr"] {
+            assert!(!is_rtl(locale), "{locale} should be ltr");
+        }
+    }
+
+    #[test]
+    fn locale_state_carries_text_direction() {
+        let state = AppLocaleState::new("ar-SA".to_string(), "ar-SA".to_string());
+        assert_eq!(state.into_dto().text_direction, TEXT_DIRECTION_RTL);
+
+        let state = AppLocaleState::new(SYSTEM_LOCALE_PREFERENCE.to_string(), "en-US".to_string());
+        assert_eq!(state.into_dto().text_direction, TEXT_DIRECTION_LTR);
+    }
+}
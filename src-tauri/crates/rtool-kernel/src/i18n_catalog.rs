@@ -1,8 +1,8 @@
 use anyhow::{Context, Result};
 use serde_json::Value;
-use std::collections::{BTreeSet, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::{OnceLock, RwLock, RwLockReadGuard, RwLockWriteGuard};
 
 struct BuiltinBundle {
@@ -149,6 +149,56 @@ impl I18nCatalog {
             .or_else(|| self.builtin.get(locale, key))
     }
 
+    fn locale_entries(&self, locale: &str) -> Option<BTreeMap<String, String>> {
+        let builtin = self.builtin.values.get(locale);
+        let overlay = self.overlay.values.get(locale);
+        if builtin.is_none() && overlay.is_none() {
+            return None;
+        }
+        let mut entries = BTreeMap::new();
+        for bucket in [builtin, overlay].into_iter().flatten() {
+            for (key, value) in bucket {
+                entries.insert(key.clone(), value.clone());
+            }
+        }
+        Some(entries)
+    }
+
+    fn loaded_locales(&self) -> BTreeSet<String> {
+        self.builtin
+            .values
+            .keys()
+            .chain(self.overlay.values.keys())
+            .cloned()
+            .collect()
+    }
+
+    fn locale_stats(&self, locale: &str) -> Option<LocaleStats> {
+        let entries = self.locale_entries(locale)?;
+        let reference = self
+            .locale_entries(STATS_REFERENCE_LOCALE)
+            .unwrap_or_default();
+        let mut stats = LocaleStats {
+            locale: locale.to_string(),
+            total_keys: reference.len() as u32,
+            ..LocaleStats::default()
+        };
+        for key in reference.keys() {
+            match entries.get(key) {
+                None => stats.missing_keys += 1,
+                Some(value) if value.trim().is_empty() => stats.empty_value_keys += 1,
+                Some(_) => stats.translated_keys += 1,
+            }
+        }
+        stats.completeness_percent = if stats.total_keys == 0 {
+            100.0
+        } else {
+            let ratio = f64::from(stats.translated_keys) / f64::from(stats.total_keys);
+            (ratio * 10_000.0).round() / 100.0
+        };
+        Some(stats)
+    }
+
     fn lookup_with_fallback(&self, locale: &str, fallback_locale: &str, key: &str) -> Option<&str> {
         self.lookup_in_locale(locale, key)
             .or_else(|| self.lookup_in_locale(fallback_locale, key))
@@ -162,6 +212,19 @@ struct OverlayLoadResult {
     warnings: Vec<String>,
 }
 
+/// Every locale is measured against the English catalog's key set.
+const STATS_REFERENCE_LOCALE: &str = "en-US";
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LocaleStats {
+    pub locale: String,
+    pub total_keys: u32,
+    pub translated_keys: u32,
+    pub missing_keys: u32,
+    pub empty_value_keys: u32,
+    pub completeness_percent: f64,
+}
+
 static CATALOG: OnceLock<RwLock<I18nCatalog>> = OnceLock::new();
 
 pub fn initialize(app_data_dir: &Path) -> Result<()> {
@@ -205,6 +268,114 @@ pub fn translate(locale: &str, fallback_locale: &str, key: &str) -> Option<Strin
         .map(ToString::to_string)
 }
 
+/// Returns the effective translations for `locale`, with overlay values taking precedence.
+pub fn locale_entries(locale: &str) -> Option<BTreeMap<String, String>> {
+    let lock = CATALOG.get()?;
+    read_guard(lock).locale_entries(locale)
+}
+
+pub fn locale_stats(locale: &str) -> Option<LocaleStats> {
+    let lock = CATALOG.get()?;
+    read_guard(lock).locale_stats(locale)
+}
+
+pub fn list_locale_stats() -> Vec<LocaleStats> {
+    let Some(lock) = CATALOG.get() else {
+        return Vec::new();
+    };
+    let catalog = read_guard(lock);
+    catalog
+        .loaded_locales()
+        .iter()
+        .filter_map(|locale| catalog.locale_stats(locale))
+        .collect()
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LocaleImportReport {
+    pub entries: BTreeMap<String, String>,
+    pub skipped_keys: Vec<String>,
+    pub invalid_keys: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LocaleImportParseError {
+    Syntax {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    NotObject,
+}
+
+/// Parses an overlay file, skipping keys unknown to the builtin catalog and collecting invalid ones.
+pub fn parse_locale_import(
+    content: &str,
+) -> std::result::Result<LocaleImportReport, LocaleImportParseError> {
+    let known_keys = CATALOG.get().map(|lock| {
+        read_guard(lock)
+            .builtin
+            .values
+            .values()
+            .flat_map(|bucket| bucket.keys().cloned())
+            .collect::<BTreeSet<_>>()
+    });
+    parse_locale_import_with(content, |key| {
+        known_keys.as_ref().is_none_or(|known| known.contains(key))
+    })
+}
+
+fn parse_locale_import_with(
+    content: &str,
+    is_known_key: impl Fn(&str) -> bool,
+) -> std::result::Result<LocaleImportReport, LocaleImportParseError> {
+    let value: Value =
+        serde_json::from_str(content).map_err(|error| LocaleImportParseError::Syntax {
+            line: error.line(),
+            column: error.column(),
+            message: error.to_string(),
+        })?;
+    let object = value.as_object().ok_or(LocaleImportParseError::NotObject)?;
+
+    let mut report = LocaleImportReport::default();
+    for (key, value) in object {
+        let Some(text) = value.as_str() else {
+            report.invalid_keys.push(key.clone());
+            continue;
+        };
+        if validate_key(key).is_err() {
+            report.invalid_keys.push(key.clone());
+            continue;
+        }
+        if !is_known_key(key) {
+            report.skipped_keys.push(key.clone());
+            continue;
+        }
+        report.entries.insert(key.clone(), text.to_string());
+    }
+    Ok(report)
+}
+
+/// Writes `entries` as the overlay for `locale`/`namespace` and reloads the catalog.
+pub fn import_overlay_namespace(
+    app_data_dir: &Path,
+    locale: &str,
+    namespace: &str,
+    entries: &BTreeMap<String, String>,
+) -> Result<PathBuf> {
+    validate_locale_code(locale)?;
+    validate_namespace(namespace)?;
+    let locale_dir = app_data_dir.join("locales").join(locale.trim());
+    fs::create_dir_all(&locale_dir)
+        .with_context(|| format!("创建语言目录失败: {}", locale_dir.display()))?;
+    let target = locale_dir.join(format!("{}.json", namespace.trim()));
+    let content = serde_json::to_string_pretty(entries).context("序列化 overlay 失败")?;
+    fs::write(&target, format!("{content}\n"))
+        .with_context(|| format!("写入 overlay 文件失败: {}", target.display()))?;
+    initialize(app_data_dir)?;
+    Ok(target)
+}
+
 fn read_guard(lock: &RwLock<I18nCatalog>) -> RwLockReadGuard<'_, I18nCatalog> {
     match lock.read() {
         Ok(guard) => guard,
@@ -408,3 +579,107 @@ fn validate_namespace(namespace: &str) -> Result<()> {
     );
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layer(locale: &str, entries: &[(&str, &str)]) -> CatalogLayer {
+        let mut layer = CatalogLayer::default();
+        layer.insert_namespace(
+            locale,
+            "common",
+            entries
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+        );
+        layer
+    }
+
+    #[test]
+    fn locale_entries_merge_overlay_over_builtin_in_key_order() {
+        let catalog = I18nCatalog {
+            builtin: layer("en-US", &[("b.key", "B"), ("a.key", "A")]),
+            overlay: layer("en-US", &[("b.key", "B2")]),
+        };
+
+        let entries = catalog.locale_entries("en-US").expect("entries");
+        assert_eq!(
+            entries.into_iter().collect::<Vec<_>>(),
+            vec![
+                ("a.key".to_string(), "A".to_string()),
+                ("b.key".to_string(), "B2".to_string()),
+            ]
+        );
+        assert!(catalog.locale_entries("fr-FR").is_none());
+    }
+
+    #[test]
+    fn locale_stats_measure_against_english_keys() {
+        let mut builtin = layer("en-US", &[("a.key", "A"), ("b.key", "B"), ("c.key", "C")]);
+        builtin.insert_namespace(
+            "zh-CN",
+            "common",
+            [("a.key", "甲"), ("b.key", " "), ("extra.key", "多余")]
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+        );
+        let catalog = I18nCatalog {
+            builtin,
+            overlay: CatalogLayer::default(),
+        };
+
+        let stats = catalog.locale_stats("zh-CN").expect("stats");
+        assert_eq!(stats.total_keys, 3);
+        assert_eq!(stats.translated_keys, 1);
+        assert_eq!(stats.empty_value_keys, 1);
+        assert_eq!(stats.missing_keys, 1);
+        assert_eq!(stats.completeness_percent, 33.33);
+        assert_eq!(
+            catalog
+                .locale_stats("en-US")
+                .expect("stats")
+                .completeness_percent,
+            100.0
+        );
+        assert!(catalog.locale_stats("fr-FR").is_none());
+        assert_eq!(
+            catalog.loaded_locales().into_iter().collect::<Vec<_>>(),
+            vec!["en-US".to_string(), "zh-CN".to_string()]
+        );
+    }
+
+    #[test]
+    fn parse_locale_import_classifies_keys() {
+        let report = parse_locale_import_with(
+            r#"{"known.key":"Hi","unknown.key":"Yo","bad key":"x","known.number":1}"#,
+            |key| key.starts_with("known."),
+        )
+        .expect("report");
+        assert_eq!(
+            report.entries.into_iter().collect::<Vec<_>>(),
+            vec![("known.key".to_string(), "Hi".to_string())]
+        );
+        assert_eq!(report.skipped_keys, vec!["unknown.key".to_string()]);
+        let mut invalid = report.invalid_keys;
+        invalid.sort();
+        assert_eq!(
+            invalid,
+            vec!["bad key".to_string(), "known.number".to_string()]
+        );
+    }
+
+    #[test]
+    fn parse_locale_import_reports_position_and_shape_errors() {
+        match parse_locale_import_with("{\n  \"a\": ", |_| true) {
+            Err(LocaleImportParseError::Syntax { line, .. }) => assert_eq!(line, 2),
+            other => panic!("expected syntax error, got {other:?}"),
+        }
+        assert_eq!(
+            parse_locale_import_with("[1, 2]", |_| true),
+            Err(LocaleImportParseError::NotObject)
+        );
+    }
+}
@@ -0,0 +1,239 @@
+pub const BYTE_UNIT_DECIMAL: &str = "decimal";
+pub const BYTE_UNIT_BINARY: &str = "binary";
+pub const DEFAULT_BYTE_UNIT: &str = BYTE_UNIT_DECIMAL;
+
+const DECIMAL_UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+const BINARY_UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+const FRENCH_DECIMAL_UNITS: [&str; 5] = ["o", "Ko", "Mo", "Go", "To"];
+const FRENCH_BINARY_UNITS: [&str; 5] = ["o", "Kio", "Mio", "Gio", "Tio"];
+
+const COMMA_DECIMAL_DOT_GROUP_LANGUAGES: [&str; 10] =
+    ["de", "es", "it", "pt", "nl", "id", "tr", "da", "el", "ro"];
+const COMMA_DECIMAL_SPACE_GROUP_LANGUAGES: [&str; 9] =
+    ["ru", "pl", "cs", "sv", "nb", "fi", "uk", "sk", "hu"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ByteUnitSystem {
+    #[default]
+    Decimal,
+    Binary,
+}
+
+impl ByteUnitSystem {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Decimal => BYTE_UNIT_DECIMAL,
+            Self::Binary => BYTE_UNIT_BINARY,
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            BYTE_UNIT_DECIMAL => Some(Self::Decimal),
+            BYTE_UNIT_BINARY => Some(Self::Binary),
+            _ => None,
+        }
+    }
+
+    fn base(self) -> f64 {
+        match self {
+            Self::Decimal => 1000.0,
+            Self::Binary => 1024.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct NumberSeparators {
+    decimal: char,
+    group: char,
+}
+
+fn locale_language(locale: &str) -> String {
+    locale
+        .trim()
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase()
+}
+
+fn number_separators(locale: &str) -> NumberSeparators {
+    let language = locale_language(locale);
+    if COMMA_DECIMAL_DOT_GROUP_LANGUAGES.contains(&language.as_str()) {
+        return NumberSeparators {
+            decimal: ',',
+            group: '.',
+        };
+    }
+    if language == "fr" {
+        return NumberSeparators {
+            decimal: ',',
+            group: '\u{202F}',
+        };
+    }
+    if COMMA_DECIMAL_SPACE_GROUP_LANGUAGES.contains(&language.as_str()) {
+        return NumberSeparators {
+            decimal: ',',
+            group: '\u{00A0}',
+        };
+    }
+    NumberSeparators {
+        decimal: '.',
+        group: ',',
+    }
+}
+
+fn byte_units(locale: &str, unit: ByteUnitSystem) -> &'static [&'static str; 5] {
+    let french = locale_language(locale) == "fr";
+    match (unit, french) {
+        (ByteUnitSystem::Decimal, false) => &DECIMAL_UNITS,
+        (ByteUnitSystem::Binary, false) => &BINARY_UNITS,
+        (ByteUnitSystem::Decimal, true) => &FRENCH_DECIMAL_UNITS,
+        (ByteUnitSystem::Binary, true) => &FRENCH_BINARY_UNITS,
+    }
+}
+
+fn group_digits(digits: &str, group: char) -> String {
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (index, ch) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push(group);
+        }
+        grouped.push(ch);
+    }
+    grouped
+}
+
+fn format_fixed(separators: NumberSeparators, value: f64, fraction_digits: usize) -> String {
+    let fixed = format!("{value:.fraction_digits$}");
+    let (integer, fraction) = fixed.split_once('.').unwrap_or((fixed.as_str(), ""));
+    let mut output = group_digits(integer, separators.group);
+    if !fraction.is_empty() {
+        output.push(separators.decimal);
+        output.push_str(fraction);
+    }
+    output
+}
+
+pub fn format_number(locale: &str, value: i64) -> String {
+    let separators = number_separators(locale);
+    let digits = value.unsigned_abs().to_string();
+    let grouped = group_digits(&digits, separators.group);
+    if value < 0 {
+        format!("-{grouped}")
+    } else {
+        grouped
+    }
+}
+
+fn byte_fraction_digits(unit_index: usize, size: f64) -> usize {
+    if unit_index == 0 || size >= 100.0 {
+        0
+    } else {
+        1
+    }
+}
+
+fn round_to_fraction_digits(value: f64, fraction_digits: usize) -> f64 {
+    let factor = 10f64.powi(fraction_digits as i32);
+    (value * factor).round() / factor
+}
+
+pub fn format_bytes(locale: &str, bytes: u64, unit: ByteUnitSystem) -> String {
+    let separators = number_separators(locale);
+    let units = byte_units(locale, unit);
+    let base = unit.base();
+
+    let mut size = bytes as f64;
+    let mut unit_index = 0;
+    while size >= base && unit_index < units.len() - 1 {
+        size /= base;
+        unit_index += 1;
+    }
+
+    let mut fraction_digits = byte_fraction_digits(unit_index, size);
+    // Rounding can carry into the next unit (999.96 KB) or past 100 (99.96 KB).
+    let rounded = round_to_fraction_digits(size, fraction_digits);
+    if rounded >= base && unit_index < units.len() - 1 {
+        size /= base;
+        unit_index += 1;
+        fraction_digits = byte_fraction_digits(unit_index, size);
+    } else if rounded >= 100.0 {
+        fraction_digits = 0;
+    }
+    format!(
+        "{} {}",
+        format_fixed(separators, size, fraction_digits),
+        units[unit_index]
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_numbers_with_locale_grouping() {
+        assert_eq!(format_number("en-US", 1_234_567), "1,234,567");
+        assert_eq!(format_number("zh-CN", -1_234), "-1,234");
+        assert_eq!(format_number("de-DE", 1_234_567), "1.234.567");
+        assert_eq!(format_number("fr-FR", 1_234), "1\u{202F}234");
+        assert_eq!(format_number("en-US", 999), "999");
+    }
+
+    #[test]
+    fn formats_bytes_with_locale_decimal_and_units() {
+        let bytes = 1_200_000_000;
+        assert_eq!(
+            format_bytes("en-US", bytes, ByteUnitSystem::Decimal),
+            "1.2 GB"
+        );
+        assert_eq!(
+            format_bytes("fr-FR", bytes, ByteUnitSystem::Decimal),
+            "1,2 Go"
+        );
+        assert_eq!(
+            format_bytes("de-DE", bytes, ByteUnitSystem::Binary),
+            "1,1 GiB"
+        );
+        assert_eq!(format_bytes("en-US", 512, ByteUnitSystem::Binary), "512 B");
+        assert_eq!(
+            format_bytes("en-US", 150 * 1024, ByteUnitSystem::Binary),
+            "150 KiB"
+        );
+    }
+
+    #[test]
+    fn promotes_byte_unit_when_rounding_carries_over() {
+        assert_eq!(
+            format_bytes("en-US", 999_960, ByteUnitSystem::Decimal),
+            "1.0 MB"
+        );
+        assert_eq!(
+            format_bytes("en-US", 1024 * 1024 - 10, ByteUnitSystem::Binary),
+            "1.0 MiB"
+        );
+        assert_eq!(
+            format_bytes("en-US", 99_960, ByteUnitSystem::Decimal),
+            "100 KB"
+        );
+        assert_eq!(
+            format_bytes("en-US", 999_400, ByteUnitSystem::Decimal),
+            "999 KB"
+        );
+    }
+
+    #[test]
+    fn parses_byte_unit_preference() {
+        assert_eq!(
+            ByteUnitSystem::parse(" Binary "),
+            Some(ByteUnitSystem::Binary)
+        );
+        assert_eq!(
+            ByteUnitSystem::parse("decimal"),
+            Some(ByteUnitSystem::Decimal)
+        );
+        assert_eq!(ByteUnitSystem::parse("si"), None);
+    }
+}
@@ -1,6 +1,7 @@
 pub mod feature;
 pub mod i18n;
 pub mod i18n_catalog;
+pub mod i18n_format;
 mod orchestrator;
 pub mod request_context;
 pub mod runtime_budget;
@@ -13,4 +14,4 @@ pub use orchestrator::{
 };
 pub use request_context::RequestContext;
 pub use runtime_budget::RuntimeBudget;
-pub use runtime_state::RuntimeState;
+pub use runtime_state::{ClipboardPauseState, RuntimeState};
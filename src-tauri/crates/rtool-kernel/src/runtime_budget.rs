@@ -8,6 +8,9 @@ pub struct RuntimeBudget {
     pub app_manager_poll_base_secs: u64,
     pub app_manager_poll_min_secs: u64,
     pub app_manager_poll_max_secs: u64,
+    pub clipboard_burst_window_ms: u64,
+    pub clipboard_burst_max_saves: u32,
+    pub clipboard_sync_debounce_ms: u64,
 }
 
 impl Default for RuntimeBudget {
@@ -18,6 +21,9 @@ impl Default for RuntimeBudget {
             app_manager_poll_base_secs: 20,
             app_manager_poll_min_secs: 5,
             app_manager_poll_max_secs: 120,
+            clipboard_burst_window_ms: 2000,
+            clipboard_burst_max_saves: 20,
+            clipboard_sync_debounce_ms: 80,
         }
     }
 }
@@ -56,6 +62,15 @@ impl RuntimeBudget {
             app_manager_poll_base_secs: parse_u64("RTOOL_APP_MANAGER_POLL_BASE_SECS", 20, 2, 300),
             app_manager_poll_min_secs: parse_u64("RTOOL_APP_MANAGER_POLL_MIN_SECS", 5, 1, 60),
             app_manager_poll_max_secs: parse_u64("RTOOL_APP_MANAGER_POLL_MAX_SECS", 120, 5, 600),
+            clipboard_burst_window_ms: parse_u64(
+                "RTOOL_CLIPBOARD_BURST_WINDOW_MS",
+                2000,
+                200,
+                60_000,
+            ),
+            clipboard_burst_max_saves: parse_usize("RTOOL_CLIPBOARD_BURST_MAX_SAVES", 20, 2, 1000)
+                as u32,
+            clipboard_sync_debounce_ms: parse_u64("RTOOL_CLIPBOARD_SYNC_DEBOUNCE_MS", 80, 10, 2000),
         };
 
         if budget.app_manager_poll_min_secs > budget.app_manager_poll_max_secs {
@@ -82,7 +97,10 @@ impl RuntimeBudget {
                 screenshot_clipboard_concurrency = budget.screenshot_clipboard_concurrency,
                 app_manager_poll_base_secs = budget.app_manager_poll_base_secs,
                 app_manager_poll_min_secs = budget.app_manager_poll_min_secs,
-                app_manager_poll_max_secs = budget.app_manager_poll_max_secs
+                app_manager_poll_max_secs = budget.app_manager_poll_max_secs,
+                clipboard_burst_window_ms = budget.clipboard_burst_window_ms,
+                clipboard_burst_max_saves = budget.clipboard_burst_max_saves,
+                clipboard_sync_debounce_ms = budget.clipboard_sync_debounce_ms
             );
             budget
         })
@@ -2,11 +2,25 @@ use crate::i18n::{AppLocaleState, LocaleStateDto, ResolvedAppLocale};
 use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClipboardPauseState {
+    pub paused_at: i64,
+    pub resume_at: Option<i64>,
+}
+
+impl ClipboardPauseState {
+    pub fn is_expired(&self, now_ms: i64) -> bool {
+        self.resume_at.is_some_and(|resume_at| now_ms >= resume_at)
+    }
+}
+
 #[derive(Clone)]
 pub struct RuntimeState {
     locale_state: Arc<Mutex<AppLocaleState>>,
     clipboard_window_compact: Arc<Mutex<bool>>,
     screenshot_shortcut_id: Arc<Mutex<Option<u32>>>,
+    clipboard_paste_target: Arc<Mutex<Option<String>>>,
+    clipboard_pause: Arc<Mutex<Option<ClipboardPauseState>>>,
     started_at: Instant,
 }
 
@@ -20,6 +34,8 @@ impl RuntimeState {
             locale_state: Arc::new(Mutex::new(initial_locale_state)),
             clipboard_window_compact: Arc::new(Mutex::new(false)),
             screenshot_shortcut_id: Arc::new(Mutex::new(screenshot_shortcut_id)),
+            clipboard_paste_target: Arc::new(Mutex::new(None)),
+            clipboard_pause: Arc::new(Mutex::new(None)),
             started_at,
         }
     }
@@ -94,4 +110,81 @@ impl RuntimeState {
             }
         }
     }
+
+    pub fn clipboard_paste_target(&self) -> Option<String> {
+        match self.clipboard_paste_target.lock() {
+            Ok(guard) => guard.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
+    }
+
+    pub fn set_clipboard_paste_target(&self, app_name: Option<String>) {
+        match self.clipboard_paste_target.lock() {
+            Ok(mut guard) => {
+                *guard = app_name;
+            }
+            Err(poisoned) => {
+                let mut guard = poisoned.into_inner();
+                *guard = app_name;
+            }
+        }
+    }
+
+    /// Expired pauses read as resumed but stay stored; the auto-resume task clears them so it
+    /// can notify listeners.
+    pub fn clipboard_pause(&self, now_ms: i64) -> Option<ClipboardPauseState> {
+        let pause = match self.clipboard_pause.lock() {
+            Ok(guard) => *guard,
+            Err(poisoned) => *poisoned.into_inner(),
+        };
+        pause.filter(|value| !value.is_expired(now_ms))
+    }
+
+    pub fn set_clipboard_pause(&self, pause: Option<ClipboardPauseState>) {
+        match self.clipboard_pause.lock() {
+            Ok(mut guard) => {
+                *guard = pause;
+            }
+            Err(poisoned) => {
+                let mut guard = poisoned.into_inner();
+                *guard = pause;
+            }
+        }
+    }
+
+    pub fn clear_clipboard_pause_if(&self, expected: ClipboardPauseState) -> bool {
+        let mut guard = match self.clipboard_pause.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        if *guard != Some(expected) {
+            return false;
+        }
+        *guard = None;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reading_an_expired_pause_leaves_it_for_auto_resume() {
+        let state = RuntimeState::new(
+            AppLocaleState::new("zh-CN".to_string(), "zh-CN".to_string()),
+            Instant::now(),
+            None,
+        );
+        let pause = ClipboardPauseState {
+            paused_at: 1_000,
+            resume_at: Some(2_000),
+        };
+        state.set_clipboard_pause(Some(pause));
+
+        assert_eq!(state.clipboard_pause(1_500), Some(pause));
+        assert_eq!(state.clipboard_pause(2_000), None);
+        assert!(state.clear_clipboard_pause_if(pause));
+        assert!(!state.clear_clipboard_pause_if(pause));
+    }
 }
@@ -1,9 +1,11 @@
 use super::ingest::{normalize_level, sanitize_for_log};
 use super::{
     DEFAULT_ALLOW_RAW_VIEW, DEFAULT_HIGH_FREQ_MAX_PER_KEY, DEFAULT_HIGH_FREQ_WINDOW_MS,
-    DEFAULT_KEEP_DAYS, DEFAULT_MIN_LEVEL, DEFAULT_REALTIME_ENABLED, SETTING_KEY_ALLOW_RAW_VIEW,
-    SETTING_KEY_HIGH_FREQ_MAX_PER_KEY, SETTING_KEY_HIGH_FREQ_WINDOW_MS, SETTING_KEY_KEEP_DAYS,
-    SETTING_KEY_MIN_LEVEL, SETTING_KEY_REALTIME_ENABLED,
+    DEFAULT_KEEP_DAYS, DEFAULT_LOG_FORMAT, DEFAULT_MIN_LEVEL, DEFAULT_REALTIME_ENABLED,
+    ERROR_DETAIL_LEVEL_MINIMAL, ERROR_DETAIL_LEVEL_STANDARD, ERROR_DETAIL_LEVEL_VERBOSE,
+    SETTING_KEY_ALLOW_RAW_VIEW, SETTING_KEY_ERROR_DETAIL_LEVEL, SETTING_KEY_HIGH_FREQ_MAX_PER_KEY,
+    SETTING_KEY_HIGH_FREQ_WINDOW_MS, SETTING_KEY_KEEP_DAYS, SETTING_KEY_LOG_FORMAT,
+    SETTING_KEY_MIN_LEVEL, SETTING_KEY_REALTIME_ENABLED, default_error_detail_level,
 };
 use crate::AppError;
 use crate::db::{self, DbConn};
@@ -13,6 +15,24 @@ fn bool_setting(value: bool) -> &'static str {
     if value { "true" } else { "false" }
 }
 
+pub(super) fn normalize_log_format(value: &str) -> Option<&'static str> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "" | "text" => Some("text"),
+        "cbor" => Some("cbor"),
+        _ => None,
+    }
+}
+
+pub(super) fn normalize_error_detail_level(value: &str) -> Option<&'static str> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "" => Some(default_error_detail_level()),
+        ERROR_DETAIL_LEVEL_MINIMAL => Some(ERROR_DETAIL_LEVEL_MINIMAL),
+        ERROR_DETAIL_LEVEL_STANDARD => Some(ERROR_DETAIL_LEVEL_STANDARD),
+        ERROR_DETAIL_LEVEL_VERBOSE => Some(ERROR_DETAIL_LEVEL_VERBOSE),
+        _ => None,
+    }
+}
+
 pub(super) async fn load_log_config(conn: &DbConn) -> LogConfigDto {
     let keys = [
         SETTING_KEY_MIN_LEVEL,
@@ -21,6 +41,8 @@ pub(super) async fn load_log_config(conn: &DbConn) -> LogConfigDto {
         SETTING_KEY_HIGH_FREQ_WINDOW_MS,
         SETTING_KEY_HIGH_FREQ_MAX_PER_KEY,
         SETTING_KEY_ALLOW_RAW_VIEW,
+        SETTING_KEY_LOG_FORMAT,
+        SETTING_KEY_ERROR_DETAIL_LEVEL,
     ];
     let settings = db::get_app_settings_batch(conn, &keys)
         .await
@@ -54,6 +76,15 @@ pub(super) async fn load_log_config(conn: &DbConn) -> LogConfigDto {
             .get(SETTING_KEY_ALLOW_RAW_VIEW)
             .and_then(|value| value.parse::<bool>().ok())
             .unwrap_or(DEFAULT_ALLOW_RAW_VIEW),
+        log_format: settings
+            .get(SETTING_KEY_LOG_FORMAT)
+            .and_then(|value| normalize_log_format(value).map(ToString::to_string))
+            .unwrap_or_else(|| DEFAULT_LOG_FORMAT.to_string()),
+        error_detail_level: settings
+            .get(SETTING_KEY_ERROR_DETAIL_LEVEL)
+            .and_then(|value| normalize_error_detail_level(value))
+            .unwrap_or_else(default_error_detail_level)
+            .to_string(),
     }
 }
 
@@ -83,6 +114,11 @@ pub(super) async fn persist_log_config(
             SETTING_KEY_ALLOW_RAW_VIEW,
             bool_setting(config.allow_raw_view),
         ),
+        (SETTING_KEY_LOG_FORMAT, config.log_format.as_str()),
+        (
+            SETTING_KEY_ERROR_DETAIL_LEVEL,
+            config.error_detail_level.as_str(),
+        ),
     ];
     db::set_app_settings_batch(conn, entries.as_slice()).await?;
     Ok(())
@@ -96,7 +132,22 @@ pub(super) fn clamp_and_normalize_config(
             .with_context("level", sanitize_for_log(&config.min_level))
     })?;
 
+    let log_format = normalize_log_format(&config.log_format).ok_or_else(|| {
+        AppError::new("invalid_log_format", "日志格式非法")
+            .with_context("logFormat", sanitize_for_log(&config.log_format))
+    })?;
+
+    let error_detail_level =
+        normalize_error_detail_level(&config.error_detail_level).ok_or_else(|| {
+            AppError::new("invalid_error_detail_level", "错误详情级别非法").with_context(
+                "errorDetailLevel",
+                sanitize_for_log(&config.error_detail_level),
+            )
+        })?;
+
     config.min_level = level.to_string();
+    config.log_format = log_format.to_string();
+    config.error_detail_level = error_detail_level.to_string();
     config.keep_days = config.keep_days.clamp(1, 90);
     config.high_freq_window_ms = config.high_freq_window_ms.clamp(100, 60_000);
     config.high_freq_max_per_key = config.high_freq_max_per_key.clamp(1, 200);
@@ -1,16 +1,53 @@
 use super::ingest::now_millis;
 use super::query;
-use super::{EXPORT_FLUSH_EVERY_PAGES, EXPORT_THROTTLE_SLEEP_MS};
-use crate::models::LogQueryDto;
+use super::{EXPORT_FLUSH_EVERY_PAGES, EXPORT_MAX_FILE_BYTES, EXPORT_THROTTLE_SLEEP_MS};
+use crate::models::{LogEntryDto, LogExportResultDto, LogQueryDto};
 use crate::{AppError, ResultExt};
 use anyhow::Context;
 use std::fs;
+use std::io::{BufRead, BufReader, BufWriter as StdBufWriter, Write};
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 use tokio::fs::File;
 use tokio::io::{AsyncWriteExt, BufWriter};
 use tokio::time::sleep;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LogExportFormat {
+    Jsonl,
+    Cbor,
+}
+
+impl LogExportFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Jsonl => "jsonl",
+            Self::Cbor => "cbor",
+        }
+    }
+}
+
+fn encode_export_entry(format: LogExportFormat, item: &LogEntryDto) -> Result<Vec<u8>, AppError> {
+    match format {
+        LogExportFormat::Jsonl => {
+            let mut line = serde_json::to_vec(item)
+                .with_context(|| format!("序列化日志导出内容失败: entryId={}", item.id))
+                .with_code("log_export_serialize_failed", "序列化日志导出内容失败")
+                .with_ctx("entryId", item.id.to_string())?;
+            line.push(b'\n');
+            Ok(line)
+        }
+        LogExportFormat::Cbor => {
+            let mut record = Vec::new();
+            ciborium::into_writer(item, &mut record)
+                .with_context(|| format!("序列化日志导出内容失败: entryId={}", item.id))
+                .with_code("log_export_serialize_failed", "序列化日志导出内容失败")
+                .with_ctx("entryId", item.id.to_string())?;
+            Ok(record)
+        }
+    }
+}
+
 async fn write_export_bytes(
     writer: &mut BufWriter<File>,
     target_path: &Path,
@@ -38,21 +75,52 @@ async fn flush_export_writer(
     Ok(())
 }
 
-pub(super) async fn export_log_entries(
+fn export_too_large_error(exported_count: u64) -> AppError {
+    AppError::new(
+        "logging_export_too_large",
+        "日志导出文件超过大小上限，请缩小时间范围后重试",
+    )
+    .with_context("maxBytes", EXPORT_MAX_FILE_BYTES.to_string())
+    .with_context("exportedCount", exported_count.to_string())
+}
+
+/// Always JSONL, whatever `log_format` is configured; CBOR goes through `export_log_entries_cbor`.
+pub(super) async fn export_log_entries_jsonl(
+    center: &super::LogCenter,
+    query: LogQueryDto,
+    output_path: Option<String>,
+) -> Result<LogExportResultDto, AppError> {
+    export_log_entries(center, query, output_path, LogExportFormat::Jsonl).await
+}
+
+pub(super) async fn export_log_entries_cbor(
     center: &super::LogCenter,
     query: LogQueryDto,
     output_path: Option<String>,
-) -> Result<String, AppError> {
+) -> Result<LogExportResultDto, AppError> {
+    export_log_entries(center, query, output_path, LogExportFormat::Cbor).await
+}
+
+async fn export_log_entries(
+    center: &super::LogCenter,
+    query: LogQueryDto,
+    output_path: Option<String>,
+    format: LogExportFormat,
+) -> Result<LogExportResultDto, AppError> {
     let mut cursor = query.cursor.clone();
     let mut page_count = 0u32;
+    let mut exported_count = 0u64;
+    let mut file_size_bytes = 0u64;
 
     let target_path = output_path
         .as_deref()
         .map(PathBuf::from)
         .unwrap_or_else(|| {
-            center
-                .log_dir
-                .join(format!("rtool-log-export-{}.jsonl", now_millis()))
+            center.log_dir.join(format!(
+                "rtool-log-export-{}.{}",
+                now_millis(),
+                format.extension()
+            ))
         });
     if let Some(parent) = target_path.parent() {
         fs::create_dir_all(parent)
@@ -75,12 +143,15 @@ pub(super) async fn export_log_entries(
 
         let page = query::query_log_entries(center, next_query).await?;
         for item in &page.items {
-            let line = serde_json::to_string(item)
-                .with_context(|| format!("序列化日志导出内容失败: entryId={}", item.id))
-                .with_code("log_export_serialize_failed", "序列化日志导出内容失败")
-                .with_ctx("entryId", item.id.to_string())?;
-            write_export_bytes(&mut writer, &target_path, line.as_bytes()).await?;
-            write_export_bytes(&mut writer, &target_path, b"\n").await?;
+            let record = encode_export_entry(format, item)?;
+            file_size_bytes = file_size_bytes.saturating_add(record.len() as u64);
+            if file_size_bytes > EXPORT_MAX_FILE_BYTES {
+                drop(writer);
+                let _ = tokio::fs::remove_file(&target_path).await;
+                return Err(export_too_large_error(exported_count));
+            }
+            write_export_bytes(&mut writer, &target_path, record.as_slice()).await?;
+            exported_count = exported_count.saturating_add(1);
         }
 
         page_count = page_count.saturating_add(1);
@@ -97,5 +168,169 @@ pub(super) async fn export_log_entries(
 
     flush_export_writer(&mut writer, &target_path).await?;
 
-    Ok(target_path.to_string_lossy().to_string())
+    Ok(LogExportResultDto {
+        path: target_path.to_string_lossy().to_string(),
+        exported_count,
+        file_size_bytes,
+    })
+}
+
+pub(super) fn convert_cbor_to_jsonl(cbor_path: &Path, output_path: &Path) -> Result<u64, AppError> {
+    let source = fs::File::open(cbor_path)
+        .with_context(|| format!("打开 CBOR 日志文件失败: {}", cbor_path.display()))
+        .with_code("log_convert_open_failed", "打开 CBOR 日志文件失败")
+        .with_ctx("cborPath", cbor_path.display().to_string())?;
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("创建日志导出目录失败: {}", parent.display()))
+            .with_code("log_export_dir_create_failed", "创建日志导出目录失败")
+            .with_ctx("outputDir", parent.display().to_string())?;
+    }
+    let target = fs::File::create(output_path)
+        .with_context(|| format!("创建日志导出文件失败: {}", output_path.display()))
+        .with_code("log_export_file_create_failed", "创建日志导出文件失败")
+        .with_ctx("targetPath", output_path.display().to_string())?;
+
+    let mut reader = BufReader::new(source);
+    let mut writer = StdBufWriter::new(target);
+    let mut converted = 0u64;
+    loop {
+        let at_end = reader
+            .fill_buf()
+            .with_context(|| format!("读取 CBOR 日志文件失败: {}", cbor_path.display()))
+            .with_code("log_convert_read_failed", "读取 CBOR 日志文件失败")
+            .with_ctx("cborPath", cbor_path.display().to_string())?
+            .is_empty();
+        if at_end {
+            break;
+        }
+
+        let entry: LogEntryDto = ciborium::from_reader(&mut reader)
+            .with_context(|| format!("解析 CBOR 日志记录失败: index={converted}"))
+            .with_code("log_convert_decode_failed", "解析 CBOR 日志记录失败")
+            .with_ctx("recordIndex", converted.to_string())?;
+        serde_json::to_writer(&mut writer, &entry)
+            .with_context(|| format!("序列化日志导出内容失败: entryId={}", entry.id))
+            .with_code("log_export_serialize_failed", "序列化日志导出内容失败")
+            .with_ctx("entryId", entry.id.to_string())?;
+        writer
+            .write_all(b"\n")
+            .with_context(|| format!("写入日志导出文件失败: {}", output_path.display()))
+            .with_code("log_export_write_failed", "写入日志导出文件失败")
+            .with_ctx("targetPath", output_path.display().to_string())?;
+        converted = converted.saturating_add(1);
+    }
+
+    writer
+        .flush()
+        .with_context(|| format!("刷新日志导出文件失败: {}", output_path.display()))
+        .with_code("log_export_flush_failed", "刷新日志导出文件失败")
+        .with_ctx("targetPath", output_path.display().to_string())?;
+    Ok(converted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::{init_db, open_db};
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    /// A log center with three entries whose configured `log_format` is CBOR.
+    async fn cbor_configured_center(label: &str) -> (PathBuf, super::super::LogCenter) {
+        let dir = std::env::temp_dir().join(format!(
+            "rtool-log-export-{label}-{}-{}",
+            std::process::id(),
+            now_millis()
+        ));
+        fs::create_dir_all(&dir).expect("create dir");
+        let db_path = dir.join("logs.db");
+        let db_conn = open_db(&db_path).await.expect("open db");
+        init_db(&db_conn).await.expect("init db");
+        let mut config = super::super::config::load_log_config(&db_conn).await;
+        config.log_format = "cbor".to_string();
+        let center = super::super::LogCenter {
+            event_sink: None,
+            db_conn,
+            log_dir: dir.clone(),
+            config: Mutex::new(config),
+            high_frequency: Mutex::new(HashMap::new()),
+            last_cleanup_at: Mutex::new(0),
+        };
+        for index in 0..3 {
+            let input = super::super::RecordLogInput {
+                level: "warn".to_string(),
+                scope: "app".to_string(),
+                event: format!("event_{index}"),
+                request_id: format!("req-{index}"),
+                window_label: Some("main".to_string()),
+                message: format!("message {index}"),
+                metadata: Some(serde_json::json!({ "index": index })),
+                raw_ref: None,
+                error_context: None,
+            };
+            super::super::store::save_log_entry(&center.db_conn, &input, index)
+                .await
+                .expect("save");
+        }
+        (dir, center)
+    }
+
+    #[tokio::test]
+    async fn jsonl_export_stays_jsonl_when_cbor_is_configured() {
+        let (dir, center) = cbor_configured_center("jsonl").await;
+        let output_path = dir.join("picked.jsonl");
+
+        let exported = export_log_entries_jsonl(
+            &center,
+            LogQueryDto::default(),
+            Some(output_path.to_string_lossy().to_string()),
+        )
+        .await
+        .expect("export");
+        let content = fs::read(&output_path).expect("read export");
+        let lines = String::from_utf8(content.clone())
+            .expect("utf8 export")
+            .lines()
+            .map(|line| serde_json::from_str::<LogEntryDto>(line).map(|_| ()))
+            .collect::<Result<Vec<_>, _>>();
+
+        drop(center);
+        let _ = fs::remove_dir_all(&dir);
+        assert_eq!(exported.exported_count, 3);
+        assert_eq!(exported.file_size_bytes, content.len() as u64);
+        assert_eq!(lines.expect("every line is JSON").len(), 3);
+    }
+
+    #[tokio::test]
+    async fn cbor_export_round_trips_through_jsonl_conversion() {
+        let (dir, center) = cbor_configured_center("cbor").await;
+        let expected = query::query_log_entries(&center, LogQueryDto::default())
+            .await
+            .expect("query")
+            .items;
+        let exported = export_log_entries_cbor(&center, LogQueryDto::default(), None)
+            .await
+            .expect("export");
+        let cbor_bytes = fs::metadata(&exported.path).expect("cbor metadata").len();
+        let jsonl_path = dir.join("converted.jsonl");
+        let converted =
+            convert_cbor_to_jsonl(Path::new(&exported.path), &jsonl_path).expect("convert");
+        let decoded = fs::read_to_string(&jsonl_path)
+            .expect("read jsonl")
+            .lines()
+            .map(|line| serde_json::from_str::<LogEntryDto>(line).expect("decode line"))
+            .collect::<Vec<_>>();
+
+        drop(center);
+        let _ = fs::remove_dir_all(&dir);
+        assert!(exported.path.ends_with(".cbor"));
+        assert_eq!(exported.exported_count, 3);
+        assert_eq!(exported.file_size_bytes, cbor_bytes);
+        assert_eq!(converted, 3);
+        assert_eq!(
+            serde_json::to_value(&decoded).expect("decoded json"),
+            serde_json::to_value(&expected).expect("expected json")
+        );
+    }
 }
@@ -1,10 +1,11 @@
 use super::store::{cleanup_expired_log_entries, save_log_entry, upsert_aggregated_log};
 use super::{
+    ERROR_DETAIL_LEVEL_MINIMAL, ERROR_DETAIL_LEVEL_VERBOSE, ERROR_SUMMARY_HIDDEN_CONTEXT_KEYS,
     LOG_RETENTION_CLEANUP_INTERVAL_MS, LogCenter, LogConfigDto, MAX_COLLECTION_ITEMS,
     MAX_NESTED_DEPTH, MAX_STRING_LEN, RecordLogInput, SENSITIVE_HOST_KEYS, SENSITIVE_PATH_KEYS,
     SENSITIVE_TEXT_KEYS,
 };
-use crate::{AppError, ResultExt};
+use crate::{AppError, ErrorContextItem, ResultExt};
 use anyhow::Context;
 use serde_json::{Map, Value};
 use std::collections::hash_map::DefaultHasher;
@@ -117,6 +118,55 @@ pub fn sanitize_for_log(value: &str) -> String {
     truncate_text(normalized, MAX_STRING_LEN)
 }
 
+fn unique_non_empty(values: impl Iterator<Item = String>) -> Vec<String> {
+    let mut unique = Vec::new();
+    for value in values {
+        if !value.is_empty() && !unique.contains(&value) {
+            unique.push(value);
+        }
+    }
+    unique
+}
+
+pub fn summarize_error_causes(
+    causes: &[String],
+    context: &[ErrorContextItem],
+    detail_level: &str,
+) -> Option<String> {
+    if detail_level == ERROR_DETAIL_LEVEL_MINIMAL {
+        return None;
+    }
+
+    let verbose = detail_level == ERROR_DETAIL_LEVEL_VERBOSE;
+    let visible_context = context
+        .iter()
+        .filter(|item| !ERROR_SUMMARY_HIDDEN_CONTEXT_KEYS.contains(&item.key.as_str()));
+    let cause_text = if verbose {
+        unique_non_empty(causes.iter().map(|cause| sanitize_for_log(cause))).join(" <- ")
+    } else {
+        causes
+            .first()
+            .map(|cause| sanitize_for_log(cause))
+            .unwrap_or_default()
+    };
+    // Nested `with_context` calls often repeat a key; each entry is shown once.
+    let context_text = if verbose {
+        unique_non_empty(
+            visible_context.map(|item| format!("{}={}", item.key, sanitize_for_log(&item.value))),
+        )
+        .join(", ")
+    } else {
+        unique_non_empty(visible_context.map(|item| item.key.clone())).join(", ")
+    };
+
+    match (cause_text.is_empty(), context_text.is_empty()) {
+        (true, true) => None,
+        (false, true) => Some(cause_text),
+        (true, false) => Some(format!("context: {context_text}")),
+        (false, false) => Some(format!("{cause_text} (context: {context_text})")),
+    }
+}
+
 fn is_sensitive_key(parent_key: Option<&str>, candidates: &[&str]) -> bool {
     parent_key.is_some_and(|key| {
         let normalized = key.to_ascii_lowercase();
@@ -235,7 +285,41 @@ pub fn cleanup_expired_logs(log_dir: &Path, keep_days: u64) -> Result<(), AppErr
     Ok(())
 }
 
+fn sanitize_error_context(context: Vec<ErrorContextItem>) -> Vec<ErrorContextItem> {
+    context
+        .into_iter()
+        .take(MAX_COLLECTION_ITEMS)
+        .map(|item| ErrorContextItem {
+            key: sanitize_for_log(&item.key),
+            value: sanitize_for_log(&item.value),
+        })
+        .collect()
+}
+
+fn attach_error_context(metadata: Option<Value>, context: &[ErrorContextItem]) -> Option<Value> {
+    if context.is_empty() {
+        return metadata;
+    }
+
+    let context_value = Value::Array(
+        context
+            .iter()
+            .map(|item| serde_json::json!({ "key": item.key, "value": item.value }))
+            .collect(),
+    );
+    match metadata {
+        Some(Value::Object(mut object)) => {
+            object.insert("errorContext".to_string(), context_value);
+            Some(Value::Object(object))
+        }
+        Some(other) => Some(serde_json::json!({ "value": other, "errorContext": context_value })),
+        None => Some(serde_json::json!({ "errorContext": context_value })),
+    }
+}
+
 fn sanitize_record_input(input: RecordLogInput) -> RecordLogInput {
+    let error_context = input.error_context.map(sanitize_error_context);
+    let metadata = input.metadata.as_ref().map(sanitize_json_value);
     RecordLogInput {
         level: normalize_level(&input.level).unwrap_or("info").to_string(),
         scope: sanitize_for_log(&input.scope),
@@ -243,8 +327,9 @@ fn sanitize_record_input(input: RecordLogInput) -> RecordLogInput {
         request_id: sanitize_for_log(&input.request_id),
         window_label: input.window_label.map(|value| sanitize_for_log(&value)),
         message: sanitize_for_log(&input.message),
-        metadata: input.metadata.as_ref().map(sanitize_json_value),
+        metadata: attach_error_context(metadata, error_context.as_deref().unwrap_or_default()),
         raw_ref: input.raw_ref.map(|value| sanitize_for_log(&value)),
+        error_context,
     }
 }
 
@@ -339,6 +424,7 @@ impl LogCenter {
             return Ok(());
         }
 
+        let _write_permit = crate::db::db_write_permit().await;
         let timestamp = now_millis();
         self.maybe_cleanup(&config, timestamp).await;
 
@@ -363,3 +449,69 @@ impl LogCenter {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context_item(key: &str, value: &str) -> ErrorContextItem {
+        ErrorContextItem {
+            key: key.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn summary_renders_nested_causes_by_detail_level() {
+        let causes = vec![
+            "读取配置失败".to_string(),
+            "permission denied".to_string(),
+            "os error 13".to_string(),
+        ];
+        let context = vec![
+            context_item("configKey", "theme"),
+            context_item("sourceType", "io"),
+        ];
+
+        assert_eq!(
+            summarize_error_causes(&causes, &context, ERROR_DETAIL_LEVEL_VERBOSE).as_deref(),
+            Some("读取配置失败 <- permission denied <- os error 13 (context: configKey=theme)")
+        );
+        assert_eq!(
+            summarize_error_causes(&causes, &context, "standard").as_deref(),
+            Some("读取配置失败 (context: configKey)")
+        );
+        assert_eq!(
+            summarize_error_causes(&causes, &context, ERROR_DETAIL_LEVEL_MINIMAL),
+            None
+        );
+    }
+
+    #[test]
+    fn summary_drops_repeated_causes_and_context_entries() {
+        let causes = vec![
+            "connection refused".to_string(),
+            " ".to_string(),
+            "connection refused".to_string(),
+            "timeout".to_string(),
+        ];
+        let context = vec![
+            context_item("detail", "a"),
+            context_item("detail", "a"),
+            context_item("detail", "b"),
+        ];
+
+        assert_eq!(
+            summarize_error_causes(&causes, &context, ERROR_DETAIL_LEVEL_VERBOSE).as_deref(),
+            Some("connection refused <- timeout (context: detail=a, detail=b)")
+        );
+        assert_eq!(
+            summarize_error_causes(&causes, &context, "standard").as_deref(),
+            Some("connection refused (context: detail)")
+        );
+        assert_eq!(
+            summarize_error_causes(&[], &[context_item("sourceChainDepth", "2")], "standard"),
+            None
+        );
+    }
+}
@@ -1,7 +1,7 @@
 pub mod logging;
 
 pub use rtool_contracts::models;
-pub use rtool_contracts::{AppError, AppResult, ResultExt};
+pub use rtool_contracts::{AppError, AppResult, ErrorContextItem, ResultExt};
 pub use rtool_data::db;
 pub use rtool_data::db_error;
 
@@ -1,5 +1,5 @@
-use crate::models::{LogConfigDto, LogEntryDto, LogPageDto, LogQueryDto};
-use crate::{AppError, ResultExt};
+use crate::models::{LogConfigDto, LogEntryDto, LogExportResultDto, LogPageDto, LogQueryDto};
+use crate::{AppError, ErrorContextItem, ResultExt};
 use anyhow::Context;
 use std::collections::HashMap;
 use std::fs;
@@ -25,7 +25,10 @@ mod query;
 #[path = "store.rs"]
 mod store;
 
-pub use ingest::{cleanup_expired_logs, sanitize_for_log, sanitize_json_value, sanitize_path};
+pub use ingest::{
+    cleanup_expired_logs, sanitize_for_log, sanitize_json_value, sanitize_path,
+    summarize_error_causes,
+};
 
 const DEFAULT_KEEP_DAYS: u32 = 7;
 const DEFAULT_MIN_LEVEL: &str = "info";
@@ -33,6 +36,10 @@ const DEFAULT_REALTIME_ENABLED: bool = true;
 const DEFAULT_HIGH_FREQ_WINDOW_MS: u32 = 1000;
 const DEFAULT_HIGH_FREQ_MAX_PER_KEY: u32 = 20;
 const DEFAULT_ALLOW_RAW_VIEW: bool = false;
+const DEFAULT_LOG_FORMAT: &str = "text";
+const ERROR_DETAIL_LEVEL_MINIMAL: &str = "minimal";
+const ERROR_DETAIL_LEVEL_STANDARD: &str = "standard";
+const ERROR_DETAIL_LEVEL_VERBOSE: &str = "verbose";
 const LOG_RETENTION_CLEANUP_INTERVAL_MS: i64 = 30 * 60 * 1000;
 
 const SETTING_KEY_MIN_LEVEL: &str = "logging.minLevel";
@@ -41,6 +48,8 @@ const SETTING_KEY_REALTIME_ENABLED: &str = "logging.realtimeEnabled";
 const SETTING_KEY_HIGH_FREQ_WINDOW_MS: &str = "logging.highFreqWindowMs";
 const SETTING_KEY_HIGH_FREQ_MAX_PER_KEY: &str = "logging.highFreqMaxPerKey";
 const SETTING_KEY_ALLOW_RAW_VIEW: &str = "logging.allowRawView";
+const SETTING_KEY_LOG_FORMAT: &str = "logging.logFormat";
+const SETTING_KEY_ERROR_DETAIL_LEVEL: &str = "logging.errorDetailLevel";
 
 const MAX_STRING_LEN: usize = 256;
 const MAX_COLLECTION_ITEMS: usize = 64;
@@ -49,11 +58,13 @@ const QUERY_LIMIT_MAX: u32 = 500;
 const QUERY_LIMIT_DEFAULT: u32 = 100;
 const EXPORT_FLUSH_EVERY_PAGES: u32 = 4;
 const EXPORT_THROTTLE_SLEEP_MS: u64 = 1;
+const EXPORT_MAX_FILE_BYTES: u64 = 200 * 1024 * 1024;
 const LOG_INGEST_QUEUE_CAPACITY: usize = 4096;
 
 const SENSITIVE_TEXT_KEYS: [&str; 5] = ["text", "content", "clipboard", "prompt", "input"];
 const SENSITIVE_PATH_KEYS: [&str; 4] = ["path", "file", "filepath", "filename"];
 const SENSITIVE_HOST_KEYS: [&str; 2] = ["host", "hostname"];
+const ERROR_SUMMARY_HIDDEN_CONTEXT_KEYS: [&str; 2] = ["sourceType", "sourceChainDepth"];
 
 pub(super) fn default_log_config() -> LogConfigDto {
     LogConfigDto {
@@ -63,6 +74,16 @@ pub(super) fn default_log_config() -> LogConfigDto {
         high_freq_window_ms: DEFAULT_HIGH_FREQ_WINDOW_MS,
         high_freq_max_per_key: DEFAULT_HIGH_FREQ_MAX_PER_KEY,
         allow_raw_view: DEFAULT_ALLOW_RAW_VIEW,
+        log_format: DEFAULT_LOG_FORMAT.to_string(),
+        error_detail_level: default_error_detail_level().to_string(),
+    }
+}
+
+pub(super) fn default_error_detail_level() -> &'static str {
+    if cfg!(debug_assertions) {
+        ERROR_DETAIL_LEVEL_VERBOSE
+    } else {
+        ERROR_DETAIL_LEVEL_STANDARD
     }
 }
 
@@ -92,6 +113,7 @@ pub struct RecordLogInput {
     pub message: String,
     pub metadata: Option<serde_json::Value>,
     pub raw_ref: Option<String>,
+    pub error_context: Option<Vec<ErrorContextItem>>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -296,6 +318,16 @@ pub fn get_log_config() -> Result<LogConfigDto, AppError> {
         .map_err(|_| AppError::new("log_config_read_failed", "读取日志配置失败"))
 }
 
+pub fn build_error_cause_summary(
+    causes: &[String],
+    context: &[ErrorContextItem],
+) -> Option<String> {
+    let detail_level = get_log_config()
+        .map(|config| config.error_detail_level)
+        .unwrap_or_else(|_| default_error_detail_level().to_string());
+    summarize_error_causes(causes, context, detail_level.as_str())
+}
+
 pub async fn update_log_config(input: LogConfigDto) -> Result<LogConfigDto, AppError> {
     let normalized = config::clamp_and_normalize_config(input)?;
     let center = get_log_center()?;
@@ -316,7 +348,22 @@ pub async fn query_log_entries(query: LogQueryDto) -> Result<LogPageDto, AppErro
 pub async fn export_log_entries(
     query: LogQueryDto,
     output_path: Option<String>,
-) -> Result<String, AppError> {
+) -> Result<LogExportResultDto, AppError> {
+    let center = get_log_center()?;
+    export::export_log_entries_jsonl(&center, query, output_path).await
+}
+
+pub async fn export_log_entries_cbor(
+    query: LogQueryDto,
+    output_path: Option<String>,
+) -> Result<LogExportResultDto, AppError> {
     let center = get_log_center()?;
-    export::export_log_entries(&center, query, output_path).await
+    export::export_log_entries_cbor(&center, query, output_path).await
+}
+
+pub fn convert_cbor_log_export_to_jsonl(
+    cbor_path: &Path,
+    output_path: &Path,
+) -> Result<u64, AppError> {
+    export::convert_cbor_to_jsonl(cbor_path, output_path)
 }
@@ -44,13 +44,19 @@ fn push_exact_match_filter(
     }
 }
 
-pub(super) async fn query_log_entries(
-    center: &super::LogCenter,
-    query: LogQueryDto,
-) -> Result<LogPageDto, AppError> {
-    let limit = query.limit.clamp(1, QUERY_LIMIT_MAX);
+fn escape_like_pattern(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+fn build_log_query_sql(
+    query: &LogQueryDto,
+    limit: u32,
+) -> Result<(String, Vec<LibsqlValue>), AppError> {
     let mut sql = String::from(
-        "SELECT id, timestamp, level, scope, event, request_id, window_label, message, metadata, raw_ref, aggregated_count FROM log_entries WHERE 1=1",
+        "SELECT id, timestamp, level, scope, event, request_id, window_label, message, metadata, raw_ref, aggregated_count, error_context_keys FROM log_entries WHERE 1=1",
     );
     let mut params = Vec::<LibsqlValue>::new();
 
@@ -120,6 +126,21 @@ pub(super) async fn query_log_entries(
         }
     }
 
+    if let Some(key) = query
+        .error_context_key
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    {
+        sql.push_str(
+            " AND error_context_keys IS NOT NULL AND error_context_keys LIKE ? ESCAPE '\\'",
+        );
+        params.push(LibsqlValue::Text(format!(
+            "%,{},%",
+            escape_like_pattern(&sanitize_for_log(key))
+        )));
+    }
+
     if let Some(start_at) = query.start_at {
         sql.push_str(" AND timestamp >= ?");
         params.push(LibsqlValue::Integer(start_at));
@@ -133,6 +154,15 @@ pub(super) async fn query_log_entries(
     sql.push_str(" ORDER BY id DESC LIMIT ?");
     params.push(LibsqlValue::Integer(i64::from(limit) + 1));
 
+    Ok((sql, params))
+}
+
+pub(super) async fn query_log_entries(
+    center: &super::LogCenter,
+    query: LogQueryDto,
+) -> Result<LogPageDto, AppError> {
+    let limit = query.limit.clamp(1, QUERY_LIMIT_MAX);
+    let (sql, params) = build_log_query_sql(&query, limit)?;
     let mut rows = center
         .db_conn
         .query(sql.as_str(), params_from_iter(params))
@@ -154,3 +184,227 @@ pub(super) async fn query_log_entries(
 
     Ok(LogPageDto { items, next_cursor })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::{init_db, open_db};
+    use std::collections::HashMap;
+    use std::path::{Path, PathBuf};
+    use std::sync::Mutex;
+    use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+    const PLAN_ROW_COUNT: i64 = 5_000;
+    const BENCH_ROW_COUNT: i64 = 100_000;
+    const BENCH_QUERY_BUDGET: Duration = Duration::from_millis(10);
+
+    fn temp_db_path() -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|value| value.as_nanos())
+            .unwrap_or_default();
+        std::env::temp_dir().join(format!(
+            "rtool-log-query-bench-{}-{nanos}.db",
+            std::process::id()
+        ))
+    }
+
+    fn remove_db_files(db_path: &Path) {
+        for suffix in ["", "-wal", "-shm"] {
+            let mut path = db_path.as_os_str().to_os_string();
+            path.push(suffix);
+            let _ = std::fs::remove_file(PathBuf::from(path));
+        }
+    }
+
+    async fn seeded_log_center(db_path: &Path, row_count: i64) -> super::super::LogCenter {
+        let db_conn = open_db(db_path).await.expect("open db");
+        init_db(&db_conn).await.expect("init db");
+        let config = super::super::config::load_log_config(&db_conn).await;
+        let center = super::super::LogCenter {
+            event_sink: None,
+            db_conn,
+            log_dir: std::env::temp_dir(),
+            config: Mutex::new(config),
+            high_frequency: Mutex::new(HashMap::new()),
+            last_cleanup_at: Mutex::new(0),
+        };
+        seed_log_entries(&center, row_count).await;
+        center
+            .db_conn
+            .execute_batch("PRAGMA optimize;")
+            .await
+            .expect("optimize");
+        center
+    }
+
+    async fn seed_log_entries(center: &super::super::LogCenter, row_count: i64) {
+        const SCOPES: [&str; 8] = [
+            "app",
+            "clipboard",
+            "launcher",
+            "settings",
+            "screenshot",
+            "logging",
+            "shell",
+            "i18n",
+        ];
+        const LEVELS: [&str; 4] = ["trace", "debug", "info", "warn"];
+
+        let transaction = center.db_conn.transaction().await.expect("begin");
+        for index in 0..row_count {
+            let position = usize::try_from(index).expect("index");
+            transaction
+                .execute(
+                    "INSERT INTO log_entries (timestamp, level, scope, event, request_id, message) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                    (
+                        index,
+                        LEVELS[position % LEVELS.len()],
+                        SCOPES[position / LEVELS.len() % SCOPES.len()],
+                        format!("event_{}", position % 50),
+                        format!("req-{index}"),
+                        "seeded",
+                    ),
+                )
+                .await
+                .expect("insert");
+        }
+        transaction.commit().await.expect("commit");
+    }
+
+    fn scope_level_time_range_query() -> LogQueryDto {
+        LogQueryDto {
+            limit: 100,
+            levels: Some(vec!["warn".to_string()]),
+            scope: Some("launcher".to_string()),
+            start_at: Some(2_000),
+            end_at: Some(4_000),
+            ..LogQueryDto::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn error_context_key_filter_matches_whole_keys_literally() {
+        let db_path = temp_db_path();
+        let center = seeded_log_center(&db_path, 0).await;
+        for (request_id, keys) in [
+            ("req-path", vec!["path", "code"]),
+            ("req-underscore", vec!["path_id"]),
+            ("req-wildcard", vec!["pathXid"]),
+            ("req-none", Vec::new()),
+        ] {
+            let input = super::super::RecordLogInput {
+                level: "error".to_string(),
+                scope: "app".to_string(),
+                event: "failed".to_string(),
+                request_id: request_id.to_string(),
+                window_label: None,
+                message: "failed".to_string(),
+                metadata: None,
+                raw_ref: None,
+                error_context: Some(
+                    keys.into_iter()
+                        .map(|key| crate::ErrorContextItem {
+                            key: key.to_string(),
+                            value: "value".to_string(),
+                        })
+                        .collect(),
+                ),
+            };
+            super::super::store::save_log_entry(&center.db_conn, &input, 1)
+                .await
+                .expect("save");
+        }
+
+        let request_ids = |key: &str| {
+            let query = LogQueryDto {
+                limit: 10,
+                error_context_key: Some(key.to_string()),
+                ..LogQueryDto::default()
+            };
+            let center = &center;
+            async move {
+                let mut ids = query_log_entries(center, query)
+                    .await
+                    .expect("query")
+                    .items
+                    .into_iter()
+                    .map(|item| item.request_id)
+                    .collect::<Vec<_>>();
+                ids.sort();
+                ids
+            }
+        };
+        let by_path = request_ids("path").await;
+        let by_underscore = request_ids(" path_id ").await;
+        let by_code = request_ids("code").await;
+
+        drop(center);
+        remove_db_files(&db_path);
+        assert_eq!(by_path, vec!["req-path".to_string()]);
+        assert_eq!(by_underscore, vec!["req-underscore".to_string()]);
+        assert_eq!(by_code, vec!["req-path".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn scope_level_time_range_query_uses_composite_index() {
+        let db_path = temp_db_path();
+        let center = seeded_log_center(&db_path, PLAN_ROW_COUNT).await;
+
+        let query = scope_level_time_range_query();
+        let (sql, params) = build_log_query_sql(&query, query.limit).expect("build sql");
+        let mut rows = center
+            .db_conn
+            .query(
+                format!("EXPLAIN QUERY PLAN {sql}").as_str(),
+                params_from_iter(params),
+            )
+            .await
+            .expect("explain");
+        let mut plan = Vec::new();
+        while let Some(row) = rows.next().await.expect("plan row") {
+            plan.push(row.get::<String>(3).expect("plan detail"));
+        }
+        drop(rows);
+
+        drop(center);
+        remove_db_files(&db_path);
+        assert!(
+            plan.iter()
+                .any(|detail| detail.contains("idx_log_scope_level_ts")),
+            "query plan did not use idx_log_scope_level_ts: {plan:?}"
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "timing benchmark; run with `cargo test -- --ignored`"]
+    async fn scope_level_time_range_query_stays_fast_on_large_table() {
+        let db_path = temp_db_path();
+        let center = seeded_log_center(&db_path, BENCH_ROW_COUNT).await;
+
+        let query = || LogQueryDto {
+            start_at: Some(20_000),
+            end_at: Some(60_000),
+            ..scope_level_time_range_query()
+        };
+        let mut best = Duration::MAX;
+        for _ in 0..3 {
+            let started_at = Instant::now();
+            let page = query_log_entries(&center, query()).await.expect("query");
+            best = best.min(started_at.elapsed());
+            assert_eq!(page.items.len(), 100);
+            assert!(
+                page.items
+                    .iter()
+                    .all(|item| item.scope == "launcher" && item.level == "warn")
+            );
+        }
+
+        drop(center);
+        remove_db_files(&db_path);
+        assert!(
+            best < BENCH_QUERY_BUDGET,
+            "scope + level + time range query took {best:?}"
+        );
+    }
+}
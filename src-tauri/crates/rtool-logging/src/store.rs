@@ -1,9 +1,9 @@
 use super::ingest::{now_millis, sanitize_for_log};
 use super::{HighFrequencyWindow, RecordLogInput};
-use crate::AppError;
 use crate::db::DbConn;
 use crate::db_error::DbResult;
 use crate::models::LogEntryDto;
+use crate::{AppError, ErrorContextItem};
 use libsql::{Row, Rows, params};
 use serde_json::Value;
 
@@ -30,6 +30,31 @@ fn serialize_metadata_value(metadata: &Option<Value>) -> Option<String> {
         .and_then(|value| serde_json::to_string(value).ok())
 }
 
+pub(super) fn encode_error_context_keys(context: Option<&[ErrorContextItem]>) -> Option<String> {
+    let mut keys = Vec::<&str>::new();
+    for item in context.unwrap_or_default() {
+        let key = item.key.trim();
+        if !key.is_empty() && !key.contains(',') && !keys.contains(&key) {
+            keys.push(key);
+        }
+    }
+    if keys.is_empty() {
+        return None;
+    }
+    Some(format!(",{},", keys.join(",")))
+}
+
+fn decode_error_context_keys(raw: Option<String>) -> Vec<String> {
+    raw.map(|value| {
+        value
+            .split(',')
+            .filter(|key| !key.is_empty())
+            .map(ToString::to_string)
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
 fn aggregated_message(key: &str) -> String {
     format!("{AGGREGATED_EVENT} key={}", sanitize_for_log(key))
 }
@@ -48,6 +73,7 @@ pub(super) fn row_to_log_entry(row: &Row) -> DbResult<LogEntryDto> {
         metadata: parse_metadata_value(row.get(8)?),
         raw_ref: row.get(9)?,
         aggregated_count: aggregated_count.and_then(|value| u32::try_from(value).ok()),
+        error_context_keys: decode_error_context_keys(row.get(11)?),
     })
 }
 
@@ -69,11 +95,12 @@ pub(super) async fn save_log_entry(
     timestamp: i64,
 ) -> DbResult<LogEntryDto> {
     let metadata = serialize_metadata_value(&input.metadata);
+    let error_context_keys = encode_error_context_keys(input.error_context.as_deref());
     let mut rows = conn
         .query(
-            "INSERT INTO log_entries (timestamp, level, scope, event, request_id, window_label, message, metadata, raw_ref, aggregated_count)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, NULL)
-         RETURNING id, timestamp, level, scope, event, request_id, window_label, message, metadata, raw_ref, aggregated_count",
+            "INSERT INTO log_entries (timestamp, level, scope, event, request_id, window_label, message, metadata, raw_ref, aggregated_count, error_context_keys)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, NULL, ?10)
+         RETURNING id, timestamp, level, scope, event, request_id, window_label, message, metadata, raw_ref, aggregated_count, error_context_keys",
             params![
                 timestamp,
                 input.level.as_str(),
@@ -83,7 +110,8 @@ pub(super) async fn save_log_entry(
                 input.window_label.as_deref(),
                 input.message.as_str(),
                 metadata,
-                input.raw_ref.as_deref()
+                input.raw_ref.as_deref(),
+                error_context_keys
             ],
         )
         .await?;
@@ -107,7 +135,7 @@ pub(super) async fn upsert_aggregated_log(
                  aggregated_count = ?2,
                  message = ?3
              WHERE id = ?4
-             RETURNING id, timestamp, level, scope, event, request_id, window_label, message, metadata, raw_ref, aggregated_count",
+             RETURNING id, timestamp, level, scope, event, request_id, window_label, message, metadata, raw_ref, aggregated_count, error_context_keys",
                 params![
                     timestamp,
                     i64::from(window.aggregated_count),
@@ -126,7 +154,7 @@ pub(super) async fn upsert_aggregated_log(
         .query(
             "INSERT INTO log_entries (timestamp, level, scope, event, request_id, window_label, message, metadata, raw_ref, aggregated_count)
          VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, NULL, NULL, ?8)
-         RETURNING id, timestamp, level, scope, event, request_id, window_label, message, metadata, raw_ref, aggregated_count",
+         RETURNING id, timestamp, level, scope, event, request_id, window_label, message, metadata, raw_ref, aggregated_count, error_context_keys",
             params![
                 timestamp,
                 input.level.as_str(),
@@ -149,3 +177,38 @@ pub(super) async fn upsert_aggregated_log(
     window.aggregated_row_id = Some(entry.id);
     Ok(entry)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context(keys: &[&str]) -> Vec<ErrorContextItem> {
+        keys.iter()
+            .map(|key| ErrorContextItem {
+                key: key.to_string(),
+                value: "value".to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn encode_error_context_keys_trims_dedups_and_skips_unsearchable_keys() {
+        let items = context(&[" path ", "code", "path", "", "a,b", "code"]);
+        assert_eq!(
+            encode_error_context_keys(Some(&items)),
+            Some(",path,code,".to_string())
+        );
+        assert_eq!(encode_error_context_keys(Some(&context(&["", " "]))), None);
+        assert_eq!(encode_error_context_keys(None), None);
+    }
+
+    #[test]
+    fn decode_error_context_keys_round_trips_encoded_keys() {
+        let encoded = encode_error_context_keys(Some(&context(&["path", "request_id"])));
+        assert_eq!(
+            decode_error_context_keys(encoded),
+            vec!["path".to_string(), "request_id".to_string()]
+        );
+        assert!(decode_error_context_keys(None).is_empty());
+    }
+}
@@ -16,6 +16,8 @@ use std::time::{Duration, SystemTime, UNIX_EPOCH};
 const APP_ICON_TTL: Duration = Duration::from_secs(60 * 60 * 24);
 const APP_ICON_FALLBACK_TTL: Duration = Duration::from_secs(60 * 10);
 const FILE_ICON_TTL: Duration = Duration::from_secs(60 * 60 * 24 * 30);
+#[cfg(target_os = "macos")]
+const APP_ICON_LRU_CAPACITY: usize = 512;
 const FALLBACK_APP_ICON: &str = "i-noto:desktop-computer";
 const FALLBACK_FILE_ICON: &str = "i-noto:page-facing-up";
 #[cfg(target_os = "macos")]
@@ -44,6 +46,73 @@ struct DiskIconEntry {
     icon_value: String,
 }
 
+#[cfg(any(target_os = "macos", test))]
+#[derive(Debug, Clone)]
+struct AppIconLruEntry {
+    signature: String,
+    last_used: u64,
+    payload: IconPayload,
+}
+
+/// Extracted app icons keyed by app path and validated against the icon source signature.
+#[cfg(any(target_os = "macos", test))]
+#[derive(Debug)]
+struct AppIconLru {
+    capacity: usize,
+    tick: u64,
+    entries: HashMap<String, AppIconLruEntry>,
+}
+
+#[cfg(any(target_os = "macos", test))]
+impl AppIconLru {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            tick: 0,
+            entries: HashMap::new(),
+        }
+    }
+
+    fn get(&mut self, app_path: &str, signature: &str) -> Option<IconPayload> {
+        let entry = self.entries.get_mut(app_path)?;
+        if entry.signature != signature {
+            self.entries.remove(app_path);
+            return None;
+        }
+        self.tick += 1;
+        entry.last_used = self.tick;
+        Some(entry.payload.clone())
+    }
+
+    fn insert(&mut self, app_path: &str, signature: &str, payload: IconPayload) {
+        if !self.entries.contains_key(app_path) && self.entries.len() >= self.capacity {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.tick += 1;
+        self.entries.insert(
+            app_path.to_string(),
+            AppIconLruEntry {
+                signature: signature.to_string(),
+                last_used: self.tick,
+                payload,
+            },
+        );
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn app_icon_lru() -> &'static Mutex<AppIconLru> {
+    static CACHE: OnceLock<Mutex<AppIconLru>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(AppIconLru::new(APP_ICON_LRU_CAPACITY)))
+}
+
 fn icon_memory_cache() -> &'static Mutex<HashMap<String, DiskIconEntry>> {
     static CACHE: OnceLock<Mutex<HashMap<String, DiskIconEntry>>> = OnceLock::new();
     CACHE.get_or_init(|| Mutex::new(HashMap::new()))
@@ -72,20 +141,11 @@ pub fn resolve_builtin_icon(icon: &str) -> IconPayload {
 
 pub fn resolve_application_icon(app: &dyn LauncherHost, app_path: &Path) -> IconPayload {
     ensure_icon_cache_schema_initialized(app);
-    let app_path_key = app_path.to_string_lossy();
 
     #[cfg(target_os = "macos")]
     {
         if let Some(source) = resolve_macos_icon_source(app_path) {
-            let key = format!(
-                "app:{app_path_key}:{}:{APP_ICON_CACHE_PROFILE}",
-                source.signature
-            );
-            if let Some(payload) = read_cached_icon(app, &key, APP_ICON_TTL) {
-                return payload;
-            }
-            if let Some(payload) = render_macos_icon_payload(app, &source) {
-                write_cached_icon(app, &key, &payload);
+            if let Some(payload) = resolve_macos_app_icon(app, app_path, &source) {
                 return payload;
             }
             tracing::debug!(
@@ -96,7 +156,43 @@ pub fn resolve_application_icon(app: &dyn LauncherHost, app_path: &Path) -> Icon
         }
     }
 
-    let fallback_key = format!("app:{app_path_key}:fallback");
+    resolve_fallback_app_icon(app, app_path)
+}
+
+#[cfg(target_os = "macos")]
+fn resolve_macos_app_icon(
+    app: &dyn LauncherHost,
+    app_path: &Path,
+    source: &MacIconSource,
+) -> Option<IconPayload> {
+    let app_path_key = app_path.to_string_lossy();
+    if let Ok(mut cache) = app_icon_lru().lock()
+        && let Some(payload) = cache.get(&app_path_key, &source.signature)
+    {
+        return Some(payload);
+    }
+
+    let key = format!(
+        "app:{app_path_key}:{}:{APP_ICON_CACHE_PROFILE}",
+        source.signature
+    );
+    let payload = match read_cached_icon(app, &key, APP_ICON_TTL) {
+        Some(payload) => payload,
+        None => {
+            let payload = render_macos_icon_payload(app, source)?;
+            write_cached_icon(app, &key, &payload);
+            payload
+        }
+    };
+    if let Ok(mut cache) = app_icon_lru().lock() {
+        cache.insert(&app_path_key, &source.signature, payload.clone());
+    }
+    Some(payload)
+}
+
+/// Fallbacks stay out of the LRU so extraction is retried once `APP_ICON_FALLBACK_TTL` passes.
+fn resolve_fallback_app_icon(app: &dyn LauncherHost, app_path: &Path) -> IconPayload {
+    let fallback_key = format!("app:{}:fallback", app_path.to_string_lossy());
     if let Some(payload) = read_cached_icon(app, &fallback_key, APP_ICON_FALLBACK_TTL) {
         return payload;
     }
@@ -596,3 +692,36 @@ fn file_extension_icon(ext: &str) -> &'static str {
         _ => FALLBACK_FILE_ICON,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payload(value: &str) -> IconPayload {
+        IconPayload {
+            kind: "iconify".to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn app_icon_lru_invalidates_on_signature_change_and_evicts_oldest() {
+        let mut cache = AppIconLru::new(2);
+        cache.insert("/apps/a", "sig-1", payload("a"));
+        cache.insert("/apps/b", "sig-1", payload("b"));
+
+        assert!(cache.get("/apps/a", "sig-2").is_none());
+        assert!(cache.get("/apps/a", "sig-1").is_none());
+
+        cache.insert("/apps/a", "sig-2", payload("a2"));
+        assert_eq!(
+            cache.get("/apps/b", "sig-1").map(|value| value.value),
+            Some("b".to_string())
+        );
+        cache.insert("/apps/c", "sig-1", payload("c"));
+
+        assert!(cache.get("/apps/a", "sig-2").is_none());
+        assert!(cache.get("/apps/b", "sig-1").is_some());
+        assert!(cache.get("/apps/c", "sig-1").is_some());
+    }
+}
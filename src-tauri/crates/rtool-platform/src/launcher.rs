@@ -10,6 +10,8 @@ pub struct AppPackageInfo {
 
 pub trait LauncherWindow: Send + Sync {
     fn show(&self) -> AppResult<()>;
+    fn hide(&self) -> AppResult<()>;
+    fn is_visible(&self) -> bool;
     fn set_focus(&self) -> AppResult<()>;
     fn set_position(&self, x: f64, y: f64) -> AppResult<()>;
     fn set_size(&self, width: f64, height: f64) -> AppResult<()>;
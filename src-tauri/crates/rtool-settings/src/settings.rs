@@ -1,5 +1,6 @@
 use rtool_capture::service::{
-    CLIPBOARD_MAX_ITEMS_MAX, CLIPBOARD_MAX_ITEMS_MIN, CLIPBOARD_MAX_TOTAL_SIZE_MB_MAX,
+    CLIPBOARD_MAX_ITEMS_MAX, CLIPBOARD_MAX_ITEMS_MIN, CLIPBOARD_MAX_PINNED_ITEMS_MAX,
+    CLIPBOARD_MAX_PINNED_ITEMS_MIN, CLIPBOARD_MAX_TOTAL_SIZE_MB_MAX,
     CLIPBOARD_MAX_TOTAL_SIZE_MB_MIN,
 };
 use rtool_capture::{
@@ -8,17 +9,20 @@ use rtool_capture::{
     SCREENSHOT_PIN_MAX_INSTANCES_MIN, SCREENSHOT_SHORTCUT_DEFAULT,
 };
 use rtool_contracts::models::{
-    LayoutSettingsUpdateInputDto, LocaleSettingsUpdateInputDto, SettingsClipboardDto,
-    SettingsClipboardUpdateInputDto, SettingsDto, SettingsScreenshotDto,
-    SettingsScreenshotUpdateInputDto, SettingsUpdateInputDto, ThemeSettingsUpdateInputDto,
+    AppManagerSettingsUpdateInputDto, CLIPBOARD_SETTINGS_VERSION, LayoutSettingsUpdateInputDto,
+    LocaleSettingsUpdateInputDto, SettingsClipboardDto, SettingsClipboardUpdateInputDto,
+    SettingsDto, SettingsScreenshotDto, SettingsScreenshotUpdateInputDto, SettingsUpdateInputDto,
+    ThemeSettingsUpdateInputDto, TraySettingsUpdateInputDto, WindowAutoHideSettingsUpdateInputDto,
 };
 use rtool_contracts::{AppError, AppResult};
 use rtool_data::db::{DbConn, get_app_setting, set_app_setting};
 use rtool_kernel::i18n::{SYSTEM_LOCALE_PREFERENCE, normalize_locale_preference};
+use rtool_kernel::i18n_format::{ByteUnitSystem, DEFAULT_BYTE_UNIT};
 
 const APP_SETTINGS_JSON_KEY: &str = "app.settings.v1";
 const DEFAULT_THEME_PREFERENCE: &str = "system";
 const DEFAULT_LAYOUT_PREFERENCE: &str = "topbar";
+const WINDOW_AUTO_HIDE_DELAY_MS_MAX: u32 = 10_000;
 
 fn normalize_theme_preference(value: &str) -> Option<&'static str> {
     match value.trim().to_ascii_lowercase().as_str() {
@@ -37,8 +41,27 @@ fn normalize_layout_preference(value: &str) -> Option<&'static str> {
     }
 }
 
+fn migrate_clipboard_settings(mut settings: SettingsClipboardDto) -> SettingsClipboardDto {
+    if settings.settings_version > CLIPBOARD_SETTINGS_VERSION {
+        tracing::warn!(
+            event = "settings_clipboard_version_newer",
+            stored_version = settings.settings_version,
+            supported_version = CLIPBOARD_SETTINGS_VERSION
+        );
+        return settings;
+    }
+
+    // v0 -> v1: capture toggles and paste-after-copy were added; serde defaults fill them in.
+    if settings.settings_version < 1 {
+        settings.settings_version = 1;
+    }
+    settings
+}
+
 fn normalize_clipboard_settings(settings: SettingsClipboardDto) -> SettingsClipboardDto {
+    let settings = migrate_clipboard_settings(settings);
     SettingsClipboardDto {
+        settings_version: settings.settings_version,
         max_items: settings
             .max_items
             .clamp(CLIPBOARD_MAX_ITEMS_MIN, CLIPBOARD_MAX_ITEMS_MAX),
@@ -47,6 +70,25 @@ fn normalize_clipboard_settings(settings: SettingsClipboardDto) -> SettingsClipb
             CLIPBOARD_MAX_TOTAL_SIZE_MB_MIN,
             CLIPBOARD_MAX_TOTAL_SIZE_MB_MAX,
         ),
+        paste_after_copy: settings.paste_after_copy,
+        capture_text: settings.capture_text,
+        capture_images: settings.capture_images,
+        capture_files: settings.capture_files,
+        perceptual_deduplicate: settings.perceptual_deduplicate,
+        storage_dir: settings
+            .storage_dir
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty()),
+        image_preview_max_width: settings.image_preview_max_width.filter(|value| *value > 0),
+        image_preview_max_height: settings.image_preview_max_height.filter(|value| *value > 0),
+        max_preview_pixels: settings.max_preview_pixels.filter(|value| *value > 0),
+        preview_failure_policy: settings.preview_failure_policy,
+        compact_content_filter: settings.compact_content_filter,
+        regular_content_filter: settings.regular_content_filter,
+        max_pinned_items: settings.max_pinned_items.clamp(
+            CLIPBOARD_MAX_PINNED_ITEMS_MIN,
+            CLIPBOARD_MAX_PINNED_ITEMS_MAX,
+        ),
     }
 }
 
@@ -82,9 +124,20 @@ fn normalize_settings(mut settings: SettingsDto) -> SettingsDto {
         .to_string();
     settings.locale.preference = normalize_locale_preference(settings.locale.preference.as_str())
         .unwrap_or_else(|| SYSTEM_LOCALE_PREFERENCE.to_string());
+    settings.locale.byte_unit = ByteUnitSystem::parse(settings.locale.byte_unit.as_str())
+        .map_or(DEFAULT_BYTE_UNIT, ByteUnitSystem::as_str)
+        .to_string();
 
     settings.clipboard = normalize_clipboard_settings(settings.clipboard);
     settings.screenshot = normalize_screenshot_settings(settings.screenshot);
+    settings.window_auto_hide.launcher_delay_ms = settings
+        .window_auto_hide
+        .launcher_delay_ms
+        .min(WINDOW_AUTO_HIDE_DELAY_MS_MAX);
+    settings.window_auto_hide.clipboard_delay_ms = settings
+        .window_auto_hide
+        .clipboard_delay_ms
+        .min(WINDOW_AUTO_HIDE_DELAY_MS_MAX);
     settings
 }
 
@@ -133,9 +186,27 @@ fn apply_locale_patch(
                 .with_context("preference", preference.clone())
         })?;
     }
+    if let Some(byte_unit) = &input.byte_unit {
+        locale.byte_unit = ByteUnitSystem::parse(byte_unit.as_str())
+            .ok_or_else(|| {
+                AppError::new("invalid_byte_unit_preference", "字节单位偏好无效")
+                    .with_context("byteUnit", byte_unit.clone())
+            })?
+            .as_str()
+            .to_string();
+    }
     Ok(())
 }
 
+fn apply_tray_patch(
+    tray: &mut rtool_contracts::models::TraySettingsDto,
+    input: &TraySettingsUpdateInputDto,
+) {
+    if let Some(live_tooltip) = input.live_tooltip {
+        tray.live_tooltip = live_tooltip;
+    }
+}
+
 fn apply_clipboard_patch(
     clipboard: &mut SettingsClipboardDto,
     input: &SettingsClipboardUpdateInputDto,
@@ -149,6 +220,69 @@ fn apply_clipboard_patch(
     if let Some(max_total_size_mb) = input.max_total_size_mb {
         clipboard.max_total_size_mb = max_total_size_mb;
     }
+    if let Some(paste_after_copy) = input.paste_after_copy {
+        clipboard.paste_after_copy = paste_after_copy;
+    }
+    if let Some(capture_text) = input.capture_text {
+        clipboard.capture_text = capture_text;
+    }
+    if let Some(capture_images) = input.capture_images {
+        clipboard.capture_images = capture_images;
+    }
+    if let Some(capture_files) = input.capture_files {
+        clipboard.capture_files = capture_files;
+    }
+    if let Some(perceptual_deduplicate) = input.perceptual_deduplicate {
+        clipboard.perceptual_deduplicate = perceptual_deduplicate;
+    }
+    if let Some(max_width) = input.image_preview_max_width {
+        clipboard.image_preview_max_width = (max_width > 0).then_some(max_width);
+    }
+    if let Some(max_height) = input.image_preview_max_height {
+        clipboard.image_preview_max_height = (max_height > 0).then_some(max_height);
+    }
+    if let Some(max_pixels) = input.max_preview_pixels {
+        clipboard.max_preview_pixels = (max_pixels > 0).then_some(max_pixels);
+    }
+    if let Some(preview_failure_policy) = input.preview_failure_policy {
+        clipboard.preview_failure_policy = preview_failure_policy;
+    }
+    if let Some(compact_content_filter) = input.compact_content_filter {
+        clipboard.compact_content_filter = compact_content_filter;
+    }
+    if let Some(regular_content_filter) = input.regular_content_filter {
+        clipboard.regular_content_filter = regular_content_filter;
+    }
+    if let Some(max_pinned_items) = input.max_pinned_items {
+        clipboard.max_pinned_items = max_pinned_items;
+    }
+}
+
+fn apply_window_auto_hide_patch(
+    window_auto_hide: &mut rtool_contracts::models::WindowAutoHideSettingsDto,
+    input: &WindowAutoHideSettingsUpdateInputDto,
+) {
+    if let Some(launcher_enabled) = input.launcher_enabled {
+        window_auto_hide.launcher_enabled = launcher_enabled;
+    }
+    if let Some(launcher_delay_ms) = input.launcher_delay_ms {
+        window_auto_hide.launcher_delay_ms = launcher_delay_ms;
+    }
+    if let Some(clipboard_enabled) = input.clipboard_enabled {
+        window_auto_hide.clipboard_enabled = clipboard_enabled;
+    }
+    if let Some(clipboard_delay_ms) = input.clipboard_delay_ms {
+        window_auto_hide.clipboard_delay_ms = clipboard_delay_ms;
+    }
+}
+
+fn apply_app_manager_patch(
+    app_manager: &mut rtool_contracts::models::AppManagerSettingsDto,
+    input: &AppManagerSettingsUpdateInputDto,
+) {
+    if let Some(default_delete_mode) = input.default_delete_mode {
+        app_manager.default_delete_mode = default_delete_mode;
+    }
 }
 
 fn apply_screenshot_patch(
@@ -188,6 +322,15 @@ fn apply_update(settings: &mut SettingsDto, input: &SettingsUpdateInputDto) -> A
     if let Some(screenshot) = &input.screenshot {
         apply_screenshot_patch(&mut settings.screenshot, screenshot);
     }
+    if let Some(tray) = &input.tray {
+        apply_tray_patch(&mut settings.tray, tray);
+    }
+    if let Some(window_auto_hide) = &input.window_auto_hide {
+        apply_window_auto_hide_patch(&mut settings.window_auto_hide, window_auto_hide);
+    }
+    if let Some(app_manager) = &input.app_manager {
+        apply_app_manager_patch(&mut settings.app_manager, app_manager);
+    }
     *settings = normalize_settings(settings.clone());
     Ok(())
 }
@@ -209,6 +352,20 @@ async fn persist_settings(db_conn: &DbConn, settings: &SettingsDto) -> AppResult
         })
 }
 
+fn parse_stored_settings(raw: &str) -> (SettingsDto, bool) {
+    match serde_json::from_str::<SettingsDto>(raw) {
+        Ok(value) => (normalize_settings(value), false),
+        Err(error) => {
+            tracing::warn!(
+                event = "settings_parse_failed",
+                detail = %error,
+                key = APP_SETTINGS_JSON_KEY
+            );
+            (normalize_settings(SettingsDto::default()), true)
+        }
+    }
+}
+
 pub async fn load_or_init_settings(db_conn: &DbConn) -> AppResult<SettingsDto> {
     let raw = get_app_setting(db_conn, APP_SETTINGS_JSON_KEY)
         .await
@@ -224,18 +381,7 @@ pub async fn load_or_init_settings(db_conn: &DbConn) -> AppResult<SettingsDto> {
         return Ok(settings);
     };
 
-    let parsed = serde_json::from_str::<SettingsDto>(raw.as_str());
-    let (settings, force_write) = match parsed {
-        Ok(value) => (normalize_settings(value), false),
-        Err(error) => {
-            tracing::warn!(
-                event = "settings_parse_failed",
-                detail = %error,
-                key = APP_SETTINGS_JSON_KEY
-            );
-            (normalize_settings(SettingsDto::default()), true)
-        }
-    };
+    let (settings, force_write) = parse_stored_settings(raw.as_str());
 
     let normalized_raw = serialize_settings(&settings)?;
     if force_write || normalized_raw != raw {
@@ -262,8 +408,193 @@ pub async fn update_locale_preference(
     let update = SettingsUpdateInputDto {
         locale: Some(LocaleSettingsUpdateInputDto {
             preference: Some(preference.to_string()),
+            ..Default::default()
         }),
         ..Default::default()
     };
     update_settings(db_conn, update).await
 }
+
+pub async fn update_clipboard_storage_dir(
+    db_conn: &DbConn,
+    storage_dir: Option<&str>,
+) -> AppResult<SettingsDto> {
+    let mut settings = load_or_init_settings(db_conn).await?;
+    settings.clipboard.storage_dir = storage_dir.map(ToString::to_string);
+    let settings = normalize_settings(settings);
+    persist_settings(db_conn, &settings).await?;
+    Ok(settings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rtool_contracts::models::{AppManagerCleanupDeleteMode, ClipboardWindowContentFilter};
+
+    #[test]
+    fn legacy_clipboard_settings_are_upgraded_with_defaults() {
+        let raw =
+            r#"{"clipboard":{"maxItems":200,"sizeCleanupEnabled":false,"maxTotalSizeMb":300}}"#;
+
+        let (settings, force_write) = parse_stored_settings(raw);
+        assert!(!force_write);
+        assert_eq!(
+            settings.clipboard.settings_version,
+            CLIPBOARD_SETTINGS_VERSION
+        );
+        assert_eq!(settings.clipboard.max_items, 200);
+        assert!(!settings.clipboard.size_cleanup_enabled);
+        assert_eq!(settings.clipboard.max_total_size_mb, 300);
+        assert!(!settings.clipboard.paste_after_copy);
+        assert!(settings.clipboard.capture_text);
+        assert!(settings.clipboard.capture_images);
+        assert!(settings.clipboard.capture_files);
+        assert!(!settings.clipboard.perceptual_deduplicate);
+        assert_ne!(serialize_settings(&settings).expect("serialize"), raw);
+    }
+
+    #[test]
+    fn newer_clipboard_settings_version_is_kept() {
+        let raw = r#"{"clipboard":{"settingsVersion":99,"maxItems":200}}"#;
+
+        let (settings, _) = parse_stored_settings(raw);
+        assert_eq!(settings.clipboard.settings_version, 99);
+        assert_eq!(settings.clipboard.max_items, 200);
+    }
+
+    #[test]
+    fn window_auto_hide_patch_clamps_delay() {
+        let mut settings = normalize_settings(SettingsDto::default());
+        assert!(settings.window_auto_hide.launcher_enabled);
+        assert!(!settings.window_auto_hide.clipboard_enabled);
+
+        let input = SettingsUpdateInputDto {
+            window_auto_hide: Some(WindowAutoHideSettingsUpdateInputDto {
+                clipboard_enabled: Some(true),
+                clipboard_delay_ms: Some(60_000),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        apply_update(&mut settings, &input).expect("apply update");
+        assert!(settings.window_auto_hide.clipboard_enabled);
+        assert_eq!(
+            settings.window_auto_hide.clipboard_delay_ms,
+            WINDOW_AUTO_HIDE_DELAY_MS_MAX
+        );
+    }
+
+    #[test]
+    fn clipboard_preview_limit_patch_sets_and_clears() {
+        let mut settings = normalize_settings(SettingsDto::default());
+        assert_eq!(settings.clipboard.image_preview_max_width, None);
+
+        let patch = |width, height| SettingsUpdateInputDto {
+            clipboard: Some(SettingsClipboardUpdateInputDto {
+                image_preview_max_width: width,
+                image_preview_max_height: height,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        apply_update(&mut settings, &patch(Some(1280), Some(720))).expect("apply update");
+        assert_eq!(settings.clipboard.image_preview_max_width, Some(1280));
+        assert_eq!(settings.clipboard.image_preview_max_height, Some(720));
+
+        apply_update(&mut settings, &patch(Some(0), None)).expect("apply update");
+        assert_eq!(settings.clipboard.image_preview_max_width, None);
+        assert_eq!(settings.clipboard.image_preview_max_height, Some(720));
+    }
+
+    #[test]
+    fn clipboard_max_preview_pixels_patch_sets_and_clears() {
+        let mut settings = normalize_settings(SettingsDto::default());
+        assert_eq!(settings.clipboard.max_preview_pixels, None);
+
+        let patch = |max_pixels| SettingsUpdateInputDto {
+            clipboard: Some(SettingsClipboardUpdateInputDto {
+                max_preview_pixels: Some(max_pixels),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        apply_update(&mut settings, &patch(50_000_000)).expect("apply update");
+        assert_eq!(settings.clipboard.max_preview_pixels, Some(50_000_000));
+
+        apply_update(&mut settings, &patch(0)).expect("apply update");
+        assert_eq!(settings.clipboard.max_preview_pixels, None);
+    }
+
+    #[test]
+    fn clipboard_content_filters_default_to_all_and_patch_per_mode() {
+        let (mut settings, _) = parse_stored_settings(r#"{"clipboard":{"maxItems":200}}"#);
+        assert_eq!(
+            settings.clipboard.compact_content_filter,
+            ClipboardWindowContentFilter::All
+        );
+        assert_eq!(
+            settings.clipboard.regular_content_filter,
+            ClipboardWindowContentFilter::All
+        );
+
+        let input = SettingsUpdateInputDto {
+            clipboard: Some(SettingsClipboardUpdateInputDto {
+                compact_content_filter: Some(ClipboardWindowContentFilter::TextOnly),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        apply_update(&mut settings, &input).expect("apply update");
+        assert_eq!(
+            settings.clipboard.compact_content_filter,
+            ClipboardWindowContentFilter::TextOnly
+        );
+        assert_eq!(
+            settings.clipboard.regular_content_filter,
+            ClipboardWindowContentFilter::All
+        );
+        assert!(!settings.clipboard.compact_content_filter.allows("image"));
+        assert!(settings.clipboard.compact_content_filter.allows("code"));
+    }
+
+    #[test]
+    fn clipboard_max_pinned_items_defaults_and_clamps() {
+        let (mut settings, _) = parse_stored_settings(r#"{"clipboard":{"maxItems":200}}"#);
+        assert_eq!(settings.clipboard.max_pinned_items, 100);
+
+        let input = SettingsUpdateInputDto {
+            clipboard: Some(SettingsClipboardUpdateInputDto {
+                max_pinned_items: Some(0),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        apply_update(&mut settings, &input).expect("apply update");
+        let settings = normalize_settings(settings);
+        assert_eq!(
+            settings.clipboard.max_pinned_items,
+            CLIPBOARD_MAX_PINNED_ITEMS_MIN
+        );
+    }
+
+    #[test]
+    fn app_manager_patch_sets_default_delete_mode() {
+        let mut settings = normalize_settings(SettingsDto::default());
+        assert_eq!(
+            settings.app_manager.default_delete_mode,
+            AppManagerCleanupDeleteMode::Trash
+        );
+
+        let input = SettingsUpdateInputDto {
+            app_manager: Some(AppManagerSettingsUpdateInputDto {
+                default_delete_mode: Some(AppManagerCleanupDeleteMode::Permanent),
+            }),
+            ..Default::default()
+        };
+        apply_update(&mut settings, &input).expect("apply update");
+        assert_eq!(
+            settings.app_manager.default_delete_mode,
+            AppManagerCleanupDeleteMode::Permanent
+        );
+    }
+}
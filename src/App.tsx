@@ -1,10 +1,11 @@
+import { emit } from "@tauri-apps/api/event";
 import { getCurrentWindow } from "@tauri-apps/api/window";
 import { useEffect, useLayoutEffect, useRef } from "react";
 import { HashRouter, useLocation, useNavigate, useRoutes } from "react-router";
 
 import type { ClipboardSyncPayload } from "@/components/clipboard/types";
 import { MessageProvider } from "@ui/message/MessageProvider";
-import type { LocaleStateDto, SettingsDto } from "@/contracts";
+import type { ClipboardWatcherStatusDto, LocaleStateDto, SettingsDto } from "@/contracts";
 import { useAsyncEffect } from "@/hooks/useAsyncEffect";
 import { useLocaleStore } from "@/i18n/store";
 import { useLayoutStore } from "@/layouts/layout.store";
@@ -20,6 +21,7 @@ function AppEventBridge() {
   const navigate = useNavigate();
   const currentRouteRef = useRef("/");
   const applySync = useClipboardStore((state) => state.applySync);
+  const applyWatcherStatus = useClipboardStore((state) => state.applyWatcherStatus);
   const hydrateThemeFromSettings = useThemeStore((state) => state.hydrateFromSettings);
   const hydrateLocaleFromSettings = useLocaleStore((state) => state.hydrateFromSettings);
   const hydrateLayoutFromSettings = useLayoutStore((state) => state.hydrateFromSettings);
@@ -27,7 +29,11 @@ function AppEventBridge() {
   const hydrateLocaleFromBackendState = useLocaleStore((state) => state.hydrateFromBackendState);
 
   useEffect(() => {
-    currentRouteRef.current = `${location.pathname}${location.search}`;
+    const route = `${location.pathname}${location.search}`;
+    currentRouteRef.current = route;
+    if (getCurrentWindow().label === "main") {
+      void emit("rtool://main/route-changed", { route });
+    }
   }, [location.pathname, location.search]);
 
   useAsyncEffect(
@@ -44,6 +50,18 @@ function AppEventBridge() {
         "clipboard-sync",
       );
 
+      listenWithCleanup<ClipboardWatcherStatusDto>(
+        stack,
+        "rtool://clipboard/watcher-status",
+        (event) => {
+          if (event.payload) {
+            applyWatcherStatus(event.payload);
+          }
+        },
+        "app-event-bridge:clipboard-watcher-status",
+        "clipboard-watcher-status",
+      );
+
       listenWithCleanup<{ route: string }>(
         stack,
         "rtool://main/navigate",
@@ -97,6 +115,7 @@ function AppEventBridge() {
     },
     [
       applySync,
+      applyWatcherStatus,
       hydrateLocaleFromBackendState,
       hydrateLocaleFromSettings,
       hydrateLayoutFromSettings,
@@ -1,9 +1,14 @@
 import type {
   AppManagerActionCode,
+  AppManagerAnnotateResidueInputDto,
+  AppManagerBackupMetaDto,
+  AppManagerBulkStartupResultDto,
   AppManagerCleanupDeleteMode,
   AppManagerCleanupReasonCode,
   AppManagerCapabilitiesDto,
+  AppManagerExportFormat,
   AppManagerCategory,
+  AppManagerGhostStartupItemDto,
   AppManagerIconKind,
   AppManagerIdentityDto,
   AppManagerIdentitySource,
@@ -29,13 +34,17 @@ import type {
   AppManagerScanWarningDto,
   AppManagerScope,
   AppManagerSizeAccuracy,
+  AppManagerSizeAccuracyMode,
   AppManagerSizeSource,
   AppManagerSnapshotMetaDto,
   AppManagerSource,
+  AppManagerStartupEntryDto,
+  AppManagerStartupEntryKind,
   AppManagerStartupScope,
   AppManagerStartupUpdateInputDto,
   AppManagerUninstallInputDto,
   AppManagerUninstallKind,
+  AppManagerPackageManager,
   AppReadonlyReasonCode,
   AppRelatedRootDto,
   AppSizeSummaryDto,
@@ -45,12 +54,16 @@ import type {
   AppManagerCleanupItemResultDto,
   AppManagerCleanupResultDto,
   AppManagerExportScanResultDto,
+  AppManagerExportFileDto,
+  AppManagerExportListDto,
+  AppManagerClearExportsResultDto,
 } from "@/contracts";
 
 export type {
   AppManagerActionCode,
   AppManagerCleanupDeleteMode,
   AppManagerCleanupReasonCode,
+  AppManagerExportFormat,
   AppManagerIndexState,
   AppManagerIndexUpdateReason,
   AppManagerPathType,
@@ -64,10 +77,13 @@ export type {
   AppManagerScanWarningDetailCode,
   AppManagerScope,
   AppManagerSizeAccuracy,
+  AppManagerSizeAccuracyMode,
   AppManagerSizeSource,
   AppManagerSource,
+  AppManagerStartupEntryKind,
   AppManagerStartupScope,
   AppManagerUninstallKind,
+  AppManagerPackageManager,
   AppReadonlyReasonCode,
   AppManagerCategory,
   AppManagerIconKind,
@@ -82,6 +98,10 @@ export type AppManagerPage = AppManagerPageDto;
 export type AppManagerSnapshotMeta = AppManagerSnapshotMetaDto;
 export type AppManagerIndexUpdatedPayload = AppManagerIndexUpdatedPayloadDto;
 export type AppManagerActionResult = AppManagerActionResultDto;
+export type AppManagerStartupEntry = AppManagerStartupEntryDto;
+export type AppManagerGhostStartupItem = AppManagerGhostStartupItemDto;
+export type AppManagerBackupMeta = AppManagerBackupMetaDto;
+export type AppManagerBulkStartupResult = AppManagerBulkStartupResultDto;
 export type AppManagerStartupUpdateInput = AppManagerStartupUpdateInputDto;
 export type AppManagerUninstallInput = AppManagerUninstallInputDto;
 export type AppRelatedRoot = AppRelatedRootDto;
@@ -97,12 +117,18 @@ export type AppManagerResolveSizesResult = AppManagerResolveSizesResultDto;
 export type AppManagerCleanupItemResult = AppManagerCleanupItemResultDto;
 export type AppManagerCleanupResult = AppManagerCleanupResultDto;
 export type AppManagerExportScanResult = AppManagerExportScanResultDto;
+export type AppManagerExportFile = AppManagerExportFileDto;
+export type AppManagerExportList = AppManagerExportListDto;
+export type AppManagerClearExportsResult = AppManagerClearExportsResultDto;
+export type AppManagerAnnotateResidueInput = AppManagerAnnotateResidueInputDto;
 
 export interface AppManagerQuery {
   keyword?: string;
   category?: AppManagerQueryCategory;
   limit?: number;
   cursor?: string;
+  sizeAccuracy?: AppManagerSizeAccuracyMode;
+  folderFilter?: string;
 }
 
 export interface AppManagerCleanupInput {
@@ -112,4 +138,5 @@ export interface AppManagerCleanupInput {
   includeMainApp: boolean;
   skipOnError?: boolean;
   confirmedFingerprint?: string;
+  confirmedItemIds?: string[];
 }
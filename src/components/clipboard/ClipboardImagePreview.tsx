@@ -19,7 +19,7 @@ function resolveImageUrlCandidates(item: ClipboardItem | null): string[] {
 
   const candidates: string[] = [];
 
-  if (item.previewPath) {
+  if (item.previewPath && !item.previewMissing) {
     candidates.push(convertFileSrc(item.previewPath));
   }
 
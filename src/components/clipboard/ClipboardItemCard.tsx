@@ -34,7 +34,7 @@ function formatTime(timestamp: number, locale: string): string {
 function resolveImageUrlCandidates(item: ClipboardItem): string[] {
   const candidates: string[] = [];
 
-  if (item.previewPath) {
+  if (item.previewPath && !item.previewMissing) {
     candidates.push(convertFileSrc(item.previewPath));
   }
 
@@ -107,7 +107,7 @@ export default function ClipboardItemCard(props: ClipboardItemCardProps) {
 
   useEffect(() => {
     setImageUrlIndex(0);
-  }, [props.item.id, props.item.previewDataUrl, props.item.previewPath]);
+  }, [props.item.id, props.item.previewDataUrl, props.item.previewPath, props.item.previewMissing]);
 
   const imageUrl = imageUrlCandidates[imageUrlIndex] ?? null;
 
@@ -216,7 +216,28 @@ export default function ClipboardItemCard(props: ClipboardItemCardProps) {
             />
           ) : (
             <div className="rounded-md border border-dashed border-border-muted bg-surface px-3 py-6 text-center text-xs text-text-muted">
-              {t("item.imageUnavailable")}
+              {props.item.previewSkipped ? (
+                <span className="btn-icon i-lucide:image mb-1 block text-[1.6rem]" aria-hidden="true" />
+              ) : null}
+              {props.item.previewSkipped
+                ? t("item.previewSkipped")
+                : props.item.previewMissing
+                  ? t("item.previewMissing")
+                  : t("item.imageUnavailable")}
+              {props.item.previewMissing && !hideActions ? (
+                <div className="mt-2">
+                  <Button
+                    variant="secondary"
+                    size="xs"
+                    onClick={(event) => {
+                      event.stopPropagation();
+                      props.onDelete();
+                    }}
+                  >
+                    {t("action.delete")}
+                  </Button>
+                </div>
+              ) : null}
             </div>
           )
         ) : null}
@@ -3,16 +3,19 @@ import { useTranslation } from "react-i18next";
 
 import ClipboardPanelView from "@/components/clipboard/ClipboardPanelView";
 import type { ClipboardItem } from "@/components/clipboard/types";
+import type { ClipboardWindowContentFilter } from "@/contracts";
 import { useBootState } from "@/components/loading";
 import { useClipboardActionFeedback } from "@/hooks/clipboard/useClipboardActionFeedback";
 import { useClipboardDeleteUndo } from "@/hooks/clipboard/useClipboardDeleteUndo";
 import { useClipboardHotkeys } from "@/hooks/clipboard/useClipboardHotkeys";
 import { clipboardExportImage } from "@/services/clipboard.service";
 import { useClipboardStore } from "@/stores/clipboard.store";
+import { useSettingsStore } from "@/stores/settings.store";
 
 export interface ClipboardPanelProps {
   className?: string;
   compactMode?: boolean;
+  contentFilter?: ClipboardWindowContentFilter;
   onCompactModeToggle?: () => void;
   alwaysOnTop?: boolean;
   onAlwaysOnTopToggle?: () => void;
@@ -24,6 +27,7 @@ const DELETE_UNDO_DELAY = 5_000;
 export default function ClipboardPanelContainer(props: ClipboardPanelProps) {
   const { t } = useTranslation(["clipboard", "common"]);
   const compactMode = props.compactMode ?? false;
+  const textOnly = props.contentFilter === "text_only";
   const alwaysOnTop = props.alwaysOnTop ?? false;
 
   const items = useClipboardStore((state) => state.items);
@@ -42,8 +46,13 @@ export default function ClipboardPanelContainer(props: ClipboardPanelProps) {
   const deleteItem = useClipboardStore((state) => state.deleteItem);
   const clearAllItems = useClipboardStore((state) => state.clearAllItems);
   const copyBack = useClipboardStore((state) => state.copyBack);
+  const copyAndPaste = useClipboardStore((state) => state.copyAndPaste);
   const copyFilePathsBack = useClipboardStore((state) => state.copyFilePathsBack);
   const copyImageBack = useClipboardStore((state) => state.copyImageBack);
+  const capturePaused = useClipboardStore((state) => state.watcherStatus?.paused ?? false);
+  const fetchWatcherStatus = useClipboardStore((state) => state.fetchWatcherStatus);
+  const setCapturePaused = useClipboardStore((state) => state.setCapturePaused);
+  const pasteAfterCopy = useSettingsStore((state) => state.clipboardSettings?.pasteAfterCopy ?? false);
 
   const [previewItem, setPreviewItem] = useState<ClipboardItem | null>(null);
   const [selectedItemId, setSelectedItemId] = useState<string | null>(null);
@@ -57,6 +66,7 @@ export default function ClipboardPanelContainer(props: ClipboardPanelProps) {
     setClearAllError,
     isClearingAll,
     handleCopyBack,
+    handleCopyAndPaste,
     handleCopyFilePaths,
     handleCopyPreviewImage,
     handleDownloadPreviewImage,
@@ -64,6 +74,7 @@ export default function ClipboardPanelContainer(props: ClipboardPanelProps) {
   } = useClipboardActionFeedback({
     t,
     copyBack,
+    copyAndPaste,
     copyFilePathsBack,
     copyImageBack,
     exportImage: clipboardExportImage,
@@ -99,12 +110,15 @@ export default function ClipboardPanelContainer(props: ClipboardPanelProps) {
       if (itemType && entry.itemType !== itemType) {
         return false;
       }
+      if (textOnly && (entry.itemType === "image" || entry.itemType === "file")) {
+        return false;
+      }
       if (!queryKeyword) {
         return true;
       }
       return entry.plainText.toLowerCase().includes(queryKeyword);
     });
-  }, [items, itemType, onlyPinned, pendingDeleteSet, query]);
+  }, [items, itemType, onlyPinned, pendingDeleteSet, query, textOnly]);
 
   const selectedItem = useMemo(
     () => visibleItems.find((entry) => entry.id === selectedItemId) ?? visibleItems[0] ?? null,
@@ -115,6 +129,10 @@ export default function ClipboardPanelContainer(props: ClipboardPanelProps) {
     void ensureInitialized();
   }, [ensureInitialized]);
 
+  useEffect(() => {
+    void fetchWatcherStatus();
+  }, [fetchWatcherStatus]);
+
   useEffect(() => {
     if (!selectedItem) {
       setSelectedItemId(null);
@@ -169,6 +187,10 @@ export default function ClipboardPanelContainer(props: ClipboardPanelProps) {
     [pinItem],
   );
 
+  const handleCapturePausedToggle = useCallback(() => {
+    void setCapturePaused(!capturePaused);
+  }, [capturePaused, setCapturePaused]);
+
   const handleCopyItem = useCallback(
     (item: ClipboardItem) => {
       void handleCopyBack(item);
@@ -176,6 +198,17 @@ export default function ClipboardPanelContainer(props: ClipboardPanelProps) {
     [handleCopyBack],
   );
 
+  const handleEnterItem = useCallback(
+    (item: ClipboardItem) => {
+      if (pasteAfterCopy) {
+        void handleCopyAndPaste(item);
+        return;
+      }
+      void handleCopyBack(item);
+    },
+    [handleCopyAndPaste, handleCopyBack, pasteAfterCopy],
+  );
+
   const handleCopyPaths = useCallback(
     (item: ClipboardItem) => {
       void handleCopyFilePaths(item);
@@ -195,7 +228,7 @@ export default function ClipboardPanelContainer(props: ClipboardPanelProps) {
     visibleItems,
     selectedItem,
     onSelectItemId: setSelectedItemId,
-    onCopyBack: handleCopyItem,
+    onCopyBack: handleEnterItem,
     onPinToggle: handlePinToggle,
     onDelete: handleDelete,
   });
@@ -237,6 +270,8 @@ export default function ClipboardPanelContainer(props: ClipboardPanelProps) {
       alwaysOnTop={alwaysOnTop}
       onCompactModeToggle={props.onCompactModeToggle}
       onAlwaysOnTopToggle={props.onAlwaysOnTopToggle}
+      capturePaused={capturePaused}
+      onCapturePausedToggle={handleCapturePausedToggle}
       searchInputRef={props.searchInputRef}
       query={query}
       itemType={itemType}
@@ -18,6 +18,8 @@ interface ClipboardPanelViewProps {
   alwaysOnTop: boolean;
   onCompactModeToggle?: () => void;
   onAlwaysOnTopToggle?: () => void;
+  capturePaused: boolean;
+  onCapturePausedToggle?: () => void;
   searchInputRef?: RefObject<HTMLInputElement | null>;
   query: string;
   itemType: string;
@@ -89,6 +91,7 @@ export default function ClipboardPanelView(props: ClipboardPanelViewProps) {
 
   const compactToggleLabel = props.compactMode ? t("action.exitCompactMode") : t("action.enterCompactMode");
   const alwaysOnTopLabel = props.alwaysOnTop ? t("action.unpinWindow") : t("action.pinWindow");
+  const capturePausedLabel = props.capturePaused ? t("action.resumeCapture") : t("action.pauseCapture");
 
   return (
     <section
@@ -101,8 +104,26 @@ export default function ClipboardPanelView(props: ClipboardPanelViewProps) {
           {props.compactMode
             ? t("panel.summaryCompact", { count: props.visibleItems.length })
             : t("panel.summary", { count: props.visibleItems.length })}
+          {props.capturePaused ? <span className="ml-2 text-warning">{t("panel.capturePaused")}</span> : null}
         </p>
         <div className="ml-auto flex flex-wrap items-center justify-end gap-2">
+          <Button
+            size="xs"
+            variant={props.capturePaused ? "secondary" : "ghost"}
+            iconOnly
+            title={capturePausedLabel}
+            aria-label={capturePausedLabel}
+            disabled={!props.onCapturePausedToggle}
+            onClick={props.onCapturePausedToggle}
+          >
+            <span
+              className={[
+                "inline-block leading-none text-[2.2rem] transform-gpu scale-[1.25] origin-center",
+                props.capturePaused ? "i-noto:play-button" : "i-noto:pause-button",
+              ].join(" ")}
+              aria-hidden="true"
+            />
+          </Button>
           <Button
             size="xs"
             variant={props.alwaysOnTop ? "secondary" : "ghost"}
@@ -1,3 +1,5 @@
+import type { ClipboardWindowContentFilter } from "@/contracts";
+
 export type ClipboardType = "text" | "link" | "image" | "file" | "code" | "color";
 
 export interface ClipboardItem {
@@ -10,6 +12,9 @@ export interface ClipboardItem {
   previewDataUrl: string | null;
   createdAt: number;
   pinned: boolean;
+  pinOrder?: number;
+  previewMissing?: boolean;
+  previewSkipped?: boolean;
 }
 
 export interface ClipboardFilter {
@@ -17,6 +22,7 @@ export interface ClipboardFilter {
   itemType?: string;
   onlyPinned?: boolean;
   limit?: number;
+  contentFilter?: ClipboardWindowContentFilter;
 }
 
 export interface ClipboardSyncPayload {
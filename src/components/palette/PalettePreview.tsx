@@ -64,6 +64,10 @@ function actionKey(kind: string | undefined): string {
     return "action.openApplication";
   }
 
+  if (kind === "copy_clipboard_item") {
+    return "action.copyClipboardItem";
+  }
+
   return "action.execute";
 }
 
@@ -221,6 +225,9 @@ export default function PalettePreview(props: PalettePreviewProps) {
         <span className="rounded-full bg-surface-soft px-2 py-1 text-text-secondary">
           {t("preview.source", { value: sourceLabel })}
         </span>
+        {item.matchedViaAlias ? (
+          <span className="rounded-full bg-surface-soft px-2 py-1 text-text-secondary">{t("preview.matchedViaAlias")}</span>
+        ) : null}
         {item.shortcut ? (
           <span className="rounded-full bg-accent-soft px-2 py-1 text-accent">
             {t("preview.shortcut", { value: item.shortcut })}
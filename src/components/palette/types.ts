@@ -1,3 +1,5 @@
+import type { LauncherMatchRangeDto } from "@/contracts";
+
 export type PaletteCategory =
   | "builtin"
   | "application"
@@ -16,7 +18,8 @@ export type LauncherAction =
   | { kind: "open_builtin_window"; windowLabel: string }
   | { kind: "open_directory"; path: string }
   | { kind: "open_file"; path: string }
-  | { kind: "open_application"; path: string };
+  | { kind: "open_application"; path: string }
+  | { kind: "copy_clipboard_item"; itemId: string };
 
 export interface PaletteItem {
   id: string;
@@ -30,6 +33,9 @@ export interface PaletteItem {
   iconKind?: "raster" | "iconify";
   iconValue?: string;
   action?: LauncherAction;
+  undoable?: boolean;
+  titleMatchRanges?: LauncherMatchRangeDto[];
+  matchedViaAlias?: boolean;
 }
 
 export interface PaletteActionResult {
@@ -18,11 +18,12 @@ export type AppErrorPayload = {
   requestId: string | null;
 };
 
-export type InvokeError = {
+export type InvokeErrorPayload = {
   code: string;
   message: string;
   context: Array<ErrorContextItem>;
   causes: Array<string>;
+  causeSummary: string | null;
   requestId: string | null;
 };
 
@@ -37,6 +38,9 @@ export type SettingsDto = {
   locale: LocaleSettingsDto;
   clipboard: SettingsClipboardDto;
   screenshot: SettingsScreenshotDto;
+  tray: TraySettingsDto;
+  windowAutoHide: WindowAutoHideSettingsDto;
+  appManager: AppManagerSettingsDto;
 };
 
 export type ThemeSettingsDto = {
@@ -50,6 +54,22 @@ export type LayoutSettingsDto = {
 
 export type LocaleSettingsDto = {
   preference: string;
+  byteUnit: string;
+};
+
+export type TraySettingsDto = {
+  liveTooltip: boolean;
+};
+
+export type WindowAutoHideSettingsDto = {
+  launcherEnabled: boolean;
+  launcherDelayMs: number;
+  clipboardEnabled: boolean;
+  clipboardDelayMs: number;
+};
+
+export type AppManagerSettingsDto = {
+  defaultDeleteMode: AppManagerCleanupDeleteMode;
 };
 
 export type SettingsUpdateInputDto = {
@@ -58,6 +78,9 @@ export type SettingsUpdateInputDto = {
   locale: LocaleSettingsUpdateInputDto | null;
   clipboard: SettingsClipboardUpdateInputDto | null;
   screenshot: SettingsScreenshotUpdateInputDto | null;
+  tray: TraySettingsUpdateInputDto | null;
+  windowAutoHide: WindowAutoHideSettingsUpdateInputDto | null;
+  appManager: AppManagerSettingsUpdateInputDto | null;
 };
 
 export type ThemeSettingsUpdateInputDto = {
@@ -71,18 +94,93 @@ export type LayoutSettingsUpdateInputDto = {
 
 export type LocaleSettingsUpdateInputDto = {
   preference: string | null;
+  byteUnit: string | null;
+};
+
+export type AppExportLocaleResultDto = {
+  filePath: string;
+  keyCount: number;
+  locale: string;
+};
+
+export type AppImportLocaleResultDto = {
+  locale: string;
+  namespace: string;
+  overlayPath: string;
+  importedCount: number;
+  skippedCount: number;
+  invalidCount: number;
+  skippedKeys: Array<string>;
+};
+
+export type I18nLocaleStatsDto = {
+  locale: string;
+  totalKeys: number;
+  translatedKeys: number;
+  missingKeys: number;
+  emptyValueKeys: number;
+  completenessPercent: number;
+};
+
+export type TraySettingsUpdateInputDto = {
+  liveTooltip: boolean | null;
+};
+
+export type WindowAutoHideSettingsUpdateInputDto = {
+  launcherEnabled: boolean | null;
+  launcherDelayMs: number | null;
+  clipboardEnabled: boolean | null;
+  clipboardDelayMs: number | null;
+};
+
+export type AppManagerSettingsUpdateInputDto = {
+  defaultDeleteMode: AppManagerCleanupDeleteMode | null;
 };
 
 export type SettingsClipboardDto = {
+  settingsVersion: number;
   maxItems: number;
   sizeCleanupEnabled: boolean;
   maxTotalSizeMb: number;
-};
+  pasteAfterCopy: boolean;
+  captureText: boolean;
+  captureImages: boolean;
+  captureFiles: boolean;
+  perceptualDeduplicate: boolean;
+  storageDir: string | null;
+  imagePreviewMaxWidth: number | null;
+  imagePreviewMaxHeight: number | null;
+  maxPreviewPixels: number | null;
+  previewFailurePolicy: ClipboardPreviewFailurePolicy;
+  compactContentFilter: ClipboardWindowContentFilter;
+  regularContentFilter: ClipboardWindowContentFilter;
+  maxPinnedItems: number;
+};
+
+export type ClipboardPreviewFailurePolicy =
+  | "keep_without_preview"
+  | "skip_item";
+
+export type ClipboardWindowContentFilter =
+  | "all"
+  | "text_only";
 
 export type SettingsClipboardUpdateInputDto = {
   maxItems: number | null;
   sizeCleanupEnabled: boolean | null;
   maxTotalSizeMb: number | null;
+  pasteAfterCopy: boolean | null;
+  captureText: boolean | null;
+  captureImages: boolean | null;
+  captureFiles: boolean | null;
+  perceptualDeduplicate: boolean | null;
+  imagePreviewMaxWidth: number | null;
+  imagePreviewMaxHeight: number | null;
+  maxPreviewPixels: number | null;
+  previewFailurePolicy: ClipboardPreviewFailurePolicy | null;
+  compactContentFilter: ClipboardWindowContentFilter | null;
+  regularContentFilter: ClipboardWindowContentFilter | null;
+  maxPinnedItems: number | null;
 };
 
 export type SettingsScreenshotDto = {
@@ -101,13 +199,61 @@ export type SettingsScreenshotUpdateInputDto = {
   pinMaxInstances: number | null;
 };
 
+export type SettingsBundleDto = {
+  version: number;
+  exportedAt: number;
+  theme: JsonValue | null;
+  layout: JsonValue | null;
+  locale: JsonValue | null;
+  clipboard: JsonValue | null;
+  screenshot: JsonValue | null;
+  logging: JsonValue | null;
+  launcherSearch: JsonValue | null;
+  tray: JsonValue | null;
+  windowAutoHide: JsonValue | null;
+  appManager: JsonValue | null;
+};
+
+export type SettingsExportResultDto = {
+  filePath: string;
+  sections: Array<string>;
+};
+
+export type SettingsImportSectionResultDto = {
+  section: string;
+  ok: boolean;
+  errorCode: string | null;
+  message: string | null;
+};
+
+export type SettingsImportResultDto = {
+  sections: Array<SettingsImportSectionResultDto>;
+  settings: SettingsDto;
+};
+
+export type DbMaintenanceResultDto = {
+  sizeBeforeBytes: number;
+  sizeAfterBytes: number;
+  reclaimedBytes: number;
+  durationMs: number;
+};
+
+export type ShortcutStatusDto = {
+  action: string;
+  accelerator: string;
+  registered: boolean;
+  fellBack: boolean;
+  reason: string | null;
+};
+
 export type LauncherActionDto =
   | { kind: "open_builtin_route"; route: string }
   | { kind: "open_builtin_tool"; toolId: string }
   | { kind: "open_builtin_window"; windowLabel: string }
   | { kind: "open_directory"; path: string }
   | { kind: "open_file"; path: string }
-  | { kind: "open_application"; path: string };
+  | { kind: "open_application"; path: string }
+  | { kind: "copy_clipboard_item"; itemId: string };
 
 export type LauncherItemDto = {
   id: string;
@@ -121,6 +267,19 @@ export type LauncherItemDto = {
   iconKind: string;
   iconValue: string;
   action: LauncherActionDto;
+  undoable: boolean;
+  titleMatchRanges: Array<LauncherMatchRangeDto>;
+  matchedViaAlias: boolean;
+};
+
+export type LauncherMatchRangeDto = {
+  start: number;
+  end: number;
+};
+
+export type LauncherUndoResultDto = {
+  undoneActionId: string;
+  message: string;
 };
 
 export type LauncherSearchSettingsDto = {
@@ -197,6 +356,8 @@ export type AppManagerQueryDto = {
   category: AppManagerCategory;
   limit: number | null;
   cursor: string | null;
+  sizeAccuracy: AppManagerSizeAccuracyMode | null;
+  folderFilter: string | null;
 };
 
 export type AppManagerSnapshotMetaDto = {
@@ -204,6 +365,9 @@ export type AppManagerSnapshotMetaDto = {
   revision: number;
   totalCount: number;
   indexState: AppManagerIndexState;
+  sourceFingerprint: string;
+  lastError: string | null;
+  buildDurationMs: number | null;
 };
 
 export type AppManagerCapabilitiesDto = {
@@ -279,6 +443,11 @@ export type AppManagerSizeAccuracy =
   | "exact"
   | "estimated";
 
+export type AppManagerSizeAccuracyMode =
+  | "quick"
+  | "standard"
+  | "exact";
+
 export type AppManagerSizeSource =
   | "app_bundle"
   | "parent_directory"
@@ -314,6 +483,19 @@ export type AppManagerUninstallKind =
   | "finder_trash"
   | "registry_command";
 
+export type AppManagerUninstallHelpTarget =
+  | "registered_uninstaller"
+  | "apps_and_features"
+  | "finder_reveal";
+
+export type AppManagerPackageManager =
+  | "winget"
+  | "scoop"
+  | "chocolatey"
+  | "msi"
+  | "inno"
+  | "nsis";
+
 export type AppManagerResidueMatchReason =
   | "related_root"
   | "bundle_id"
@@ -325,7 +507,8 @@ export type AppManagerResidueMatchReason =
   | "startup_shortcut"
   | "uninstall_registry"
   | "startup_registry"
-  | "run_registry";
+  | "run_registry"
+  | "user_software_registry";
 
 export type ManagedAppDto = {
   id: string;
@@ -342,12 +525,17 @@ export type ManagedAppDto = {
   sizeAccuracy: AppManagerSizeAccuracy;
   sizeSource: AppManagerSizeSource;
   sizeComputedAt: number | null;
+  sizeAccuracyUsed: AppManagerSizeAccuracyMode | null;
   startupEnabled: boolean;
   startupScope: AppManagerStartupScope;
   startupEditable: boolean;
   readonlyReasonCode: AppReadonlyReasonCode | null;
   uninstallSupported: boolean;
   uninstallKind: AppManagerUninstallKind | null;
+  packageManager: AppManagerPackageManager | null;
+  packageId: string | null;
+  folderPath: string | null;
+  folderName: string | null;
   capabilities: AppManagerCapabilitiesDto;
   identity: AppManagerIdentityDto;
   riskLevel: AppManagerRiskLevel;
@@ -382,6 +570,7 @@ export type AppManagerUninstallInputDto = {
 
 export type AppManagerDetailQueryDto = {
   appId: string;
+  sizeAccuracy: AppManagerSizeAccuracyMode | null;
 };
 
 export type AppRelatedRootDto = {
@@ -393,6 +582,7 @@ export type AppRelatedRootDto = {
   kind: AppManagerResidueKind;
   exists: boolean;
   readonly: boolean;
+  cleanable: boolean;
   readonlyReasonCode: AppReadonlyReasonCode | null;
 };
 
@@ -400,6 +590,7 @@ export type AppSizeSummaryDto = {
   appBytes: number | null;
   residueBytes: number | null;
   totalBytes: number | null;
+  sizeAccuracyUsed: AppManagerSizeAccuracyMode | null;
 };
 
 export type ManagedAppDetailDto = {
@@ -414,12 +605,19 @@ export type AppManagerResidueScanInputDto = {
   mode: AppManagerResidueScanMode | null;
 };
 
+export type AppManagerScanPathInputDto = {
+  path: string;
+  mode: AppManagerResidueScanMode | null;
+};
+
 export type AppManagerResidueScanMode =
   | "quick"
   | "deep";
 
 export type AppManagerResolveSizesInputDto = {
   appIds: Array<string>;
+  cancelToken: string | null;
+  parallelism: number | null;
 };
 
 export type AppManagerResolvedSizeDto = {
@@ -428,10 +626,12 @@ export type AppManagerResolvedSizeDto = {
   sizeAccuracy: AppManagerSizeAccuracy;
   sizeSource: AppManagerSizeSource;
   sizeComputedAt: number | null;
+  cancelled: boolean;
 };
 
 export type AppManagerResolveSizesResultDto = {
   items: Array<AppManagerResolvedSizeDto>;
+  cancelled: boolean;
 };
 
 export type AppManagerResidueItemDto = {
@@ -448,6 +648,8 @@ export type AppManagerResidueItemDto = {
   recommended: boolean;
   readonly: boolean;
   readonlyReasonCode: AppReadonlyReasonCode | null;
+  annotation: string | null;
+  userSkip: boolean;
 };
 
 export type AppManagerResidueGroupDto = {
@@ -456,6 +658,8 @@ export type AppManagerResidueGroupDto = {
   scope: AppManagerScope;
   kind: AppManagerResidueKind;
   totalSizeBytes: number;
+  totalItems: number;
+  recommendedItems: number;
   items: Array<AppManagerResidueItemDto>;
 };
 
@@ -494,10 +698,11 @@ export type AppManagerResidueScanResultDto = {
 export type AppManagerCleanupInputDto = {
   appId: string;
   selectedItemIds: Array<string>;
-  deleteMode: AppManagerCleanupDeleteMode;
+  deleteMode: AppManagerCleanupDeleteMode | null;
   includeMainApp: boolean;
   skipOnError: boolean | null;
   confirmedFingerprint: string | null;
+  confirmedItemIds: Array<string>;
 };
 
 export type AppManagerCleanupDeleteMode =
@@ -514,6 +719,8 @@ export type AppManagerCleanupReasonCode =
   | "self_uninstall_forbidden"
   | "managed_by_policy"
   | "not_found"
+  | "user_skip"
+  | "requires_confirmation"
   | "app_manager_cleanup_delete_failed"
   | "app_manager_cleanup_not_found"
   | "app_manager_cleanup_path_invalid"
@@ -539,14 +746,52 @@ export type AppManagerCleanupResultDto = {
   failed: Array<AppManagerCleanupItemResultDto>;
 };
 
+export type AppManagerAnnotateResidueInputDto = {
+  appId: string;
+  itemId: string;
+  note: string;
+  skip: boolean;
+};
+
 export type AppManagerExportScanInputDto = {
   appId: string;
+  format: AppManagerExportFormat | null;
 };
 
+export type AppManagerExportFormat =
+  | "json"
+  | "markdown"
+  | "html";
+
 export type AppManagerExportScanResultDto = {
   appId: string;
   filePath: string;
   directoryPath: string;
+  formatUsed: string;
+};
+
+export type AppManagerExportFileDto = {
+  fileName: string;
+  filePath: string;
+  format: AppManagerExportFormat;
+  sizeBytes: number;
+  modifiedAt: number | null;
+};
+
+export type AppManagerExportListDto = {
+  directoryPath: string;
+  files: Array<AppManagerExportFileDto>;
+  totalBytes: number;
+};
+
+export type AppManagerClearExportsInputDto = {
+  olderThanDays: number | null;
+};
+
+export type AppManagerClearExportsResultDto = {
+  deletedCount: number;
+  failedCount: number;
+  reclaimedBytes: number;
 };
 
 export type AppManagerActionResultDto = {
@@ -554,6 +799,57 @@ export type AppManagerActionResultDto = {
   code: AppManagerActionCode;
   message: string;
   detail: string | null;
+  uninstallHelpTarget: AppManagerUninstallHelpTarget | null;
+};
+
+export type AppManagerStartupEntryKind =
+  | "file"
+  | "registry_key";
+
+export type AppManagerStartupEntryDto = {
+  appId: string;
+  scope: AppManagerStartupScope;
+  kind: AppManagerStartupEntryKind;
+  location: string;
+};
+
+export type AppManagerBackupMetaDto = {
+  backupId: string;
+  revision: number;
+  createdAt: number;
+  itemCount: number;
+  enabledCount: number;
+};
+
+export type AppManagerBulkStartupStatus =
+  | "updated"
+  | "unchanged"
+  | "skipped"
+  | "failed";
+
+export type AppManagerBulkStartupItemDto = {
+  appId: string;
+  name: string;
+  enabled: boolean;
+  status: AppManagerBulkStartupStatus;
+  message: string | null;
+};
+
+export type AppManagerBulkStartupResultDto = {
+  backupId: string;
+  updatedCount: number;
+  failedCount: number;
+  items: Array<AppManagerBulkStartupItemDto>;
+};
+
+export type AppManagerGhostStartupReason =
+  | "path_not_found";
+
+export type AppManagerGhostStartupItemDto = {
+  label: string;
+  path: string;
+  scope: AppManagerStartupScope;
+  reason: AppManagerGhostStartupReason;
 };
 
 export type AppManagerActionCode =
@@ -568,6 +864,7 @@ export type ClipboardFilterDto = {
   itemType: string | null;
   onlyPinned: boolean | null;
   limit: number | null;
+  contentFilter: ClipboardWindowContentFilter | null;
 };
 
 export type ClipboardItemDto = {
@@ -580,12 +877,115 @@ export type ClipboardItemDto = {
   previewDataUrl: string | null;
   createdAt: number;
   pinned: boolean;
+  pinOrder: number;
+  previewMissing: boolean;
+  previewSkipped: boolean;
+  contentSizeBytes: number | null;
+  width: number | null;
+  height: number | null;
+  originalWidth: number | null;
+  originalHeight: number | null;
+};
+
+export type ClipboardItemDetailDto = {
+  item: ClipboardItemDto;
+  charCount: number;
+  lineCount: number;
+  fileCount: number;
+};
+
+export type ClipboardSearchField =
+  | "plainText"
+  | "sourceApp";
+
+export type ClipboardSearchMatchDto = {
+  field: ClipboardSearchField;
+  start: number;
+  end: number;
+};
+
+export type ClipboardSearchHitDto = {
+  item: ClipboardItemDto;
+  matches: Array<ClipboardSearchMatchDto>;
+};
+
+export type ClipboardSearchResultDto = {
+  items: Array<ClipboardSearchHitDto>;
+  totalCount: number;
+  truncated: boolean;
 };
 
 export type ClipboardSettingsDto = {
   maxItems: number;
   sizeCleanupEnabled: boolean;
   maxTotalSizeMb: number;
+  captureText: boolean;
+  captureImages: boolean;
+  captureFiles: boolean;
+  perceptualDeduplicate: boolean;
+  clipboardStorageDir: string | null;
+  imagePreviewMaxWidth: number | null;
+  imagePreviewMaxHeight: number | null;
+  maxPreviewPixels: number | null;
+  previewFailurePolicy: ClipboardPreviewFailurePolicy;
+  compactContentFilter: ClipboardWindowContentFilter;
+  regularContentFilter: ClipboardWindowContentFilter;
+  maxPinnedItems: number;
+};
+
+export type ClipboardKindCountDto = {
+  itemType: string;
+  count: number;
+};
+
+export type ClipboardPreviewRepairResultDto = {
+  repairedCount: number;
+  orphanRemovedCount: number;
+};
+
+export type ClipboardTextTransformDto =
+  | "trim"
+  | "lowercase"
+  | "uppercase"
+  | "json_pretty"
+  | "base64_encode"
+  | "base64_decode"
+  | "url_encode"
+  | "url_decode";
+
+export type ClipboardMigrateResultDto = {
+  filesMoved: number;
+  dbMigrated: boolean;
+  oldPath: string;
+  newPath: string;
+};
+
+export type ClipboardSizeSummaryDto = {
+  totalItems: number;
+  textItems: number;
+  imageItems: number;
+  totalSizeBytes: number;
+  pinnedSizeBytes: number;
+  previewDirSizeBytes: number;
+};
+
+export type ClipboardStorageStatsDto = {
+  totalCount: number;
+  countByKind: Array<ClipboardKindCountDto>;
+  dbBytes: number;
+  previewBytes: number;
+  previewFileCount: number;
+  previewScanTruncated: boolean;
+  maxItems: number;
+  sizeCleanupEnabled: boolean;
+  maxTotalSizeMb: number;
+};
+
+export type ClipboardWatcherStatusDto = {
+  running: boolean;
+  paused: boolean;
+  pausedAt: number | null;
+  resumeAt: number | null;
 };
 
 export type ClipboardWindowOpenedPayload = {
@@ -594,6 +994,7 @@ export type ClipboardWindowOpenedPayload = {
 
 export type ClipboardWindowModeAppliedDto = {
   compact: boolean;
+  contentFilter: ClipboardWindowContentFilter;
   appliedWidthLogical: number;
   appliedHeightLogical: number;
   scaleFactor: number;
@@ -705,6 +1106,7 @@ export type LogQueryDto = {
   requestId: string | null;
   windowLabel: string | null;
   keyword: string | null;
+  errorContextKey: string | null;
   startAt: number | null;
   endAt: number | null;
 };
@@ -721,6 +1123,7 @@ export type LogEntryDto = {
   metadata: JsonValue | null;
   rawRef: string | null;
   aggregatedCount: number | null;
+  errorContextKeys: Array<string>;
 };
 
 export type LogPageDto = {
@@ -728,6 +1131,12 @@ export type LogPageDto = {
   nextCursor: string | null;
 };
 
+export type LogExportResultDto = {
+  path: string;
+  exportedCount: number;
+  fileSizeBytes: number;
+};
+
 export type LogConfigDto = {
   minLevel: string;
   keepDays: number;
@@ -735,11 +1144,14 @@ export type LogConfigDto = {
   highFreqWindowMs: number;
   highFreqMaxPerKey: number;
   allowRawView: boolean;
+  logFormat: string;
+  errorDetailLevel: string;
 };
 
 export type LocaleStateDto = {
   preference: string;
   resolved: string;
+  textDirection: string;
 };
 
 // <generated-models:end>
@@ -775,50 +1187,83 @@ export type AppFeatureRequestMap = {
 export type AppManagerRequestDto =
   | CommandWithPayload<"list", { query?: AppManagerQueryDto }>
   | CommandNoPayload<"list_snapshot_meta">
+  | CommandNoPayload<"get_snapshot_meta">
   | CommandWithPayload<"resolve_sizes", { input: AppManagerResolveSizesInputDto }>
+  | CommandWithPayload<"cancel_resolve_sizes", { cancelToken: string }>
   | CommandWithPayload<"get_detail_core", { query: AppManagerDetailQueryDto }>
+  | CommandWithPayload<"list_related_roots", { appId: string }>
   | CommandWithPayload<"get_detail_heavy", { input: AppManagerResidueScanInputDto }>
+  | CommandWithPayload<"scan_path", { input: AppManagerScanPathInputDto }>
   | CommandWithPayload<"cleanup", { input: AppManagerCleanupInputDto }>
+  | CommandWithPayload<"annotate_residue_item", { input: AppManagerAnnotateResidueInputDto }>
   | CommandWithPayload<"export_scan_result", { input: AppManagerExportScanInputDto }>
+  | CommandNoPayload<"list_exports">
+  | CommandWithPayload<"clear_exports", { input: AppManagerClearExportsInputDto }>
   | CommandNoPayload<"refresh_index">
+  | CommandNoPayload<"refresh_index_async">
   | CommandWithPayload<"set_startup", { input: AppManagerStartupUpdateInputDto }>
   | CommandWithPayload<"uninstall", { input: AppManagerUninstallInputDto }>
   | CommandWithPayload<"open_uninstall_help", { appId: string }>
   | CommandWithPayload<"open_permission_help", { appId: string }>
-  | CommandWithPayload<"reveal_path", { path: string }>;
+  | CommandWithPayload<"reveal_path", { path: string }>
+  | CommandWithPayload<"reveal_startup_entry", { appId: string }>
+  | CommandNoPayload<"scan_ghost_startup_items">
+  | CommandWithPayload<"remove_ghost_startup_item", { label: string }>
+  | CommandNoPayload<"list_auto_backups">
+  | CommandWithPayload<"restore_auto_backup", { backupId: string }>;
 
 export type ClipboardRequestDto =
   | CommandWithPayload<"list", { filter?: ClipboardFilterDto }>
+  | CommandWithPayload<"search", { query: string; limit?: number }>
+  | CommandWithPayload<"get_item", { id: string }>
   | CommandWithPayload<"pin", { id: string; pinned: boolean }>
+  | CommandWithPayload<"reorder_pinned", { itemIds: Array<string> }>
   | CommandWithPayload<"delete", { id: string }>
   | CommandNoPayload<"clear_all">
   | CommandWithPayload<"save_text", { text: string }>
   | CommandWithPayload<"window_set_mode", { compact: boolean }>
   | CommandWithPayload<"window_apply_mode", { compact: boolean }>
   | CommandWithPayload<"copy_back", { id: string }>
+  | CommandWithPayload<"copy_transformed", { id: string; transform: ClipboardTextTransformDto }>
+  | CommandWithPayload<"copy_and_paste", { id: string }>
   | CommandWithPayload<"copy_file_paths", { id: string }>
   | CommandWithPayload<"copy_image_back", { id: string }>
-  | CommandWithPayload<"export_image", { id: string }>;
+  | CommandWithPayload<"export_image", { id: string }>
+  | CommandNoPayload<"storage_stats">
+  | CommandNoPayload<"size_summary">
+  | CommandNoPayload<"repair">
+  | CommandWithPayload<"migrate_storage", { newDir: string }>
+  | CommandWithPayload<"set_paused", { paused: boolean; durationMinutes?: number }>
+  | CommandNoPayload<"watcher_status">;
 
 export type LauncherRequestDto =
   | CommandWithPayload<"search", { query: string; limit?: number }>
-  | CommandWithPayload<"execute", { action: LauncherActionDto }>
+  | CommandWithPayload<"execute", { action: LauncherActionDto; itemId?: string }>
   | CommandNoPayload<"get_search_settings">
   | CommandWithPayload<"update_search_settings", { input: LauncherUpdateSearchSettingsInputDto }>
   | CommandNoPayload<"get_status">
   | CommandNoPayload<"rebuild_index">
-  | CommandNoPayload<"reset_search_settings">;
+  | CommandNoPayload<"reset_search_settings">
+  | CommandNoPayload<"undo">;
 
 export type LocaleRequestDto =
   | CommandNoPayload<"get">
-  | CommandWithPayload<"set", { preference: string }>;
+  | CommandWithPayload<"set", { preference: string }>
+  | CommandWithPayload<"format_bytes", { bytes: number }>
+  | CommandWithPayload<"format_number", { value: number }>
+  | CommandWithPayload<"export", { locale: string; outputPath?: string }>
+  | CommandWithPayload<"import", { locale: string; namespace?: string; filePath: string }>
+  | CommandWithPayload<"stats", { locale: string }>
+  | CommandNoPayload<"list_stats">;
 
 export type LoggingRequestDto =
   | CommandWithPayload<"client_log", { level: string; scope: string; message: string; metadata?: JsonValue; requestId?: string }>
   | CommandWithPayload<"query", { query?: LogQueryDto }>
   | CommandNoPayload<"get_config">
   | CommandWithPayload<"update_config", { config: LogConfigDto }>
-  | CommandWithPayload<"export_jsonl", { query?: LogQueryDto; outputPath?: string }>;
+  | CommandWithPayload<"export_jsonl", { query?: LogQueryDto; outputPath?: string }>
+  | CommandWithPayload<"export_cbor", { query?: LogQueryDto; outputPath?: string }>
+  | CommandWithPayload<"convert_cbor_to_jsonl", { cborPath: string; outputPath: string }>;
 
 export type ScreenshotRequestDto =
   | CommandWithPayload<"start_session", { input: ScreenshotStartInputDto }>
@@ -830,6 +1275,10 @@ export type ScreenshotRequestDto =
 
 export type SettingsRequestDto =
   | CommandNoPayload<"get">
-  | CommandWithPayload<"update", { input: SettingsUpdateInputDto }>;
+  | CommandWithPayload<"update", { input: SettingsUpdateInputDto }>
+  | CommandWithPayload<"export_all", { outputPath?: string }>
+  | CommandWithPayload<"import_all", { inputPath: string }>
+  | CommandNoPayload<"shortcuts_status">
+  | CommandNoPayload<"db_maintenance">;
 
 // <generated-contracts:end>
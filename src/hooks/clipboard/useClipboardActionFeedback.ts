@@ -1,7 +1,7 @@
 import { useCallback, useState } from "react";
 
 import type { ClipboardItem } from "@/components/clipboard/types";
-import { runRecoverable } from "@/services/recoverable";
+import { describeRecoverableFailure, runRecoverable } from "@/services/recoverable";
 
 type Translate = (key: string, options?: Record<string, unknown>) => string;
 
@@ -37,6 +37,7 @@ function resolveCopyBackErrorMessage(item: ClipboardItem, message: string, t: Tr
 interface UseClipboardActionFeedbackOptions {
   t: Translate;
   copyBack: (id: string) => Promise<void>;
+  copyAndPaste: (id: string) => Promise<void>;
   copyFilePathsBack: (id: string) => Promise<void>;
   copyImageBack: (id: string) => Promise<void>;
   exportImage: (id: string) => Promise<{ saved: boolean; path: string | null }>;
@@ -55,6 +56,7 @@ interface UseClipboardActionFeedbackResult {
   setClearAllError: (message: string | null) => void;
   isClearingAll: boolean;
   handleCopyBack: (item: ClipboardItem) => Promise<void>;
+  handleCopyAndPaste: (item: ClipboardItem) => Promise<void>;
   handleCopyFilePaths: (item: ClipboardItem) => Promise<void>;
   handleCopyPreviewImage: (id: string) => Promise<void>;
   handleDownloadPreviewImage: (id: string) => Promise<void>;
@@ -64,7 +66,7 @@ interface UseClipboardActionFeedbackResult {
 export function useClipboardActionFeedback(
   options: UseClipboardActionFeedbackOptions,
 ): UseClipboardActionFeedbackResult {
-  const { t, copyBack, copyFilePathsBack, copyImageBack, exportImage, clearAllItems } = options;
+  const { t, copyBack, copyAndPaste, copyFilePathsBack, copyImageBack, exportImage, clearAllItems } = options;
   const [actionFeedback, setActionFeedback] = useState<ClipboardActionFeedback | null>(null);
   const [clearAllError, setClearAllError] = useState<string | null>(null);
   const [isClearingAll, setIsClearingAll] = useState(false);
@@ -79,7 +81,7 @@ export function useClipboardActionFeedback(
       });
 
       if (!result.ok) {
-        setActionFeedback(toActionFeedback("error", result.message));
+        setActionFeedback(toActionFeedback("error", describeRecoverableFailure(result)));
         return;
       }
 
@@ -98,7 +100,7 @@ export function useClipboardActionFeedback(
       });
 
       if (!result.ok) {
-        setActionFeedback(toActionFeedback("error", result.message));
+        setActionFeedback(toActionFeedback("error", describeRecoverableFailure(result)));
         return;
       }
 
@@ -128,7 +130,7 @@ export function useClipboardActionFeedback(
       );
 
       if (!result.ok) {
-        setActionFeedback(toActionFeedback("error", resolveCopyBackErrorMessage(item, result.message, t)));
+        setActionFeedback(toActionFeedback("error", resolveCopyBackErrorMessage(item, describeRecoverableFailure(result), t)));
         return;
       }
 
@@ -137,6 +139,22 @@ export function useClipboardActionFeedback(
     [copyBack, copyImageBack, t],
   );
 
+  const handleCopyAndPaste = useCallback(
+    async (item: ClipboardItem) => {
+      const result = await runRecoverable(() => copyAndPaste(item.id), {
+        scope: "clipboard-panel",
+        action: "copy_and_paste",
+        message: "copy and paste failed",
+        metadata: { id: item.id, itemType: item.itemType },
+      });
+
+      if (!result.ok) {
+        setActionFeedback(toActionFeedback("error", resolveCopyBackErrorMessage(item, describeRecoverableFailure(result), t)));
+      }
+    },
+    [copyAndPaste, t],
+  );
+
   const handleCopyFilePaths = useCallback(
     async (item: ClipboardItem) => {
       const result = await runRecoverable(
@@ -153,7 +171,7 @@ export function useClipboardActionFeedback(
       );
 
       if (!result.ok) {
-        setActionFeedback(toActionFeedback("error", result.message));
+        setActionFeedback(toActionFeedback("error", describeRecoverableFailure(result)));
         return;
       }
 
@@ -197,6 +215,7 @@ export function useClipboardActionFeedback(
     setClearAllError,
     isClearingAll,
     handleCopyBack,
+    handleCopyAndPaste,
     handleCopyFilePaths,
     handleCopyPreviewImage,
     handleDownloadPreviewImage,
@@ -1,5 +1,7 @@
 import { DEFAULT_LOCALE_PREFERENCE, FALLBACK_LOCALE, LOCALE_STORAGE_KEY } from "@/i18n/constants";
-import type { AppLocale, LocalePreference } from "@/i18n/types";
+import type { AppLocale, LocalePreference, TextDirection } from "@/i18n/types";
+
+const RTL_LANGUAGES = new Set(["ar", "he", "fa", "ur"]);
 
 function normalizeLocale(value: string | null | undefined): AppLocale | null {
   if (!value) {
@@ -97,11 +99,17 @@ export function setStoredLocalePreference(preference: LocalePreference) {
   } catch {}
 }
 
-export function applyLocaleToDocument(locale: AppLocale) {
+export function resolveTextDirection(locale: string): TextDirection {
+  const language = locale.trim().split(/[-_]/)[0]?.toLowerCase() ?? "";
+  return RTL_LANGUAGES.has(language) ? "rtl" : "ltr";
+}
+
+export function applyLocaleToDocument(locale: AppLocale, direction: TextDirection = resolveTextDirection(locale)) {
   if (typeof document === "undefined") {
     return;
   }
 
   document.documentElement.lang = locale;
+  document.documentElement.dir = direction;
   document.documentElement.setAttribute("data-locale", locale);
 }
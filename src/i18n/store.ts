@@ -2,8 +2,8 @@ import { create } from "zustand";
 
 import type { SettingsDto } from "@/contracts";
 import i18n from "@/i18n";
-import { resolveLocale } from "@/i18n/runtime";
-import type { AppLocale, LocalePreference, LocaleState } from "@/i18n/types";
+import { resolveLocale, resolveTextDirection } from "@/i18n/runtime";
+import type { AppLocale, LocalePreference, LocaleState, TextDirection } from "@/i18n/types";
 import {
   type BackendLocaleState,
   fetchBackendLocaleState,
@@ -26,31 +26,41 @@ type LocaleStore = LocaleState & LocaleActions;
 const BACKEND_SYNC_MIN_INTERVAL_MS = 2_000;
 let lastBackendSyncAt = 0;
 
-function applyLocaleToDocument(locale: AppLocale) {
+function applyLocaleToDocument(locale: AppLocale, direction: TextDirection) {
   if (typeof document === "undefined") {
     return;
   }
 
   document.documentElement.lang = locale;
+  document.documentElement.dir = direction;
   document.documentElement.setAttribute("data-locale", locale);
 }
 
-function applyLanguage(locale: AppLocale) {
-  applyLocaleToDocument(locale);
+function applyLanguage(locale: AppLocale, direction: TextDirection) {
+  applyLocaleToDocument(locale, direction);
   void i18n.changeLanguage(locale);
 }
 
+function normalizeTextDirection(value: string | undefined, locale: AppLocale): TextDirection {
+  if (value === "rtl" || value === "ltr") {
+    return value;
+  }
+  return resolveTextDirection(locale);
+}
+
 function applyLocaleState(
-  set: (partial: Pick<LocaleState, "preference" | "resolved" | "initialized">) => void,
+  set: (partial: Pick<LocaleState, "preference" | "resolved" | "textDirection" | "initialized">) => void,
   preference: LocalePreference,
   resolved: AppLocale,
+  textDirection: TextDirection = resolveTextDirection(resolved),
 ) {
   set({
     preference,
     resolved,
+    textDirection,
     initialized: true,
   });
-  applyLanguage(resolved);
+  applyLanguage(resolved, textDirection);
 }
 
 function normalizeLocalePreference(preference: string | undefined): LocalePreference {
@@ -64,6 +74,7 @@ function normalizeLocalePreference(preference: string | undefined): LocalePrefer
 export const useLocaleStore = create<LocaleStore>((set, get) => ({
   preference: "system",
   resolved: resolveLocale("system"),
+  textDirection: resolveTextDirection(resolveLocale("system")),
   initialized: false,
   async init() {
     if (get().initialized) {
@@ -119,13 +130,19 @@ export const useLocaleStore = create<LocaleStore>((set, get) => ({
   hydrateFromBackendState(state) {
     const preference = normalizeLocalePreference(state.preference);
     const resolved = resolveLocale(state.resolved);
+    const textDirection = normalizeTextDirection(state.textDirection, resolved);
     const current = get();
-    if (current.initialized && current.preference === preference && current.resolved === resolved) {
+    if (
+      current.initialized &&
+      current.preference === preference &&
+      current.resolved === resolved &&
+      current.textDirection === textDirection
+    ) {
       lastBackendSyncAt = Date.now();
       return;
     }
     lastBackendSyncAt = Date.now();
-    applyLocaleState(set, preference, resolved);
+    applyLocaleState(set, preference, resolved, textDirection);
   },
   async setPreference(preference) {
     const optimisticResolved = resolveLocale(preference);
@@ -138,7 +155,12 @@ export const useLocaleStore = create<LocaleStore>((set, get) => ({
         return;
       }
 
-      applyLocaleState(set, backendState.preference, backendState.resolved);
+      applyLocaleState(
+        set,
+        backendState.preference,
+        backendState.resolved,
+        normalizeTextDirection(backendState.textDirection, backendState.resolved),
+      );
     } catch (error) {
       logWarn("locale", "backend_set_failed", {
         preference,
@@ -2,8 +2,11 @@ export type AppLocale = string;
 
 export type LocalePreference = "system" | AppLocale;
 
+export type TextDirection = "ltr" | "rtl";
+
 export interface LocaleState {
   preference: LocalePreference;
   resolved: AppLocale;
+  textDirection: TextDirection;
   initialized: boolean;
 }
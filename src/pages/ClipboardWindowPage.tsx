@@ -3,10 +3,12 @@ import { currentMonitor, getCurrentWindow } from "@tauri-apps/api/window";
 import { useCallback, useEffect, useMemo, useRef, useState, type MouseEvent } from "react";
 
 import ClipboardPanel from "@/components/clipboard/ClipboardPanelContainer";
+import type { ClipboardWindowContentFilter } from "@/contracts";
 import { useAsyncEffect } from "@/hooks/useAsyncEffect";
 import { useWindowLayoutPersistence } from "@/hooks/window/useWindowLayoutPersistence";
 import type { StoredWindowLayout, WindowLayoutBounds } from "@/hooks/window/window-layout.types";
 import { useLocaleStore } from "@/i18n/store";
+import { useSettingsStore } from "@/stores/settings.store";
 import { clipboardWindowApplyMode, clipboardWindowSetMode } from "@/services/clipboard.service";
 import { runRecoverable } from "@/services/recoverable";
 import { addSafeResolveUnlisten, listenWithCleanup } from "@/services/tauri-event";
@@ -23,6 +25,7 @@ interface ClipboardWindowOpenedPayload {
 
 interface ClipboardWindowModeAppliedPayload {
   compact: boolean;
+  contentFilter: ClipboardWindowContentFilter;
   appliedWidthLogical: number;
   appliedHeightLogical: number;
   scaleFactor: number;
@@ -33,9 +36,16 @@ export default function ClipboardWindowPage() {
   const modeResizeTimerRef = useRef<number | null>(null);
   const [compactMode, setCompactMode] = useState(false);
   const [alwaysOnTop, setAlwaysOnTop] = useState(false);
+  const [appliedMode, setAppliedMode] = useState<ClipboardWindowModeAppliedPayload | null>(null);
   const appWindow = useMemo(() => getCurrentWindow(), []);
   const syncLocaleFromBackend = useLocaleStore((state) => state.syncFromBackend);
+  const fetchClipboardSettings = useSettingsStore((state) => state.fetchClipboardSettings);
+  const configuredContentFilter = useSettingsStore((state) =>
+    compactMode ? state.clipboardSettings?.compactContentFilter : state.clipboardSettings?.regularContentFilter,
+  );
   const isCompact = compactMode;
+  const contentFilter =
+    (appliedMode?.compact === isCompact ? appliedMode.contentFilter : configuredContentFilter) ?? "all";
   const enabled = appWindow.label === CLIPBOARD_WINDOW_LABEL;
 
   const resolveScaleFactor = useCallback(async () => {
@@ -193,6 +203,7 @@ export default function ClipboardWindowPage() {
       }
 
       const payload = modeResult.data;
+      setAppliedMode(payload);
       const widthDelta = Math.abs(payload.appliedWidthLogical - targetWidthLogical);
       if (widthDelta > 1) {
         console.warn("[clipboard-window] backend resize mismatch, using fallback", {
@@ -260,6 +271,7 @@ export default function ClipboardWindowPage() {
         }
 
         void syncLocaleFromBackend();
+        void fetchClipboardSettings();
       });
       addSafeResolveUnlisten(stack, unlistenFocusChangedPromise, "clipboard-window:focus-changed", "focus-changed");
 
@@ -300,7 +312,7 @@ export default function ClipboardWindowPage() {
 
       searchInputRef.current?.focus();
     },
-    [appWindow, applyModeSize, enabled, syncLocaleFromBackend],
+    [appWindow, applyModeSize, enabled, fetchClipboardSettings, syncLocaleFromBackend],
     {
       scope: "clipboard-window",
       onError: (error) => {
@@ -342,6 +354,7 @@ export default function ClipboardWindowPage() {
           className="h-full w-full flex-1 rounded-none border-none bg-transparent p-0"
           searchInputRef={searchInputRef}
           compactMode={isCompact}
+          contentFilter={contentFilter}
           onCompactModeToggle={handleCompactModeToggle}
           alwaysOnTop={alwaysOnTop}
           onAlwaysOnTopToggle={handleAlwaysOnTopToggle}
@@ -8,10 +8,9 @@ import type { PaletteItem } from "@/components/palette/types";
 import { Button, Input } from "@/components/ui";
 import { cx } from "@/components/ui/utils";
 import { useAsyncEffect } from "@/hooks/useAsyncEffect";
-import { useWindowFocusAutoHide } from "@/hooks/window/useWindowFocusAutoHide";
 import { useWindowLayoutPersistence } from "@/hooks/window/useWindowLayoutPersistence";
 import { useLocaleStore } from "@/i18n/store";
-import { listenWithCleanup } from "@/services/tauri-event";
+import { addSafeResolveUnlisten, listenWithCleanup } from "@/services/tauri-event";
 
 import { renderHighlightedText } from "./launcher/highlight";
 import { useLauncherWindowState } from "./launcher/useLauncherWindowState";
@@ -56,7 +55,6 @@ export default function LauncherWindowPage() {
   const [searchSeed, setSearchSeed] = useState(0);
   const [hasSearchedOnce, setHasSearchedOnce] = useState(false);
   const [alwaysOnTop, setAlwaysOnTop] = useState(false);
-  const alwaysOnTopRef = useRef(false);
   const syncLocaleFromBackend = useLocaleStore((state) => state.syncFromBackend);
 
   const {
@@ -76,7 +74,6 @@ export default function LauncherWindowPage() {
     sections,
     flatItems,
     selectedItem,
-    highlightContext,
     updateQuery,
     updateActiveTab,
     cycleActiveTab,
@@ -128,23 +125,10 @@ export default function LauncherWindowPage() {
     resolveBounds: resolveLayoutBounds,
   });
 
-  const shouldSkipHide = useCallback(() => alwaysOnTopRef.current, []);
-  const handleWindowFocus = useCallback(() => {
-    void syncLocaleFromBackend();
-  }, [syncLocaleFromBackend]);
-
-  const { cancelScheduledHide } = useWindowFocusAutoHide({
-    appWindow,
-    enabled,
-    shouldSkipHide,
-    onFocus: handleWindowFocus,
-  });
-
   const syncAlwaysOnTopState = useCallback(() => {
     void appWindow
       .isAlwaysOnTop()
       .then((result) => {
-        alwaysOnTopRef.current = result;
         setAlwaysOnTop(result);
       })
       .catch((caughtError: unknown) => {
@@ -157,16 +141,12 @@ export default function LauncherWindowPage() {
     void appWindow
       .setAlwaysOnTop(next)
       .then(() => {
-        if (next) {
-          cancelScheduledHide();
-        }
-        alwaysOnTopRef.current = next;
         setAlwaysOnTop(next);
       })
       .catch((caughtError: unknown) => {
         console.warn("[launcher-window] toggle always-on-top failed", { next, error: caughtError });
       });
-  }, [alwaysOnTop, appWindow, cancelScheduledHide]);
+  }, [alwaysOnTop, appWindow]);
 
   useEffect(() => {
     if (!enabled) {
@@ -176,10 +156,6 @@ export default function LauncherWindowPage() {
     syncAlwaysOnTopState();
   }, [enabled, syncAlwaysOnTopState]);
 
-  useEffect(() => {
-    alwaysOnTopRef.current = alwaysOnTop;
-  }, [alwaysOnTop]);
-
   useEffect(() => {
     if (!selectedItem?.id) {
       return;
@@ -225,11 +201,18 @@ export default function LauncherWindowPage() {
         return;
       }
 
+      // Blur auto-hide is handled by the shell (see the windowAutoHide settings).
+      const unlistenFocusPromise = appWindow.onFocusChanged(({ payload: focused }) => {
+        if (focused) {
+          void syncLocaleFromBackend();
+        }
+      });
+      addSafeResolveUnlisten(stack, unlistenFocusPromise, "launcher-window:focus-changed", "focus-changed");
+
       listenWithCleanup(
         stack,
         "rtool://launcher/opened",
         () => {
-          cancelScheduledHide();
           syncAlwaysOnTopState();
           setOpenCycle((value) => value + 1);
           setSearchSeed((value) => value + 1);
@@ -303,7 +286,7 @@ export default function LauncherWindowPage() {
       }, "remove-keydown-listener");
     },
     [
-      cancelScheduledHide,
+      appWindow,
       cycleActiveTab,
       enabled,
       executeVisibleSelection,
@@ -313,6 +296,7 @@ export default function LauncherWindowPage() {
       reset,
       resetViewState,
       syncAlwaysOnTopState,
+      syncLocaleFromBackend,
     ],
     {
       scope: "launcher-window",
@@ -491,7 +475,7 @@ export default function LauncherWindowPage() {
                         >
                           <LauncherItemIcon item={entry.item} />
                           <div className="w-full truncate whitespace-nowrap text-xs font-medium leading-4 text-text-primary">
-                            {renderHighlightedText(entry.item.title, highlightContext)}
+                            {renderHighlightedText(entry.item.title, entry.item.titleMatchRanges)}
                           </div>
                         </Button>
                       );
@@ -232,7 +232,7 @@ export default function LogCenterPage() {
             </div>
 
             {!filtersCollapsed ? (
-              <div className="grid grid-cols-1 gap-2 sm:grid-cols-2 lg:grid-cols-3 xl:grid-cols-6">
+              <div className="grid grid-cols-1 gap-2 sm:grid-cols-2 lg:grid-cols-3 xl:grid-cols-7">
                 <div className="space-y-1">
                   <div className="text-xs text-text-muted">{t("filters.scope")}</div>
                   <Input
@@ -260,6 +260,15 @@ export default function LogCenterPage() {
                   />
                 </div>
 
+                <div className="space-y-1">
+                  <div className="text-xs text-text-muted">{t("filters.errorContextKey")}</div>
+                  <Input
+                    value={filters.errorContextKey}
+                    onChange={(event) => setFilters({ errorContextKey: event.currentTarget.value })}
+                    placeholder={t("filters.errorContextKeyPlaceholder")}
+                  />
+                </div>
+
                 <div className="space-y-1">
                   <div className="text-xs text-text-muted">{t("filters.keyword")}</div>
                   <Input
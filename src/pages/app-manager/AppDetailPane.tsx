@@ -99,7 +99,7 @@ export interface AppDetailActions {
   onSelectAllResidues: (itemIds: string[]) => void;
   onToggleIncludeMain: (checked: boolean) => void;
   onSetDeleteMode: (mode: AppManagerCleanupDeleteMode) => void;
-  onCleanupNow: () => void | Promise<void>;
+  onCleanupNow: (confirmedItemIds?: string[]) => void | Promise<void>;
   onRetryFailed: () => void | Promise<void>;
   onRevealPath: (path: string) => void;
   onScanAgain: () => void | Promise<void>;
@@ -241,13 +241,16 @@ interface ResidueCardProps {
   item: ResidueItem;
   checked: boolean;
   disabled: boolean;
+  safeToClean: boolean;
+  safeToCleanLabel: string;
   revealPathButtonClass: string;
   onToggleResidue: (itemId: string, checked: boolean) => void;
   onRevealPath: (path: string) => void;
 }
 
 function ResidueCard(props: ResidueCardProps): ReactElement {
-  const { item, checked, disabled, revealPathButtonClass, onToggleResidue, onRevealPath } = props;
+  const { item, checked, disabled, safeToClean, safeToCleanLabel, revealPathButtonClass, onToggleResidue, onRevealPath } =
+    props;
   const cardClassName = `rounded-lg border px-3 py-2.5 transition-colors ${getSelectableCardClass(checked, disabled)}`;
 
   const toggle = (): void => {
@@ -280,7 +283,17 @@ function ResidueCard(props: ResidueCardProps): ReactElement {
           >
             {toBreadcrumb(item.path)}
           </button>
-          <div className="mt-1 text-[11px] text-text-secondary">{`${item.groupLabel} · ${item.kind}`}</div>
+          <div className="mt-1 flex items-center gap-1.5 text-[11px] text-text-secondary">
+            <span className="truncate">{`${item.groupLabel} · ${item.kind}`}</span>
+            {safeToClean ? (
+              <span className="shrink-0 rounded border border-success/35 bg-success/10 px-1 text-success">
+                {safeToCleanLabel}
+              </span>
+            ) : null}
+          </div>
+          {item.annotation ? (
+            <div className="mt-1 truncate text-[11px] text-text-muted">{item.annotation}</div>
+          ) : null}
         </div>
         <span className="shrink-0 pt-0.5 text-sm text-text-primary">{formatBytes(item.sizeBytes)}</span>
       </div>
@@ -352,6 +365,9 @@ function AppDetailPaneImpl(props: AppDetailPaneProps): ReactElement {
   const selectedResidueCount = isHeavyPending ? 0 : selectedResidueIds.length;
   const residueCount = isHeavyPending ? 0 : flatResidues.length;
   const selectedResidueIdSet = new Set(selectedResidueIds);
+  const cleanableRootPaths = new Set(
+    (coreDetail?.relatedRoots ?? []).filter((root) => root.cleanable).map((root) => root.path),
+  );
   const selectableResidueIds = flatResidues
     .filter((item) => !(item.readonly && item.readonlyReasonCode === "managed_by_policy"))
     .map((item) => item.itemId);
@@ -524,7 +540,26 @@ function AppDetailPaneImpl(props: AppDetailPaneProps): ReactElement {
               >
                 {t("result.retryFailed")}
               </Button>
-              <Button size="xs" variant="danger" disabled={cleanupLoading} onClick={() => void onCleanupNow()}>
+              <Button
+                size="xs"
+                variant="danger"
+                disabled={cleanupLoading}
+                onClick={() => {
+                  const riskyIds =
+                    selectedDeleteMode === "permanent"
+                      ? flatResidues
+                          .filter(
+                            (item) =>
+                              selectedResidueIdSet.has(item.itemId) &&
+                              (item.scope === "system" || item.riskLevel === "high"),
+                          )
+                          .map((item) => item.itemId)
+                      : [];
+                  const confirmed =
+                    riskyIds.length > 0 && window.confirm(t("cleanup.permanentRiskConfirm", { count: riskyIds.length }));
+                  void onCleanupNow(confirmed ? riskyIds : []);
+                }}
+              >
                 {cleanupLoading ? t("cleanup.cleaning") : t("cleanup.cleanNow")}
               </Button>
             </div>
@@ -689,6 +724,8 @@ function AppDetailPaneImpl(props: AppDetailPaneProps): ReactElement {
                       item={item}
                       checked={checked}
                       disabled={disabled}
+                      safeToClean={cleanableRootPaths.has(item.path)}
+                      safeToCleanLabel={t("cleanup.safeToClean")}
                       revealPathButtonClass={revealPathButtonClass}
                       onToggleResidue={onToggleResidue}
                       onRevealPath={onRevealPath}
@@ -58,6 +58,7 @@ export function useAppManagerActions(options: UseAppManagerActionsOptions) {
         selectedItemIds: string[];
         includeMainApp?: boolean;
         deleteMode: AppManagerCleanupDeleteMode;
+        confirmedItemIds?: string[];
       },
     ) => {
       const includeMainApp = payload.includeMainApp ?? true;
@@ -81,6 +82,7 @@ export function useAppManagerActions(options: UseAppManagerActionsOptions) {
           deleteMode: payload.deleteMode,
           skipOnError: true,
           confirmedFingerprint: includeMainApp ? app.fingerprint : undefined,
+          confirmedItemIds: payload.confirmedItemIds,
         });
 
         updateAppUiState(app.id, (state) => ({
@@ -108,18 +110,22 @@ export function useAppManagerActions(options: UseAppManagerActionsOptions) {
     [getCurrentKeyword, hasApp, loadDetailCore, loadDetailHeavy, loadListFirstPage, setAppUiStatePatch, updateAppUiState],
   );
 
-  const cleanupNow = useCallback(async () => {
-    if (!selectedApp) {
-      return;
-    }
+  const cleanupNow = useCallback(
+    async (confirmedItemIds: string[] = []) => {
+      if (!selectedApp) {
+        return;
+      }
 
-    const state = getAppUiState(selectedApp.id);
-    await runCleanup(selectedApp, {
-      selectedItemIds: state.selectedResidueIds,
-      includeMainApp: state.includeMain,
-      deleteMode: state.deleteMode,
-    });
-  }, [getAppUiState, runCleanup, selectedApp]);
+      const state = getAppUiState(selectedApp.id);
+      await runCleanup(selectedApp, {
+        selectedItemIds: state.selectedResidueIds,
+        includeMainApp: state.includeMain,
+        deleteMode: state.deleteMode,
+        confirmedItemIds,
+      });
+    },
+    [getAppUiState, runCleanup, selectedApp],
+  );
 
   const retryFailed = useCallback(async () => {
     if (!selectedApp) {
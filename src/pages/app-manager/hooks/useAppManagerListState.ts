@@ -9,7 +9,7 @@ import { useLatestRef } from "@/hooks/useLatestRef";
 import { useAsyncEffect } from "@/hooks/useAsyncEffect";
 import {
   appManagerList,
-  appManagerRefreshIndex,
+  appManagerRefreshIndexAsync,
 } from "@/services/app-manager.service";
 import { listenWithCleanup } from "@/services/tauri-event";
 
@@ -118,14 +118,14 @@ export function useAppManagerListState(options: UseAppManagerListStateOptions =
   const refreshList = useCallback(async () => {
     setRefreshing(true);
     try {
-      await appManagerRefreshIndex();
-      await loadListFirstPage(keywordRef.current);
+      const meta = await appManagerRefreshIndexAsync();
+      setIndexState(meta.indexState);
     } catch (error) {
       setListError(error instanceof Error ? error.message : String(error));
     } finally {
       setRefreshing(false);
     }
-  }, [keywordRef, loadListFirstPage]);
+  }, []);
 
   const selectApp = useCallback((appId: string) => {
     setSelectedAppId(appId);
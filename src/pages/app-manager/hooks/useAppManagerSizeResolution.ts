@@ -1,7 +1,8 @@
-import { type Dispatch, type SetStateAction, useCallback, useRef, useState } from "react";
+import { type Dispatch, type SetStateAction, useCallback, useEffect, useRef, useState } from "react";
 
 import { useLatestRef } from "@/hooks/useLatestRef";
-import { appManagerResolveSizes } from "@/services/app-manager.service";
+import { appManagerCancelResolveSizes, appManagerResolveSizes } from "@/services/app-manager.service";
+import { createRequestId } from "@/services/invoke";
 import type { ManagedApp } from "@/components/app-manager/types";
 
 import { delay, initialSizeState, retainById, type AppSizeState } from "./state";
@@ -28,6 +29,18 @@ export function useAppManagerSizeResolution(options: UseAppManagerSizeResolution
   const sizeQueueRef = useRef<string[]>([]);
   const sizeQueuedSetRef = useRef(new Set<string>());
   const sizeFlushingRef = useRef(false);
+  const activeCancelTokenRef = useRef<string | null>(null);
+
+  const cancelActiveSizeResolution = useCallback(() => {
+    const cancelToken = activeCancelTokenRef.current;
+    if (!cancelToken) {
+      return;
+    }
+    activeCancelTokenRef.current = null;
+    void appManagerCancelResolveSizes(cancelToken).catch(() => undefined);
+  }, []);
+
+  useEffect(() => cancelActiveSizeResolution, [cancelActiveSizeResolution]);
 
   const updateSizeState = useCallback((appIds: string[], state: AppSizeState) => {
     setSizeStateByAppId((previous) => {
@@ -67,8 +80,10 @@ export function useAppManagerSizeResolution(options: UseAppManagerSizeResolution
         }
 
         updateSizeState(candidates, "resolving");
+        const cancelToken = createRequestId();
+        activeCancelTokenRef.current = cancelToken;
         try {
-          const resolved = await appManagerResolveSizes({ appIds: candidates });
+          const resolved = await appManagerResolveSizes({ appIds: candidates, cancelToken, parallelism: null });
           const resolvedById = new Map(resolved.items.map((item) => [item.appId, item]));
 
           setItems((previous) =>
@@ -103,6 +118,10 @@ export function useAppManagerSizeResolution(options: UseAppManagerSizeResolution
           const message = error instanceof Error ? error.message : String(error);
           setListError(message);
           updateSizeState(candidates, "pending");
+        } finally {
+          if (activeCancelTokenRef.current === cancelToken) {
+            activeCancelTokenRef.current = null;
+          }
         }
 
         await delay(12);
@@ -151,13 +170,14 @@ export function useAppManagerSizeResolution(options: UseAppManagerSizeResolution
       });
 
       if (replace) {
+        cancelActiveSizeResolution();
         sizeQueueRef.current = [];
         sizeQueuedSetRef.current = new Set<string>();
       }
 
       return { mergedItems, keepIds };
     },
-    [itemsRef, setItems, sizeQueueRef, sizeQueuedSetRef],
+    [cancelActiveSizeResolution, itemsRef, setItems, sizeQueueRef, sizeQueuedSetRef],
   );
 
   return {
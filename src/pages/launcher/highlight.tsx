@@ -1,49 +1,35 @@
 import type { ReactNode } from "react";
 
-export interface HighlightContext {
-  matcher: RegExp | null;
-  tokenSet: Set<string>;
-}
-
-function escapeRegExp(value: string): string {
-  return value.replace(/[.*+?^${}()|[\]\\]/g, "\\$&");
-}
-
-export function createHighlightContext(query: string): HighlightContext {
-  const tokens = query
-    .trim()
-    .split(/\s+/)
-    .map((token) => token.trim())
-    .filter(Boolean);
-
-  if (tokens.length === 0) {
-    return {
-      matcher: null,
-      tokenSet: new Set(),
-    };
-  }
+import type { LauncherMatchRangeDto } from "@/contracts";
 
-  return {
-    matcher: new RegExp(`(${tokens.map(escapeRegExp).join("|")})`, "ig"),
-    tokenSet: new Set(tokens.map((token) => token.toLowerCase())),
-  };
-}
-
-export function renderHighlightedText(text: string, context: HighlightContext): ReactNode {
-  if (!context.matcher || context.tokenSet.size === 0) {
+export function renderHighlightedText(text: string, ranges: LauncherMatchRangeDto[] | undefined): ReactNode {
+  if (!ranges || ranges.length === 0) {
     return text;
   }
 
-  return text.split(context.matcher).map((part, index) => {
-    const lower = part.toLowerCase();
-    if (context.tokenSet.has(lower)) {
-      return (
-        <mark key={`${lower}-${index}`} className="rounded bg-accent-soft px-[1px] font-semibold text-accent">
-          {part}
-        </mark>
-      );
+  // Ranges count Unicode code points, so slice by code point rather than UTF-16 unit.
+  const chars = Array.from(text);
+  const parts: ReactNode[] = [];
+  let cursor = 0;
+  for (const range of ranges) {
+    const start = Math.max(range.start, cursor);
+    const end = Math.min(range.end, chars.length);
+    if (start >= end) {
+      continue;
+    }
+    if (start > cursor) {
+      parts.push(<span key={`text-${cursor}`}>{chars.slice(cursor, start).join("")}</span>);
     }
+    parts.push(
+      <mark key={`match-${start}`} className="rounded bg-accent-soft px-[1px] font-semibold text-accent">
+        {chars.slice(start, end).join("")}
+      </mark>,
+    );
+    cursor = end;
+  }
+  if (cursor < chars.length) {
+    parts.push(<span key={`text-${cursor}`}>{chars.slice(cursor).join("")}</span>);
+  }
 
-    return <span key={`${lower}-${index}`}>{part}</span>;
-  });
+  return parts;
 }
@@ -2,7 +2,6 @@ import { useCallback, useEffect, useMemo, useRef, useState } from "react";
 
 import { selectLauncherItems, useLauncherStore } from "@/stores/launcher.store";
 
-import { createHighlightContext } from "./highlight";
 import {
   buildLauncherSectionLabelMap,
   buildLauncherSections,
@@ -39,7 +38,6 @@ export function useLauncherWindowState({ t }: UseLauncherWindowStateOptions) {
   const selectedEntry = flatItems[selectedVisibleIndex] ?? null;
   const selectedItem = selectedEntry?.item ?? null;
   const activeTabLabel = tabs.find((tab) => tab.key === activeTab)?.label ?? t("launcher.tab.all");
-  const highlightContext = useMemo(() => createHighlightContext(query), [query]);
 
   const flatItemsRef = useRef(flatItems);
   const selectedVisibleIndexRef = useRef(selectedVisibleIndex);
@@ -148,7 +146,6 @@ export function useLauncherWindowState({ t }: UseLauncherWindowStateOptions) {
     flatItems,
     selectedEntry,
     selectedItem,
-    highlightContext,
     updateQuery,
     updateActiveTab,
     cycleActiveTab,
@@ -104,6 +104,139 @@ export default function ClipboardSettingsSection(props: ClipboardSettingsSection
           </p>
         </div>
 
+        <div className="max-w-[560px] space-y-3 rounded-lg border border-border-glass bg-surface-glass-soft px-3 py-3 shadow-inset-soft">
+          <p className="m-0 text-xs text-text-muted">{t("clipboard.captureDesc")}</p>
+          <SwitchField
+            checked={props.state.captureText}
+            label={t("clipboard.captureText")}
+            controlPosition="end"
+            onChange={(event) => props.state.onCaptureTextChange(event.currentTarget.checked)}
+          />
+          <SwitchField
+            checked={props.state.captureImages}
+            label={t("clipboard.captureImages")}
+            controlPosition="end"
+            onChange={(event) => props.state.onCaptureImagesChange(event.currentTarget.checked)}
+          />
+          <SwitchField
+            checked={props.state.captureFiles}
+            label={t("clipboard.captureFiles")}
+            controlPosition="end"
+            onChange={(event) => props.state.onCaptureFilesChange(event.currentTarget.checked)}
+          />
+          <SwitchField
+            checked={props.state.perceptualDeduplicate}
+            label={t("clipboard.perceptualDeduplicate")}
+            description={t("clipboard.perceptualDeduplicateDesc")}
+            controlPosition="end"
+            onChange={(event) => props.state.onPerceptualDeduplicateChange(event.currentTarget.checked)}
+          />
+          <SwitchField
+            checked={props.state.skipImagesWithoutPreview}
+            label={t("clipboard.skipImagesWithoutPreview")}
+            description={t("clipboard.skipImagesWithoutPreviewDesc")}
+            controlPosition="end"
+            onChange={(event) => props.state.onSkipImagesWithoutPreviewChange(event.currentTarget.checked)}
+          />
+          <div className="space-y-1">
+            <label className="text-xs text-text-secondary">{t("clipboard.imagePreviewMaxSize")}</label>
+            <div className="grid gap-2 sm:grid-cols-2">
+              <Input
+                id="clipboard-image-preview-max-width"
+                type="number"
+                min={1}
+                placeholder={t("clipboard.imagePreviewMaxWidth")}
+                aria-label={t("clipboard.imagePreviewMaxWidth")}
+                value={props.state.imagePreviewMaxWidthInput}
+                invalid={props.state.imagePreviewLimitInvalid}
+                onChange={(event) => props.state.onImagePreviewMaxWidthChange(event.currentTarget.value)}
+              />
+              <Input
+                id="clipboard-image-preview-max-height"
+                type="number"
+                min={1}
+                placeholder={t("clipboard.imagePreviewMaxHeight")}
+                aria-label={t("clipboard.imagePreviewMaxHeight")}
+                value={props.state.imagePreviewMaxHeightInput}
+                invalid={props.state.imagePreviewLimitInvalid}
+                onChange={(event) => props.state.onImagePreviewMaxHeightChange(event.currentTarget.value)}
+              />
+            </div>
+            <p className={`m-0 text-xs ${props.state.imagePreviewLimitInvalid ? "text-danger" : "text-text-muted"}`}>
+              {props.state.imagePreviewLimitInvalid
+                ? t("clipboard.imagePreviewLimitInvalid")
+                : t("clipboard.imagePreviewMaxSizeDesc")}
+            </p>
+          </div>
+          <div className="space-y-1">
+            <label htmlFor="clipboard-max-preview-megapixels" className="text-xs text-text-secondary">
+              {t("clipboard.maxPreviewMegapixels")}
+            </label>
+            <Input
+              id="clipboard-max-preview-megapixels"
+              type="number"
+              min={1}
+              value={props.state.maxPreviewMegapixelsInput}
+              invalid={props.state.maxPreviewMegapixelsInvalid}
+              onChange={(event) => props.state.onMaxPreviewMegapixelsChange(event.currentTarget.value)}
+            />
+            <p className={`m-0 text-xs ${props.state.maxPreviewMegapixelsInvalid ? "text-danger" : "text-text-muted"}`}>
+              {props.state.maxPreviewMegapixelsInvalid
+                ? t("clipboard.imagePreviewLimitInvalid")
+                : t("clipboard.maxPreviewMegapixelsDesc")}
+            </p>
+          </div>
+        </div>
+
+        <div className="max-w-[560px] space-y-1 rounded-lg border border-border-glass bg-surface-glass-soft px-3 py-3 shadow-inset-soft">
+          <label htmlFor="clipboard-max-pinned-items" className="text-xs text-text-secondary">
+            {t("clipboard.maxPinnedItems")}
+          </label>
+          <Input
+            id="clipboard-max-pinned-items"
+            type="number"
+            min={props.state.limits.maxPinnedItemsMin}
+            max={props.state.limits.maxPinnedItemsMax}
+            value={props.state.maxPinnedItemsInput}
+            invalid={props.state.maxPinnedItemsInvalid}
+            onChange={(event) => props.state.onMaxPinnedItemsChange(event.currentTarget.value)}
+          />
+          <p className={`m-0 text-xs ${props.state.maxPinnedItemsInvalid ? "text-danger" : "text-text-muted"}`}>
+            {props.state.maxPinnedItemsInvalid
+              ? t("clipboard.maxPinnedItemsInvalid", {
+                  min: props.state.limits.maxPinnedItemsMin,
+                  max: props.state.limits.maxPinnedItemsMax,
+                })
+              : t("clipboard.maxPinnedItemsDesc")}
+          </p>
+        </div>
+
+        <div className="max-w-[560px] space-y-3 rounded-lg border border-border-glass bg-surface-glass-soft px-3 py-3 shadow-inset-soft">
+          <p className="m-0 text-xs text-text-muted">{t("clipboard.windowContentDesc")}</p>
+          <SwitchField
+            checked={props.state.compactTextOnly}
+            label={t("clipboard.compactTextOnly")}
+            controlPosition="end"
+            onChange={(event) => props.state.onCompactTextOnlyChange(event.currentTarget.checked)}
+          />
+          <SwitchField
+            checked={props.state.regularTextOnly}
+            label={t("clipboard.regularTextOnly")}
+            controlPosition="end"
+            onChange={(event) => props.state.onRegularTextOnlyChange(event.currentTarget.checked)}
+          />
+        </div>
+
+        <div className="max-w-[560px] rounded-lg border border-border-glass bg-surface-glass-soft px-3 py-3 shadow-inset-soft">
+          <SwitchField
+            checked={props.state.pasteAfterCopy}
+            label={t("clipboard.pasteAfterCopy")}
+            description={t("clipboard.pasteAfterCopyDesc")}
+            controlPosition="end"
+            onChange={(event) => props.state.onPasteAfterCopyChange(event.currentTarget.checked)}
+          />
+        </div>
+
         <div className="space-y-2">
           <div className="flex flex-wrap items-center gap-2">
             <Button
@@ -1,6 +1,7 @@
 import { useTranslation } from "react-i18next";
 
 import { RadioGroup, Select, SwitchField } from "@/components/ui";
+import { Button } from "@ui/button";
 import type { GeneralSettingsSectionState } from "@/pages/settings/hooks/useSettingsPageState";
 
 interface GeneralSettingsSectionProps {
@@ -90,6 +91,59 @@ export default function GeneralSettingsSection(props: GeneralSettingsSectionProp
               />
             </div>
           </div>
+
+          <div className="grid gap-0 border-t border-border-strong md:grid-cols-[220px_1fr] md:items-center">
+            <div className="border-b border-border-strong px-4 py-3 md:border-b-0 md:border-r">
+              <div className="text-xs font-semibold text-text-primary">{t("general.trayLiveTooltip.label")}</div>
+              <div className="mt-1 text-xs text-text-muted">{t("general.trayLiveTooltip.desc")}</div>
+            </div>
+            <div className="px-4 py-3">
+              <SwitchField
+                checked={props.state.trayLiveTooltip}
+                controlPosition="end"
+                label={t("general.trayLiveTooltip.toggle")}
+                onChange={(event) => props.state.onTrayLiveTooltipChange(event.currentTarget.checked)}
+              />
+            </div>
+          </div>
+
+          <div className="grid gap-0 border-t border-border-strong md:grid-cols-[220px_1fr] md:items-center">
+            <div className="border-b border-border-strong px-4 py-3 md:border-b-0 md:border-r">
+              <div className="text-xs font-semibold text-text-primary">{t("general.windowAutoHide.label")}</div>
+              <div className="mt-1 text-xs text-text-muted">{t("general.windowAutoHide.desc")}</div>
+            </div>
+            <div className="space-y-3 px-4 py-3">
+              <SwitchField
+                checked={props.state.launcherAutoHide}
+                controlPosition="end"
+                label={t("general.windowAutoHide.launcher")}
+                onChange={(event) => props.state.onLauncherAutoHideChange(event.currentTarget.checked)}
+              />
+              <SwitchField
+                checked={props.state.clipboardAutoHide}
+                controlPosition="end"
+                label={t("general.windowAutoHide.clipboard")}
+                onChange={(event) => props.state.onClipboardAutoHideChange(event.currentTarget.checked)}
+              />
+            </div>
+          </div>
+
+          <div className="grid gap-0 border-t border-border-strong md:grid-cols-[220px_1fr] md:items-center">
+            <div className="border-b border-border-strong px-4 py-3 md:border-b-0 md:border-r">
+              <div className="text-xs font-semibold text-text-primary">{t("general.dbMaintenance.label")}</div>
+              <div className="mt-1 text-xs text-text-muted">{t("general.dbMaintenance.desc")}</div>
+            </div>
+            <div className="px-4 py-3">
+              <Button
+                size="default"
+                variant="secondary"
+                disabled={props.state.dbMaintenanceRunning}
+                onClick={props.state.onRunDbMaintenance}
+              >
+                {props.state.dbMaintenanceRunning ? t("general.dbMaintenance.running") : t("general.dbMaintenance.run")}
+              </Button>
+            </div>
+          </div>
         </div>
       </div>
     </section>
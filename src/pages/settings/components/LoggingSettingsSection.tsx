@@ -91,6 +91,25 @@ export default function LoggingSettingsSection(props: LoggingSettingsSectionProp
             </div>
           </div>
 
+          <div className="rounded-lg border border-border-glass bg-surface-glass-soft px-3 py-3 shadow-inset-soft">
+            <div className="space-y-1">
+              <label className="text-xs text-text-secondary" htmlFor="logging-error-detail-level">
+                {t("logging.errorDetailLevel")}
+              </label>
+              <Select
+                id="logging-error-detail-level"
+                value={props.state.errorDetailLevel}
+                options={[
+                  { value: "minimal", label: t("logging.errorDetailLevel.minimal") },
+                  { value: "standard", label: t("logging.errorDetailLevel.standard") },
+                  { value: "verbose", label: t("logging.errorDetailLevel.verbose") },
+                ]}
+                onChange={(event) => props.state.onErrorDetailLevelChange(event.currentTarget.value)}
+              />
+              <p className="m-0 text-xs text-text-muted">{t("logging.errorDetailLevel.desc")}</p>
+            </div>
+          </div>
+
           <div className="rounded-lg border border-border-glass bg-surface-glass-soft px-3 py-3 shadow-inset-soft">
             <SwitchField
               checked={props.state.realtimeEnabled}
@@ -8,7 +8,10 @@ import { useLocaleStore } from "@/i18n/store";
 import type { LocalePreference } from "@/i18n/types";
 import { useLayoutStore } from "@/layouts/layout.store";
 import type { LayoutPreference } from "@/layouts/layout.types";
+import type { ErrorDetailLevel } from "@/services/logging.service";
 import { screenshotGetSettings, screenshotUpdateSettings } from "@/services/screenshot.service";
+import { formatBytes } from "@/pages/app-manager/format";
+import { getSettings, patchSettings, runDbMaintenance } from "@/services/settings.service";
 import { useLoggingStore } from "@/stores/logging.store";
 import { useSettingsStore } from "@/stores/settings.store";
 import { useThemeStore } from "@/theme/store";
@@ -25,6 +28,10 @@ const MAX_MAX_ITEMS = 10_000;
 const MIN_MAX_TOTAL_SIZE_MB = 100;
 const MAX_MAX_TOTAL_SIZE_MB = 10_240;
 const CLIPBOARD_SIZE_MB_PRESETS = ["500", "1024", "5120"];
+const MIN_MAX_PINNED_ITEMS = 1;
+const MAX_MAX_PINNED_ITEMS = 1000;
+const DEFAULT_MAX_PINNED_ITEMS = 100;
+const PIXELS_PER_MEGAPIXEL = 1_000_000;
 const DEFAULT_CLIPBOARD_SIZE_PRESET_MB = "500";
 const MIN_KEEP_DAYS = 1;
 const MAX_KEEP_DAYS = 90;
@@ -42,6 +49,7 @@ const MAX_SCREENSHOT_MAX_TOTAL_SIZE_MB = 20_480;
 const MIN_SCREENSHOT_PIN_MAX_INSTANCES = 1;
 const MAX_SCREENSHOT_PIN_MAX_INSTANCES = 6;
 const CLIPBOARD_SAVE_TOAST_DEDUPE_KEY = "settings-clipboard-save";
+const DB_MAINTENANCE_TOAST_DEDUPE_KEY = "settings-db-maintenance";
 
 type SizeThresholdMode = "preset" | "custom";
 
@@ -74,6 +82,14 @@ export interface GeneralSettingsSectionState {
   onThemePreferenceChange: (value: string) => void;
   transparentWindowBackground: boolean;
   onTransparentWindowBackgroundChange: (checked: boolean) => void;
+  trayLiveTooltip: boolean;
+  onTrayLiveTooltipChange: (checked: boolean) => void;
+  launcherAutoHide: boolean;
+  onLauncherAutoHideChange: (checked: boolean) => void;
+  clipboardAutoHide: boolean;
+  onClipboardAutoHideChange: (checked: boolean) => void;
+  dbMaintenanceRunning: boolean;
+  onRunDbMaintenance: () => void;
 }
 
 export interface ClipboardSettingsSectionState {
@@ -89,12 +105,29 @@ export interface ClipboardSettingsSectionState {
   customSizeInputRef: RefObject<HTMLInputElement | null>;
   maxTotalSizeInvalid: boolean;
   clipboardSizeHelperText: string;
+  pasteAfterCopy: boolean;
+  captureText: boolean;
+  captureImages: boolean;
+  captureFiles: boolean;
+  perceptualDeduplicate: boolean;
+  skipImagesWithoutPreview: boolean;
+  imagePreviewMaxWidthInput: string;
+  imagePreviewMaxHeightInput: string;
+  imagePreviewLimitInvalid: boolean;
+  maxPreviewMegapixelsInput: string;
+  maxPreviewMegapixelsInvalid: boolean;
+  compactTextOnly: boolean;
+  regularTextOnly: boolean;
+  maxPinnedItemsInput: string;
+  maxPinnedItemsInvalid: boolean;
 
   limits: {
     maxItemsMin: number;
     maxItemsMax: number;
     maxTotalSizeMin: number;
     maxTotalSizeMax: number;
+    maxPinnedItemsMin: number;
+    maxPinnedItemsMax: number;
   };
 
   loading: boolean;
@@ -105,6 +138,18 @@ export interface ClipboardSettingsSectionState {
 
   onMaxItemsChange: (value: string) => void;
   onSizeCleanupEnabledChange: (checked: boolean) => void;
+  onPasteAfterCopyChange: (checked: boolean) => void;
+  onCaptureTextChange: (checked: boolean) => void;
+  onCaptureImagesChange: (checked: boolean) => void;
+  onCaptureFilesChange: (checked: boolean) => void;
+  onPerceptualDeduplicateChange: (checked: boolean) => void;
+  onSkipImagesWithoutPreviewChange: (checked: boolean) => void;
+  onImagePreviewMaxWidthChange: (value: string) => void;
+  onImagePreviewMaxHeightChange: (value: string) => void;
+  onMaxPreviewMegapixelsChange: (value: string) => void;
+  onCompactTextOnlyChange: (checked: boolean) => void;
+  onRegularTextOnlyChange: (checked: boolean) => void;
+  onMaxPinnedItemsChange: (value: string) => void;
   onPresetSelect: (presetValue: string) => void;
   onCustomModeSelect: () => void;
   onCustomSizeChange: (value: string) => void;
@@ -150,6 +195,7 @@ export interface LoggingSettingsSectionState {
   highFreqWindowMsInput: string;
   highFreqMaxPerKeyInput: string;
   allowRawView: boolean;
+  errorDetailLevel: string;
 
   keepDaysOptions: SelectOptionInput[];
   windowMsOptions: SelectOptionInput[];
@@ -179,6 +225,7 @@ export interface LoggingSettingsSectionState {
   onHighFreqWindowMsChange: (value: string) => void;
   onHighFreqMaxPerKeyChange: (value: string) => void;
   onAllowRawViewChange: (checked: boolean) => void;
+  onErrorDetailLevelChange: (value: string) => void;
   onSave: () => Promise<void>;
 }
 
@@ -201,6 +248,19 @@ function localeDisplayLabel(locale: string, t: (key: string) => string): string
   return locale;
 }
 
+/** Empty input means "no limit", which the backend stores as `0`/null. */
+function parseOptionalLimit(value: string): number | null {
+  return value.trim() ? parsePositiveInt(value) : 0;
+}
+
+function formatOptionalLimit(value: number | null | undefined): string {
+  return value ? String(value) : "";
+}
+
+function formatMegapixelLimit(pixels: number | null | undefined): string {
+  return pixels ? String(Math.max(1, Math.round(pixels / PIXELS_PER_MEGAPIXEL))) : "";
+}
+
 function buildNumericSelectOptions(presets: string[], currentValue: string): SelectOptionInput[] {
   const values = new Set(presets);
   const normalizedCurrentValue = currentValue.trim();
@@ -255,6 +315,30 @@ export function useSettingsPageState(): UseSettingsPageStateResult {
 
   const [maxItemsInput, setMaxItemsInput] = useState(String(clipboardSettings?.maxItems ?? 1000));
   const [sizeCleanupEnabled, setSizeCleanupEnabled] = useState(clipboardSettings?.sizeCleanupEnabled ?? true);
+  const [pasteAfterCopy, setPasteAfterCopy] = useState(clipboardSettings?.pasteAfterCopy ?? false);
+  const [captureText, setCaptureText] = useState(clipboardSettings?.captureText ?? true);
+  const [captureImages, setCaptureImages] = useState(clipboardSettings?.captureImages ?? true);
+  const [captureFiles, setCaptureFiles] = useState(clipboardSettings?.captureFiles ?? true);
+  const [perceptualDeduplicate, setPerceptualDeduplicate] = useState(
+    clipboardSettings?.perceptualDeduplicate ?? false,
+  );
+  const [skipImagesWithoutPreview, setSkipImagesWithoutPreview] = useState(
+    clipboardSettings?.previewFailurePolicy === "skip_item",
+  );
+  const [imagePreviewMaxWidthInput, setImagePreviewMaxWidthInput] = useState(
+    formatOptionalLimit(clipboardSettings?.imagePreviewMaxWidth),
+  );
+  const [imagePreviewMaxHeightInput, setImagePreviewMaxHeightInput] = useState(
+    formatOptionalLimit(clipboardSettings?.imagePreviewMaxHeight),
+  );
+  const [maxPreviewMegapixelsInput, setMaxPreviewMegapixelsInput] = useState(
+    formatMegapixelLimit(clipboardSettings?.maxPreviewPixels),
+  );
+  const [compactTextOnly, setCompactTextOnly] = useState(clipboardSettings?.compactContentFilter === "text_only");
+  const [regularTextOnly, setRegularTextOnly] = useState(clipboardSettings?.regularContentFilter === "text_only");
+  const [maxPinnedItemsInput, setMaxPinnedItemsInput] = useState(
+    String(clipboardSettings?.maxPinnedItems ?? DEFAULT_MAX_PINNED_ITEMS),
+  );
   const [selectedPresetMb, setSelectedPresetMb] = useState(() => {
     const initialValue = String(clipboardSettings?.maxTotalSizeMb ?? DEFAULT_CLIPBOARD_SIZE_PRESET_MB);
     return CLIPBOARD_SIZE_MB_PRESETS.includes(initialValue) ? initialValue : DEFAULT_CLIPBOARD_SIZE_PRESET_MB;
@@ -268,6 +352,10 @@ export function useSettingsPageState(): UseSettingsPageStateResult {
   );
   const customSizeInputRef = useRef<HTMLInputElement>(null);
   const [activeSection, setActiveSection] = useState<SettingsSection>("general");
+  const [trayLiveTooltip, setTrayLiveTooltip] = useState(false);
+  const [launcherAutoHide, setLauncherAutoHide] = useState(true);
+  const [clipboardAutoHide, setClipboardAutoHide] = useState(false);
+  const [dbMaintenanceRunning, setDbMaintenanceRunning] = useState(false);
   const [screenshotLoading, setScreenshotLoading] = useState(false);
   const [screenshotSaving, setScreenshotSaving] = useState(false);
   const [screenshotShortcutInput, setScreenshotShortcutInput] = useState("");
@@ -295,6 +383,7 @@ export function useSettingsPageState(): UseSettingsPageStateResult {
   const [logHighFreqWindowMsInput, setLogHighFreqWindowMsInput] = useState(String(1000));
   const [logHighFreqMaxPerKeyInput, setLogHighFreqMaxPerKeyInput] = useState(String(20));
   const [logAllowRawView, setLogAllowRawView] = useState(false);
+  const [logErrorDetailLevel, setLogErrorDetailLevel] = useState("standard");
   const [loggingSaveMessage, setLoggingSaveMessage] = useState<MessageState | null>(null);
 
   const settingsNavItems: SettingsNavItem[] = useMemo(
@@ -363,12 +452,32 @@ export function useSettingsPageState(): UseSettingsPageStateResult {
 
     void loadScreenshot();
 
+    void getSettings()
+      .then((settings) => {
+        setTrayLiveTooltip(settings.tray.liveTooltip);
+        setLauncherAutoHide(settings.windowAutoHide.launcherEnabled);
+        setClipboardAutoHide(settings.windowAutoHide.clipboardEnabled);
+      })
+      .catch(() => undefined);
+
   }, [fetchClipboardSettings, fetchLoggingConfig]);
 
   useEffect(() => {
     if (clipboardSettings) {
       setMaxItemsInput(String(clipboardSettings.maxItems));
       setSizeCleanupEnabled(clipboardSettings.sizeCleanupEnabled);
+      setPasteAfterCopy(clipboardSettings.pasteAfterCopy);
+      setCaptureText(clipboardSettings.captureText);
+      setCaptureImages(clipboardSettings.captureImages);
+      setCaptureFiles(clipboardSettings.captureFiles);
+      setPerceptualDeduplicate(clipboardSettings.perceptualDeduplicate);
+      setSkipImagesWithoutPreview(clipboardSettings.previewFailurePolicy === "skip_item");
+      setImagePreviewMaxWidthInput(formatOptionalLimit(clipboardSettings.imagePreviewMaxWidth));
+      setImagePreviewMaxHeightInput(formatOptionalLimit(clipboardSettings.imagePreviewMaxHeight));
+      setMaxPreviewMegapixelsInput(formatMegapixelLimit(clipboardSettings.maxPreviewPixels));
+      setCompactTextOnly(clipboardSettings.compactContentFilter === "text_only");
+      setRegularTextOnly(clipboardSettings.regularContentFilter === "text_only");
+      setMaxPinnedItemsInput(String(clipboardSettings.maxPinnedItems));
       const thresholdValue = String(clipboardSettings.maxTotalSizeMb);
       setCustomSizeMbInput(thresholdValue);
       if (CLIPBOARD_SIZE_MB_PRESETS.includes(thresholdValue)) {
@@ -405,6 +514,7 @@ export function useSettingsPageState(): UseSettingsPageStateResult {
     setLogHighFreqWindowMsInput(String(loggingConfig.highFreqWindowMs));
     setLogHighFreqMaxPerKeyInput(String(loggingConfig.highFreqMaxPerKey));
     setLogAllowRawView(loggingConfig.allowRawView);
+    setLogErrorDetailLevel(loggingConfig.errorDetailLevel);
   }, [loggingConfig]);
 
   const parsedMaxItems = useMemo(() => parsePositiveInt(maxItemsInput), [maxItemsInput]);
@@ -423,14 +533,51 @@ export function useSettingsPageState(): UseSettingsPageStateResult {
       parsedCustomSizeMb < MIN_MAX_TOTAL_SIZE_MB ||
       parsedCustomSizeMb > MAX_MAX_TOTAL_SIZE_MB);
 
-  const clipboardInvalid = maxItemsInvalid || maxTotalSizeInvalid;
+  const parsedImagePreviewMaxWidth = useMemo(
+    () => parseOptionalLimit(imagePreviewMaxWidthInput),
+    [imagePreviewMaxWidthInput],
+  );
+  const parsedImagePreviewMaxHeight = useMemo(
+    () => parseOptionalLimit(imagePreviewMaxHeightInput),
+    [imagePreviewMaxHeightInput],
+  );
+  const imagePreviewLimitInvalid = parsedImagePreviewMaxWidth === null || parsedImagePreviewMaxHeight === null;
+  const parsedMaxPreviewPixels = useMemo(() => {
+    const megapixels = parseOptionalLimit(maxPreviewMegapixelsInput);
+    return megapixels === null ? null : megapixels * PIXELS_PER_MEGAPIXEL;
+  }, [maxPreviewMegapixelsInput]);
+  const maxPreviewMegapixelsInvalid = parsedMaxPreviewPixels === null;
+  const parsedMaxPinnedItems = useMemo(() => parsePositiveInt(maxPinnedItemsInput), [maxPinnedItemsInput]);
+  const maxPinnedItemsInvalid =
+    parsedMaxPinnedItems === null ||
+    parsedMaxPinnedItems < MIN_MAX_PINNED_ITEMS ||
+    parsedMaxPinnedItems > MAX_MAX_PINNED_ITEMS;
+
+  const clipboardInvalid =
+    maxItemsInvalid ||
+    maxTotalSizeInvalid ||
+    imagePreviewLimitInvalid ||
+    maxPreviewMegapixelsInvalid ||
+    maxPinnedItemsInvalid;
   const clipboardUnchanged =
     parsedMaxItems !== null &&
     effectiveMaxTotalSizeMb !== null &&
     clipboardSettings !== null &&
     parsedMaxItems === clipboardSettings.maxItems &&
     effectiveMaxTotalSizeMb === clipboardSettings.maxTotalSizeMb &&
-    sizeCleanupEnabled === clipboardSettings.sizeCleanupEnabled;
+    sizeCleanupEnabled === clipboardSettings.sizeCleanupEnabled &&
+    pasteAfterCopy === clipboardSettings.pasteAfterCopy &&
+    captureText === clipboardSettings.captureText &&
+    captureImages === clipboardSettings.captureImages &&
+    captureFiles === clipboardSettings.captureFiles &&
+    perceptualDeduplicate === clipboardSettings.perceptualDeduplicate &&
+    skipImagesWithoutPreview === (clipboardSettings.previewFailurePolicy === "skip_item") &&
+    parsedImagePreviewMaxWidth === (clipboardSettings.imagePreviewMaxWidth ?? 0) &&
+    parsedImagePreviewMaxHeight === (clipboardSettings.imagePreviewMaxHeight ?? 0) &&
+    maxPreviewMegapixelsInput === formatMegapixelLimit(clipboardSettings.maxPreviewPixels) &&
+    compactTextOnly === (clipboardSettings.compactContentFilter === "text_only") &&
+    regularTextOnly === (clipboardSettings.regularContentFilter === "text_only") &&
+    parsedMaxPinnedItems === clipboardSettings.maxPinnedItems;
 
   const parsedKeepDays = useMemo(() => parsePositiveInt(logKeepDaysInput), [logKeepDaysInput]);
   const parsedHighFreqWindowMs = useMemo(() => parsePositiveInt(logHighFreqWindowMsInput), [logHighFreqWindowMsInput]);
@@ -470,8 +617,15 @@ export function useSettingsPageState(): UseSettingsPageStateResult {
     logMinLevel === "warn" ||
     logMinLevel === "error";
 
+  const validErrorDetailLevel =
+    logErrorDetailLevel === "minimal" || logErrorDetailLevel === "standard" || logErrorDetailLevel === "verbose";
+
   const loggingInvalid =
-    !validMinLevel || logKeepDaysInvalid || logHighFreqWindowInvalid || logHighFreqMaxPerKeyInvalid;
+    !validMinLevel ||
+    !validErrorDetailLevel ||
+    logKeepDaysInvalid ||
+    logHighFreqWindowInvalid ||
+    logHighFreqMaxPerKeyInvalid;
 
   const loggingUnchanged =
     loggingConfig !== null &&
@@ -480,7 +634,8 @@ export function useSettingsPageState(): UseSettingsPageStateResult {
     loggingConfig.realtimeEnabled === logRealtimeEnabled &&
     loggingConfig.highFreqWindowMs === parsedHighFreqWindowMs &&
     loggingConfig.highFreqMaxPerKey === parsedHighFreqMaxPerKey &&
-    loggingConfig.allowRawView === logAllowRawView;
+    loggingConfig.allowRawView === logAllowRawView &&
+    loggingConfig.errorDetailLevel === logErrorDetailLevel;
 
   const normalizedScreenshotShortcut = screenshotShortcutInput.trim();
   const screenshotShortcutInvalid = normalizedScreenshotShortcut.length === 0;
@@ -572,6 +727,77 @@ export function useSettingsPageState(): UseSettingsPageStateResult {
     void setTransparentWindowBackground(checked);
   };
 
+  const handleTrayLiveTooltipChange = (checked: boolean) => {
+    if (checked === trayLiveTooltip) {
+      return;
+    }
+    setTrayLiveTooltip(checked);
+    void patchSettings({ tray: { liveTooltip: checked } })
+      .then((settings) => {
+        setTrayLiveTooltip(settings.tray.liveTooltip);
+      })
+      .catch(() => {
+        setTrayLiveTooltip(!checked);
+      });
+  };
+
+  const handleLauncherAutoHideChange = (checked: boolean) => {
+    if (checked === launcherAutoHide) {
+      return;
+    }
+    setLauncherAutoHide(checked);
+    void patchSettings({ windowAutoHide: { launcherEnabled: checked } })
+      .then((settings) => {
+        setLauncherAutoHide(settings.windowAutoHide.launcherEnabled);
+      })
+      .catch(() => {
+        setLauncherAutoHide(!checked);
+      });
+  };
+
+  const handleClipboardAutoHideChange = (checked: boolean) => {
+    if (checked === clipboardAutoHide) {
+      return;
+    }
+    setClipboardAutoHide(checked);
+    void patchSettings({ windowAutoHide: { clipboardEnabled: checked } })
+      .then((settings) => {
+        setClipboardAutoHide(settings.windowAutoHide.clipboardEnabled);
+      })
+      .catch(() => {
+        setClipboardAutoHide(!checked);
+      });
+  };
+
+  const handleRunDbMaintenance = () => {
+    if (dbMaintenanceRunning) {
+      return;
+    }
+    setDbMaintenanceRunning(true);
+    void runDbMaintenance()
+      .then((result) => {
+        globalMessage.success({
+          description: t("general.dbMaintenance.done", {
+            reclaimed: formatBytes(result.reclaimedBytes),
+            size: formatBytes(result.sizeAfterBytes),
+          }),
+          dedupeKey: DB_MAINTENANCE_TOAST_DEDUPE_KEY,
+        });
+      })
+      .catch((error: unknown) => {
+        const message = error instanceof Error ? error.message : String(error);
+        const isBusy = message.includes("db_maintenance_in_progress") || message.includes("db_maintenance_busy");
+        globalMessage.error({
+          description: isBusy ? t("general.dbMaintenance.busy") : t("general.dbMaintenance.failed", { message }),
+          dedupeKey: DB_MAINTENANCE_TOAST_DEDUPE_KEY,
+          duration: 5000,
+        });
+      })
+      .finally(() => {
+        setDbMaintenanceRunning(false);
+      });
+  };
+
   const handleSaveScreenshot = async () => {
     if (
       screenshotShortcutInvalid ||
@@ -638,11 +864,41 @@ export function useSettingsPageState(): UseSettingsPageStateResult {
       return;
     }
 
+    if (parsedImagePreviewMaxWidth === null || parsedImagePreviewMaxHeight === null || parsedMaxPreviewPixels === null) {
+      globalMessage.error({
+        description: t("clipboard.imagePreviewLimitInvalid"),
+        dedupeKey: CLIPBOARD_SAVE_TOAST_DEDUPE_KEY,
+        duration: 5000,
+      });
+      return;
+    }
+
+    if (parsedMaxPinnedItems === null || maxPinnedItemsInvalid) {
+      globalMessage.error({
+        description: t("clipboard.maxPinnedItemsInvalid", { min: MIN_MAX_PINNED_ITEMS, max: MAX_MAX_PINNED_ITEMS }),
+        dedupeKey: CLIPBOARD_SAVE_TOAST_DEDUPE_KEY,
+        duration: 5000,
+      });
+      return;
+    }
+
     try {
       await updateClipboardSettings({
         maxItems: parsedMaxItems,
         sizeCleanupEnabled,
         maxTotalSizeMb: effectiveMaxTotalSizeMb,
+        pasteAfterCopy,
+        captureText,
+        captureImages,
+        captureFiles,
+        perceptualDeduplicate,
+        previewFailurePolicy: skipImagesWithoutPreview ? "skip_item" : "keep_without_preview",
+        imagePreviewMaxWidth: parsedImagePreviewMaxWidth,
+        imagePreviewMaxHeight: parsedImagePreviewMaxHeight,
+        maxPreviewPixels: parsedMaxPreviewPixels,
+        compactContentFilter: compactTextOnly ? "text_only" : "all",
+        regularContentFilter: regularTextOnly ? "text_only" : "all",
+        maxPinnedItems: parsedMaxPinnedItems,
       });
       globalMessage.success({
         description: t("clipboard.saved"),
@@ -685,6 +941,8 @@ export function useSettingsPageState(): UseSettingsPageStateResult {
         highFreqWindowMs: parsedHighFreqWindowMs,
         highFreqMaxPerKey: parsedHighFreqMaxPerKey,
         allowRawView: logAllowRawView,
+        logFormat: loggingConfig?.logFormat ?? "text",
+        errorDetailLevel: logErrorDetailLevel as ErrorDetailLevel,
       });
       setLoggingSaveMessage({ text: t("logging.saved"), isError: false });
     } catch (saveError) {
@@ -715,6 +973,54 @@ export function useSettingsPageState(): UseSettingsPageStateResult {
     setSizeCleanupEnabled(checked);
   }, []);
 
+  const onPasteAfterCopyChange = useCallback((checked: boolean) => {
+    setPasteAfterCopy(checked);
+  }, []);
+
+  const onCaptureTextChange = useCallback((checked: boolean) => {
+    setCaptureText(checked);
+  }, []);
+
+  const onCaptureImagesChange = useCallback((checked: boolean) => {
+    setCaptureImages(checked);
+  }, []);
+
+  const onCaptureFilesChange = useCallback((checked: boolean) => {
+    setCaptureFiles(checked);
+  }, []);
+
+  const onPerceptualDeduplicateChange = useCallback((checked: boolean) => {
+    setPerceptualDeduplicate(checked);
+  }, []);
+
+  const onSkipImagesWithoutPreviewChange = useCallback((checked: boolean) => {
+    setSkipImagesWithoutPreview(checked);
+  }, []);
+
+  const onImagePreviewMaxWidthChange = useCallback((value: string) => {
+    setImagePreviewMaxWidthInput(value);
+  }, []);
+
+  const onImagePreviewMaxHeightChange = useCallback((value: string) => {
+    setImagePreviewMaxHeightInput(value);
+  }, []);
+
+  const onMaxPreviewMegapixelsChange = useCallback((value: string) => {
+    setMaxPreviewMegapixelsInput(value);
+  }, []);
+
+  const onCompactTextOnlyChange = useCallback((checked: boolean) => {
+    setCompactTextOnly(checked);
+  }, []);
+
+  const onRegularTextOnlyChange = useCallback((checked: boolean) => {
+    setRegularTextOnly(checked);
+  }, []);
+
+  const onMaxPinnedItemsChange = useCallback((value: string) => {
+    setMaxPinnedItemsInput(value);
+  }, []);
+
   const onPresetSelect = useCallback((presetValue: string) => {
     setSizeThresholdMode("preset");
     setSelectedPresetMb(presetValue);
@@ -758,6 +1064,11 @@ export function useSettingsPageState(): UseSettingsPageStateResult {
     setLoggingSaveMessage(null);
   }, []);
 
+  const onErrorDetailLevelChange = useCallback((value: string) => {
+    setLogErrorDetailLevel(value);
+    setLoggingSaveMessage(null);
+  }, []);
+
   const onKeepDaysChange = useCallback((value: string) => {
     setLogKeepDaysInput(value);
     setLoggingSaveMessage(null);
@@ -802,6 +1113,14 @@ export function useSettingsPageState(): UseSettingsPageStateResult {
       onThemePreferenceChange: handleThemePreferenceChange,
       transparentWindowBackground,
       onTransparentWindowBackgroundChange: handleTransparentWindowBackgroundChange,
+      trayLiveTooltip,
+      onTrayLiveTooltipChange: handleTrayLiveTooltipChange,
+      launcherAutoHide,
+      onLauncherAutoHideChange: handleLauncherAutoHideChange,
+      clipboardAutoHide,
+      onClipboardAutoHideChange: handleClipboardAutoHideChange,
+      dbMaintenanceRunning,
+      onRunDbMaintenance: handleRunDbMaintenance,
     },
     clipboard: {
       maxItemsInput,
@@ -815,11 +1134,28 @@ export function useSettingsPageState(): UseSettingsPageStateResult {
       customSizeInputRef,
       maxTotalSizeInvalid,
       clipboardSizeHelperText,
+      pasteAfterCopy,
+      captureText,
+      captureImages,
+      captureFiles,
+      perceptualDeduplicate,
+      skipImagesWithoutPreview,
+      imagePreviewMaxWidthInput,
+      imagePreviewMaxHeightInput,
+      imagePreviewLimitInvalid,
+      maxPreviewMegapixelsInput,
+      maxPreviewMegapixelsInvalid,
+      compactTextOnly,
+      regularTextOnly,
+      maxPinnedItemsInput,
+      maxPinnedItemsInvalid,
       limits: {
         maxItemsMin: MIN_MAX_ITEMS,
         maxItemsMax: MAX_MAX_ITEMS,
         maxTotalSizeMin: MIN_MAX_TOTAL_SIZE_MB,
         maxTotalSizeMax: MAX_MAX_TOTAL_SIZE_MB,
+        maxPinnedItemsMin: MIN_MAX_PINNED_ITEMS,
+        maxPinnedItemsMax: MAX_MAX_PINNED_ITEMS,
       },
       loading: clipboardLoading,
       saving: clipboardSaving,
@@ -828,6 +1164,18 @@ export function useSettingsPageState(): UseSettingsPageStateResult {
       error: clipboardError,
       onMaxItemsChange,
       onSizeCleanupEnabledChange,
+      onPasteAfterCopyChange,
+      onCaptureTextChange,
+      onCaptureImagesChange,
+      onCaptureFilesChange,
+      onPerceptualDeduplicateChange,
+      onSkipImagesWithoutPreviewChange,
+      onImagePreviewMaxWidthChange,
+      onImagePreviewMaxHeightChange,
+      onMaxPreviewMegapixelsChange,
+      onCompactTextOnlyChange,
+      onRegularTextOnlyChange,
+      onMaxPinnedItemsChange,
       onPresetSelect,
       onCustomModeSelect,
       onCustomSizeChange,
@@ -870,6 +1218,7 @@ export function useSettingsPageState(): UseSettingsPageStateResult {
       highFreqWindowMsInput: logHighFreqWindowMsInput,
       highFreqMaxPerKeyInput: logHighFreqMaxPerKeyInput,
       allowRawView: logAllowRawView,
+      errorDetailLevel: logErrorDetailLevel,
       keepDaysOptions: logKeepDaysOptions,
       windowMsOptions: logWindowMsOptions,
       maxPerKeyOptions: logMaxPerKeyOptions,
@@ -894,6 +1243,7 @@ export function useSettingsPageState(): UseSettingsPageStateResult {
       onHighFreqWindowMsChange,
       onHighFreqMaxPerKeyChange,
       onAllowRawViewChange,
+      onErrorDetailLevelChange,
       onSave: handleSaveLogging,
     },
   };
@@ -1,17 +1,27 @@
 import type {
   AppManagerActionResult,
+  AppManagerAnnotateResidueInput,
+  AppManagerBackupMeta,
+  AppManagerBulkStartupResult,
   AppManagerCleanupInput,
   AppManagerCleanupResult,
+  AppManagerClearExportsResult,
+  AppManagerExportFormat,
+  AppManagerExportList,
   AppManagerExportScanResult,
+  AppManagerGhostStartupItem,
   AppManagerPage,
   AppManagerQuery,
   AppManagerResidueScanMode,
   AppManagerResidueScanResult,
   AppManagerResolveSizesInput,
   AppManagerResolveSizesResult,
+  AppManagerSizeAccuracyMode,
   AppManagerSnapshotMeta,
+  AppManagerStartupEntry,
   AppManagerStartupUpdateInput,
   AppManagerUninstallInput,
+  AppRelatedRoot,
   ManagedAppDetail,
 } from "@/components/app-manager/types";
 import type { AppManagerQueryDto, AppManagerRequestDto } from "@/contracts";
@@ -34,6 +44,8 @@ function toAppManagerQueryDto(query?: AppManagerQuery): AppManagerQueryDto {
     category: query?.category ?? "all",
     limit: query?.limit ?? null,
     cursor: query?.cursor ?? null,
+    sizeAccuracy: query?.sizeAccuracy ?? null,
+    folderFilter: query?.folderFilter?.trim() ? query.folderFilter.trim() : null,
   };
 }
 
@@ -52,6 +64,10 @@ export function appManagerListSnapshotMeta(): Promise<AppManagerSnapshotMeta> {
   return invokeAppManager(createAppManagerRequest({ kind: "list_snapshot_meta" }));
 }
 
+export function appManagerGetSnapshotMeta(): Promise<AppManagerSnapshotMeta> {
+  return invokeAppManager(createAppManagerRequest({ kind: "get_snapshot_meta" }));
+}
+
 export function appManagerResolveSizes(input: AppManagerResolveSizesInput): Promise<AppManagerResolveSizesResult> {
   return invokeAppManager(
     createAppManagerRequest({
@@ -61,21 +77,46 @@ export function appManagerResolveSizes(input: AppManagerResolveSizesInput): Prom
   );
 }
 
+export function appManagerCancelResolveSizes(cancelToken: string): Promise<boolean> {
+  return invokeAppManager(
+    createAppManagerRequest({
+      kind: "cancel_resolve_sizes",
+      payload: { cancelToken },
+    }),
+  );
+}
+
 export function appManagerRefreshIndex(): Promise<AppManagerActionResult> {
   return invokeAppManager(createAppManagerRequest({ kind: "refresh_index" }));
 }
 
-export function appManagerGetDetailCore(appId: string): Promise<ManagedAppDetail> {
+export function appManagerRefreshIndexAsync(): Promise<AppManagerSnapshotMeta> {
+  return invokeAppManager(createAppManagerRequest({ kind: "refresh_index_async" }));
+}
+
+export function appManagerGetDetailCore(
+  appId: string,
+  sizeAccuracy?: AppManagerSizeAccuracyMode,
+): Promise<ManagedAppDetail> {
   return invokeAppManager(
     createAppManagerRequest({
       kind: "get_detail_core",
       payload: {
-        query: { appId },
+        query: { appId, sizeAccuracy: sizeAccuracy ?? null },
       },
     }),
   );
 }
 
+export function appManagerListRelatedRoots(appId: string): Promise<AppRelatedRoot[]> {
+  return invokeAppManager(
+    createAppManagerRequest({
+      kind: "list_related_roots",
+      payload: { appId },
+    }),
+  );
+}
+
 export function appManagerGetDetailHeavy(
   appId: string,
   mode: AppManagerResidueScanMode = "deep",
@@ -90,6 +131,20 @@ export function appManagerGetDetailHeavy(
   );
 }
 
+export function appManagerScanPath(
+  path: string,
+  mode: AppManagerResidueScanMode = "deep",
+): Promise<AppManagerResidueScanResult> {
+  return invokeAppManager(
+    createAppManagerRequest({
+      kind: "scan_path",
+      payload: {
+        input: { path, mode },
+      },
+    }),
+  );
+}
+
 export function appManagerCleanup(input: AppManagerCleanupInput): Promise<AppManagerCleanupResult> {
   return invokeAppManager(
     createAppManagerRequest({
@@ -99,18 +154,37 @@ export function appManagerCleanup(input: AppManagerCleanupInput): Promise<AppMan
           ...input,
           skipOnError: input.skipOnError ?? null,
           confirmedFingerprint: input.confirmedFingerprint ?? null,
+          confirmedItemIds: input.confirmedItemIds ?? [],
         },
       },
     }),
   );
 }
 
-export function appManagerExportScanResult(appId: string): Promise<AppManagerExportScanResult> {
+export function appManagerExportScanResult(
+  appId: string,
+  format?: AppManagerExportFormat,
+): Promise<AppManagerExportScanResult> {
   return invokeAppManager(
     createAppManagerRequest({
       kind: "export_scan_result",
       payload: {
-        input: { appId },
+        input: { appId, format: format ?? null },
+      },
+    }),
+  );
+}
+
+export function appManagerListExports(): Promise<AppManagerExportList> {
+  return invokeAppManager(createAppManagerRequest({ kind: "list_exports" }));
+}
+
+export function appManagerClearExports(olderThanDays?: number): Promise<AppManagerClearExportsResult> {
+  return invokeAppManager(
+    createAppManagerRequest({
+      kind: "clear_exports",
+      payload: {
+        input: { olderThanDays: olderThanDays ?? null },
       },
     }),
   );
@@ -156,6 +230,15 @@ export function appManagerOpenPermissionHelp(appId: string): Promise<AppManagerA
   );
 }
 
+export function appManagerAnnotateResidueItem(input: AppManagerAnnotateResidueInput): Promise<void> {
+  return invokeAppManager(
+    createAppManagerRequest({
+      kind: "annotate_residue_item",
+      payload: { input },
+    }),
+  );
+}
+
 export function appManagerRevealPath(path: string): Promise<void> {
   return invokeAppManager(
     createAppManagerRequest({
@@ -164,3 +247,38 @@ export function appManagerRevealPath(path: string): Promise<void> {
     }),
   );
 }
+
+export function appManagerRevealStartupEntry(appId: string): Promise<AppManagerStartupEntry> {
+  return invokeAppManager(
+    createAppManagerRequest({
+      kind: "reveal_startup_entry",
+      payload: { appId },
+    }),
+  );
+}
+
+export function appManagerScanGhostStartupItems(): Promise<AppManagerGhostStartupItem[]> {
+  return invokeAppManager(createAppManagerRequest({ kind: "scan_ghost_startup_items" }));
+}
+
+export function appManagerRemoveGhostStartupItem(label: string): Promise<void> {
+  return invokeAppManager(
+    createAppManagerRequest({
+      kind: "remove_ghost_startup_item",
+      payload: { label },
+    }),
+  );
+}
+
+export function appManagerListAutoBackups(): Promise<AppManagerBackupMeta[]> {
+  return invokeAppManager(createAppManagerRequest({ kind: "list_auto_backups" }));
+}
+
+export function appManagerRestoreAutoBackup(backupId: string): Promise<AppManagerBulkStartupResult> {
+  return invokeAppManager(
+    createAppManagerRequest({
+      kind: "restore_auto_backup",
+      payload: { backupId },
+    }),
+  );
+}
@@ -2,7 +2,16 @@ import type {
   ClipboardFilterDto,
   ClipboardRequestDto,
   ClipboardImageExportResultDto,
+  ClipboardItemDetailDto,
   ClipboardItemDto,
+  ClipboardMigrateResultDto,
+  ClipboardPreviewRepairResultDto,
+  ClipboardSearchResultDto,
+  ClipboardSizeSummaryDto,
+  ClipboardStorageStatsDto,
+  ClipboardTextTransformDto,
+  ClipboardWatcherStatusDto,
+  ClipboardWindowContentFilter,
   ClipboardWindowModeAppliedDto,
 } from "@/contracts";
 import { invokeFeature } from "@/services/invoke";
@@ -12,6 +21,7 @@ export interface ClipboardFilterInput {
   itemType?: string | null;
   onlyPinned?: boolean | null;
   limit?: number | null;
+  contentFilter?: ClipboardWindowContentFilter | null;
 }
 
 function invokeClipboard<T>(request: ClipboardRequestDto): Promise<T> {
@@ -25,6 +35,7 @@ export async function clipboardList(filter?: ClipboardFilterInput): Promise<Clip
         itemType: filter.itemType ?? null,
         onlyPinned: filter.onlyPinned ?? null,
         limit: filter.limit ?? null,
+        contentFilter: filter.contentFilter ?? null,
       }
     : undefined;
   return invokeClipboard<ClipboardItemDto[]>({
@@ -33,10 +44,28 @@ export async function clipboardList(filter?: ClipboardFilterInput): Promise<Clip
   });
 }
 
+export async function clipboardSearch(
+  query: string,
+  limit?: number,
+): Promise<ClipboardSearchResultDto> {
+  return invokeClipboard<ClipboardSearchResultDto>({
+    kind: "search",
+    payload: { query, limit },
+  });
+}
+
+export async function clipboardGetItem(id: string): Promise<ClipboardItemDetailDto> {
+  return invokeClipboard<ClipboardItemDetailDto>({ kind: "get_item", payload: { id } });
+}
+
 export async function clipboardPin(id: string, pinned: boolean): Promise<void> {
   await invokeClipboard<void>({ kind: "pin", payload: { id, pinned } });
 }
 
+export async function clipboardReorderPinned(itemIds: string[]): Promise<void> {
+  await invokeClipboard<void>({ kind: "reorder_pinned", payload: { itemIds } });
+}
+
 export async function clipboardDelete(id: string): Promise<void> {
   await invokeClipboard<void>({ kind: "delete", payload: { id } });
 }
@@ -66,6 +95,14 @@ export async function clipboardCopyBack(id: string): Promise<void> {
   await invokeClipboard<void>({ kind: "copy_back", payload: { id } });
 }
 
+export async function clipboardCopyTransformed(id: string, transform: ClipboardTextTransformDto): Promise<string> {
+  return invokeClipboard<string>({ kind: "copy_transformed", payload: { id, transform } });
+}
+
+export async function clipboardCopyAndPaste(id: string): Promise<void> {
+  await invokeClipboard<void>({ kind: "copy_and_paste", payload: { id } });
+}
+
 export async function clipboardCopyFilePaths(id: string): Promise<void> {
   await invokeClipboard<void>({ kind: "copy_file_paths", payload: { id } });
 }
@@ -77,3 +114,36 @@ export async function clipboardCopyImageBack(id: string): Promise<void> {
 export async function clipboardExportImage(id: string): Promise<ClipboardImageExportResultDto> {
   return invokeClipboard<ClipboardImageExportResultDto>({ kind: "export_image", payload: { id } });
 }
+
+export async function clipboardStorageStats(): Promise<ClipboardStorageStatsDto> {
+  return invokeClipboard<ClipboardStorageStatsDto>({ kind: "storage_stats" });
+}
+
+export async function clipboardGetSizeSummary(): Promise<ClipboardSizeSummaryDto> {
+  return invokeClipboard<ClipboardSizeSummaryDto>({ kind: "size_summary" });
+}
+
+export async function clipboardRepairPreviews(): Promise<ClipboardPreviewRepairResultDto> {
+  return invokeClipboard<ClipboardPreviewRepairResultDto>({ kind: "repair" });
+}
+
+export async function clipboardMigrateStorage(newDir: string): Promise<ClipboardMigrateResultDto> {
+  return invokeClipboard<ClipboardMigrateResultDto>({
+    kind: "migrate_storage",
+    payload: { newDir },
+  });
+}
+
+export async function clipboardSetPaused(
+  paused: boolean,
+  durationMinutes?: number,
+): Promise<ClipboardWatcherStatusDto> {
+  return invokeClipboard<ClipboardWatcherStatusDto>({
+    kind: "set_paused",
+    payload: { paused, durationMinutes },
+  });
+}
+
+export async function clipboardWatcherStatus(): Promise<ClipboardWatcherStatusDto> {
+  return invokeClipboard<ClipboardWatcherStatusDto>({ kind: "watcher_status" });
+}
@@ -20,6 +20,7 @@ interface InvokeErrorPayload {
   code?: string;
   message?: string;
   causes?: string[];
+  causeSummary?: string;
   context?: InvokeErrorContextItem[];
   requestId?: string;
 }
@@ -34,7 +35,7 @@ const FEATURE_COMMAND_MAP: Record<AppFeatureKey, string> = {
   settings: "rt_settings",
 };
 
-function createRequestId(): string {
+export function createRequestId(): string {
   if (typeof crypto !== "undefined" && typeof crypto.randomUUID === "function") {
     return crypto.randomUUID();
   }
@@ -106,6 +107,11 @@ function resolveErrorMessage(error: unknown, payload: InvokeErrorPayload | null)
   return String(error);
 }
 
+export function resolveInvokeErrorCauseSummary(error: unknown): string | null {
+  const summary = toInvokeErrorPayload(error)?.causeSummary;
+  return summary && summary.trim().length > 0 ? summary : null;
+}
+
 export async function invokeWithLog<T, F extends AppFeatureKey = AppFeatureKey>(
   feature: F,
   request: AppFeatureRequestMap[F],
@@ -177,6 +183,7 @@ export async function invokeWithLog<T, F extends AppFeatureKey = AppFeatureKey>(
         error: message,
         errorCode: invokeErrorPayload?.code ?? "unknown_error",
         errorCauses: invokeErrorPayload?.causes ?? [],
+        errorCauseSummary: invokeErrorPayload?.causeSummary ?? null,
         errorContext: invokeErrorPayload?.context ?? [],
       },
       requestId,
@@ -9,6 +9,7 @@ import type {
   LauncherSearchResponseDto as LauncherSearchResponse,
   LauncherSearchSettingsDto as LauncherSearchSettings,
   LauncherStatusDto as LauncherStatus,
+  LauncherUndoResultDto as LauncherUndoResult,
   LauncherUpdateSearchSettingsInputDto as LauncherUpdateSearchSettingsInput,
 } from "@/contracts";
 import { invokeFeature } from "@/services/invoke";
@@ -27,6 +28,7 @@ export type {
   LauncherSearchResponse,
   LauncherSearchSettings,
   LauncherStatus,
+  LauncherUndoResult,
   LauncherUpdateSearchSettingsInput,
 };
 
@@ -34,8 +36,8 @@ export async function launcherSearch(query: string, limit?: number): Promise<Lau
   return invokeLauncher<LauncherSearchResponse>({ kind: "search", payload: { query, limit } });
 }
 
-export async function launcherExecute(action: LauncherAction): Promise<LauncherActionResult> {
-  return invokeLauncher<LauncherActionResult>({ kind: "execute", payload: { action } });
+export async function launcherExecute(action: LauncherAction, itemId?: string): Promise<LauncherActionResult> {
+  return invokeLauncher<LauncherActionResult>({ kind: "execute", payload: { action, itemId } });
 }
 
 export async function launcherGetSearchSettings(): Promise<LauncherSearchSettings> {
@@ -62,3 +64,7 @@ export async function launcherRebuildIndex(): Promise<LauncherRebuildResult> {
 export async function launcherResetSearchSettings(): Promise<LauncherSearchSettings> {
   return invokeLauncher<LauncherSearchSettings>({ kind: "reset_search_settings" });
 }
+
+export async function launcherUndoLastAction(): Promise<LauncherUndoResult> {
+  return invokeLauncher<LauncherUndoResult>({ kind: "undo" });
+}
@@ -1,4 +1,7 @@
 import type {
+  AppExportLocaleResultDto,
+  AppImportLocaleResultDto,
+  I18nLocaleStatsDto,
   LocaleRequestDto,
   LocaleStateDto as BackendLocaleState,
 } from "@/contracts";
@@ -26,3 +29,31 @@ export async function fetchBackendLocaleState(): Promise<BackendLocaleState> {
 export async function saveBackendLocalePreference(preference: LocalePreference): Promise<BackendLocaleState> {
   return invokeLocale<BackendLocaleState>({ kind: "set", payload: { preference } });
 }
+
+export async function formatBytesForLocale(bytes: number): Promise<string> {
+  return invokeLocale<string>({ kind: "format_bytes", payload: { bytes } });
+}
+
+export async function formatNumberForLocale(value: number): Promise<string> {
+  return invokeLocale<string>({ kind: "format_number", payload: { value } });
+}
+
+export async function exportLocaleCatalog(locale: string, outputPath?: string): Promise<AppExportLocaleResultDto> {
+  return invokeLocale<AppExportLocaleResultDto>({ kind: "export", payload: { locale, outputPath } }, false);
+}
+
+export async function importLocaleFile(
+  locale: string,
+  filePath: string,
+  namespace?: string,
+): Promise<AppImportLocaleResultDto> {
+  return invokeLocale<AppImportLocaleResultDto>({ kind: "import", payload: { locale, namespace, filePath } }, false);
+}
+
+export async function getLocaleStats(locale: string): Promise<I18nLocaleStatsDto> {
+  return invokeLocale<I18nLocaleStatsDto>({ kind: "stats", payload: { locale } });
+}
+
+export async function listLocaleStats(): Promise<I18nLocaleStatsDto[]> {
+  return invokeLocale<I18nLocaleStatsDto[]>({ kind: "list_stats" });
+}
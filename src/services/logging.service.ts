@@ -1,10 +1,19 @@
 import { listen, type UnlistenFn } from "@tauri-apps/api/event";
 
-import type { LogConfigDto, LogEntryDto, LogPageDto, LogQueryDto, LoggingRequestDto } from "@/contracts";
+import type {
+  LogConfigDto,
+  LogEntryDto,
+  LogExportResultDto,
+  LogPageDto,
+  LogQueryDto,
+  LoggingRequestDto,
+} from "@/contracts";
 import { invokeFeature } from "@/services/invoke";
 import { safeUnlisten } from "@/services/tauri-event";
 
 export type LogLevel = "trace" | "debug" | "info" | "warn" | "error";
+export type LogFormat = "text" | "cbor";
+export type ErrorDetailLevel = "minimal" | "standard" | "verbose";
 
 export interface LogEntry {
   id: number;
@@ -44,6 +53,8 @@ export interface LoggingConfig {
   highFreqWindowMs: number;
   highFreqMaxPerKey: number;
   allowRawView: boolean;
+  logFormat: LogFormat;
+  errorDetailLevel: ErrorDetailLevel;
 }
 
 function invokeLogging<T>(
@@ -74,8 +85,8 @@ export async function saveLoggingConfig(config: LoggingConfig): Promise<LoggingC
   return dto as LoggingConfig;
 }
 
-export async function exportLogs(query?: LogQuery, outputPath?: string): Promise<string> {
-  return invokeLogging<string>({
+export async function exportLogs(query?: LogQuery, outputPath?: string): Promise<LogExportResultDto> {
+  return invokeLogging<LogExportResultDto>({
     kind: "export_jsonl",
     payload: {
       query: query as LogQueryDto | undefined,
@@ -84,6 +95,23 @@ export async function exportLogs(query?: LogQuery, outputPath?: string): Promise
   });
 }
 
+export async function exportLogsCbor(query?: LogQuery, outputPath?: string): Promise<LogExportResultDto> {
+  return invokeLogging<LogExportResultDto>({
+    kind: "export_cbor",
+    payload: {
+      query: query as LogQueryDto | undefined,
+      outputPath,
+    },
+  });
+}
+
+export async function convertCborLogsToJsonl(cborPath: string, outputPath: string): Promise<string> {
+  return invokeLogging<string>({
+    kind: "convert_cbor_to_jsonl",
+    payload: { cborPath, outputPath },
+  });
+}
+
 export async function subscribeLogStream(onEntry: (entry: LogEntry) => void): Promise<UnlistenFn> {
   const unlisten = await listen<LogEntryDto>("rtool://logging/stream", (event) => {
     onEntry(event.payload as LogEntry);
@@ -1,6 +1,7 @@
+import { resolveInvokeErrorCauseSummary } from "@/services/invoke";
 import { logWarn } from "@/services/logger";
 
-export type RecoverableResult<T> = { ok: true; data: T } | { ok: false; message: string };
+export type RecoverableResult<T> = { ok: true; data: T } | { ok: false; message: string; causeSummary: string | null };
 
 export interface RecoverableContext {
   scope: string;
@@ -34,6 +35,14 @@ function logRecoverableError(context: RecoverableContext, message: string, error
   });
 }
 
+export function describeRecoverableFailure(result: { message: string; causeSummary: string | null }): string {
+  if (!result.causeSummary) {
+    return result.message;
+  }
+
+  return `${result.message} (${result.causeSummary})`;
+}
+
 export async function runRecoverable<T>(
   task: () => Promise<T>,
   context: RecoverableContext,
@@ -47,6 +56,7 @@ export async function runRecoverable<T>(
     return {
       ok: false,
       message,
+      causeSummary: resolveInvokeErrorCauseSummary(error),
     };
   }
 }
@@ -61,6 +71,7 @@ export function runRecoverableSync<T>(task: () => T, context: RecoverableContext
     return {
       ok: false,
       message,
+      causeSummary: resolveInvokeErrorCauseSummary(error),
     };
   }
 }
@@ -1,7 +1,14 @@
 import type {
+  AppManagerCleanupDeleteMode,
+  ClipboardPreviewFailurePolicy,
+  ClipboardWindowContentFilter,
+  DbMaintenanceResultDto,
   SettingsRequestDto,
   SettingsDto,
+  SettingsExportResultDto,
+  SettingsImportResultDto,
   SettingsUpdateInputDto,
+  ShortcutStatusDto,
 } from "@/contracts";
 import type { LayoutPreference } from "@/layouts/layout.types";
 import { invokeFeature } from "@/services/invoke";
@@ -29,6 +36,18 @@ export interface SettingsPatchInput {
     maxItems?: number;
     sizeCleanupEnabled?: boolean;
     maxTotalSizeMb?: number;
+    pasteAfterCopy?: boolean;
+    captureText?: boolean;
+    captureImages?: boolean;
+    captureFiles?: boolean;
+    perceptualDeduplicate?: boolean;
+    imagePreviewMaxWidth?: number;
+    imagePreviewMaxHeight?: number;
+    maxPreviewPixels?: number;
+    previewFailurePolicy?: ClipboardPreviewFailurePolicy;
+    compactContentFilter?: ClipboardWindowContentFilter;
+    regularContentFilter?: ClipboardWindowContentFilter;
+    maxPinnedItems?: number;
   };
   screenshot?: {
     shortcut?: string;
@@ -37,6 +56,18 @@ export interface SettingsPatchInput {
     maxTotalSizeMb?: number;
     pinMaxInstances?: number;
   };
+  tray?: {
+    liveTooltip?: boolean;
+  };
+  windowAutoHide?: {
+    launcherEnabled?: boolean;
+    launcherDelayMs?: number;
+    clipboardEnabled?: boolean;
+    clipboardDelayMs?: number;
+  };
+  appManager?: {
+    defaultDeleteMode?: AppManagerCleanupDeleteMode;
+  };
 }
 
 export async function getSettings(): Promise<SettingsDto> {
@@ -49,3 +80,25 @@ export async function patchSettings(input: SettingsPatchInput): Promise<Settings
     payload: { input: input as SettingsUpdateInputDto },
   });
 }
+
+export async function exportAllSettings(outputPath?: string): Promise<SettingsExportResultDto> {
+  return invokeSettings<SettingsExportResultDto>({
+    kind: "export_all",
+    payload: { outputPath },
+  });
+}
+
+export async function importAllSettings(inputPath: string): Promise<SettingsImportResultDto> {
+  return invokeSettings<SettingsImportResultDto>({
+    kind: "import_all",
+    payload: { inputPath },
+  });
+}
+
+export async function runDbMaintenance(): Promise<DbMaintenanceResultDto> {
+  return invokeSettings<DbMaintenanceResultDto>({ kind: "db_maintenance" });
+}
+
+export async function getShortcutsStatus(): Promise<ShortcutStatusDto[]> {
+  return invokeSettings<ShortcutStatusDto[]>({ kind: "shortcuts_status" });
+}
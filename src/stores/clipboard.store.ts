@@ -1,14 +1,19 @@
 import { create } from "zustand";
 
 import type { ClipboardFilter, ClipboardItem, ClipboardSyncPayload } from "@/components/clipboard/types";
+import type { ClipboardWatcherStatusDto } from "@/contracts";
 import {
   clipboardClearAll,
+  clipboardCopyAndPaste,
   clipboardCopyBack,
   clipboardCopyFilePaths,
   clipboardCopyImageBack,
   clipboardDelete,
   clipboardList,
   clipboardPin,
+  clipboardReorderPinned,
+  clipboardSetPaused,
+  clipboardWatcherStatus,
 } from "@/services/clipboard.service";
 import { runRecoverable } from "@/services/recoverable";
 import {
@@ -27,6 +32,7 @@ interface ClipboardState {
   itemType: string;
   onlyPinned: boolean;
   error: string | null;
+  watcherStatus: ClipboardWatcherStatusDto | null;
 }
 
 interface ClipboardActions {
@@ -36,12 +42,17 @@ interface ClipboardActions {
   ensureInitialized: () => Promise<void>;
   applySync: (payload: ClipboardSyncPayload) => void;
   pinItem: (id: string, pinned: boolean) => Promise<void>;
+  reorderPinned: (itemIds: string[]) => Promise<void>;
   deleteItem: (id: string) => Promise<void>;
   clearAllItems: () => Promise<void>;
   copyBack: (id: string) => Promise<void>;
+  copyAndPaste: (id: string) => Promise<void>;
   copyFilePathsBack: (id: string) => Promise<void>;
   copyImageBack: (id: string) => Promise<void>;
   upsertItem: (item: ClipboardItem) => void;
+  fetchWatcherStatus: () => Promise<void>;
+  setCapturePaused: (paused: boolean, durationMinutes?: number) => Promise<void>;
+  applyWatcherStatus: (status: ClipboardWatcherStatusDto) => void;
 }
 
 type ClipboardStore = ClipboardState & ClipboardActions;
@@ -51,6 +62,12 @@ function compareClipboardItems(left: ClipboardItem, right: ClipboardItem): numbe
   if (left.pinned !== right.pinned) {
     return left.pinned ? -1 : 1;
   }
+  if (left.pinned) {
+    const orderDelta = (left.pinOrder ?? 0) - (right.pinOrder ?? 0);
+    if (orderDelta !== 0) {
+      return orderDelta;
+    }
+  }
   return right.createdAt - left.createdAt;
 }
 
@@ -85,6 +102,7 @@ export const useClipboardStore = create<ClipboardStore>((set, get) => ({
   itemType: "",
   onlyPinned: false,
   error: null,
+  watcherStatus: null,
   setQuery(query) {
     set({ query });
   },
@@ -179,6 +197,9 @@ export const useClipboardStore = create<ClipboardStore>((set, get) => ({
   async pinItem(id, pinned) {
     await clipboardPin(id, pinned);
   },
+  async reorderPinned(itemIds) {
+    await clipboardReorderPinned(itemIds);
+  },
   async deleteItem(id) {
     await clipboardDelete(id);
   },
@@ -188,6 +209,9 @@ export const useClipboardStore = create<ClipboardStore>((set, get) => ({
   async copyBack(id) {
     await clipboardCopyBack(id);
   },
+  async copyAndPaste(id) {
+    await clipboardCopyAndPaste(id);
+  },
   async copyFilePathsBack(id) {
     await clipboardCopyFilePaths(id);
   },
@@ -202,4 +226,15 @@ export const useClipboardStore = create<ClipboardStore>((set, get) => ({
       reason: "manual_upsert",
     });
   },
+  async fetchWatcherStatus() {
+    const status = await clipboardWatcherStatus();
+    set({ watcherStatus: status });
+  },
+  async setCapturePaused(paused, durationMinutes) {
+    const status = await clipboardSetPaused(paused, durationMinutes);
+    set({ watcherStatus: status });
+  },
+  applyWatcherStatus(status) {
+    set({ watcherStatus: status });
+  },
 }));
@@ -120,7 +120,7 @@ export const useLauncherStore = create<LauncherStore>((set, get) => ({
       return null;
     }
     try {
-      const result = (await launcherExecute(selected.action as LauncherAction)) as PaletteActionResult;
+      const result = (await launcherExecute(selected.action as LauncherAction, selected.id)) as PaletteActionResult;
 
       set({
         lastAction: result,
@@ -21,6 +21,7 @@ interface LogFilters {
   scope: string;
   requestId: string;
   windowLabel: string;
+  errorContextKey: string;
   keyword: string;
   startAt: number | null;
   endAt: number | null;
@@ -62,6 +63,7 @@ const DEFAULT_FILTERS: LogFilters = {
   scope: "",
   requestId: "",
   windowLabel: "",
+  errorContextKey: "",
   keyword: "",
   startAt: null,
   endAt: null,
@@ -75,6 +77,7 @@ function buildQuery(filters: LogFilters, cursor?: string) {
     scope: filters.scope.trim() || undefined,
     requestId: filters.requestId.trim() || undefined,
     windowLabel: filters.windowLabel.trim() || undefined,
+    errorContextKey: filters.errorContextKey.trim() || undefined,
     keyword: filters.keyword.trim() || undefined,
     startAt: filters.startAt ?? undefined,
     endAt: filters.endAt ?? undefined,
@@ -98,6 +101,11 @@ function entryMatchesFilters(entry: LogEntry, filters: LogFilters): boolean {
     return false;
   }
 
+  const errorContextKey = filters.errorContextKey.trim();
+  if (errorContextKey && !(entry.errorContextKeys ?? []).includes(errorContextKey)) {
+    return false;
+  }
+
   if (filters.startAt !== null && entry.timestamp < filters.startAt) {
     return false;
   }
@@ -263,9 +271,9 @@ export const useLoggingStore = create<LoggingStore>((set, get) => ({
     set({ exporting: true, error: null });
     try {
       const query = buildQuery(get().filters);
-      const exportedPath = await exportLogs(query, outputPath);
-      set({ exporting: false, lastExportPath: exportedPath });
-      return exportedPath;
+      const result = await exportLogs(query, outputPath);
+      set({ exporting: false, lastExportPath: result.path });
+      return result.path;
     } catch (error) {
       const message = error instanceof Error ? error.message : String(error);
       set({ exporting: false, error: message });
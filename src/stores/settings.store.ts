@@ -1,5 +1,6 @@
 import { create } from "zustand";
 
+import type { ClipboardPreviewFailurePolicy, ClipboardWindowContentFilter } from "@/contracts";
 import {
   getFreshStartupSettings,
   getPendingStartupSettingsRequest,
@@ -10,12 +11,36 @@ interface ClipboardSettings {
   maxItems: number;
   sizeCleanupEnabled: boolean;
   maxTotalSizeMb: number;
+  pasteAfterCopy: boolean;
+  captureText: boolean;
+  captureImages: boolean;
+  captureFiles: boolean;
+  perceptualDeduplicate: boolean;
+  imagePreviewMaxWidth: number | null;
+  imagePreviewMaxHeight: number | null;
+  maxPreviewPixels: number | null;
+  previewFailurePolicy: ClipboardPreviewFailurePolicy;
+  compactContentFilter: ClipboardWindowContentFilter;
+  regularContentFilter: ClipboardWindowContentFilter;
+  maxPinnedItems: number;
 }
 
 interface ClipboardSettingsUpdateInput {
   maxItems: number;
   sizeCleanupEnabled?: boolean;
   maxTotalSizeMb?: number;
+  pasteAfterCopy?: boolean;
+  captureText?: boolean;
+  captureImages?: boolean;
+  captureFiles?: boolean;
+  perceptualDeduplicate?: boolean;
+  imagePreviewMaxWidth?: number;
+  imagePreviewMaxHeight?: number;
+  maxPreviewPixels?: number;
+  previewFailurePolicy?: ClipboardPreviewFailurePolicy;
+  compactContentFilter?: ClipboardWindowContentFilter;
+  regularContentFilter?: ClipboardWindowContentFilter;
+  maxPinnedItems?: number;
 }
 
 interface SettingsState {
@@ -62,6 +87,18 @@ export const useSettingsStore = create<SettingsStore>((set) => ({
           maxItems: input.maxItems,
           sizeCleanupEnabled: input.sizeCleanupEnabled,
           maxTotalSizeMb: input.maxTotalSizeMb,
+          pasteAfterCopy: input.pasteAfterCopy,
+          captureText: input.captureText,
+          captureImages: input.captureImages,
+          captureFiles: input.captureFiles,
+          perceptualDeduplicate: input.perceptualDeduplicate,
+          imagePreviewMaxWidth: input.imagePreviewMaxWidth,
+          imagePreviewMaxHeight: input.imagePreviewMaxHeight,
+          maxPreviewPixels: input.maxPreviewPixels,
+          previewFailurePolicy: input.previewFailurePolicy,
+          compactContentFilter: input.compactContentFilter,
+          regularContentFilter: input.regularContentFilter,
+          maxPinnedItems: input.maxPinnedItems,
         },
       });
       set({ clipboardSettings: settings.clipboard, saving: false });